@@ -0,0 +1,1374 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+// Various collation-related algorithms and constants in this file are
+// adapted from ICU4C and, therefore, are subject to the ICU license as
+// described in LICENSE.
+
+//! This module holds the `TailoringBuilder` that turns parsed collation
+//! rules into tailoring `CollationData` on top of the root collation.
+//!
+//! Like ICU4C's `CollationBuilder`, the builder keeps a linked list of
+//! nodes for each root primary weight that the rules refer to. The list
+//! starts with the root primary itself and holds the root secondary and
+//! tertiary weights that resets refer to as well as the tailored items
+//! in collation order. Once all rules have been processed, weights are
+//! allocated for the tailored nodes between the neighboring root weights,
+//! and the resulting collation elements are encoded into a `CodePointTrie`
+//! and the expansion and contraction tables.
+//!
+//! Unlike ICU4C, there is no canonical closure: the collation element
+//! iterator works on NFD, so mappings are keyed by their NFD form and
+//! a tailored string is stored as a contraction starting from its first
+//! character.
+
+use alloc::collections::BTreeMap;
+use alloc::vec;
+use alloc::vec::Vec;
+use icu_collections::codepointtrie;
+use icu_normalizer::provider::{DecompositionData, DecompositionTables};
+use icu_normalizer::Decomposition;
+use icu_properties::props::CanonicalCombiningClass;
+use zerovec::ule::AsULE;
+use zerovec::{ZeroSlice, ZeroVec};
+
+use crate::elements::{
+    ccc_from_trie_value, CollationElement32, CollationElements, Tag, CONTRACT_HAS_STARTER,
+    CONTRACT_NEXT_CCC, CONTRACT_TRAILING_CCC, FALLBACK_CE32, JAMO_COUNT, NO_CE,
+    SPECIAL_CE32_LOW_BYTE, TERTIARY_MASK,
+};
+use crate::provider::{CollationData, CollationSpecialPrimariesValidated};
+use crate::rules::{CollationRulesError, Level, RuleItem};
+
+/// The secondary and tertiary weight of a primary collation element
+/// without diacritics or case/variant distinctions.
+const COMMON_WEIGHT16: u16 = 0x0500;
+
+/// The maximum number of collation elements that an expansion can hold.
+const MAX_EXPANSION_LENGTH: usize = 31;
+
+/// The clear-case-bits mask for 64-bit collation elements.
+const CLEAR_CASE_MASK: u64 = 0xFFFF_FFFF_FFFF_3FFF;
+
+/// The first valid lead byte of a secondary weight above the common weight.
+///
+/// The bytes between the common weight and this one are used for
+/// compressing runs of common weights in sort keys.
+const MIN_SECONDARY_LEAD_BYTE: u8 = 0x46;
+
+/// The first valid lead byte of a tertiary weight above the common weight.
+const MIN_TERTIARY_LEAD_BYTE: u8 = 0x06;
+
+/// The largest tertiary byte; the two high bits hold the case bits.
+const MAX_TERTIARY_BYTE: u8 = 0x3F;
+
+/// Tertiary weights are limited by the case bits.
+const TERTIARY_LIMIT: u32 = 0x4000;
+
+/// Secondary weights are 16-bit.
+const SECONDARY_LIMIT: u32 = 0x10000;
+
+// `Char16Trie` node encoding constants. These have to stay in sync with
+// the reader in `icu_collections::char16trie`.
+const MAX_BRANCH_LINEAR_SUB_NODE_LENGTH: usize = 5;
+const MIN_LINEAR_MATCH: u16 = 0x30;
+const MAX_LINEAR_MATCH_LENGTH: usize = 0x10;
+const MIN_VALUE_LEAD: u16 = MIN_LINEAR_MATCH + MAX_LINEAR_MATCH_LENGTH as u16; // 0x40
+const NODE_TYPE_MASK: u16 = MIN_VALUE_LEAD - 1; // 0x003f
+const VALUE_IS_FINAL: u16 = 0x8000;
+const MAX_ONE_UNIT_VALUE: i32 = 0x3fff;
+const MIN_TWO_UNIT_VALUE_LEAD: u16 = 0x4000;
+const MAX_TWO_UNIT_VALUE: i32 = 0x3ffeffff;
+const THREE_UNIT_VALUE_LEAD: u16 = 0x7fff;
+const MAX_ONE_UNIT_NODE_VALUE: i32 = 0xff;
+const MIN_TWO_UNIT_NODE_VALUE_LEAD: u16 =
+    MIN_VALUE_LEAD + ((MAX_ONE_UNIT_NODE_VALUE as u16 + 1) << 6); // 0x4040
+const MAX_TWO_UNIT_NODE_VALUE: i32 = 0xfdffff;
+const THREE_UNIT_NODE_VALUE_LEAD: u16 = 0x7fc0;
+const MAX_ONE_UNIT_DELTA: usize = 0xfbff;
+const MIN_TWO_UNIT_DELTA_LEAD: u16 = 0xfc00;
+const MAX_TWO_UNIT_DELTA: usize = 0x3feffff;
+const THREE_UNIT_DELTA_LEAD: u16 = 0xffff;
+
+/// A `Char16Trie` key with its value.
+type TrieEntry = (Vec<u16>, i32);
+
+/// A collation element in a mapping: either a root collation element
+/// or the collation element of a node whose weights are assigned later.
+#[derive(Copy, Clone, Debug)]
+enum CeRef {
+    Root(u64),
+    Node(usize),
+}
+
+/// A root weight or a tailored item in one of the per-primary lists.
+#[derive(Copy, Clone, Debug)]
+struct Node {
+    /// Primary, secondary or tertiary. Never identical.
+    level: Level,
+    /// The 32-bit primary or the 16-bit secondary or tertiary weight.
+    /// Assigned only after all rules have been processed for tailored
+    /// nodes.
+    weight: u32,
+    tailored: bool,
+    next: Option<usize>,
+    /// The position of the rule that created a tailored node.
+    position: usize,
+    /// The collation element of this node, computed along with the weights.
+    ce: u64,
+}
+
+/// The collation elements of a tailored string.
+#[derive(Debug)]
+struct Mapping {
+    prefix: Vec<CeRef>,
+    node: usize,
+    extension: Vec<CeRef>,
+    position: usize,
+}
+
+/// The root weights that the weight allocation needs to stay clear of.
+struct RootWeights {
+    /// All primaries of the root collation, sorted.
+    primaries: Vec<u32>,
+    /// Primary, secondary and tertiary of the root collation elements
+    /// whose secondary or tertiary weight is not common, sorted.
+    secondaries_and_tertiaries: Vec<(u32, u16, u16)>,
+}
+
+impl RootWeights {
+    fn new(root: &CollationData, jamo: &[<u32 as AsULE>::ULE; JAMO_COUNT]) -> Self {
+        let mut weights = RootWeights {
+            primaries: Vec::new(),
+            secondaries_and_tertiaries: Vec::new(),
+        };
+        for range in root.trie.iter_ranges() {
+            let ce32 = CollationElement32::new(range.value);
+            match ce32.tag_checked() {
+                Some(Tag::Fallback) | Some(Tag::Implicit) => {}
+                Some(Tag::Offset) => {
+                    for c in range.range {
+                        if let Some(c) = char::from_u32(c) {
+                            weights.add_ce(root.ce_from_offset_ce32(c, ce32).bits());
+                        }
+                    }
+                }
+                _ => weights.add_ce32(root, ce32, 0),
+            }
+        }
+        for ce32 in jamo.iter() {
+            weights.add_ce32(root, CollationElement32::new(u32::from_unaligned(*ce32)), 0);
+        }
+        weights.primaries.sort_unstable();
+        weights.primaries.dedup();
+        weights.secondaries_and_tertiaries.sort_unstable();
+        weights.secondaries_and_tertiaries.dedup();
+        weights
+    }
+
+    fn add_ce(&mut self, ce: u64) {
+        let p = (ce >> 32) as u32;
+        if p == 0 {
+            return;
+        }
+        self.primaries.push(p);
+        let s = (ce >> 16) as u16;
+        let t = (ce as u16) & TERTIARY_MASK;
+        if s != COMMON_WEIGHT16 || t != COMMON_WEIGHT16 {
+            self.secondaries_and_tertiaries.push((p, s, t));
+        }
+    }
+
+    fn add_ce32(&mut self, root: &CollationData, ce32: CollationElement32, depth: u8) {
+        // GIGO guard against cyclic data
+        if depth > 2 {
+            return;
+        }
+        if let Some(ce) = ce32.to_ce_self_contained() {
+            self.add_ce(ce.bits());
+            return;
+        }
+        match ce32.tag_checked() {
+            Some(Tag::Expansion32) => {
+                for ce32 in root.get_ce32s(ce32.index(), ce32.len()).iter() {
+                    self.add_ce32(root, CollationElement32::new(ce32), depth + 1);
+                }
+            }
+            Some(Tag::Expansion) => {
+                for ce in root.get_ces(ce32.index(), ce32.len()).iter() {
+                    self.add_ce(ce);
+                }
+            }
+            Some(Tag::Prefix) | Some(Tag::Contraction) => {
+                let (default, trie) = root.get_default_and_trie_impl(ce32.index());
+                self.add_ce32(root, default, depth + 1);
+                for (_, value) in char16trie_entries(trie) {
+                    self.add_ce32(root, CollationElement32::new(value as u32), depth + 1);
+                }
+            }
+            Some(Tag::Digit) => {
+                self.add_ce32(root, root.get_ce32(ce32.index()), depth + 1);
+            }
+            _ => {}
+        }
+    }
+
+    /// The greatest root primary below `p`.
+    fn primary_before(&self, p: u32) -> Option<u32> {
+        let i = self.primaries.partition_point(|&q| q < p);
+        i.checked_sub(1)
+            .and_then(|i| self.primaries.get(i))
+            .copied()
+    }
+
+    /// The smallest root secondary above `s` for primary `p`.
+    fn secondary_after(&self, p: u32, s: u16) -> u32 {
+        let i = self
+            .secondaries_and_tertiaries
+            .partition_point(|&(ep, es, _)| (ep, es) <= (p, s));
+        match self.secondaries_and_tertiaries.get(i) {
+            Some(&(ep, es, _)) if ep == p => u32::from(es),
+            _ => SECONDARY_LIMIT,
+        }
+    }
+
+    /// The smallest root tertiary above `t` for primary `p` and secondary `s`.
+    fn tertiary_after(&self, p: u32, s: u16, t: u16) -> u32 {
+        let i = self
+            .secondaries_and_tertiaries
+            .partition_point(|&e| e <= (p, s, t));
+        match self.secondaries_and_tertiaries.get(i) {
+            Some(&(ep, es, et)) if ep == p && es == s => u32::from(et),
+            _ => TERTIARY_LIMIT,
+        }
+    }
+}
+
+/// Builds tailoring data from a list of resets and relations.
+pub(crate) struct TailoringBuilder<'a> {
+    root: &'a CollationData<'a>,
+    jamo: &'a [<u32 as AsULE>::ULE; JAMO_COUNT],
+    diacritics: &'a ZeroSlice<u16>,
+    decompositions: &'a DecompositionData<'a>,
+    tables: &'a DecompositionTables<'a>,
+    special_primaries: &'a CollationSpecialPrimariesValidated<'a>,
+    root_weights: Option<RootWeights>,
+    nodes: Vec<Node>,
+    /// The first node of the list for each root primary.
+    lists: BTreeMap<u32, usize>,
+    /// Tailored strings in NFD.
+    mappings: BTreeMap<Vec<char>, Mapping>,
+}
+
+impl<'a> TailoringBuilder<'a> {
+    pub(crate) fn new(
+        root: &'a CollationData<'a>,
+        jamo: &'a [<u32 as AsULE>::ULE; JAMO_COUNT],
+        diacritics: &'a ZeroSlice<u16>,
+        decompositions: &'a DecompositionData<'a>,
+        tables: &'a DecompositionTables<'a>,
+        special_primaries: &'a CollationSpecialPrimariesValidated<'a>,
+    ) -> Self {
+        TailoringBuilder {
+            root,
+            jamo,
+            diacritics,
+            decompositions,
+            tables,
+            special_primaries,
+            root_weights: None,
+            nodes: Vec::new(),
+            lists: BTreeMap::new(),
+            mappings: BTreeMap::new(),
+        }
+    }
+
+    pub(crate) fn build(
+        mut self,
+        items: &[RuleItem],
+    ) -> Result<CollationData<'static>, CollationRulesError> {
+        // The current insertion point and the collation elements that
+        // precede it in the reset string.
+        let mut current: Option<(usize, Vec<CeRef>)> = None;
+        let mut pending_before = None;
+        let mut last_position = 0;
+        for item in items {
+            match item {
+                RuleItem::Reset {
+                    string,
+                    before,
+                    position,
+                } => {
+                    let nfd = self.nfd(string);
+                    current = Some(self.reset_position(&nfd, *before, *position)?);
+                    pending_before = *before;
+                    last_position = *position;
+                }
+                RuleItem::Relation {
+                    level,
+                    string,
+                    extension,
+                    position,
+                } => {
+                    let Some((node, prefix)) = current.as_mut() else {
+                        return Err(CollationRulesError::Syntax(*position));
+                    };
+                    if let Some(before) = pending_before.take() {
+                        if before != *level {
+                            return Err(CollationRulesError::Syntax(*position));
+                        }
+                    }
+                    let nfd = self.nfd(string);
+                    self.check_tailorable(&nfd, *position)?;
+                    if *level != Level::Identical {
+                        *node = self.insert_tailored_after(*node, *level, *position);
+                    }
+                    let extension = if extension.is_empty() {
+                        Vec::new()
+                    } else {
+                        let nfd = self.nfd(extension);
+                        self.ces_for(&nfd)
+                    };
+                    if prefix.len() + 1 + extension.len() > MAX_EXPANSION_LENGTH {
+                        return Err(CollationRulesError::Overflow(*position));
+                    }
+                    self.mappings.insert(
+                        nfd,
+                        Mapping {
+                            prefix: prefix.clone(),
+                            node: *node,
+                            extension,
+                            position: *position,
+                        },
+                    );
+                    last_position = *position;
+                }
+            }
+        }
+        self.assign_weights()?;
+        self.encode(last_position)
+    }
+
+    fn nfd(&self, s: &str) -> Vec<char> {
+        Decomposition::new(s.chars(), self.decompositions, self.tables).collect()
+    }
+
+    fn ccc(&self, c: char) -> CanonicalCombiningClass {
+        ccc_from_trie_value(self.decompositions.trie.get(c))
+    }
+
+    /// Computes the root collation elements for an NFD string,
+    /// omitting completely ignorable ones.
+    fn root_ces(&self, nfd: &[char]) -> Vec<u64> {
+        let mut iter = CollationElements::new(
+            nfd.iter().copied(),
+            self.root,
+            self.root,
+            self.jamo,
+            self.diacritics,
+            self.decompositions,
+            self.tables,
+            None,
+            false,
+        );
+        iter.init();
+        let mut ces = Vec::new();
+        loop {
+            let ce = iter.next();
+            if ce == NO_CE {
+                break;
+            }
+            if ce.bits() != 0 {
+                ces.push(ce.bits());
+            }
+        }
+        ces
+    }
+
+    /// Computes the collation elements for an NFD string, taking the
+    /// strings tailored so far into account.
+    fn ces_for(&self, nfd: &[char]) -> Vec<CeRef> {
+        let mut ces = Vec::new();
+        let mut start = 0;
+        let mut i = 0;
+        while i < nfd.len() {
+            // Greedy longest match of a tailored string
+            let longest = (i + 1..=nfd.len())
+                .rev()
+                .find_map(|end| Some(end).zip(nfd.get(i..end).and_then(|s| self.mappings.get(s))));
+            if let Some((end, mapping)) = longest {
+                if let Some(run) = nfd.get(start..i) {
+                    ces.extend(self.root_ces(run).into_iter().map(CeRef::Root));
+                }
+                ces.extend(mapping.prefix.iter().copied());
+                ces.push(CeRef::Node(mapping.node));
+                ces.extend(mapping.extension.iter().copied());
+                i = end;
+                start = end;
+            } else {
+                i += 1;
+            }
+        }
+        if let Some(run) = nfd.get(start..) {
+            ces.extend(self.root_ces(run).into_iter().map(CeRef::Root));
+        }
+        ces
+    }
+
+    fn root_weights(&mut self) -> &RootWeights {
+        let (root, jamo) = (self.root, self.jamo);
+        self.root_weights
+            .get_or_insert_with(|| RootWeights::new(root, jamo))
+    }
+
+    /// Finds the node that a reset points to and returns it together with
+    /// the collation elements of the reset string that precede it.
+    fn reset_position(
+        &mut self,
+        nfd: &[char],
+        before: Option<Level>,
+        position: usize,
+    ) -> Result<(usize, Vec<CeRef>), CollationRulesError> {
+        let mut ces = if let Some(mapping) = self.mappings.get(nfd) {
+            let mut ces = mapping.prefix.clone();
+            ces.push(CeRef::Node(mapping.node));
+            ces
+        } else {
+            self.ces_for(nfd)
+        };
+        let Some(last) = ces.pop() else {
+            // Resetting to a completely ignorable string
+            return Err(CollationRulesError::Unsupported(position));
+        };
+        let node = match (last, before) {
+            (CeRef::Node(node), None) => node,
+            (CeRef::Root(ce), None) => {
+                let p = (ce >> 32) as u32;
+                let s = (ce >> 16) as u16;
+                let t = (ce as u16) & TERTIARY_MASK;
+                if p == 0 || s < COMMON_WEIGHT16 || t < COMMON_WEIGHT16 {
+                    return Err(CollationRulesError::Unsupported(position));
+                }
+                let mut node = self.find_or_insert_list(p);
+                if s != COMMON_WEIGHT16 {
+                    node = self.find_or_insert_root_node(node, s, Level::Secondary);
+                }
+                if t != COMMON_WEIGHT16 {
+                    node = self.find_or_insert_root_node(node, t, Level::Tertiary);
+                }
+                node
+            }
+            (CeRef::Root(ce), Some(Level::Primary)) => {
+                let p = (ce >> 32) as u32;
+                if p == 0 {
+                    return Err(CollationRulesError::Unsupported(position));
+                }
+                let Some(previous) = self.root_weights().primary_before(p) else {
+                    return Err(CollationRulesError::Unsupported(position));
+                };
+                // Insert after everything that is already tailored to sort
+                // after the previous root primary.
+                let mut node = self.find_or_insert_list(previous);
+                while let Some(next) = self.nodes.get(node).and_then(|n| n.next) {
+                    node = next;
+                }
+                node
+            }
+            _ => return Err(CollationRulesError::Unsupported(position)),
+        };
+        Ok((node, ces))
+    }
+
+    fn push_node(&mut self, node: Node) -> usize {
+        self.nodes.push(node);
+        self.nodes.len() - 1
+    }
+
+    fn find_or_insert_list(&mut self, p: u32) -> usize {
+        if let Some(&head) = self.lists.get(&p) {
+            return head;
+        }
+        let head = self.push_node(Node {
+            level: Level::Primary,
+            weight: p,
+            tailored: false,
+            next: None,
+            position: 0,
+            ce: 0,
+        });
+        self.lists.insert(p, head);
+        head
+    }
+
+    /// Finds or inserts the node for a root secondary or tertiary weight
+    /// after the given node, which holds the stronger weights.
+    ///
+    /// Tailored nodes of the same level are skipped so that the root
+    /// weight sorts after the items that were tailored to sort after the
+    /// stronger weights.
+    fn find_or_insert_root_node(&mut self, mut index: usize, weight: u16, level: Level) -> usize {
+        let weight = u32::from(weight);
+        let mut next_index = self.nodes.get(index).and_then(|n| n.next);
+        while let Some(next) = next_index {
+            let Some(node) = self.nodes.get(next) else {
+                break;
+            };
+            if node.level < level {
+                break;
+            }
+            if node.level == level && !node.tailored {
+                if node.weight == weight {
+                    return next;
+                }
+                if node.weight > weight {
+                    break;
+                }
+            }
+            index = next;
+            next_index = node.next;
+        }
+        self.insert_node_after(
+            index,
+            Node {
+                level,
+                weight,
+                tailored: false,
+                next: None,
+                position: 0,
+                ce: 0,
+            },
+        )
+    }
+
+    /// Inserts a tailored node to sort after the given node at the given
+    /// level.
+    fn insert_tailored_after(&mut self, mut index: usize, level: Level, position: usize) -> usize {
+        // Skip the weaker nodes that belong to the given node.
+        while let Some(next) = self.nodes.get(index).and_then(|n| n.next) {
+            if self.nodes.get(next).is_some_and(|n| n.level <= level) {
+                break;
+            }
+            index = next;
+        }
+        self.insert_node_after(
+            index,
+            Node {
+                level,
+                weight: 0,
+                tailored: true,
+                next: None,
+                position,
+                ce: 0,
+            },
+        )
+    }
+
+    fn insert_node_after(&mut self, index: usize, mut node: Node) -> usize {
+        node.next = self.nodes.get(index).and_then(|n| n.next);
+        let new_index = self.push_node(node);
+        if let Some(previous) = self.nodes.get_mut(index) {
+            previous.next = Some(new_index);
+        }
+        new_index
+    }
+
+    /// The first character of a tailored string must be one that starts
+    /// a contraction in the collation element iterator.
+    fn check_tailorable(&self, nfd: &[char], position: usize) -> Result<(), CollationRulesError> {
+        let Some(&c) = nfd.first() else {
+            return Err(CollationRulesError::Syntax(position));
+        };
+        if ('\u{1100}'..='\u{11FF}').contains(&c)
+            || self.ccc(c) != CanonicalCombiningClass::NotReordered
+            || self.root.ce32_for_char(c).tag_checked() == Some(Tag::Prefix)
+        {
+            return Err(CollationRulesError::Unsupported(position));
+        }
+        Ok(())
+    }
+
+    /// Walks the lists and allocates weights for the tailored nodes
+    /// between their neighboring root weights.
+    fn assign_weights(&mut self) -> Result<(), CollationRulesError> {
+        let heads: Vec<usize> = self.lists.values().copied().collect();
+        for head in heads {
+            let (mut p, mut s, mut t) = (0, COMMON_WEIGHT16, COMMON_WEIGHT16);
+            let (mut p_root, mut s_root) = (true, true);
+            let mut next = Some(head);
+            while let Some(index) = next {
+                let Some(&node) = self.nodes.get(index) else {
+                    break;
+                };
+                if node.tailored && node.weight == 0 {
+                    let (run, stop) = self.collect_run(index, node.level);
+                    let (lower, limit, ranges) = match node.level {
+                        Level::Primary => {
+                            let lead = (p >> 24) as u8;
+                            let second = if self.special_primaries.is_compressible(lead) {
+                                (4, 0xFE)
+                            } else {
+                                (2, 0xFF)
+                            };
+                            (
+                                p,
+                                stop.map_or_else(|| primary_limit(p), u64::from),
+                                vec![(lead, lead), second, (2, 0xFF), (2, 0xFF)],
+                            )
+                        }
+                        Level::Secondary => {
+                            let default = if p_root {
+                                self.root_weights().secondary_after(p, s)
+                            } else {
+                                SECONDARY_LIMIT
+                            };
+                            (
+                                u32::from(s),
+                                u64::from(stop.unwrap_or(default)),
+                                vec![(MIN_SECONDARY_LEAD_BYTE, 0xFF), (2, 0xFF)],
+                            )
+                        }
+                        _ => {
+                            let default = if p_root && s_root {
+                                self.root_weights().tertiary_after(p, s, t)
+                            } else {
+                                TERTIARY_LIMIT
+                            };
+                            (
+                                u32::from(t),
+                                u64::from(stop.unwrap_or(default)),
+                                vec![
+                                    (MIN_TERTIARY_LEAD_BYTE, MAX_TERTIARY_BYTE),
+                                    (2, MAX_TERTIARY_BYTE),
+                                ],
+                            )
+                        }
+                    };
+                    let weights = allocate_weights(lower, limit, run.len(), &ranges)
+                        .ok_or(CollationRulesError::Overflow(node.position))?;
+                    for (&i, w) in run.iter().zip(weights) {
+                        if let Some(n) = self.nodes.get_mut(i) {
+                            n.weight = w;
+                        }
+                    }
+                }
+                let Some(node) = self.nodes.get_mut(index) else {
+                    break;
+                };
+                match node.level {
+                    Level::Primary => {
+                        p = node.weight;
+                        s = COMMON_WEIGHT16;
+                        t = COMMON_WEIGHT16;
+                        p_root = !node.tailored;
+                        s_root = true;
+                    }
+                    Level::Secondary => {
+                        s = node.weight as u16;
+                        t = COMMON_WEIGHT16;
+                        s_root = !node.tailored;
+                    }
+                    _ => {
+                        t = node.weight as u16;
+                    }
+                }
+                node.ce = (u64::from(p) << 32) | (u64::from(s) << 16) | u64::from(t);
+                next = node.next;
+            }
+        }
+        Ok(())
+    }
+
+    /// Collects the run of consecutive tailored nodes at the given level,
+    /// skipping weaker nodes, and returns it together with the weight of
+    /// the root node at the same level that ends the run, if any.
+    fn collect_run(&self, start: usize, level: Level) -> (Vec<usize>, Option<u32>) {
+        let mut run = Vec::new();
+        let mut next = Some(start);
+        while let Some(index) = next {
+            let Some(node) = self.nodes.get(index) else {
+                break;
+            };
+            if node.level < level {
+                break;
+            }
+            if node.level == level {
+                if !node.tailored {
+                    return (run, Some(node.weight));
+                }
+                run.push(index);
+            }
+            next = node.next;
+        }
+        (run, None)
+    }
+
+    fn resolve(&self, ce: CeRef) -> u64 {
+        match ce {
+            CeRef::Root(ce) => ce,
+            CeRef::Node(index) => self.nodes.get(index).map_or(0, |n| n.ce),
+        }
+    }
+
+    /// Sets the case bits of the tailored collation elements based on
+    /// the root collation elements of the tailored string.
+    ///
+    /// This is ICU4C's `CollationBuilder::setCaseBits()`.
+    fn set_case_bits(&self, nfd: &[char], ces: &mut [u64]) {
+        let num_tailored_primaries = ces.iter().filter(|&&ce| (ce >> 32) != 0).count();
+        let mut cases: u64 = 0;
+        if num_tailored_primaries > 0 {
+            let mut last_case = 0;
+            let mut num_base_primaries = 0;
+            for ce in self.root_ces(nfd) {
+                if (ce >> 32) == 0 {
+                    continue;
+                }
+                num_base_primaries += 1;
+                let case = (ce >> 14) & 3;
+                if num_base_primaries < num_tailored_primaries {
+                    cases |= case << ((num_base_primaries - 1) * 2);
+                } else if num_base_primaries == num_tailored_primaries {
+                    last_case = case;
+                } else if case != last_case {
+                    // More base primaries than tailored primaries with
+                    // differing case bits: mixed case.
+                    last_case = 1;
+                    break;
+                }
+            }
+            if num_base_primaries >= num_tailored_primaries {
+                cases |= last_case << ((num_tailored_primaries - 1) * 2);
+            }
+        }
+        for ce in ces.iter_mut() {
+            *ce &= CLEAR_CASE_MASK;
+            if (*ce >> 32) != 0 {
+                *ce |= (cases & 3) << 14;
+                cases >>= 2;
+            } else if (*ce & 0xFFFF_0000) == 0 && *ce != 0 {
+                // Tertiary collation elements must have uppercase bits.
+                *ce |= 0x8000;
+            }
+        }
+    }
+
+    /// Encodes the mappings into collation data.
+    fn encode(self, position: usize) -> Result<CollationData<'static>, CollationRulesError> {
+        let mut encoder = Encoder::default();
+        // Group the suffixes and collation elements of the mappings by their
+        // first character.
+        let mut by_char: BTreeMap<char, Vec<Suffix>> = BTreeMap::new();
+        for (nfd, mapping) in self.mappings.iter() {
+            let Some((&c, suffix)) = nfd.split_first() else {
+                continue;
+            };
+            let mut ces: Vec<u64> = mapping
+                .prefix
+                .iter()
+                .chain(core::iter::once(&CeRef::Node(mapping.node)))
+                .chain(mapping.extension.iter())
+                .map(|&ce| self.resolve(ce))
+                .collect();
+            self.set_case_bits(nfd, &mut ces);
+            by_char
+                .entry(c)
+                .or_default()
+                .push((suffix, ces, mapping.position));
+        }
+
+        let mut values = BTreeMap::new();
+        for (c, entries) in by_char {
+            let root_ce32 = self.root.ce32_for_char(c);
+            let root_contraction = root_ce32.tag_checked() == Some(Tag::Contraction);
+            let default = entries.iter().find(|(suffix, _, _)| suffix.is_empty());
+            let ce32 = match default {
+                Some((_, ces, position)) if entries.len() == 1 && !root_contraction => {
+                    encoder.encode_ces(ces, *position)?
+                }
+                _ => {
+                    let default_ce32 = match default {
+                        Some((_, ces, position)) => encoder.encode_ces(ces, *position)?,
+                        None => encoder.encode_ces(&self.root_ces(&[c]), position)?,
+                    };
+                    let mut suffixes: BTreeMap<Vec<u16>, u32> = BTreeMap::new();
+                    for (suffix, ces, position) in entries.iter() {
+                        if !suffix.is_empty() {
+                            let units = suffix
+                                .iter()
+                                .flat_map(|c| c.encode_utf16(&mut [0; 2]).to_vec())
+                                .collect();
+                            suffixes.insert(units, encoder.encode_ces(ces, *position)?);
+                        }
+                    }
+                    if root_contraction {
+                        // Keep the root contractions that the rules don't
+                        // override.
+                        let (_, trie) = self.root.get_default_and_trie_impl(root_ce32.index());
+                        for (units, _) in char16trie_entries(trie) {
+                            if suffixes.contains_key(&units) {
+                                continue;
+                            }
+                            let mut nfd = vec![c];
+                            nfd.extend(
+                                char::decode_utf16(units.iter().copied())
+                                    .map(|r| r.unwrap_or(char::REPLACEMENT_CHARACTER)),
+                            );
+                            let ce32 = encoder.encode_ces(&self.root_ces(&nfd), position)?;
+                            suffixes.insert(units, ce32);
+                        }
+                    }
+                    let mut flags = CONTRACT_NEXT_CCC;
+                    for units in suffixes.keys() {
+                        let cccs: Vec<CanonicalCombiningClass> =
+                            char::decode_utf16(units.iter().copied())
+                                .map(|r| self.ccc(r.unwrap_or(char::REPLACEMENT_CHARACTER)))
+                                .collect();
+                        if cccs.first() == Some(&CanonicalCombiningClass::NotReordered) {
+                            flags &= !CONTRACT_NEXT_CCC;
+                        }
+                        if cccs
+                            .last()
+                            .is_some_and(|&ccc| ccc != CanonicalCombiningClass::NotReordered)
+                        {
+                            flags |= CONTRACT_TRAILING_CCC;
+                        }
+                        if cccs.contains(&CanonicalCombiningClass::NotReordered) {
+                            flags |= CONTRACT_HAS_STARTER;
+                        }
+                    }
+                    let entries: Vec<TrieEntry> = suffixes
+                        .into_iter()
+                        .map(|(units, ce32)| (units, ce32 as i32))
+                        .collect();
+                    let index = encoder.contexts.len();
+                    if index >= 1 << 19 {
+                        return Err(CollationRulesError::Overflow(position));
+                    }
+                    encoder.contexts.push((default_ce32 >> 16) as u16);
+                    encoder.contexts.push(default_ce32 as u16);
+                    encoder.contexts.extend(write_char16trie(&entries));
+                    ((index as u32) << 13)
+                        | flags
+                        | u32::from(SPECIAL_CE32_LOW_BYTE)
+                        | Tag::Contraction as u32
+                }
+            };
+            values.insert(u32::from(c), ce32);
+        }
+
+        let trie = codepointtrie::builder::build_fast(&values, FALLBACK_CE32.bits())
+            .ok_or(CollationRulesError::Overflow(position))?;
+        Ok(CollationData {
+            trie,
+            ces: ZeroVec::alloc_from_slice(&encoder.ces),
+            ce32s: ZeroVec::alloc_from_slice(&encoder.ce32s),
+            contexts: ZeroVec::alloc_from_slice(&encoder.contexts),
+        })
+    }
+}
+
+/// The limit for tailored weights after a root primary: the primary with
+/// its last non-zero byte incremented.
+fn primary_limit(p: u32) -> u64 {
+    u64::from(p) + (1u64 << ((p.trailing_zeros().min(24) / 8) * 8))
+}
+
+/// Allocates `count` weights in ascending order that are greater than
+/// `lower` and less than `limit`, using as few bytes as possible.
+///
+/// `ranges` holds the valid byte range for each byte of the weight,
+/// most significant first.
+fn allocate_weights(lower: u32, limit: u64, count: usize, ranges: &[(u8, u8)]) -> Option<Vec<u32>> {
+    let slots = ranges.len();
+    let lower_bytes: Vec<u8> = (0..slots)
+        .map(|i| (lower >> (8 * (slots - 1 - i))) as u8)
+        .collect();
+    'lengths: for length in 1..=slots {
+        let mut bytes = lower_bytes.clone();
+        for b in bytes.iter_mut().skip(length) {
+            *b = 0;
+        }
+        let mut weights = Vec::with_capacity(count);
+        for _ in 0..count {
+            if !increment_weight(&mut bytes, ranges, length) {
+                continue 'lengths;
+            }
+            let weight = bytes.iter().fold(0u32, |acc, &b| (acc << 8) | u32::from(b));
+            if weight <= lower || u64::from(weight) >= limit {
+                continue 'lengths;
+            }
+            weights.push(weight);
+        }
+        return Some(weights);
+    }
+    None
+}
+
+/// Advances `bytes` to the next weight of the given length whose bytes
+/// all lie within their ranges.
+fn increment_weight(bytes: &mut [u8], ranges: &[(u8, u8)], length: usize) -> bool {
+    let mut i = length - 1;
+    loop {
+        let (Some(b), Some(&(min, max))) = (bytes.get_mut(i), ranges.get(i)) else {
+            return false;
+        };
+        if *b < min {
+            *b = min;
+            break;
+        }
+        if *b < max {
+            *b += 1;
+            break;
+        }
+        *b = min;
+        if i == 0 {
+            return false;
+        }
+        i -= 1;
+    }
+    // The more significant bytes may still be out of range when they
+    // come from the lower bound.
+    for j in 0..i {
+        let (Some(&b), Some(&(min, max))) = (bytes.get(j), ranges.get(j)) else {
+            return false;
+        };
+        if b > max {
+            return false;
+        }
+        if b < min {
+            for (k, b) in bytes.iter_mut().enumerate().take(length).skip(j) {
+                *b = ranges.get(k).map_or(0, |r| r.0);
+            }
+            break;
+        }
+    }
+    true
+}
+
+/// A contraction suffix with its collation elements and rule position.
+type Suffix<'m> = (&'m [char], Vec<u64>, usize);
+
+/// Accumulates the expansion and contraction tables.
+#[derive(Default)]
+struct Encoder {
+    ces: Vec<u64>,
+    ce32s: Vec<u32>,
+    contexts: Vec<u16>,
+}
+
+impl Encoder {
+    /// Encodes a sequence of collation elements as a `CollationElement32`,
+    /// adding to the expansion tables if needed.
+    fn encode_ces(&mut self, ces: &[u64], position: usize) -> Result<u32, CollationRulesError> {
+        if let [ce] = ces {
+            if let Some(ce32) = ce32_for_ce(*ce) {
+                return Ok(ce32);
+            }
+        }
+        if ces.is_empty() || ces.len() > MAX_EXPANSION_LENGTH {
+            return Err(CollationRulesError::Overflow(position));
+        }
+        let (index, tag) = if let Some(ce32s) = ces
+            .iter()
+            .map(|&ce| ce32_for_ce(ce))
+            .collect::<Option<Vec<u32>>>()
+        {
+            let index = self.ce32s.len();
+            self.ce32s.extend(ce32s);
+            (index, Tag::Expansion32)
+        } else {
+            let index = self.ces.len();
+            self.ces.extend_from_slice(ces);
+            (index, Tag::Expansion)
+        };
+        if index >= 1 << 19 {
+            return Err(CollationRulesError::Overflow(position));
+        }
+        Ok(((index as u32) << 13)
+            | ((ces.len() as u32) << 8)
+            | u32::from(SPECIAL_CE32_LOW_BYTE)
+            | tag as u32)
+    }
+}
+
+/// Returns the self-contained `CollationElement32` form of a collation
+/// element if there is one.
+fn ce32_for_ce(ce: u64) -> Option<u32> {
+    let p = (ce >> 32) as u32;
+    let lower32 = ce as u32;
+    if ce & 0x0000_FFFF_00FF_00FF == 0 {
+        // Simple
+        Some(p | ((lower32 >> 16) & 0xFF00) | ((lower32 >> 8) & 0xFF))
+    } else if lower32 == 0x0500_0500 && (p & 0xFF) == 0 {
+        Some(p | u32::from(SPECIAL_CE32_LOW_BYTE) | Tag::LongPrimary as u32)
+    } else if p == 0 && (lower32 & 0xFF) == 0 {
+        Some(lower32 | u32::from(SPECIAL_CE32_LOW_BYTE) | Tag::LongSecondary as u32)
+    } else {
+        None
+    }
+}
+
+/// Serializes sorted, unique, non-empty keys and their values into the
+/// `Char16Trie` format.
+fn write_char16trie(entries: &[TrieEntry]) -> Vec<u16> {
+    write_trie_node(entries, 0)
+}
+
+fn write_trie_node(entries: &[TrieEntry], depth: usize) -> Vec<u16> {
+    let (value, rest) = match entries.split_first() {
+        Some((first, rest)) if first.0.len() == depth => (Some(first.1), rest),
+        _ => (None, entries),
+    };
+    let (Some(first), Some(last)) = (rest.first(), rest.last()) else {
+        return write_value(value.unwrap_or(0), true);
+    };
+    // Since the keys are sorted, the common prefix of the first and the
+    // last key is shared by all of them, and no key ends within it.
+    let common = first
+        .0
+        .iter()
+        .skip(depth)
+        .zip(last.0.iter().skip(depth))
+        .take_while(|(a, b)| a == b)
+        .count();
+    let (node_type, body) = if common > 0 {
+        let length = common.min(MAX_LINEAR_MATCH_LENGTH);
+        let mut body: Vec<u16> = first.0.iter().skip(depth).take(length).copied().collect();
+        body.extend(write_trie_node(rest, depth + length));
+        (MIN_LINEAR_MATCH + length as u16 - 1, body)
+    } else {
+        let mut groups: Vec<(u16, &[TrieEntry])> = Vec::new();
+        let mut start = 0;
+        while let Some((key, _)) = rest.get(start) {
+            let unit = key.get(depth).copied().unwrap_or(0);
+            let end = start
+                + rest
+                    .iter()
+                    .skip(start)
+                    .take_while(|(k, _)| k.get(depth) == Some(&unit))
+                    .count()
+                    .max(1);
+            groups.push((unit, rest.get(start..end).unwrap_or_default()));
+            start = end;
+        }
+        let count = groups.len();
+        let mut body = Vec::new();
+        let node_type = if count - 1 < usize::from(MIN_LINEAR_MATCH) {
+            (count - 1) as u16
+        } else {
+            body.push((count - 1) as u16);
+            0
+        };
+        body.extend(write_branch(&groups, depth));
+        (node_type, body)
+    };
+    let mut out = match value {
+        Some(v) => write_node_value(v, node_type),
+        None => vec![node_type],
+    };
+    out.extend(body);
+    out
+}
+
+fn write_branch(groups: &[(u16, &[TrieEntry])], depth: usize) -> Vec<u16> {
+    let count = groups.len();
+    if count > MAX_BRANCH_LINEAR_SUB_NODE_LENGTH {
+        // Binary split: units less than the split unit are in the lower
+        // half, which follows the upper half.
+        let half = count >> 1;
+        let lower = write_branch(groups.get(..half).unwrap_or_default(), depth);
+        let upper = write_branch(groups.get(half..).unwrap_or_default(), depth);
+        let mut out = vec![groups.get(half).map_or(0, |g| g.0)];
+        out.extend(write_delta(upper.len()));
+        out.extend(upper);
+        out.extend(lower);
+        return out;
+    }
+    let Some(((last_unit, last_group), init)) = groups.split_last() else {
+        return Vec::new();
+    };
+    let is_final = |group: &[TrieEntry]| {
+        group.len() == 1 && group.first().is_some_and(|(k, _)| k.len() == depth + 1)
+    };
+    let subs: Vec<Vec<u16>> = init
+        .iter()
+        .map(|(_, group)| write_trie_node(group, depth + 1))
+        .collect();
+    let last_sub = write_trie_node(last_group, depth + 1);
+    // The non-final sub-nodes follow the last sub-node in order, so the
+    // jump deltas are computed from the last entry to the first.
+    let mut heads: Vec<Vec<u16>> = Vec::with_capacity(init.len());
+    let mut heads_after = 0;
+    for (i, (unit, group)) in init.iter().enumerate().rev() {
+        let mut head = vec![*unit];
+        if is_final(group) {
+            head.extend(subs.get(i).into_iter().flatten());
+        } else {
+            let subs_before: usize = init
+                .iter()
+                .zip(subs.iter())
+                .take(i)
+                .filter(|((_, g), _)| !is_final(g))
+                .map(|(_, sub)| sub.len())
+                .sum();
+            let delta = heads_after + 1 + last_sub.len() + subs_before;
+            head.extend(write_value(delta as i32, false));
+        }
+        heads_after += head.len();
+        heads.push(head);
+    }
+    let mut out: Vec<u16> = heads.into_iter().rev().flatten().collect();
+    out.push(*last_unit);
+    out.extend(last_sub);
+    for ((_, group), sub) in init.iter().zip(subs) {
+        if !is_final(group) {
+            out.extend(sub);
+        }
+    }
+    out
+}
+
+fn write_value(v: i32, is_final: bool) -> Vec<u16> {
+    let final_bit = if is_final { VALUE_IS_FINAL } else { 0 };
+    if (0..=MAX_ONE_UNIT_VALUE).contains(&v) {
+        vec![v as u16 | final_bit]
+    } else if (0..=MAX_TWO_UNIT_VALUE).contains(&v) {
+        vec![
+            (MIN_TWO_UNIT_VALUE_LEAD + (v >> 16) as u16) | final_bit,
+            v as u16,
+        ]
+    } else {
+        vec![
+            THREE_UNIT_VALUE_LEAD | final_bit,
+            (v >> 16) as u16,
+            v as u16,
+        ]
+    }
+}
+
+fn write_node_value(v: i32, node_type: u16) -> Vec<u16> {
+    if (0..=MAX_ONE_UNIT_NODE_VALUE).contains(&v) {
+        vec![((v as u16 + 1) << 6) | node_type]
+    } else if (0..=MAX_TWO_UNIT_NODE_VALUE).contains(&v) {
+        vec![
+            (MIN_TWO_UNIT_NODE_VALUE_LEAD + ((v >> 10) as u16 & 0x7fc0)) | node_type,
+            v as u16,
+        ]
+    } else {
+        vec![
+            THREE_UNIT_NODE_VALUE_LEAD | node_type,
+            (v >> 16) as u16,
+            v as u16,
+        ]
+    }
+}
+
+fn write_delta(delta: usize) -> Vec<u16> {
+    if delta <= MAX_ONE_UNIT_DELTA {
+        vec![delta as u16]
+    } else if delta <= MAX_TWO_UNIT_DELTA {
+        vec![MIN_TWO_UNIT_DELTA_LEAD + (delta >> 16) as u16, delta as u16]
+    } else {
+        vec![THREE_UNIT_DELTA_LEAD, (delta >> 16) as u16, delta as u16]
+    }
+}
+
+/// Lists the keys and values of a serialized `Char16Trie`.
+fn char16trie_entries(trie: &ZeroSlice<u16>) -> Vec<TrieEntry> {
+    let mut entries = Vec::new();
+    enumerate_trie_node(trie, 0, &mut Vec::new(), &mut entries);
+    entries
+}
+
+fn trie_unit(trie: &ZeroSlice<u16>, pos: usize) -> u16 {
+    trie.get(pos).unwrap_or(0)
+}
+
+fn read_value(trie: &ZeroSlice<u16>, pos: usize, lead: u16) -> i32 {
+    if lead < MIN_TWO_UNIT_VALUE_LEAD {
+        i32::from(lead)
+    } else if lead < THREE_UNIT_VALUE_LEAD {
+        (i32::from(lead - MIN_TWO_UNIT_VALUE_LEAD) << 16) | i32::from(trie_unit(trie, pos))
+    } else {
+        ((u32::from(trie_unit(trie, pos)) << 16) | u32::from(trie_unit(trie, pos + 1))) as i32
+    }
+}
+
+fn skip_value(pos: usize, lead: u16) -> usize {
+    if lead < MIN_TWO_UNIT_VALUE_LEAD {
+        pos
+    } else if lead < THREE_UNIT_VALUE_LEAD {
+        pos + 1
+    } else {
+        pos + 2
+    }
+}
+
+fn read_node_value(trie: &ZeroSlice<u16>, pos: usize, lead: u16) -> i32 {
+    if lead < MIN_TWO_UNIT_NODE_VALUE_LEAD {
+        i32::from((lead >> 6) - 1)
+    } else if lead < THREE_UNIT_NODE_VALUE_LEAD {
+        ((i32::from(lead & 0x7fc0) - i32::from(MIN_TWO_UNIT_NODE_VALUE_LEAD)) << 10)
+            | i32::from(trie_unit(trie, pos))
+    } else {
+        ((u32::from(trie_unit(trie, pos)) << 16) | u32::from(trie_unit(trie, pos + 1))) as i32
+    }
+}
+
+fn skip_node_value(pos: usize, lead: u16) -> usize {
+    if lead < MIN_TWO_UNIT_NODE_VALUE_LEAD {
+        pos
+    } else if lead < THREE_UNIT_NODE_VALUE_LEAD {
+        pos + 1
+    } else {
+        pos + 2
+    }
+}
+
+fn read_delta(trie: &ZeroSlice<u16>, pos: usize) -> (usize, usize) {
+    let lead = trie_unit(trie, pos);
+    if lead < MIN_TWO_UNIT_DELTA_LEAD {
+        (usize::from(lead), pos + 1)
+    } else if lead < THREE_UNIT_DELTA_LEAD {
+        (
+            (usize::from(lead - MIN_TWO_UNIT_DELTA_LEAD) << 16)
+                | usize::from(trie_unit(trie, pos + 1)),
+            pos + 2,
+        )
+    } else {
+        (
+            (usize::from(trie_unit(trie, pos + 1)) << 16) | usize::from(trie_unit(trie, pos + 2)),
+            pos + 3,
+        )
+    }
+}
+
+fn enumerate_trie_node(
+    trie: &ZeroSlice<u16>,
+    mut pos: usize,
+    prefix: &mut Vec<u16>,
+    entries: &mut Vec<TrieEntry>,
+) {
+    // GIGO guard against malformed data
+    if prefix.len() > 64 || pos >= trie.len() {
+        return;
+    }
+    let mut node = trie_unit(trie, pos);
+    pos += 1;
+    if node >= MIN_VALUE_LEAD {
+        if node & VALUE_IS_FINAL != 0 {
+            entries.push((
+                prefix.clone(),
+                read_value(trie, pos, node & !VALUE_IS_FINAL),
+            ));
+            return;
+        }
+        entries.push((prefix.clone(), read_node_value(trie, pos, node)));
+        pos = skip_node_value(pos, node);
+        node &= NODE_TYPE_MASK;
+    }
+    if node < MIN_LINEAR_MATCH {
+        let mut length = usize::from(node);
+        if length == 0 {
+            length = usize::from(trie_unit(trie, pos));
+            pos += 1;
+        }
+        enumerate_branch(trie, pos, length + 1, prefix, entries);
+    } else {
+        let length = usize::from(node - MIN_LINEAR_MATCH) + 1;
+        let old_len = prefix.len();
+        prefix.extend((pos..pos + length).map(|i| trie_unit(trie, i)));
+        enumerate_trie_node(trie, pos + length, prefix, entries);
+        prefix.truncate(old_len);
+    }
+}
+
+fn enumerate_branch(
+    trie: &ZeroSlice<u16>,
+    mut pos: usize,
+    mut length: usize,
+    prefix: &mut Vec<u16>,
+    entries: &mut Vec<TrieEntry>,
+) {
+    while length > MAX_BRANCH_LINEAR_SUB_NODE_LENGTH {
+        let (delta, upper) = read_delta(trie, pos + 1);
+        enumerate_branch(trie, upper + delta, length >> 1, prefix, entries);
+        length -= length >> 1;
+        pos = upper;
+    }
+    for _ in 0..length - 1 {
+        prefix.push(trie_unit(trie, pos));
+        let node = trie_unit(trie, pos + 1);
+        let value_lead = node & !VALUE_IS_FINAL;
+        if node & VALUE_IS_FINAL != 0 {
+            entries.push((prefix.clone(), read_value(trie, pos + 2, value_lead)));
+        } else {
+            let delta = read_value(trie, pos + 2, value_lead) as usize;
+            enumerate_trie_node(
+                trie,
+                skip_value(pos + 2, value_lead) + delta,
+                prefix,
+                entries,
+            );
+        }
+        prefix.pop();
+        pos = skip_value(pos + 2, value_lead);
+    }
+    prefix.push(trie_unit(trie, pos));
+    enumerate_trie_node(trie, pos + 1, prefix, entries);
+    prefix.pop();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use icu_collections::char16trie::{Char16Trie, TrieResult};
+
+    #[test]
+    fn char16trie_round_trip() {
+        let mut entries: Vec<TrieEntry> = Vec::new();
+        for (i, key) in [
+            "a",
+            "ab",
+            "abcdefghijklmnopqrstuvwxyz",
+            "b",
+            "c",
+            "ca",
+            "d",
+            "e",
+            "f",
+            "g",
+            "h",
+            "\u{300}",
+            "\u{301}\u{302}",
+        ]
+        .iter()
+        .enumerate()
+        {
+            let value = match i % 4 {
+                0 => i as i32,
+                1 => 0x1234_5678,
+                2 => -1,
+                _ => 0x3fff + i as i32,
+            };
+            entries.push((key.encode_utf16().collect(), value));
+        }
+        entries.sort();
+        let units = write_char16trie(&entries);
+        let trie = Char16Trie::new(ZeroVec::alloc_from_slice(&units));
+        for (key, value) in entries.iter() {
+            let mut iter = trie.iter();
+            let mut result = TrieResult::NoMatch;
+            for &u in key {
+                result = iter.next16(u);
+            }
+            match result {
+                TrieResult::FinalValue(v) | TrieResult::Intermediate(v) => assert_eq!(v, *value),
+                _ => panic!("no value for {key:?}"),
+            }
+        }
+        let zv = ZeroVec::alloc_from_slice(&units);
+        assert_eq!(char16trie_entries(&zv), entries);
+    }
+}
@@ -12,10 +12,13 @@
 use alloc::collections::VecDeque;
 use alloc::vec::Vec;
 
+use crate::builder::TailoringBuilder;
 use crate::elements::CharacterAndClassAndTrieValue;
 use crate::elements::CollationElement32;
 use crate::elements::Tag;
 use crate::elements::BACKWARD_COMBINING_MARKER;
+#[cfg(feature = "search")]
+use crate::elements::CASE_MASK;
 use crate::elements::CE_BUFFER_SIZE;
 use crate::elements::FALLBACK_CE32;
 use crate::elements::NON_ROUND_TRIP_MARKER;
@@ -42,6 +45,7 @@ use crate::provider::CollationRootV1;
 use crate::provider::CollationSpecialPrimariesV1;
 use crate::provider::CollationSpecialPrimariesValidated;
 use crate::provider::CollationTailoringV1;
+use crate::rules::CollationRulesError;
 use core::array;
 use core::cmp::Ordering;
 use core::convert::{Infallible, TryFrom};
@@ -657,40 +661,7 @@ impl Collator {
             return Err(DataError::custom("invalid").with_marker(CollationJamoV1::INFO));
         }
 
-        // `variant_count` isn't stable yet:
-        // https://github.com/rust-lang/rust/issues/73662
-        if special_primaries.get().last_primaries.len() <= (MaxVariable::Currency as usize) {
-            return Err(DataError::custom("invalid").with_marker(CollationSpecialPrimariesV1::INFO));
-        }
-        let special_primaries = special_primaries.map_project(|csp, _| {
-            if csp.last_primaries.len()
-                == (MaxVariable::Currency as usize)
-                    + core::mem::size_of_val(
-                        &CollationSpecialPrimariesValidated::HARDCODED_FALLBACK.compressible_bytes,
-                    ) / core::mem::size_of::<u16>()
-            {
-                CollationSpecialPrimariesValidated {
-                    compressible_bytes: array::from_fn(|i| {
-                        #[expect(clippy::unwrap_used)] // protected by the if
-                        {
-                            csp.last_primaries
-                                .get((MaxVariable::Currency as usize) + i)
-                                .unwrap()
-                        }
-                    }),
-                    last_primaries: csp.last_primaries.truncated(MaxVariable::Currency as usize),
-                    numeric_primary: csp.numeric_primary,
-                }
-            } else {
-                // Data without compressible bytes, add hardcoded data
-                CollationSpecialPrimariesValidated {
-                    last_primaries: csp.last_primaries,
-                    compressible_bytes: CollationSpecialPrimariesValidated::HARDCODED_FALLBACK
-                        .compressible_bytes,
-                    numeric_primary: csp.numeric_primary,
-                }
-            }
-        });
+        let special_primaries = validate_special_primaries(special_primaries)?;
 
         Ok(Collator {
             special_primaries,
@@ -705,6 +676,198 @@ impl Collator {
             lithuanian_dot_above: locale_dependent.lithuanian_dot_above,
         })
     }
+
+    /// Creates a collator from collation rules in the ICU syntax, such as
+    /// `&a < b <<< B`, applied on top of the root collation.
+    ///
+    /// Settings in the rules, such as `[strength 2]`, become the defaults
+    /// for the options that are not set in `options`.
+    ///
+    /// Context prefixes (`|`), special reset positions such as
+    /// `[first regular]`, `[before 2]` and `[before 3]`, `[import]`,
+    /// `[reorder]`, and tailoring strings that start with a combining
+    /// character or a conjoining jamo are not supported and result in
+    /// [`CollationRulesError::Unsupported`].
+    ///
+    /// ✨ *Enabled with the `compiled_data` Cargo feature.*
+    ///
+    /// [📚 Help choosing a constructor](icu_provider::constructors)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use core::cmp::Ordering;
+    /// use icu::collator::{options::CollatorOptions, Collator};
+    ///
+    /// let collator =
+    ///     Collator::try_new_from_rules("&a < b <<< B", CollatorOptions::default())
+    ///         .unwrap();
+    /// let collator = collator.as_borrowed();
+    ///
+    /// assert_eq!(collator.compare("a", "b"), Ordering::Less);
+    /// assert_eq!(collator.compare("b", "B"), Ordering::Less);
+    /// assert_eq!(collator.compare("B", "c"), Ordering::Less);
+    /// ```
+    #[cfg(feature = "compiled_data")]
+    pub fn try_new_from_rules(
+        rules: &str,
+        options: CollatorOptions,
+    ) -> Result<Self, CollationRulesError> {
+        Self::try_new_from_rules_internal(
+            DataPayload::from_static_ref(crate::provider::Baked::SINGLETON_COLLATION_ROOT_V1),
+            DataPayload::from_static_ref(
+                icu_normalizer::provider::Baked::SINGLETON_NORMALIZER_NFD_DATA_V1,
+            ),
+            DataPayload::from_static_ref(
+                icu_normalizer::provider::Baked::SINGLETON_NORMALIZER_NFD_TABLES_V1,
+            ),
+            DataPayload::from_static_ref(crate::provider::Baked::SINGLETON_COLLATION_JAMO_V1),
+            DataPayload::from_static_ref(
+                crate::provider::Baked::SINGLETON_COLLATION_SPECIAL_PRIMARIES_V1,
+            ),
+            crate::provider::Baked.load(Default::default())?.payload,
+            rules,
+            options,
+        )
+    }
+
+    icu_provider::gen_buffer_data_constructors!(
+        (rules: &str, options: CollatorOptions) -> error: CollationRulesError,
+        functions: [
+            try_new_from_rules: skip,
+            try_new_from_rules_with_buffer_provider,
+            try_new_from_rules_unstable,
+            Self
+        ]
+    );
+
+    #[doc = icu_provider::gen_buffer_unstable_docs!(UNSTABLE, Self::try_new_from_rules)]
+    pub fn try_new_from_rules_unstable<D>(
+        provider: &D,
+        rules: &str,
+        options: CollatorOptions,
+    ) -> Result<Self, CollationRulesError>
+    where
+        D: DataProvider<CollationSpecialPrimariesV1>
+            + DataProvider<CollationRootV1>
+            + DataProvider<CollationDiacriticsV1>
+            + DataProvider<CollationJamoV1>
+            + DataProvider<NormalizerNfdDataV1>
+            + DataProvider<NormalizerNfdTablesV1>
+            + ?Sized,
+    {
+        Self::try_new_from_rules_internal(
+            provider.load(Default::default())?.payload,
+            provider.load(Default::default())?.payload,
+            provider.load(Default::default())?.payload,
+            provider.load(Default::default())?.payload,
+            provider.load(Default::default())?.payload,
+            provider.load(Default::default())?.payload,
+            rules,
+            options,
+        )
+    }
+
+    #[expect(clippy::too_many_arguments)]
+    fn try_new_from_rules_internal(
+        root: DataPayload<CollationRootV1>,
+        decompositions: DataPayload<NormalizerNfdDataV1>,
+        tables: DataPayload<NormalizerNfdTablesV1>,
+        jamo: DataPayload<CollationJamoV1>,
+        special_primaries: DataPayload<CollationSpecialPrimariesV1>,
+        diacritics: DataPayload<CollationDiacriticsV1>,
+        rules: &str,
+        options: CollatorOptions,
+    ) -> Result<Self, CollationRulesError> {
+        let parsed = crate::rules::parse(rules)?;
+        let special_primaries = validate_special_primaries(special_primaries)?;
+
+        if diacritics.get().secondaries.len() != OPTIMIZED_DIACRITICS_MAX_COUNT {
+            return Err(DataError::custom("invalid")
+                .with_marker(CollationDiacriticsV1::INFO)
+                .into());
+        }
+        let Ok(jamo_array) =
+            <&[<u32 as AsULE>::ULE; JAMO_COUNT]>::try_from(jamo.get().ce32s.as_ule_slice())
+        else {
+            return Err(DataError::custom("invalid")
+                .with_marker(CollationJamoV1::INFO)
+                .into());
+        };
+
+        let tailoring = if parsed.items.is_empty() {
+            None
+        } else {
+            Some(DataPayload::from_owned(
+                TailoringBuilder::new(
+                    root.get(),
+                    jamo_array,
+                    &diacritics.get().secondaries,
+                    decompositions.get(),
+                    tables.get(),
+                    special_primaries.get(),
+                )
+                .build(&parsed.items)?,
+            ))
+        };
+
+        let mut merged_options = CollatorOptionsBitField::from(options);
+        merged_options.set_defaults(parsed.settings);
+
+        Ok(Collator {
+            special_primaries,
+            root,
+            tailoring,
+            jamo,
+            diacritics,
+            options: merged_options,
+            reordering: None,
+            decompositions,
+            tables,
+            lithuanian_dot_above: false,
+        })
+    }
+}
+
+/// Validates the special primaries and supplies the compressible lead bytes
+/// if the data doesn't have them.
+fn validate_special_primaries(
+    special_primaries: DataPayload<CollationSpecialPrimariesV1>,
+) -> Result<DataPayload<ErasedMarker<CollationSpecialPrimariesValidated<'static>>>, DataError> {
+    // `variant_count` isn't stable yet:
+    // https://github.com/rust-lang/rust/issues/73662
+    if special_primaries.get().last_primaries.len() <= (MaxVariable::Currency as usize) {
+        return Err(DataError::custom("invalid").with_marker(CollationSpecialPrimariesV1::INFO));
+    }
+    Ok(special_primaries.map_project(|csp, _| {
+        if csp.last_primaries.len()
+            == (MaxVariable::Currency as usize)
+                + core::mem::size_of_val(
+                    &CollationSpecialPrimariesValidated::HARDCODED_FALLBACK.compressible_bytes,
+                ) / core::mem::size_of::<u16>()
+        {
+            CollationSpecialPrimariesValidated {
+                compressible_bytes: array::from_fn(|i| {
+                    #[expect(clippy::unwrap_used)] // protected by the if
+                    {
+                        csp.last_primaries
+                            .get((MaxVariable::Currency as usize) + i)
+                            .unwrap()
+                    }
+                }),
+                last_primaries: csp.last_primaries.truncated(MaxVariable::Currency as usize),
+                numeric_primary: csp.numeric_primary,
+            }
+        } else {
+            // Data without compressible bytes, add hardcoded data
+            CollationSpecialPrimariesValidated {
+                last_primaries: csp.last_primaries,
+                compressible_bytes: CollationSpecialPrimariesValidated::HARDCODED_FALLBACK
+                    .compressible_bytes,
+                numeric_primary: csp.numeric_primary,
+            }
+        }
+    }))
 }
 
 macro_rules! compare {
@@ -1766,6 +1929,70 @@ impl CollatorBorrowed<'_> {
         Ordering::Equal
     }
 
+    /// Appends the collation elements of `chars` to `keys`, masked to the
+    /// levels that this collator distinguishes and omitting the ones that
+    /// are ignorable at those levels. Up to the tertiary level, strings
+    /// that compare as equal have the same keys.
+    #[cfg(feature = "search")]
+    pub(crate) fn append_search_keys<I>(&self, chars: I, keys: &mut Vec<u64>)
+    where
+        I: Iterator<Item = char>,
+    {
+        let mut iter = collation_elements!(
+            self,
+            chars,
+            self.tailoring_or_root(),
+            self.numeric_primary()
+        );
+        iter.init();
+        let variable_top = self.variable_top();
+        let strength = self.options.strength();
+        let tertiary_mask = self.options.tertiary_mask().unwrap_or_default();
+        let case_mask = if self.options.case_level() {
+            CASE_MASK
+        } else {
+            0
+        };
+        let mut after_variable = false;
+        loop {
+            let ce = iter.next();
+            if ce == NO_CE {
+                break;
+            }
+            let p = ce.primary();
+            if p < variable_top && p > MERGE_SEPARATOR_PRIMARY {
+                // Shifted variable CEs and the primary ignorables that follow
+                // them only matter on the quaternary level.
+                after_variable = true;
+                continue;
+            }
+            if p != 0 {
+                after_variable = false;
+            } else if after_variable {
+                continue;
+            }
+            let non_primary = ce.non_primary();
+            let key = match strength {
+                Strength::Primary => {
+                    (u64::from(p) << 32) | u64::from(non_primary.case() & case_mask)
+                }
+                Strength::Secondary => {
+                    (u64::from(p) << 32)
+                        | (u64::from(non_primary.secondary()) << 16)
+                        | u64::from(non_primary.case() & case_mask)
+                }
+                _ => {
+                    (u64::from(p) << 32)
+                        | (u64::from(non_primary.secondary()) << 16)
+                        | u64::from((non_primary.bits() as u16) & (tertiary_mask | case_mask))
+                }
+            };
+            if key != 0 {
+                keys.push(key);
+            }
+        }
+    }
+
     fn sort_key_levels(&self) -> u8 {
         #[expect(clippy::indexing_slicing)]
         let mut levels = LEVEL_MASKS[self.options.strength() as usize];
@@ -240,7 +240,7 @@ impl<'data> CollationData<'data> {
         debug_assert!(false);
         SINGLE_U64
     }
-    fn get_default_and_trie_impl(
+    pub(crate) fn get_default_and_trie_impl(
         &'data self,
         index: usize,
     ) -> (CollationElement32, &'data ZeroSlice<u16>) {
@@ -0,0 +1,526 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+// Various collation-related algorithms and constants in this file are
+// adapted from ICU4C and, therefore, are subject to the ICU license as
+// described in LICENSE.
+
+//! This module holds the parser for the ICU/CLDR collation rule syntax
+//! (`&a < b <<< B`). The parser turns the rule string into a flat list
+//! of resets and relations plus the settings that the rules specify.
+//! Turning these into collation data is the job of the `builder`
+//! module.
+//!
+//! The parser follows ICU4C's `CollationRuleParser`.
+
+use alloc::string::String;
+use alloc::vec::Vec;
+use icu_provider::DataError;
+
+use crate::options::{AlternateHandling, CollatorOptionsBitField, MaxVariable, Strength};
+use crate::preferences::CollationCaseFirst;
+
+/// An error returned when building a [`Collator`](crate::Collator) from
+/// collation rules.
+///
+/// The positions carried by the variants are byte indices into the
+/// rule string.
+#[derive(Debug, Clone, Copy, PartialEq, displaydoc::Display)]
+#[non_exhaustive]
+pub enum CollationRulesError {
+    /// The rules are not well-formed.
+    #[displaydoc("Syntax error in collation rules at index {0}")]
+    Syntax(usize),
+    /// The rules are well-formed but use a construct that is not supported
+    /// by ICU4X, such as `[import]`, `[reorder]`, context prefixes, special
+    /// reset positions, or tailoring combining diacritics or conjoining jamo.
+    #[displaydoc("Unsupported collation rule at index {0}")]
+    Unsupported(usize),
+    /// The rules tailor more items than there is room for between the
+    /// neighboring collation weights, or a tailored item expands to too many
+    /// collation elements.
+    #[displaydoc("Too many tailored items around the collation rule at index {0}")]
+    Overflow(usize),
+    /// Loading the root collation data failed.
+    #[displaydoc("{0}")]
+    Data(DataError),
+}
+
+impl core::error::Error for CollationRulesError {}
+
+impl From<DataError> for CollationRulesError {
+    fn from(e: DataError) -> Self {
+        CollationRulesError::Data(e)
+    }
+}
+
+/// The strength of a relation operator.
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Debug)]
+pub(crate) enum Level {
+    /// `<`
+    Primary = 0,
+    /// `<<`
+    Secondary = 1,
+    /// `<<<`
+    Tertiary = 2,
+    /// `=`
+    Identical = 3,
+}
+
+/// A reset or a relation in the order of appearance in the rules.
+#[derive(Debug)]
+pub(crate) enum RuleItem {
+    /// `&string` or `&[before n]string`
+    Reset {
+        string: String,
+        before: Option<Level>,
+        position: usize,
+    },
+    /// `< string`, `<< string / extension`, etc.
+    ///
+    /// Starred relations are expanded into one `Relation` per character.
+    Relation {
+        level: Level,
+        string: String,
+        extension: String,
+        position: usize,
+    },
+}
+
+/// The result of parsing a rule string.
+#[derive(Debug)]
+pub(crate) struct ParsedRules {
+    pub items: Vec<RuleItem>,
+    /// The options set by `[...]` settings, for use as defaults.
+    pub settings: CollatorOptionsBitField,
+}
+
+/// Parses the given rules.
+pub(crate) fn parse(rules: &str) -> Result<ParsedRules, CollationRulesError> {
+    Parser {
+        rules,
+        pos: 0,
+        items: Vec::new(),
+        settings: CollatorOptionsBitField::default(),
+    }
+    .parse()
+}
+
+/// Pattern_White_Space, which is immutable by Unicode policy.
+fn is_white_space(c: char) -> bool {
+    matches!(
+        c,
+        '\u{0009}'
+            ..='\u{000D}' | ' ' | '\u{0085}' | '\u{200E}' | '\u{200F}' | '\u{2028}' | '\u{2029}'
+    )
+}
+
+/// ASCII punctuation and symbols need to be quoted or escaped to be used
+/// as literal text.
+fn is_syntax_char(c: char) -> bool {
+    matches!(c, '!'..='/' | ':'..='@' | '['..='`' | '{'..='~')
+}
+
+struct Parser<'a> {
+    rules: &'a str,
+    /// Byte index of the next character to read
+    pos: usize,
+    items: Vec<RuleItem>,
+    settings: CollatorOptionsBitField,
+}
+
+impl<'a> Parser<'a> {
+    fn peek(&self) -> Option<char> {
+        self.rules.get(self.pos..).and_then(|s| s.chars().next())
+    }
+
+    fn bump(&mut self) -> Option<char> {
+        let c = self.peek()?;
+        self.pos += c.len_utf8();
+        Some(c)
+    }
+
+    fn skip_white_space(&mut self) {
+        while self.peek().is_some_and(is_white_space) {
+            self.bump();
+        }
+    }
+
+    fn parse(mut self) -> Result<ParsedRules, CollationRulesError> {
+        loop {
+            self.skip_white_space();
+            let Some(c) = self.peek() else {
+                break;
+            };
+            match c {
+                '&' => self.parse_rule_chain()?,
+                '[' => self.parse_setting()?,
+                '#' => {
+                    // Comment until the end of the line
+                    while let Some(c) = self.bump() {
+                        if matches!(
+                            c,
+                            '\n' | '\u{000C}' | '\r' | '\u{0085}' | '\u{2028}' | '\u{2029}'
+                        ) {
+                            break;
+                        }
+                    }
+                }
+                '@' => {
+                    // Legacy syntax for `[backwards 2]`
+                    self.bump();
+                    self.settings.set_backward_second_level(Some(true));
+                }
+                '!' => {
+                    // Legacy Thai/Lao reversal, which is always on.
+                    self.bump();
+                }
+                _ => return Err(CollationRulesError::Syntax(self.pos)),
+            }
+        }
+        Ok(ParsedRules {
+            items: self.items,
+            settings: self.settings,
+        })
+    }
+
+    fn parse_rule_chain(&mut self) -> Result<(), CollationRulesError> {
+        let position = self.pos;
+        self.bump(); // '&'
+        self.skip_white_space();
+        let mut before = None;
+        if self.peek() == Some('[') {
+            let bracket = self.pos;
+            let words = self.read_bracketed()?;
+            match words.as_slice() {
+                ["before", "1"] => before = Some(Level::Primary),
+                ["before", "2"] => before = Some(Level::Secondary),
+                ["before", "3"] => before = Some(Level::Tertiary),
+                // Special reset positions such as `[first regular]`
+                ["first" | "last", ..] => return Err(CollationRulesError::Unsupported(bracket)),
+                _ => return Err(CollationRulesError::Syntax(bracket)),
+            }
+            self.skip_white_space();
+        }
+        if self.peek() == Some('[') {
+            return Err(CollationRulesError::Unsupported(self.pos));
+        }
+        let string = self.parse_string()?;
+        if string.is_empty() {
+            return Err(CollationRulesError::Syntax(self.pos));
+        }
+        self.items.push(RuleItem::Reset {
+            string,
+            before,
+            position,
+        });
+
+        let mut has_relation = false;
+        loop {
+            self.skip_white_space();
+            let position = self.pos;
+            let Some((level, starred)) = self.parse_relation_operator()? else {
+                break;
+            };
+            has_relation = true;
+            self.skip_white_space();
+            if starred {
+                self.parse_starred_characters(level, position)?;
+                continue;
+            }
+            let string = self.parse_string()?;
+            if string.is_empty() {
+                return Err(CollationRulesError::Syntax(self.pos));
+            }
+            self.skip_white_space();
+            if self.peek() == Some('|') {
+                // Context prefix `prefix|string`
+                return Err(CollationRulesError::Unsupported(self.pos));
+            }
+            let mut extension = String::new();
+            if self.peek() == Some('/') {
+                self.bump();
+                self.skip_white_space();
+                extension = self.parse_string()?;
+                if extension.is_empty() {
+                    return Err(CollationRulesError::Syntax(self.pos));
+                }
+            }
+            self.items.push(RuleItem::Relation {
+                level,
+                string,
+                extension,
+                position,
+            });
+        }
+        if !has_relation {
+            return Err(CollationRulesError::Syntax(self.pos));
+        }
+        Ok(())
+    }
+
+    /// Returns the level and whether the operator is starred, or `None`
+    /// if the next character does not start a relation operator.
+    fn parse_relation_operator(&mut self) -> Result<Option<(Level, bool)>, CollationRulesError> {
+        let position = self.pos;
+        let level = match self.peek() {
+            Some('<') => {
+                let mut count = 0;
+                while count < 4 && self.peek() == Some('<') {
+                    self.bump();
+                    count += 1;
+                }
+                match count {
+                    1 => Level::Primary,
+                    2 => Level::Secondary,
+                    3 => Level::Tertiary,
+                    // Quaternary relations
+                    _ => return Err(CollationRulesError::Unsupported(position)),
+                }
+            }
+            Some(';') => {
+                self.bump();
+                return Ok(Some((Level::Secondary, false)));
+            }
+            Some(',') => {
+                self.bump();
+                return Ok(Some((Level::Tertiary, false)));
+            }
+            Some('=') => {
+                self.bump();
+                Level::Identical
+            }
+            _ => return Ok(None),
+        };
+        let starred = self.peek() == Some('*');
+        if starred {
+            self.bump();
+        }
+        Ok(Some((level, starred)))
+    }
+
+    /// Parses the characters and ranges of a starred relation, such as
+    /// `<* a-cx`.
+    fn parse_starred_characters(
+        &mut self,
+        level: Level,
+        position: usize,
+    ) -> Result<(), CollationRulesError> {
+        let mut chars: Vec<char> = self.parse_string()?.chars().collect();
+        if chars.is_empty() {
+            return Err(CollationRulesError::Syntax(self.pos));
+        }
+        let mut prev = None;
+        loop {
+            for &c in chars.iter() {
+                self.push_starred(level, c, position);
+                prev = Some(c);
+            }
+            if self.peek() != Some('-') {
+                break;
+            }
+            self.bump();
+            let range_position = self.pos;
+            // The end of a range cannot start another range.
+            let start = prev
+                .take()
+                .ok_or(CollationRulesError::Syntax(range_position))?;
+            let next: Vec<char> = self.parse_string()?.chars().collect();
+            let Some((&end, rest)) = next.split_first() else {
+                return Err(CollationRulesError::Syntax(range_position));
+            };
+            if end < start {
+                return Err(CollationRulesError::Syntax(range_position));
+            }
+            // `start` itself has already been added.
+            for c in (start..=end).skip(1) {
+                self.push_starred(level, c, position);
+            }
+            chars = rest.to_vec();
+        }
+        Ok(())
+    }
+
+    fn push_starred(&mut self, level: Level, c: char, position: usize) {
+        self.items.push(RuleItem::Relation {
+            level,
+            string: c.into(),
+            extension: String::new(),
+            position,
+        });
+    }
+
+    /// Parses a string of literal characters, quoted text, and escapes.
+    ///
+    /// Unquoted white space and unquoted syntax characters end the string.
+    /// In addition to ICU4C's quoting, `\uhhhh`, `\Uhhhhhhhh`, and `\x{h...}`
+    /// escapes are recognized.
+    fn parse_string(&mut self) -> Result<String, CollationRulesError> {
+        let mut string = String::new();
+        while let Some(c) = self.peek() {
+            if is_syntax_char(c) {
+                if c == '\'' {
+                    self.bump();
+                    if self.peek() == Some('\'') {
+                        // Double apostrophe encodes a single one.
+                        self.bump();
+                        string.push('\'');
+                        continue;
+                    }
+                    // Quoted literal text until the next single apostrophe
+                    loop {
+                        let Some(c) = self.bump() else {
+                            return Err(CollationRulesError::Syntax(self.pos));
+                        };
+                        if c == '\'' {
+                            if self.peek() == Some('\'') {
+                                // Double apostrophe inside quoted text
+                                self.bump();
+                            } else {
+                                break;
+                            }
+                        }
+                        string.push(c);
+                    }
+                } else if c == '\\' {
+                    let position = self.pos;
+                    self.bump();
+                    let c = self
+                        .parse_escape()
+                        .ok_or(CollationRulesError::Syntax(position))?;
+                    string.push(c);
+                } else {
+                    break;
+                }
+            } else if is_white_space(c) {
+                break;
+            } else {
+                self.bump();
+                string.push(c);
+            }
+        }
+        if string.chars().any(|c| matches!(c, '\u{FFFD}'..='\u{FFFF}')) {
+            return Err(CollationRulesError::Syntax(self.pos));
+        }
+        Ok(string)
+    }
+
+    /// Parses the part of an escape after the backslash.
+    fn parse_escape(&mut self) -> Option<char> {
+        let c = self.bump()?;
+        let (digits, braced) = match c {
+            'u' => (4, false),
+            'U' => (8, false),
+            'x' if self.peek() == Some('{') => {
+                self.bump();
+                (6, true)
+            }
+            // Any other character is taken literally.
+            _ => return Some(c),
+        };
+        let mut value = 0u32;
+        let mut count = 0;
+        while count < digits {
+            let Some(digit) = self.peek().and_then(|c| c.to_digit(16)) else {
+                break;
+            };
+            self.bump();
+            value = (value << 4) | digit;
+            count += 1;
+        }
+        if braced {
+            if count == 0 || self.bump() != Some('}') {
+                return None;
+            }
+        } else if count != digits {
+            return None;
+        }
+        char::from_u32(value)
+    }
+
+    /// Reads the whitespace-separated words between `[` and the matching `]`.
+    fn read_bracketed(&mut self) -> Result<Vec<&'a str>, CollationRulesError> {
+        let start = self.pos;
+        self.bump(); // '['
+        let content_start = self.pos;
+        let mut depth = 1;
+        while depth > 0 {
+            match self.bump() {
+                Some('[') => depth += 1,
+                Some(']') => depth -= 1,
+                Some(_) => {}
+                None => return Err(CollationRulesError::Syntax(start)),
+            }
+        }
+        let content = self
+            .rules
+            .get(content_start..self.pos - 1)
+            .ok_or(CollationRulesError::Syntax(start))?;
+        Ok(content
+            .split(is_white_space)
+            .filter(|w| !w.is_empty())
+            .collect())
+    }
+
+    fn parse_setting(&mut self) -> Result<(), CollationRulesError> {
+        let position = self.pos;
+        let words = self.read_bracketed()?;
+        let syntax = CollationRulesError::Syntax(position);
+        let settings = &mut self.settings;
+        match words.as_slice() {
+            ["strength", value] => settings.set_strength(Some(match *value {
+                "1" => Strength::Primary,
+                "2" => Strength::Secondary,
+                "3" => Strength::Tertiary,
+                "4" => Strength::Quaternary,
+                "I" => Strength::Identical,
+                _ => return Err(syntax),
+            })),
+            ["alternate", value] => settings.set_alternate_handling(Some(match *value {
+                "non-ignorable" => AlternateHandling::NonIgnorable,
+                "shifted" => AlternateHandling::Shifted,
+                _ => return Err(syntax),
+            })),
+            ["backwards", "2"] => settings.set_backward_second_level(Some(true)),
+            ["caseFirst", value] => settings.set_case_first(Some(match *value {
+                "off" => CollationCaseFirst::False,
+                "lower" => CollationCaseFirst::Lower,
+                "upper" => CollationCaseFirst::Upper,
+                _ => return Err(syntax),
+            })),
+            ["caseLevel", value] => settings.set_case_level(Some(on_off(value).ok_or(syntax)?)),
+            ["numericOrdering", value] => settings.set_numeric(Some(on_off(value).ok_or(syntax)?)),
+            ["maxVariable", value] => settings.set_max_variable(Some(match *value {
+                "space" => MaxVariable::Space,
+                "punct" => MaxVariable::Punctuation,
+                "symbol" => MaxVariable::Symbol,
+                "currency" => MaxVariable::Currency,
+                _ => return Err(syntax),
+            })),
+            // Input is always normalized, so this setting has no effect.
+            ["normalization", value] => {
+                on_off(value).ok_or(syntax)?;
+            }
+            ["hiraganaQ", "off"] => {}
+            // A performance hint only
+            ["optimize", ..] => {}
+            ["hiraganaQ", "on"]
+            | ["reorder", ..]
+            | ["import", ..]
+            | ["suppressContractions", ..] => {
+                return Err(CollationRulesError::Unsupported(position))
+            }
+            _ => return Err(syntax),
+        }
+        Ok(())
+    }
+}
+
+fn on_off(value: &str) -> Option<bool> {
+    match value {
+        "on" => Some(true),
+        "off" => Some(false),
+        _ => None,
+    }
+}
@@ -0,0 +1,319 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! This module holds the `StringSearcher` struct that finds matches of
+//! a pattern in text according to a collator.
+//!
+//! The text is split into grapheme clusters, and adjacent clusters whose
+//! collation elements are not the concatenation of their individual
+//! collation elements (because of a contraction such as Spanish
+//! traditional "ch") are merged into one segment. A match is a run of
+//! segments whose collation elements, masked to the levels that the
+//! collator distinguishes, equal those of the pattern.
+//!
+//! The collation elements of each segment are computed once. The search
+//! keys of all segments are then scanned in a single pass with a prefix
+//! table of the pattern (Knuth–Morris–Pratt), so the search takes time
+//! linear in the length of the text and the pattern.
+
+use alloc::vec::Vec;
+use core::ops::Range;
+use icu_segmenter::GraphemeClusterSegmenterBorrowed;
+use utf16_iter::Utf16CharsEx;
+use utf8_iter::Utf8CharsEx;
+
+use crate::CollatorBorrowed;
+
+/// A run of grapheme clusters, as indices into the decoded text, with the
+/// search keys of its collation elements.
+type Segment = (Range<usize>, Vec<u64>);
+
+/// Finds occurrences of a pattern in text using the equivalence defined by
+/// a collator.
+///
+/// Text matches the pattern if it compares as equal to the pattern with the
+/// collator, so the strength of the collator determines which differences
+/// are ignored. At primary strength, "resume" matches "Résumé", and with
+/// Turkish tailoring, "i" matches "İ" but not "ı".
+///
+/// Matches start and end on grapheme cluster boundaries and do not split
+/// contractions. Matches do not overlap: after a match, the search continues
+/// at its end. Shifted variable characters (see
+/// [`AlternateHandling`](crate::options::AlternateHandling)) are ignored.
+/// The quaternary and identical strengths are treated as tertiary.
+///
+/// ✨ *Enabled with the `search` Cargo feature.*
+///
+/// # Examples
+///
+/// ```
+/// use icu::collator::{options::*, Collator, StringSearcher};
+/// use icu::locale::locale;
+///
+/// let mut options = CollatorOptions::default();
+/// options.strength = Some(Strength::Primary);
+///
+/// let collator = Collator::try_new(Default::default(), options).unwrap();
+/// let searcher = StringSearcher::new(&collator, "resume");
+/// assert_eq!(searcher.find_str("My Résumé"), Some(3..11));
+///
+/// let collator = Collator::try_new(locale!("tr").into(), options).unwrap();
+/// let searcher = StringSearcher::new(&collator, "i");
+/// assert_eq!(searcher.find_all_str("Iıİi"), [3..5, 5..6]);
+/// ```
+#[derive(Debug)]
+pub struct StringSearcher<'a> {
+    collator: &'a CollatorBorrowed<'a>,
+    segmenter: GraphemeClusterSegmenterBorrowed<'a>,
+    pattern: Vec<u64>,
+    /// For each prefix of the pattern, the length of its longest proper
+    /// prefix that is also a suffix
+    prefix_table: Vec<usize>,
+}
+
+impl<'a> StringSearcher<'a> {
+    /// Creates a searcher for `pattern` that uses the grapheme cluster
+    /// segmenter from compiled data.
+    ///
+    /// ✨ *Enabled with the `compiled_data` Cargo feature.*
+    #[cfg(feature = "compiled_data")]
+    pub fn new(collator: &'a CollatorBorrowed<'a>, pattern: &str) -> Self {
+        Self::new_with_segmenter(
+            collator,
+            icu_segmenter::GraphemeClusterSegmenter::new(),
+            pattern,
+        )
+    }
+
+    /// Creates a searcher for `pattern` that uses the given grapheme cluster
+    /// segmenter.
+    pub fn new_with_segmenter(
+        collator: &'a CollatorBorrowed<'a>,
+        segmenter: GraphemeClusterSegmenterBorrowed<'a>,
+        pattern: &str,
+    ) -> Self {
+        let mut keys = Vec::new();
+        collator.append_search_keys(pattern.chars(), &mut keys);
+        Self {
+            collator,
+            segmenter,
+            prefix_table: prefix_table(&keys),
+            pattern: keys,
+        }
+    }
+
+    /// Returns the byte range of the first match in guaranteed well-formed
+    /// UTF-8 text.
+    pub fn find_str(&self, text: &str) -> Option<Range<usize>> {
+        self.search_str(text, true).into_iter().next()
+    }
+
+    /// Returns the byte ranges of all matches in guaranteed well-formed
+    /// UTF-8 text.
+    pub fn find_all_str(&self, text: &str) -> Vec<Range<usize>> {
+        self.search_str(text, false)
+    }
+
+    /// Returns the byte range of the first match in potentially ill-formed
+    /// UTF-8 text.
+    ///
+    /// Ill-formed sequences are treated as U+FFFD REPLACEMENT CHARACTER.
+    pub fn find_utf8(&self, text: &[u8]) -> Option<Range<usize>> {
+        self.search_utf8(text, true).into_iter().next()
+    }
+
+    /// Returns the byte ranges of all matches in potentially ill-formed
+    /// UTF-8 text.
+    ///
+    /// Ill-formed sequences are treated as U+FFFD REPLACEMENT CHARACTER.
+    pub fn find_all_utf8(&self, text: &[u8]) -> Vec<Range<usize>> {
+        self.search_utf8(text, false)
+    }
+
+    /// Returns the code unit range of the first match in potentially
+    /// ill-formed UTF-16 text.
+    ///
+    /// Unpaired surrogates are treated as U+FFFD REPLACEMENT CHARACTER.
+    pub fn find_utf16(&self, text: &[u16]) -> Option<Range<usize>> {
+        self.search_utf16(text, true).into_iter().next()
+    }
+
+    /// Returns the code unit ranges of all matches in potentially
+    /// ill-formed UTF-16 text.
+    ///
+    /// Unpaired surrogates are treated as U+FFFD REPLACEMENT CHARACTER.
+    pub fn find_all_utf16(&self, text: &[u16]) -> Vec<Range<usize>> {
+        self.search_utf16(text, false)
+    }
+
+    fn search_str(&self, text: &str, first_only: bool) -> Vec<Range<usize>> {
+        let chars: Vec<(usize, char)> = text.char_indices().collect();
+        self.search(
+            &chars,
+            text.len(),
+            self.segmenter.segment_str(text),
+            first_only,
+        )
+    }
+
+    fn search_utf8(&self, text: &[u8], first_only: bool) -> Vec<Range<usize>> {
+        let chars: Vec<(usize, char)> = text.char_indices().collect();
+        self.search(
+            &chars,
+            text.len(),
+            self.segmenter.segment_utf8(text),
+            first_only,
+        )
+    }
+
+    fn search_utf16(&self, text: &[u16], first_only: bool) -> Vec<Range<usize>> {
+        let chars: Vec<(usize, char)> = text.char_indices().collect();
+        self.search(
+            &chars,
+            text.len(),
+            self.segmenter.segment_utf16(text),
+            first_only,
+        )
+    }
+
+    /// Searches decoded text, given as code unit offsets and characters,
+    /// with its grapheme cluster boundaries in code units.
+    fn search(
+        &self,
+        chars: &[(usize, char)],
+        len: usize,
+        boundaries: impl Iterator<Item = usize>,
+        first_only: bool,
+    ) -> Vec<Range<usize>> {
+        let mut matches = Vec::new();
+        if self.pattern.is_empty() {
+            return matches;
+        }
+        let segments = self.segments(chars, boundaries);
+        let offset = |index: usize| chars.get(index).map_or(len, |&(offset, _)| offset);
+
+        // The keys of all segments, with the key offsets at which the
+        // segments that are not ignorable start and end. A match cannot start
+        // with ignorable text, and it ends with the last segment that
+        // completes the pattern.
+        let mut keys = Vec::new();
+        let mut starts = Vec::new();
+        let mut ends = Vec::new();
+        for (cluster, segment_keys) in &segments {
+            if segment_keys.is_empty() {
+                continue;
+            }
+            starts.push((keys.len(), cluster.start));
+            keys.extend_from_slice(segment_keys);
+            ends.push((keys.len(), cluster.end));
+        }
+        let find = |boundaries: &[(usize, usize)], key_offset: usize| {
+            boundaries
+                .binary_search_by_key(&key_offset, |&(offset, _)| offset)
+                .ok()
+                .and_then(|i| boundaries.get(i))
+                .map(|&(_, index)| index)
+        };
+
+        let mut matched = 0;
+        // Matches do not overlap, so the next match starts at this key offset or later.
+        let mut next_start = 0;
+        for (i, key) in keys.iter().enumerate() {
+            while matched > 0 && self.pattern.get(matched) != Some(key) {
+                matched = self.prefix_table.get(matched - 1).copied().unwrap_or(0);
+            }
+            if self.pattern.get(matched) == Some(key) {
+                matched += 1;
+            }
+            if matched < self.pattern.len() {
+                continue;
+            }
+            let key_end = i + 1;
+            let key_start = key_end - matched;
+            matched = self.prefix_table.get(matched - 1).copied().unwrap_or(0);
+            if key_start < next_start {
+                continue;
+            }
+            if let (Some(start), Some(end)) = (find(&starts, key_start), find(&ends, key_end)) {
+                matches.push(offset(start)..offset(end));
+                if first_only {
+                    break;
+                }
+                next_start = key_end;
+            }
+        }
+        matches
+    }
+
+    /// Splits the text into grapheme clusters and merges the ones that
+    /// a contraction or canonical reordering ties together.
+    fn segments(
+        &self,
+        chars: &[(usize, char)],
+        boundaries: impl Iterator<Item = usize>,
+    ) -> Vec<Segment> {
+        let mut segments: Vec<Segment> = Vec::new();
+        let mut previous: Option<Segment> = None;
+        let mut cluster_start = 0;
+        let mut index = 0;
+        for boundary in boundaries.chain(core::iter::once(usize::MAX)) {
+            while chars
+                .get(index)
+                .is_some_and(|&(offset, _)| offset < boundary)
+            {
+                index += 1;
+            }
+            if index == cluster_start {
+                continue;
+            }
+            let cluster = cluster_start..index;
+            cluster_start = index;
+            let keys = self.keys(chars, cluster.clone());
+            if let (Some((segment, segment_keys)), Some((previous_cluster, previous_keys))) =
+                (segments.last_mut(), previous.as_ref())
+            {
+                let joined = self.keys(chars, previous_cluster.start..cluster.end);
+                if joined.len() != previous_keys.len() + keys.len()
+                    || !joined.starts_with(previous_keys)
+                    || !joined.ends_with(&keys)
+                {
+                    segment.end = cluster.end;
+                    *segment_keys = self.keys(chars, segment.clone());
+                    previous = Some((cluster, keys));
+                    continue;
+                }
+            }
+            segments.push((cluster.clone(), keys.clone()));
+            previous = Some((cluster, keys));
+        }
+        segments
+    }
+
+    fn keys(&self, chars: &[(usize, char)], range: Range<usize>) -> Vec<u64> {
+        let mut keys = Vec::new();
+        if let Some(chars) = chars.get(range) {
+            self.collator
+                .append_search_keys(chars.iter().map(|&(_, c)| c), &mut keys);
+        }
+        keys
+    }
+}
+
+/// Returns the prefix table of `pattern` for the Knuth–Morris–Pratt search:
+/// for each prefix, the length of its longest proper prefix that is also
+/// a suffix.
+fn prefix_table(pattern: &[u64]) -> Vec<usize> {
+    let mut table = Vec::with_capacity(pattern.len());
+    let mut length = 0;
+    for (i, key) in pattern.iter().enumerate() {
+        while length > 0 && pattern.get(length) != Some(key) {
+            length = table.get(length - 1).copied().unwrap_or(0);
+        }
+        if i > 0 && pattern.get(length) == Some(key) {
+            length += 1;
+        }
+        table.push(length);
+    }
+    table
+}
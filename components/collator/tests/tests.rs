@@ -2081,6 +2081,216 @@ fn test_latin1_utf16_fi() {
     );
 }
 
+#[test]
+fn test_rules_basic() {
+    let collator =
+        Collator::try_new_from_rules("&a < b <<< B", CollatorOptions::default()).unwrap();
+    let collator = collator.as_borrowed();
+    assert_eq!(collator.compare("a", "b"), Ordering::Less);
+    assert_eq!(collator.compare("b", "B"), Ordering::Less);
+    assert_eq!(collator.compare("B", "c"), Ordering::Less);
+    assert_eq!(collator.compare("A", "b"), Ordering::Less);
+    assert_eq!(collator.compare("ab", "ba"), Ordering::Less);
+    assert_eq!(collator.compare("bz", "Ba"), Ordering::Greater);
+    assert_eq!(collator.compare("ba", "c"), Ordering::Less);
+
+    let mut options = CollatorOptions::default();
+    options.strength = Some(Strength::Secondary);
+    let collator = Collator::try_new_from_rules("&a < b <<< B", options).unwrap();
+    assert_eq!(collator.as_borrowed().compare("b", "B"), Ordering::Equal);
+}
+
+#[test]
+fn test_rules_match_compiled_tailoring() {
+    let rules = "&N < ñ <<< Ñ &C < ch <<< cH <<< Ch <<< CH &L < ll <<< lL <<< Ll <<< LL";
+    let from_rules = Collator::try_new_from_rules(rules, CollatorOptions::default()).unwrap();
+    let from_rules = from_rules.as_borrowed();
+    let compiled =
+        Collator::try_new(locale!("es-u-co-trad").into(), CollatorOptions::default()).unwrap();
+    let words = [
+        "cuna", "chaleco", "Chile", "CHILE", "cz", "d", "llama", "luz", "Llama", "mz", "ñu", "Ñu",
+        "nz", "o", "pollo", "polvo", "caña", "cana", "canz",
+    ];
+    for a in words {
+        for b in words {
+            assert_eq!(
+                from_rules.compare(a, b),
+                compiled.compare(a, b),
+                "{a} vs {b}"
+            );
+        }
+    }
+}
+
+#[test]
+fn test_rules_expansion() {
+    let collator =
+        Collator::try_new_from_rules("&AE << ä <<< Ä", CollatorOptions::default()).unwrap();
+    let collator = collator.as_borrowed();
+    assert_eq!(collator.compare("ä", "ae"), Ordering::Greater);
+    assert_eq!(collator.compare("ä", "af"), Ordering::Less);
+    assert_eq!(collator.compare("Ä", "ä"), Ordering::Greater);
+    assert_eq!(collator.compare("äz", "aez"), Ordering::Greater);
+    assert_eq!(collator.compare("äa", "aez"), Ordering::Less);
+
+    let mut options = CollatorOptions::default();
+    options.strength = Some(Strength::Primary);
+    let collator = Collator::try_new_from_rules("&AE << ä <<< Ä", options).unwrap();
+    assert_eq!(collator.as_borrowed().compare("Ä", "ae"), Ordering::Equal);
+}
+
+#[test]
+fn test_rules_before_and_settings() {
+    let collator =
+        Collator::try_new_from_rules("[strength 1]&[before 1]b < x", CollatorOptions::default())
+            .unwrap();
+    let collator = collator.as_borrowed();
+    assert_eq!(collator.compare("a", "x"), Ordering::Less);
+    assert_eq!(collator.compare("x", "b"), Ordering::Less);
+    assert_eq!(collator.compare("a", "A"), Ordering::Equal);
+    assert_eq!(collator.resolved_options().strength, Strength::Primary);
+
+    // Explicit options take precedence over the settings in the rules.
+    let mut options = CollatorOptions::default();
+    options.strength = Some(Strength::Tertiary);
+    let collator = Collator::try_new_from_rules("[strength 1]", options).unwrap();
+    assert_eq!(collator.as_borrowed().compare("a", "A"), Ordering::Less);
+}
+
+#[test]
+fn test_rules_keep_root_contractions() {
+    let root = Collator::try_new(Default::default(), CollatorOptions::default()).unwrap();
+    let collator =
+        Collator::try_new_from_rules(r"&z < l\u0327", CollatorOptions::default()).unwrap();
+    let collator = collator.as_borrowed();
+    assert_eq!(collator.compare("l\u{327}", "z"), Ordering::Greater);
+    for (a, b) in [("l·a", "lb"), ("L·", "L"), ("ла", "л\u{306}")] {
+        assert_eq!(collator.compare(a, b), root.compare(a, b), "{a} vs {b}");
+    }
+}
+
+#[test]
+fn test_rules_errors() {
+    let options = CollatorOptions::default();
+    assert_eq!(
+        Collator::try_new_from_rules("a < b", options).unwrap_err(),
+        CollationRulesError::Syntax(0)
+    );
+    assert_eq!(
+        Collator::try_new_from_rules("&a < b | c", options).unwrap_err(),
+        CollationRulesError::Unsupported(7)
+    );
+    assert_eq!(
+        Collator::try_new_from_rules("[import de]", options).unwrap_err(),
+        CollationRulesError::Unsupported(0)
+    );
+    assert!(matches!(
+        Collator::try_new_from_rules(r"&a < \u0301", options),
+        Err(CollationRulesError::Unsupported(_))
+    ));
+}
+
+#[test]
+#[cfg(feature = "search")]
+fn test_search_strength() {
+    let mut options = CollatorOptions::default();
+    options.strength = Some(Strength::Primary);
+    let collator = Collator::try_new(Default::default(), options).unwrap();
+    let searcher = StringSearcher::new(&collator, "resume");
+    assert_eq!(searcher.find_str("My Résumé"), Some(3..11));
+    assert_eq!(searcher.find_all_str("resume RÉSUMÉ"), [0..6, 7..15]);
+    assert_eq!(searcher.find_str("resum"), None);
+
+    options.strength = Some(Strength::Secondary);
+    let collator = Collator::try_new(Default::default(), options).unwrap();
+    let searcher = StringSearcher::new(&collator, "resume");
+    assert_eq!(searcher.find_str("Résumé RESUME"), Some(9..15));
+
+    let searcher = StringSearcher::new(&collator, "");
+    assert_eq!(searcher.find_str("resume"), None);
+}
+
+#[test]
+#[cfg(feature = "search")]
+fn test_search_turkish() {
+    let mut options = CollatorOptions::default();
+    options.strength = Some(Strength::Primary);
+    let root = Collator::try_new(Default::default(), options).unwrap();
+    let searcher = StringSearcher::new(&root, "i");
+    assert_eq!(searcher.find_all_str("Iıİi"), [0..1, 3..5, 5..6]);
+
+    let collator = Collator::try_new(locale!("tr").into(), options).unwrap();
+    let searcher = StringSearcher::new(&collator, "i");
+    assert_eq!(searcher.find_all_str("Iıİi"), [3..5, 5..6]);
+    let searcher = StringSearcher::new(&collator, "ı");
+    assert_eq!(searcher.find_all_str("Iıİi"), [0..1, 1..3]);
+}
+
+#[test]
+#[cfg(feature = "search")]
+fn test_search_grapheme_boundaries() {
+    let mut options = CollatorOptions::default();
+    options.strength = Some(Strength::Primary);
+    let collator = Collator::try_new(Default::default(), options).unwrap();
+    let searcher = StringSearcher::new(&collator, "e");
+    // Ignorable marks extend the match to the end of the grapheme cluster.
+    assert_eq!(searcher.find_all_str("e\u{301}e"), [0..3, 3..4]);
+
+    options.strength = Some(Strength::Secondary);
+    let collator = Collator::try_new(Default::default(), options).unwrap();
+    let searcher = StringSearcher::new(&collator, "e");
+    assert_eq!(searcher.find_str("e\u{301}e"), Some(3..4));
+    let searcher = StringSearcher::new(&collator, "\u{301}");
+    assert_eq!(searcher.find_str("e\u{301}"), None);
+}
+
+#[test]
+#[cfg(feature = "search")]
+fn test_search_contractions() {
+    let collator =
+        Collator::try_new(locale!("es-u-co-trad").into(), CollatorOptions::default()).unwrap();
+    let searcher = StringSearcher::new(&collator, "c");
+    assert_eq!(searcher.find_str("chico"), Some(3..4));
+    let searcher = StringSearcher::new(&collator, "ch");
+    assert_eq!(searcher.find_str("chico"), Some(0..2));
+    let searcher = StringSearcher::new(&collator, "h");
+    assert_eq!(searcher.find_str("chico"), None);
+}
+
+#[test]
+#[cfg(feature = "search")]
+fn test_search_utf8_and_utf16() {
+    let mut options = CollatorOptions::default();
+    options.strength = Some(Strength::Primary);
+    let collator = Collator::try_new(Default::default(), options).unwrap();
+    let searcher = StringSearcher::new(&collator, "cafe");
+    let text = "☕ Café \u{1F600} cafe";
+    let utf16: Vec<u16> = text.encode_utf16().collect();
+    assert_eq!(searcher.find_all_utf8(text.as_bytes()), [4..9, 15..19]);
+    assert_eq!(searcher.find_all_utf16(&utf16), [2..6, 10..14]);
+    assert_eq!(searcher.find_utf16(&utf16), Some(2..6));
+    assert_eq!(searcher.find_utf8(b"\xFFcafe"), Some(1..5));
+}
+
+#[test]
+#[cfg(feature = "search")]
+fn test_search_repetitive() {
+    let mut options = CollatorOptions::default();
+    options.strength = Some(Strength::Primary);
+    let collator = Collator::try_new(Default::default(), options).unwrap();
+    // A partial match that fails continues with its longest matching suffix.
+    let searcher = StringSearcher::new(&collator, "aab");
+    assert_eq!(searcher.find_all_str("aaaabAAB"), [2..5, 5..8]);
+    // Matches do not overlap.
+    let searcher = StringSearcher::new(&collator, "aa");
+    assert_eq!(searcher.find_all_str("aAaaa"), [0..2, 2..4]);
+    let searcher = StringSearcher::new(&collator, "abab");
+    assert_eq!(searcher.find_all_str("abababab"), [0..4, 4..8]);
+    // Ignorable marks inside a match are part of it.
+    let searcher = StringSearcher::new(&collator, "ee");
+    assert_eq!(searcher.find_all_str("e\u{301}eee"), [0..4, 4..6]);
+}
+
 // TODO: Test languages that map to the root.
 // The languages that map to root without script reordering are:
 // ca (at least for now)
@@ -0,0 +1,175 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! A simple builder for fast-type [`CodePointTrie`]s at runtime.
+//!
+//! This builder shares identical data and index blocks, but does not perform the
+//! further compaction of ICU4C's `umutablecptrie`. Use the `icu_codepointtrie_builder`
+//! crate to build small-type or maximally compacted tries, such as those in data.
+
+use crate::codepointtrie::{CodePointTrie, CodePointTrieHeader, TrieType, TrieValue};
+use alloc::collections::BTreeMap;
+use alloc::vec;
+use alloc::vec::Vec;
+use zerovec::ZeroVec;
+
+/// An array of blocks, in which identical blocks are stored once.
+struct Blocks<T> {
+    array: Vec<T>,
+    offsets: BTreeMap<Vec<T>, u16>,
+}
+
+impl<T: Copy + Ord> Blocks<T> {
+    /// Returns the offset of `block` in the array, appending it if it is new.
+    fn add(&mut self, block: Vec<T>) -> Option<u16> {
+        if let Some(&offset) = self.offsets.get(&block) {
+            return Some(offset);
+        }
+        let offset = u16::try_from(self.array.len()).ok()?;
+        self.array.extend_from_slice(&block);
+        self.offsets.insert(block, offset);
+        Some(offset)
+    }
+}
+
+/// Builds a fast-type [`CodePointTrie`] that maps the code points in `values` to their values
+/// and all other code points to `null_value`.
+///
+/// Returns `None` if `values` contains a key above U+10FFFF, or if the trie is too large for
+/// the 16-bit offsets of its index.
+///
+/// ✨ *Enabled with the `alloc` Cargo feature.*
+///
+/// # Examples
+///
+/// ```
+/// use icu::collections::codepointtrie::builder::build_fast;
+/// use std::collections::BTreeMap;
+///
+/// let values = BTreeMap::from([('a' as u32, 1u8), ('🐱' as u32, 2)]);
+/// let trie = build_fast(&values, 0).expect("the trie is small");
+///
+/// assert_eq!(trie.get32('a' as u32), 1);
+/// assert_eq!(trie.get32('b' as u32), 0);
+/// assert_eq!(trie.get32('🐱' as u32), 2);
+/// ```
+pub fn build_fast<T: TrieValue + Ord>(
+    values: &BTreeMap<u32, T>,
+    null_value: T,
+) -> Option<CodePointTrie<'static, T>> {
+    let has_values = |start: u32, len: u32| values.range(start..start + len).next().is_some();
+    let block = |start: u32, len: u32| {
+        (start..start + len)
+            .map(|c| values.get(&c).copied().unwrap_or(null_value))
+            .collect::<Vec<T>>()
+    };
+
+    let max = values.keys().next_back().copied().unwrap_or(0);
+    if max > 0x10FFFF {
+        return None;
+    }
+    let high_start = if max < 0x10000 {
+        0x10000
+    } else {
+        (max + 1 + 0x3FFF) & !0x3FFF
+    };
+
+    // The data starts with the null block.
+    let mut data = Blocks {
+        array: vec![null_value; 64],
+        offsets: BTreeMap::from([(vec![null_value; 64], 0), (vec![null_value; 16], 0)]),
+    };
+    let mut index = Blocks {
+        array: (0..0x10000)
+            .step_by(64)
+            .map(|start| {
+                if has_values(start, 64) {
+                    data.add(block(start, 64))
+                } else {
+                    Some(0)
+                }
+            })
+            .collect::<Option<Vec<u16>>>()?,
+        offsets: BTreeMap::new(),
+    };
+    let mut index3_null_offset = 0x7fff;
+    if high_start > 0x10000 {
+        let index1_start = index.array.len();
+        index
+            .array
+            .resize(index1_start + ((high_start - 0x10000) >> 14) as usize, 0);
+        index3_null_offset = index.add(vec![0; 32])?;
+        for (i1, start1) in (0x10000..high_start).step_by(0x4000).enumerate() {
+            let index2 = (start1..start1 + 0x4000)
+                .step_by(0x200)
+                .map(|start2| {
+                    if !has_values(start2, 0x200) {
+                        return Some(index3_null_offset);
+                    }
+                    let index3 = (start2..start2 + 0x200)
+                        .step_by(16)
+                        .map(|start3| data.add(block(start3, 16)))
+                        .collect::<Option<Vec<u16>>>()?;
+                    index.add(index3)
+                })
+                .collect::<Option<Vec<u16>>>()?;
+            let index2 = index.add(index2)?;
+            *index.array.get_mut(index1_start + i1)? = index2;
+        }
+    }
+    // The high value and the error value
+    data.array.push(null_value);
+    data.array.push(null_value);
+    if data.array.len() > 0xFFFF || index.array.len() >= 0x8000 {
+        return None;
+    }
+
+    CodePointTrie::try_new(
+        CodePointTrieHeader {
+            high_start,
+            shifted12_high_start: (high_start >> 12) as u16,
+            index3_null_offset,
+            data_null_offset: 0,
+            null_value: null_value.to_u32(),
+            trie_type: TrieType::Fast,
+        },
+        ZeroVec::alloc_from_slice(&index.array),
+        ZeroVec::alloc_from_slice(&data.array),
+    )
+    .ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_build_fast() {
+        let values: BTreeMap<u32, u32> = [0x41, 0x3B1, 0xFFFF, 0x10000, 0x1F431, 0x10FFFF]
+            .into_iter()
+            .enumerate()
+            .map(|(i, c)| (c, i as u32 + 1))
+            .chain((0x4E00..0x4F00).map(|c| (c, 7)))
+            .collect();
+        let trie = build_fast(&values, 0xFFFF).unwrap();
+        assert_eq!(trie.get32(0x10FFFF + 1), trie.error_value());
+        for c in 0..=0x10FFFF {
+            assert_eq!(
+                trie.get32(c),
+                values.get(&c).copied().unwrap_or(0xFFFF),
+                "{c:X}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_build_fast_bmp_only() {
+        let values = BTreeMap::from([(0x20AC, 3u8)]);
+        let trie = build_fast(&values, 0).unwrap();
+        assert_eq!(trie.get32(0x20AC), 3);
+        assert_eq!(trie.get32(0x20AD), 0);
+        assert_eq!(trie.get32(0x1F431), 0);
+        assert_eq!(build_fast(&BTreeMap::from([(0x110000, 1u8)]), 0), None);
+    }
+}
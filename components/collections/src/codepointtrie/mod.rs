@@ -31,6 +31,8 @@
 
 extern crate alloc;
 
+#[cfg(feature = "alloc")]
+pub mod builder;
 mod cptrie;
 mod error;
 mod impl_const;
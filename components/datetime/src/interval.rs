@@ -0,0 +1,947 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Formatting of date and time intervals.
+
+use crate::error::DateTimeFormatterLoadError;
+use crate::external_loaders::*;
+use crate::fieldsets::enums::{CompositeFieldSet, TimeFieldSet};
+use crate::format::datetime::try_write_pattern_items;
+use crate::format::DateTimeInputUnchecked;
+use crate::neo::{DateTimeFormatter, DateTimeFormatterPreferences};
+use crate::parts;
+use crate::pattern::{FormattedDateTimePatternError, RawDateTimeNamesBorrowed};
+use crate::provider::fields::{self, FieldSymbol};
+use crate::provider::neo::{DatetimePatternsIntervalV1, IntervalField, IntervalPatternKey};
+use crate::provider::pattern::runtime::{PatternBorrowed, PatternMetadata};
+use crate::provider::pattern::{GenericPatternItem, PatternItem};
+use crate::provider::PackedSkeletonVariant;
+use crate::raw::neo::{
+    DateTimeZonePatternDataBorrowed, DateTimeZonePatternSelectionData, IntervalPieces,
+    ItemsAndOptions,
+};
+use crate::scaffold::*;
+use crate::size_test_macro::size_test;
+use core::fmt;
+use icu_calendar::AnyCalendarKind;
+use icu_provider::prelude::*;
+use writeable::{impl_display_with_writeable, PartsWrite, Writeable};
+use zerovec::ZeroSlice;
+
+/// The fallback pattern used when no interval data is loaded: `{0} – {1}`.
+const DEFAULT_FALLBACK: &ZeroSlice<GenericPatternItem> = zerovec::zeroslice!(
+    GenericPatternItem;
+    GenericPatternItem::to_unaligned_const;
+    [
+        GenericPatternItem::Placeholder(0),
+        GenericPatternItem::Literal('\u{2009}'),
+        GenericPatternItem::Literal('–'),
+        GenericPatternItem::Literal('\u{2009}'),
+        GenericPatternItem::Placeholder(1),
+    ]
+);
+
+size_test!(
+    DateTimeIntervalFormatter<crate::fieldsets::YMD>,
+    date_time_interval_formatter_size,
+    464
+);
+
+/// [`DateTimeIntervalFormatter`] formats a range between two dates and/or times,
+/// such as "Jan 3 – 7, 2025" or "10:00 – 11:30 AM".
+///
+/// It is constructed from the same field sets as [`DateTimeFormatter`]. The fields that are
+/// the same at the start and the end of the interval are shown only once, based on the field
+/// with the greatest difference, following the CLDR `intervalFormats` data.
+///
+/// If there is no interval pattern for the field set and the greatest difference, the start
+/// and the end are formatted separately and joined with the locale's fallback pattern.
+/// This is always the case for:
+///
+/// - dates formatted in calendars other than the Gregorian calendar,
+/// - field sets with a time zone,
+/// - intervals that differ only in the seconds,
+/// - date and time field sets where the date differs.
+///
+/// If the start and the end would be formatted identically, a single value is formatted.
+#[doc = date_time_interval_formatter_size!()]
+///
+/// # Examples
+///
+/// ```
+/// use icu::datetime::fieldsets::YMD;
+/// use icu::datetime::input::Date;
+/// use icu::datetime::DateTimeIntervalFormatter;
+/// use icu::locale::locale;
+/// use writeable::assert_writeable_eq;
+///
+/// let formatter =
+///     DateTimeIntervalFormatter::try_new(locale!("en").into(), YMD::medium())
+///         .unwrap();
+///
+/// let start = Date::try_new_gregorian(2025, 1, 3).unwrap();
+///
+/// assert_writeable_eq!(
+///     formatter.format(&start, &Date::try_new_gregorian(2025, 1, 7).unwrap()),
+///     "Jan 3 – 7, 2025"
+/// );
+/// assert_writeable_eq!(
+///     formatter.format(&start, &Date::try_new_gregorian(2025, 2, 7).unwrap()),
+///     "Jan 3 – Feb 7, 2025"
+/// );
+/// assert_writeable_eq!(
+///     formatter.format(&start, &Date::try_new_gregorian(2026, 2, 7).unwrap()),
+///     "Jan 3, 2025 – Feb 7, 2026"
+/// );
+/// assert_writeable_eq!(formatter.format(&start, &start), "Jan 3, 2025");
+/// ```
+///
+/// Times:
+///
+/// ```
+/// use icu::datetime::fieldsets::T;
+/// use icu::datetime::input::Time;
+/// use icu::datetime::DateTimeIntervalFormatter;
+/// use icu::locale::locale;
+/// use writeable::assert_writeable_eq;
+///
+/// let formatter =
+///     DateTimeIntervalFormatter::try_new(locale!("en").into(), T::hm()).unwrap();
+///
+/// assert_writeable_eq!(
+///     formatter.format(
+///         &Time::try_new(10, 0, 0, 0).unwrap(),
+///         &Time::try_new(11, 30, 0, 0).unwrap()
+///     ),
+///     "10:00 – 11:30 AM"
+/// );
+/// assert_writeable_eq!(
+///     formatter.format(
+///         &Time::try_new(10, 0, 0, 0).unwrap(),
+///         &Time::try_new(13, 30, 0, 0).unwrap()
+///     ),
+///     "10:00 AM – 1:30 PM"
+/// );
+/// ```
+#[derive(Debug, Clone)]
+pub struct DateTimeIntervalFormatter<FSet: DateTimeNamesMarker> {
+    formatter: DateTimeFormatter<FSet>,
+    patterns: Option<DataPayload<DatetimePatternsIntervalV1>>,
+    /// Whether the interval patterns apply to the formatter's patterns.
+    use_patterns: bool,
+    /// Whether the interval patterns apply only to the time of a date and time pattern.
+    glued_only: bool,
+}
+
+impl<FSet: DateTimeMarkers> DateTimeIntervalFormatter<FSet>
+where
+    FSet::D: DateDataMarkers,
+    FSet::T: TimeMarkers,
+    FSet::Z: ZoneMarkers,
+    FSet: GetField<CompositeFieldSet>,
+{
+    /// Creates a new [`DateTimeIntervalFormatter`] from compiled data with
+    /// datetime components specified at build time.
+    ///
+    /// This loads the same data as [`DateTimeFormatter::try_new`], as well as the
+    /// interval patterns for the field set.
+    ///
+    /// ✨ *Enabled with the `compiled_data` Cargo feature.*
+    ///
+    /// [📚 Help choosing a constructor](icu_provider::constructors)
+    #[cfg(feature = "compiled_data")]
+    pub fn try_new(
+        prefs: DateTimeFormatterPreferences,
+        field_set_with_options: FSet,
+    ) -> Result<Self, DateTimeFormatterLoadError>
+    where
+        crate::provider::Baked: AllAnyCalendarFormattingDataMarkers<FSet>,
+    {
+        Self::try_new_internal(
+            &crate::provider::Baked,
+            &ExternalLoaderCompiledData,
+            prefs,
+            field_set_with_options.get_field(),
+        )
+    }
+
+    #[doc = icu_provider::gen_buffer_unstable_docs!(BUFFER, Self::try_new)]
+    #[cfg(feature = "serde")]
+    pub fn try_new_with_buffer_provider<P>(
+        provider: &P,
+        prefs: DateTimeFormatterPreferences,
+        field_set_with_options: FSet,
+    ) -> Result<Self, DateTimeFormatterLoadError>
+    where
+        P: BufferProvider + ?Sized,
+    {
+        use crate::provider::compat::CompatProvider;
+        let deser_provider = provider.as_deserializing();
+        let compat_provider = CompatProvider(&deser_provider, &provider);
+        Self::try_new_internal(
+            &compat_provider,
+            &ExternalLoaderBuffer(provider),
+            prefs,
+            field_set_with_options.get_field(),
+        )
+    }
+
+    #[doc = icu_provider::gen_buffer_unstable_docs!(UNSTABLE, Self::try_new)]
+    pub fn try_new_unstable<P>(
+        provider: &P,
+        prefs: DateTimeFormatterPreferences,
+        field_set_with_options: FSet,
+    ) -> Result<Self, DateTimeFormatterLoadError>
+    where
+        P: ?Sized
+            + AllAnyCalendarFormattingDataMarkers<FSet>
+            + AllAnyCalendarExternalDataMarkers
+            + DataProvider<DatetimePatternsIntervalV1>,
+    {
+        Self::try_new_internal(
+            provider,
+            &ExternalLoaderUnstable(provider),
+            prefs,
+            field_set_with_options.get_field(),
+        )
+    }
+}
+
+impl<FSet: DateTimeMarkers> DateTimeIntervalFormatter<FSet>
+where
+    FSet::D: DateDataMarkers,
+    FSet::T: TimeMarkers,
+    FSet::Z: ZoneMarkers,
+{
+    fn try_new_internal<P, L>(
+        provider: &P,
+        loader: &L,
+        prefs: DateTimeFormatterPreferences,
+        field_set_with_options: CompositeFieldSet,
+    ) -> Result<Self, DateTimeFormatterLoadError>
+    where
+        P: ?Sized
+            + AllAnyCalendarFormattingDataMarkers<FSet>
+            + DataProvider<DatetimePatternsIntervalV1>,
+        L: DecimalFormatterLoader + FormattableAnyCalendarLoader,
+    {
+        let formatter =
+            DateTimeFormatter::try_new_internal(provider, loader, prefs, field_set_with_options)?;
+        // Date interval patterns are only available for the Gregorian calendar
+        let is_gregorian = formatter.calendar.any_calendar().kind() == AnyCalendarKind::Gregorian;
+        let hour_cycle = prefs.hour_cycle.map(fields::Hour::from_hour_cycle);
+        let (attributes, use_patterns, glued_only) = match field_set_with_options {
+            CompositeFieldSet::Date(field_set) => {
+                (Some(Ok(field_set.id_str())), is_gregorian, false)
+            }
+            CompositeFieldSet::CalendarPeriod(field_set) => {
+                (Some(Ok(field_set.id_str())), is_gregorian, false)
+            }
+            CompositeFieldSet::Time(field_set) => (Some(Err(field_set)), true, false),
+            CompositeFieldSet::DateTime(field_set) => {
+                (Some(Err(field_set.to_time_field_set())), true, true)
+            }
+            CompositeFieldSet::DateZone(combo) => (Some(Ok(combo.dt().id_str())), false, false),
+            CompositeFieldSet::TimeZone(combo) => (Some(Err(combo.dt())), false, false),
+            CompositeFieldSet::DateTimeZone(combo) => {
+                (Some(Err(combo.dt().to_time_field_set())), false, false)
+            }
+            CompositeFieldSet::Zone(_) => (None, false, false),
+        };
+        let locale = DatetimePatternsIntervalV1::INFO.make_locale(prefs.locale_preferences);
+        let load = |attributes| {
+            provider.load(DataRequest {
+                id: DataIdentifierBorrowed::for_marker_attributes_and_locale(attributes, &locale),
+                ..Default::default()
+            })
+        };
+        let patterns = match attributes {
+            Some(Ok(attributes)) => Some(load(attributes)?.payload),
+            // Time patterns depend on the hour cycle. As in `DateTimeFormatter`, first try
+            // the explicit hour cycle, and then the default hour cycle.
+            Some(Err(field_set)) => {
+                let response = match hour_cycle {
+                    Some(hour_cycle) => load(field_set.id_str_for_hour_cycle(Some(hour_cycle)))
+                        .allow_identifier_not_found()?,
+                    None => None,
+                };
+                Some(match response {
+                    Some(response) => response.payload,
+                    None => load(TimeFieldSet::id_str_for_hour_cycle(field_set, None))?.payload,
+                })
+            }
+            None => None,
+        };
+        Ok(Self {
+            formatter,
+            patterns,
+            use_patterns,
+            glued_only,
+        })
+    }
+}
+
+impl<FSet: DateTimeMarkers> DateTimeIntervalFormatter<FSet>
+where
+    FSet::D: DateInputMarkers,
+    FSet::T: TimeMarkers,
+    FSet::Z: ZoneMarkers,
+{
+    /// Formats the interval between two datetimes after first converting them
+    /// to the formatter's calendar.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu::datetime::fieldsets::YMD;
+    /// use icu::datetime::input::Date;
+    /// use icu::datetime::parts;
+    /// use icu::datetime::DateTimeIntervalFormatter;
+    /// use icu::locale::locale;
+    /// use writeable::assert_writeable_parts_eq;
+    ///
+    /// let formatter =
+    ///     DateTimeIntervalFormatter::try_new(locale!("en").into(), YMD::long())
+    ///         .unwrap();
+    ///
+    /// assert_writeable_parts_eq!(
+    ///     formatter.format(
+    ///         &Date::try_new_iso(2025, 1, 3).unwrap(),
+    ///         &Date::try_new_iso(2025, 1, 7).unwrap()
+    ///     ),
+    ///     "January 3 – 7, 2025",
+    ///     [
+    ///         (0, 9, parts::START_RANGE),
+    ///         (0, 7, parts::MONTH),
+    ///         (8, 9, icu::decimal::parts::INTEGER),
+    ///         (8, 9, parts::DAY),
+    ///         (18, 19, icu::decimal::parts::INTEGER),
+    ///         (18, 19, parts::DAY),
+    ///         (18, 19, parts::END_RANGE),
+    ///         (21, 25, icu::decimal::parts::INTEGER),
+    ///         (21, 25, parts::YEAR),
+    ///     ]
+    /// );
+    /// ```
+    pub fn format<'a, I>(&'a self, start: &I, end: &I) -> FormattedDateTimeInterval<'a>
+    where
+        I: ?Sized + ConvertCalendar,
+        I::Converted<'a>: Sized + AllInputMarkers<FSet>,
+    {
+        let calendar = self.formatter.calendar.any_calendar();
+        let start = DateTimeInputUnchecked::extract_from_neo_input::<
+            FSet::D,
+            FSet::T,
+            FSet::Z,
+            I::Converted<'a>,
+        >(&start.to_calendar(calendar));
+        let end = DateTimeInputUnchecked::extract_from_neo_input::<
+            FSet::D,
+            FSet::T,
+            FSet::Z,
+            I::Converted<'a>,
+        >(&end.to_calendar(calendar));
+        let kind = self.select(&start, &end);
+        FormattedDateTimeInterval {
+            kind,
+            start,
+            end,
+            names: self.formatter.names.as_borrowed(),
+        }
+    }
+
+    fn select(
+        &self,
+        start: &DateTimeInputUnchecked,
+        end: &DateTimeInputUnchecked,
+    ) -> IntervalKind<'_> {
+        let selection = &self.formatter.selection;
+        let start_pattern = selection.select(start);
+        let end_pattern = selection.select(end);
+        let fallback = IntervalKind::Fallback {
+            start: start_pattern,
+            end: end_pattern,
+            fallback: self
+                .patterns
+                .as_ref()
+                .map(|patterns| patterns.get().fallback.items.as_slice())
+                .unwrap_or(DEFAULT_FALLBACK),
+        };
+
+        // A bit set of the displayed fields, indexed by `DifferenceField`
+        let mut displayed = 0u8;
+        for item in start_pattern.iter_items() {
+            if let PatternItem::Field(field) = item {
+                if let Some(field) = DifferenceField::for_symbol(field.symbol) {
+                    displayed |= field.bit();
+                }
+            }
+        }
+        if start.zone_id != end.zone_id || start.zone_offset != end.zone_offset {
+            // There are no interval patterns for time zones
+            return fallback;
+        }
+        let Some(mut difference) = DifferenceField::greatest_difference(start, end) else {
+            return IntervalKind::Single(start_pattern);
+        };
+        if difference == DifferenceField::Era && displayed & difference.bit() == 0 {
+            difference = DifferenceField::Year;
+        }
+        if difference == DifferenceField::DayPeriod && displayed & difference.bit() == 0 {
+            difference = DifferenceField::Hour;
+        }
+        if displayed & difference.bit() == 0 {
+            // The fields that differ are not displayed. If no smaller field is displayed,
+            // the start and the end look the same.
+            return if displayed >> (difference as u8) != 0 {
+                fallback
+            } else {
+                IntervalKind::Single(start_pattern)
+            };
+        }
+
+        let (Some(patterns), true) = (self.patterns.as_ref(), self.use_patterns) else {
+            return fallback;
+        };
+        let Some(difference) = difference.to_interval_field() else {
+            return fallback;
+        };
+        let Some(pieces) = start_pattern.interval_pieces() else {
+            return fallback;
+        };
+        let Some((length, variant)) = Self::select_length_and_variant(selection, start, end) else {
+            return fallback;
+        };
+        match pieces {
+            IntervalPieces::Single(_) if self.glued_only => return fallback,
+            IntervalPieces::Glued { .. } if difference <= IntervalField::Day => return fallback,
+            _ => (),
+        }
+        let key = IntervalPatternKey {
+            length: match length {
+                crate::options::Length::Long => 0,
+                crate::options::Length::Medium => 1,
+                crate::options::Length::Short => 2,
+            },
+            variant: match variant {
+                PackedSkeletonVariant::Standard => 0,
+                PackedSkeletonVariant::Variant0 => 1,
+                PackedSkeletonVariant::Variant1 => 2,
+            },
+            difference,
+        };
+        match patterns.get().get(key) {
+            Some(pattern) => IntervalKind::Interval {
+                pieces,
+                pattern,
+                metadata: start_pattern.metadata(),
+            },
+            None => fallback,
+        }
+    }
+
+    /// Returns the length and variant of the patterns selected for the start and the end,
+    /// or `None` if they are different.
+    fn select_length_and_variant(
+        selection: &DateTimeZonePatternSelectionData,
+        start: &DateTimeInputUnchecked,
+        end: &DateTimeInputUnchecked,
+    ) -> Option<(crate::options::Length, PackedSkeletonVariant)> {
+        let start = selection.select_length_and_variant(start)?;
+        let end = selection.select_length_and_variant(end)?;
+        (start == end).then_some(start)
+    }
+}
+
+/// A field that can differ between the start and the end of an interval,
+/// from the largest to the smallest.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
+enum DifferenceField {
+    Era = 0,
+    Year = 1,
+    Month = 2,
+    Day = 3,
+    DayPeriod = 4,
+    Hour = 5,
+    Minute = 6,
+    Second = 7,
+}
+
+impl DifferenceField {
+    fn bit(self) -> u8 {
+        1 << (self as u8)
+    }
+
+    fn for_symbol(symbol: FieldSymbol) -> Option<Self> {
+        Some(match symbol {
+            FieldSymbol::Era => Self::Era,
+            FieldSymbol::Year(_) => Self::Year,
+            FieldSymbol::Month(_) => Self::Month,
+            FieldSymbol::Week(_) | FieldSymbol::Day(_) | FieldSymbol::Weekday(_) => Self::Day,
+            FieldSymbol::DayPeriod(_) => Self::DayPeriod,
+            FieldSymbol::Hour(_) => Self::Hour,
+            FieldSymbol::Minute => Self::Minute,
+            FieldSymbol::Second(_) | FieldSymbol::DecimalSecond(_) => Self::Second,
+            FieldSymbol::TimeZone(_) => return None,
+        })
+    }
+
+    fn to_interval_field(self) -> Option<IntervalField> {
+        Some(match self {
+            Self::Era => IntervalField::Era,
+            Self::Year => IntervalField::Year,
+            Self::Month => IntervalField::Month,
+            Self::Day => IntervalField::Day,
+            Self::DayPeriod => IntervalField::DayPeriod,
+            Self::Hour => IntervalField::Hour,
+            Self::Minute => IntervalField::Minute,
+            // CLDR has no interval patterns with seconds
+            Self::Second => return None,
+        })
+    }
+
+    /// Returns the largest field that differs between the start and the end.
+    fn greatest_difference(
+        start: &DateTimeInputUnchecked,
+        end: &DateTimeInputUnchecked,
+    ) -> Option<Self> {
+        let era = |input: &DateTimeInputUnchecked| input.year.and_then(|y| y.era()).map(|e| e.era);
+        let is_pm = |input: &DateTimeInputUnchecked| input.hour.map(|h| h.number() >= 12);
+        if era(start) != era(end) {
+            Some(Self::Era)
+        } else if start.year != end.year {
+            Some(Self::Year)
+        } else if start.month != end.month {
+            Some(Self::Month)
+        } else if start.day_of_month != end.day_of_month || start.iso_weekday != end.iso_weekday {
+            Some(Self::Day)
+        } else if is_pm(start) != is_pm(end) {
+            Some(Self::DayPeriod)
+        } else if start.hour != end.hour {
+            Some(Self::Hour)
+        } else if start.minute != end.minute {
+            Some(Self::Minute)
+        } else if start.second != end.second || start.subsecond != end.subsecond {
+            Some(Self::Second)
+        } else {
+            None
+        }
+    }
+}
+
+#[derive(Debug)]
+enum IntervalKind<'a> {
+    /// The start and the end look the same, so only the start is formatted.
+    Single(DateTimeZonePatternDataBorrowed<'a>),
+    /// The start and the end are formatted separately and joined by the fallback pattern.
+    Fallback {
+        start: DateTimeZonePatternDataBorrowed<'a>,
+        end: DateTimeZonePatternDataBorrowed<'a>,
+        fallback: &'a ZeroSlice<GenericPatternItem>,
+    },
+    /// The interval pattern replaces the date or the time pattern in the pieces.
+    Interval {
+        pieces: IntervalPieces<'a>,
+        pattern: PatternBorrowed<'a>,
+        metadata: PatternMetadata,
+    },
+}
+
+/// An intermediate type during a datetime interval formatting operation.
+///
+/// Not intended to be stored: convert to a string first.
+#[derive(Debug)]
+pub struct FormattedDateTimeInterval<'a> {
+    kind: IntervalKind<'a>,
+    start: DateTimeInputUnchecked,
+    end: DateTimeInputUnchecked,
+    names: RawDateTimeNamesBorrowed<'a>,
+}
+
+impl FormattedDateTimeInterval<'_> {
+    fn write_items<S: PartsWrite + ?Sized>(
+        &self,
+        metadata: PatternMetadata,
+        items: impl Iterator<Item = PatternItem>,
+        input: &DateTimeInputUnchecked,
+        sink: &mut S,
+    ) -> Result<Result<(), FormattedDateTimePatternError>, fmt::Error> {
+        try_write_pattern_items(
+            metadata,
+            items,
+            input,
+            &self.names,
+            self.names.decimal_formatter,
+            sink,
+        )
+    }
+
+    fn write_in_part<S: PartsWrite + ?Sized>(
+        &self,
+        part: writeable::Part,
+        metadata: PatternMetadata,
+        items: impl Iterator<Item = PatternItem>,
+        input: &DateTimeInputUnchecked,
+        sink: &mut S,
+    ) -> Result<Result<(), FormattedDateTimePatternError>, fmt::Error> {
+        let mut r = Ok(());
+        let mut items = Some(items);
+        sink.with_part(part, |w| {
+            if let Some(items) = items.take() {
+                r = self.write_items(metadata, items, input, w)?;
+            }
+            Ok(())
+        })?;
+        Ok(r)
+    }
+
+    /// Writes an interval pattern, where the end of the interval starts at the
+    /// first field that repeats an earlier field.
+    fn write_interval<S: PartsWrite + ?Sized>(
+        &self,
+        metadata: PatternMetadata,
+        items: ItemsAndOptions,
+        sink: &mut S,
+    ) -> Result<Result<(), FormattedDateTimePatternError>, fmt::Error> {
+        let is_repeated = |index: usize, item: PatternItem| {
+            matches!(item, PatternItem::Field(_))
+                && items.iter_items().take(index).any(|other| other == item)
+        };
+        let Some(split) = items
+            .iter_items()
+            .enumerate()
+            .position(|(index, item)| is_repeated(index, item))
+        else {
+            debug_assert!(false, "interval pattern without repeated field");
+            return self.write_items(metadata, items.iter_items(), &self.start, sink);
+        };
+        // The parts cover the fields of the start and the fields of the end that repeat
+        // the start, without the literals around them and the fields that are shared.
+        let start_end = items
+            .iter_items()
+            .take(split)
+            .enumerate()
+            .filter(|(_, item)| matches!(item, PatternItem::Field(_)))
+            .last()
+            .map(|(index, _)| index + 1)
+            .unwrap_or(split);
+        let end_end = items
+            .iter_items()
+            .enumerate()
+            .skip(split)
+            .filter(|&(_, item)| {
+                matches!(item, PatternItem::Field(_))
+                    && items.iter_items().take(split).any(|other| other == item)
+            })
+            .last()
+            .map(|(index, _)| index + 1)
+            .unwrap_or(split);
+
+        let mut r = self.write_in_part(
+            parts::START_RANGE,
+            metadata,
+            items.iter_items().take(start_end),
+            &self.start,
+            sink,
+        )?;
+        r = r.and(self.write_items(
+            metadata,
+            items.iter_items().take(split).skip(start_end),
+            &self.start,
+            sink,
+        )?);
+        r = r.and(self.write_in_part(
+            parts::END_RANGE,
+            metadata,
+            items.iter_items().take(end_end).skip(split),
+            &self.end,
+            sink,
+        )?);
+        r = r.and(self.write_items(metadata, items.iter_items().skip(end_end), &self.end, sink)?);
+        Ok(r)
+    }
+}
+
+impl Writeable for FormattedDateTimeInterval<'_> {
+    fn write_to_parts<S: PartsWrite + ?Sized>(&self, sink: &mut S) -> Result<(), fmt::Error> {
+        let result = match self.kind {
+            IntervalKind::Single(pattern) => {
+                self.write_items(pattern.metadata(), pattern.iter_items(), &self.start, sink)
+            }
+            IntervalKind::Fallback {
+                start,
+                end,
+                fallback,
+            } => {
+                let mut r = Ok(());
+                for item in fallback.iter() {
+                    r = r.and(match item {
+                        GenericPatternItem::Placeholder(0) => self.write_in_part(
+                            parts::START_RANGE,
+                            start.metadata(),
+                            start.iter_items(),
+                            &self.start,
+                            sink,
+                        )?,
+                        GenericPatternItem::Placeholder(_) => self.write_in_part(
+                            parts::END_RANGE,
+                            end.metadata(),
+                            end.iter_items(),
+                            &self.end,
+                            sink,
+                        )?,
+                        GenericPatternItem::Literal(c) => {
+                            sink.write_char(c)?;
+                            Ok(())
+                        }
+                    });
+                }
+                Ok(r)
+            }
+            IntervalKind::Interval {
+                pieces: IntervalPieces::Single(options),
+                pattern,
+                ..
+            } => self.write_interval(
+                pattern.metadata,
+                ItemsAndOptions {
+                    items: pattern.items,
+                    ..options
+                },
+                sink,
+            ),
+            IntervalKind::Interval {
+                pieces: IntervalPieces::Glued { date, time, glue },
+                pattern,
+                metadata,
+            } => {
+                // Only the time differs, so the date is formatted once
+                let mut r = Ok(());
+                for item in glue.iter() {
+                    r = r.and(match item {
+                        GenericPatternItem::Placeholder(0) => self.write_interval(
+                            pattern.metadata,
+                            ItemsAndOptions {
+                                items: pattern.items,
+                                ..time
+                            },
+                            sink,
+                        )?,
+                        GenericPatternItem::Placeholder(1) => {
+                            self.write_items(metadata, date.iter_items(), &self.start, sink)?
+                        }
+                        GenericPatternItem::Placeholder(_) => Ok(()),
+                        GenericPatternItem::Literal(c) => {
+                            sink.write_char(c)?;
+                            Ok(())
+                        }
+                    });
+                }
+                Ok(r)
+            }
+        };
+        // As in `FormattedDateTime`, an error should not occur in normal usage.
+        match result {
+            Ok(Ok(())) => Ok(()),
+            Err(fmt::Error) => Err(fmt::Error),
+            Ok(Err(e)) => {
+                debug_assert!(
+                    false,
+                    "unexpected error in FormattedDateTimeInterval: {e:?}"
+                );
+                Ok(())
+            }
+        }
+    }
+}
+
+impl_display_with_writeable!(FormattedDateTimeInterval<'_>);
+
+#[cfg(test)]
+#[cfg(feature = "compiled_data")]
+mod tests {
+    use super::*;
+    use crate::fieldsets::{self, T, YMD, YMDT};
+    use crate::input::{Date, DateTime, Time};
+    use crate::options::TimePrecision;
+    use icu_locale_core::locale;
+    use writeable::{assert_writeable_eq, assert_writeable_parts_eq};
+
+    fn datetime(
+        year: i32,
+        month: u8,
+        day: u8,
+        hour: u8,
+        minute: u8,
+    ) -> DateTime<icu_calendar::Iso> {
+        DateTime {
+            date: Date::try_new_iso(year, month, day).unwrap(),
+            time: Time::try_new(hour, minute, 0, 0).unwrap(),
+        }
+    }
+
+    #[test]
+    fn test_date_lengths() {
+        let start = Date::try_new_iso(2025, 1, 3).unwrap();
+        let end = Date::try_new_iso(2025, 1, 7).unwrap();
+        for (field_set, expected) in [
+            (YMD::long(), "January 3\u{2009}–\u{2009}7, 2025"),
+            (YMD::medium(), "Jan 3\u{2009}–\u{2009}7, 2025"),
+            (YMD::short(), "1/3/25\u{2009}–\u{2009}1/7/25"),
+        ] {
+            let formatter =
+                DateTimeIntervalFormatter::try_new(locale!("en").into(), field_set).unwrap();
+            assert_writeable_eq!(formatter.format(&start, &end), expected);
+        }
+    }
+
+    #[test]
+    fn test_date_locales() {
+        let start = Date::try_new_iso(2025, 1, 3).unwrap();
+        let end = Date::try_new_iso(2025, 2, 7).unwrap();
+        for (locale, expected) in [
+            (locale!("fr"), "3 janv.\u{2009}–\u{2009}7 févr. 2025"),
+            (locale!("es"), "3 ene\u{2009}–\u{2009}7 feb 2025"),
+            (locale!("ja"), "2025/01/03～2025/02/07"),
+        ] {
+            let formatter =
+                DateTimeIntervalFormatter::try_new(locale.clone().into(), YMD::medium()).unwrap();
+            assert_writeable_eq!(formatter.format(&start, &end), expected, "{locale}");
+        }
+    }
+
+    #[test]
+    fn test_non_gregorian_fallback() {
+        let formatter =
+            DateTimeIntervalFormatter::try_new(locale!("en-u-ca-hebrew").into(), YMD::medium())
+                .unwrap();
+        assert_writeable_eq!(
+            formatter.format(
+                &Date::try_new_iso(2025, 1, 3).unwrap(),
+                &Date::try_new_iso(2025, 1, 7).unwrap()
+            ),
+            "3 Tevet 5785\u{2009}–\u{2009}7 Tevet 5785"
+        );
+    }
+
+    #[test]
+    fn test_time() {
+        let formatter = DateTimeIntervalFormatter::try_new(locale!("en").into(), T::hm()).unwrap();
+        // Only the minutes differ
+        assert_writeable_eq!(
+            formatter.format(
+                &Time::try_new(10, 0, 0, 0).unwrap(),
+                &Time::try_new(10, 30, 0, 0).unwrap()
+            ),
+            "10:00\u{2009}–\u{2009}10:30\u{202f}AM"
+        );
+        // The seconds are not displayed
+        assert_writeable_eq!(
+            formatter.format(
+                &Time::try_new(10, 0, 0, 0).unwrap(),
+                &Time::try_new(10, 0, 30, 0).unwrap()
+            ),
+            "10:00\u{202f}AM"
+        );
+
+        let formatter =
+            DateTimeIntervalFormatter::try_new(locale!("en-u-hc-h23").into(), T::hm()).unwrap();
+        assert_writeable_eq!(
+            formatter.format(
+                &Time::try_new(10, 0, 0, 0).unwrap(),
+                &Time::try_new(13, 30, 0, 0).unwrap()
+            ),
+            "10:00\u{2009}–\u{2009}13:30"
+        );
+
+        // There are no interval patterns with seconds
+        let formatter = DateTimeIntervalFormatter::try_new(locale!("en").into(), T::hms()).unwrap();
+        assert_writeable_eq!(
+            formatter.format(
+                &Time::try_new(10, 0, 0, 0).unwrap(),
+                &Time::try_new(10, 0, 30, 0).unwrap()
+            ),
+            "10:00:00\u{202f}AM\u{2009}–\u{2009}10:00:30\u{202f}AM"
+        );
+    }
+
+    #[test]
+    fn test_date_time() {
+        let formatter = DateTimeIntervalFormatter::try_new(
+            locale!("en").into(),
+            YMDT::medium().with_time_precision(TimePrecision::Minute),
+        )
+        .unwrap();
+        // Same day: the date is formatted once
+        assert_writeable_parts_eq!(
+            formatter.format(&datetime(2025, 1, 3, 10, 0), &datetime(2025, 1, 3, 11, 30)),
+            "Jan 3, 2025, 10:00\u{2009}–\u{2009}11:30\u{202f}AM",
+            [
+                (0, 3, parts::MONTH),
+                (4, 5, icu_decimal::parts::INTEGER),
+                (4, 5, parts::DAY),
+                (7, 11, icu_decimal::parts::INTEGER),
+                (7, 11, parts::YEAR),
+                (13, 18, parts::START_RANGE),
+                (13, 15, icu_decimal::parts::INTEGER),
+                (13, 15, parts::HOUR),
+                (16, 18, icu_decimal::parts::INTEGER),
+                (16, 18, parts::MINUTE),
+                (27, 32, parts::END_RANGE),
+                (27, 29, icu_decimal::parts::INTEGER),
+                (27, 29, parts::HOUR),
+                (30, 32, icu_decimal::parts::INTEGER),
+                (30, 32, parts::MINUTE),
+                (35, 37, parts::DAY_PERIOD),
+            ]
+        );
+        // Different days
+        assert_writeable_parts_eq!(
+            formatter.format(&datetime(2025, 1, 3, 10, 0), &datetime(2025, 1, 4, 11, 30)),
+            "Jan 3, 2025, 10:00\u{202f}AM\u{2009}–\u{2009}Jan 4, 2025, 11:30\u{202f}AM",
+            [
+                (0, 23, parts::START_RANGE),
+                (0, 3, parts::MONTH),
+                (4, 5, icu_decimal::parts::INTEGER),
+                (4, 5, parts::DAY),
+                (7, 11, icu_decimal::parts::INTEGER),
+                (7, 11, parts::YEAR),
+                (13, 15, icu_decimal::parts::INTEGER),
+                (13, 15, parts::HOUR),
+                (16, 18, icu_decimal::parts::INTEGER),
+                (16, 18, parts::MINUTE),
+                (21, 23, parts::DAY_PERIOD),
+                (32, 55, parts::END_RANGE),
+                (32, 35, parts::MONTH),
+                (36, 37, icu_decimal::parts::INTEGER),
+                (36, 37, parts::DAY),
+                (39, 43, icu_decimal::parts::INTEGER),
+                (39, 43, parts::YEAR),
+                (45, 47, icu_decimal::parts::INTEGER),
+                (45, 47, parts::HOUR),
+                (48, 50, icu_decimal::parts::INTEGER),
+                (48, 50, parts::MINUTE),
+                (53, 55, parts::DAY_PERIOD),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_zone_fallback() {
+        let formatter = DateTimeIntervalFormatter::try_new(
+            locale!("en").into(),
+            fieldsets::zone::LocalizedOffsetShort,
+        )
+        .unwrap();
+        let zone = |hours| {
+            icu_time::TimeZone::UNKNOWN
+                .with_offset(icu_time::zone::UtcOffset::try_from_seconds(hours * 3600).ok())
+        };
+        assert_writeable_eq!(
+            formatter.format(&zone(-8), &zone(-7)),
+            "GMT-8\u{2009}–\u{2009}GMT-7"
+        );
+        assert_writeable_eq!(formatter.format(&zone(-8), &zone(-8)), "GMT-8");
+    }
+}
@@ -129,8 +129,10 @@ mod error;
 mod external_loaders;
 pub mod fieldsets;
 mod format;
+mod interval;
 mod neo;
 pub mod options;
+pub mod parse;
 pub mod parts;
 pub mod pattern;
 pub mod provider;
@@ -140,12 +142,14 @@ pub(crate) mod size_test_macro;
 pub mod unchecked;
 
 pub use error::{DateTimeFormatterLoadError, MismatchedCalendarError};
+pub use interval::{DateTimeIntervalFormatter, FormattedDateTimeInterval};
 
 pub use neo::DateTimeFormatter;
 pub use neo::DateTimeFormatterPreferences;
 pub use neo::FixedCalendarDateTimeFormatter;
 pub use neo::FormattedDateTime;
 pub use neo::NoCalendarFormatter;
+pub use parse::{DateTimeParser, FixedCalendarDateTimeParser};
 
 /// Locale preferences used by this crate
 pub mod preferences {
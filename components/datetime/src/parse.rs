@@ -0,0 +1,1582 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Parsing of localized date and time strings.
+//!
+//! The parsers in this module are the inverse of the formatters in this crate: they are
+//! built from the same field sets and load the same patterns and names, and they read a
+//! string in the form the formatter would have written it.
+//!
+//! # Examples
+//!
+//! ```
+//! use icu::datetime::fieldsets::YMD;
+//! use icu::datetime::input::Date;
+//! use icu::datetime::parse::ParseLeniency;
+//! use icu::datetime::DateTimeParser;
+//! use icu::locale::locale;
+//!
+//! let parser = DateTimeParser::try_new(locale!("es").into(), YMD::medium()).unwrap();
+//!
+//! assert_eq!(
+//!     parser.parse_date("15 ene 2025"),
+//!     Ok(Date::try_new_iso(2025, 1, 15).unwrap())
+//! );
+//!
+//! // User input often deviates from the canonical format:
+//! let parser = parser.with_leniency(ParseLeniency::Lenient);
+//! assert_eq!(
+//!     parser.parse_date("15 ENE. 2025"),
+//!     Ok(Date::try_new_iso(2025, 1, 15).unwrap())
+//! );
+//! ```
+
+use crate::error::ErrorField;
+use crate::neo::{DateTimeFormatter, FixedCalendarDateTimeFormatter};
+use crate::pattern::{
+    GetNameForDayPeriodError, GetNameForMonthError, GetNameForWeekdayError, MonthPlaceholderValue,
+    RawDateTimeNamesBorrowed,
+};
+use crate::provider::fields::{self, Field, FieldLength, FieldSymbol, Second, Year};
+use crate::provider::neo::YearNames;
+use crate::provider::pattern::PatternItem;
+use crate::raw::neo::DateTimeZonePatternSelectionData;
+use crate::scaffold::*;
+use crate::unchecked::MissingInputFieldKind;
+use alloc::string::String;
+use alloc::vec::Vec;
+use icu_calendar::types::{DayOfWeekInMonth, MonthCode, Weekday};
+use icu_calendar::{AsCalendar, Calendar, Date, DateError, Iso, Ref};
+use icu_decimal::DecimalFormatter;
+use icu_pattern::PatternItem as OffsetPatternItem;
+use icu_time::zone::UtcOffset;
+use icu_time::{DateTime, Hour, Time, ZonedDateTime};
+use tinystr::TinyStr16;
+
+/// How strictly a parser matches its input against the localized pattern.
+///
+/// In both modes, the different space characters used by CLDR (such as U+0020 SPACE,
+/// U+00A0 NO-BREAK SPACE and U+202F NARROW NO-BREAK SPACE) are interchangeable.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+#[non_exhaustive]
+pub enum ParseLeniency {
+    /// The input must have the shape that the formatter produces.
+    ///
+    /// Numbers must be written with the locale's digits and padded to the field width,
+    /// names must match exactly, and the weekday, if present, must agree with the date.
+    #[default]
+    Strict,
+    /// The input may deviate from the formatter output in ways common in user input.
+    ///
+    /// Names are matched case-insensitively and may omit a trailing full stop, ASCII digits
+    /// are accepted in addition to the locale's digits, numbers need no padding, and
+    /// whitespace and punctuation between fields are optional.
+    Lenient,
+}
+
+/// The kind of error that occurred while parsing a date or time.
+#[derive(Debug, Copy, Clone, PartialEq, displaydoc::Display)]
+#[non_exhaustive]
+pub enum DateTimeParseErrorKind {
+    /// The input did not contain a literal character required by the pattern.
+    #[displaydoc("Expected the literal {0:?}")]
+    ExpectedLiteral(char),
+    /// The input did not contain a number for a numeric field.
+    #[displaydoc("Expected a number for the field {0:?}")]
+    ExpectedNumber(ErrorField),
+    /// The input did not match any of the names for a field.
+    #[displaydoc("No name matched for the field {0:?}")]
+    UnknownName(ErrorField),
+    /// The names for a field were not loaded.
+    #[displaydoc("Names for the field {0:?} are not loaded")]
+    NamesNotLoaded(ErrorField),
+    /// The field cannot be parsed.
+    ///
+    /// This currently happens for time zone names, which can only be parsed if
+    /// they were formatted as an offset.
+    #[displaydoc("The field {0:?} is not supported in parsing")]
+    UnsupportedField(ErrorField),
+    /// The input continued after the end of the pattern.
+    #[displaydoc("Unexpected input after the end of the pattern")]
+    TrailingInput,
+    /// The pattern did not contain a field required for the requested output type.
+    #[displaydoc("Missing input field {0:?}")]
+    MissingInputField(MissingInputFieldKind),
+    /// The pattern did not contain a time zone offset.
+    #[displaydoc("Missing time zone offset")]
+    MissingTimeZoneOffset,
+    /// The parsed fields do not form a valid date.
+    #[displaydoc("{0}")]
+    InvalidDate(DateError),
+    /// The parsed fields do not form a valid time of day.
+    #[displaydoc("Invalid time of day")]
+    InvalidTime,
+    /// The parsed time zone offset is out of range.
+    #[displaydoc("Invalid time zone offset")]
+    InvalidOffset,
+    /// A field disagrees with the date described by the other fields,
+    /// such as a weekday that does not match the day of the month.
+    #[displaydoc("The field {0:?} is inconsistent with the other fields")]
+    InconsistentField(ErrorField),
+}
+
+/// An error from parsing a date or time, together with the position in the input where it occurred.
+#[derive(Debug, Copy, Clone, PartialEq, displaydoc::Display)]
+#[displaydoc("{kind} at index {index}")]
+#[non_exhaustive]
+pub struct DateTimeParseError {
+    /// What went wrong.
+    pub kind: DateTimeParseErrorKind,
+    /// The UTF-8 byte index into the input at which parsing failed.
+    ///
+    /// For errors that concern the combination of several fields, such as
+    /// [`DateTimeParseErrorKind::InvalidDate`], this is the start of the first of those fields.
+    pub index: usize,
+}
+
+impl core::error::Error for DateTimeParseError {}
+
+impl DateTimeParseError {
+    fn new(kind: DateTimeParseErrorKind, index: usize) -> Self {
+        Self { kind, index }
+    }
+}
+
+/// [`DateTimeParser`] reads localized dates and times in a calendar selected at runtime.
+///
+/// It is constructed from the same field sets as [`DateTimeFormatter`] and accepts the
+/// strings that the corresponding formatter produces. Parsed dates are returned in the
+/// ISO calendar; use [`Date::to_calendar`] to convert them.
+///
+/// For more details, please read the [module docs](crate::parse).
+///
+/// # Examples
+///
+/// ```
+/// use icu::datetime::fieldsets::YMDT;
+/// use icu::datetime::input::{Date, DateTime, Time};
+/// use icu::datetime::parse::DateTimeParseErrorKind;
+/// use icu::datetime::DateTimeParser;
+/// use icu::locale::locale;
+///
+/// let parser =
+///     DateTimeParser::try_new(locale!("en").into(), YMDT::medium()).unwrap();
+///
+/// assert_eq!(
+///     parser.parse_datetime("Jan 15, 2025, 4:09:35\u{202F}PM"),
+///     Ok(DateTime {
+///         date: Date::try_new_iso(2025, 1, 15).unwrap(),
+///         time: Time::try_new(16, 9, 35, 0).unwrap(),
+///     })
+/// );
+///
+/// // The error reports where the input stopped matching:
+/// let error = parser
+///     .parse_datetime("Jan 15 2025, 4:09:35\u{202F}PM")
+///     .unwrap_err();
+/// assert_eq!(error.kind, DateTimeParseErrorKind::ExpectedLiteral(','));
+/// assert_eq!(error.index, 6);
+/// ```
+///
+/// Parsing with the Hebrew calendar:
+///
+/// ```
+/// use icu::datetime::fieldsets::YMD;
+/// use icu::datetime::input::Date;
+/// use icu::datetime::DateTimeParser;
+/// use icu::locale::locale;
+///
+/// let parser =
+///     DateTimeParser::try_new(locale!("en-u-ca-hebrew").into(), YMD::medium())
+///         .unwrap();
+///
+/// assert_eq!(
+///     parser.parse_date("30 Nisan 5784"),
+///     Ok(Date::try_new_iso(2024, 5, 8).unwrap())
+/// );
+/// ```
+#[derive(Debug, Clone)]
+pub struct DateTimeParser<FSet: DateTimeNamesMarker> {
+    formatter: DateTimeFormatter<FSet>,
+    symbols: NumberSymbols,
+    leniency: ParseLeniency,
+}
+
+impl<FSet: DateTimeMarkers> DateTimeParser<FSet>
+where
+    FSet::D: DateDataMarkers,
+    FSet::T: TimeMarkers,
+    FSet::Z: ZoneMarkers,
+    FSet: GetField<crate::fieldsets::enums::CompositeFieldSet>,
+{
+    /// Creates a new [`DateTimeParser`] from compiled data with
+    /// datetime components specified at build time.
+    ///
+    /// This loads the same data as [`DateTimeFormatter::try_new`]. To use other data
+    /// providers, construct a formatter and use [`DateTimeParser::from_formatter`].
+    ///
+    /// ✨ *Enabled with the `compiled_data` Cargo feature.*
+    ///
+    /// [📚 Help choosing a constructor](icu_provider::constructors)
+    #[cfg(feature = "compiled_data")]
+    pub fn try_new(
+        prefs: crate::DateTimeFormatterPreferences,
+        field_set_with_options: FSet,
+    ) -> Result<Self, crate::DateTimeFormatterLoadError>
+    where
+        crate::provider::Baked: AllAnyCalendarFormattingDataMarkers<FSet>,
+    {
+        DateTimeFormatter::try_new(prefs, field_set_with_options).map(Self::from_formatter)
+    }
+}
+
+impl<FSet: DateTimeMarkers> DateTimeParser<FSet> {
+    /// Creates a parser that accepts the output of the given formatter.
+    pub fn from_formatter(formatter: DateTimeFormatter<FSet>) -> Self {
+        Self {
+            symbols: NumberSymbols::new(formatter.names.as_borrowed().decimal_formatter),
+            formatter,
+            leniency: Default::default(),
+        }
+    }
+
+    /// Sets how strictly the input needs to match the localized pattern.
+    pub fn with_leniency(mut self, leniency: ParseLeniency) -> Self {
+        self.leniency = leniency;
+        self
+    }
+
+    fn parse_fields(&self, input: &str) -> Result<ParsedFields, DateTimeParseError> {
+        parse_with_selection(
+            &self.formatter.selection,
+            self.formatter.names.as_borrowed(),
+            &self.symbols,
+            self.leniency,
+            input,
+        )
+    }
+
+    /// Parses a date, returning it in the ISO calendar.
+    pub fn parse_date(&self, input: &str) -> Result<Date<Iso>, DateTimeParseError> {
+        let fields = self.parse_fields(input)?;
+        fields
+            .date(self.formatter.calendar(), self.leniency)
+            .map(|date| date.to_iso())
+    }
+
+    /// Parses a time of day.
+    pub fn parse_time(&self, input: &str) -> Result<Time, DateTimeParseError> {
+        self.parse_fields(input)?.time()
+    }
+
+    /// Parses a date and a time of day, returning the date in the ISO calendar.
+    pub fn parse_datetime(&self, input: &str) -> Result<DateTime<Iso>, DateTimeParseError> {
+        let fields = self.parse_fields(input)?;
+        Ok(DateTime {
+            date: fields
+                .date(self.formatter.calendar(), self.leniency)?
+                .to_iso(),
+            time: fields.time()?,
+        })
+    }
+
+    /// Parses a date, a time of day, and a time zone offset, returning the date in the ISO calendar.
+    ///
+    /// Time zone names can only be parsed if they were formatted as an offset.
+    pub fn parse_zoned_datetime(
+        &self,
+        input: &str,
+    ) -> Result<ZonedDateTime<Iso, UtcOffset>, DateTimeParseError> {
+        let fields = self.parse_fields(input)?;
+        Ok(ZonedDateTime {
+            date: fields
+                .date(self.formatter.calendar(), self.leniency)?
+                .to_iso(),
+            time: fields.time()?,
+            zone: fields.offset()?,
+        })
+    }
+}
+
+/// [`FixedCalendarDateTimeParser`] reads localized dates and times in a calendar selected at
+/// compile time.
+///
+/// It is constructed from the same field sets as [`FixedCalendarDateTimeFormatter`] and accepts
+/// the strings that the corresponding formatter produces.
+///
+/// For more details, please read the [module docs](crate::parse).
+///
+/// # Examples
+///
+/// ```
+/// use icu::calendar::Gregorian;
+/// use icu::datetime::fieldsets::{T, YMD};
+/// use icu::datetime::input::{Date, Time};
+/// use icu::datetime::parse::ParseLeniency;
+/// use icu::datetime::FixedCalendarDateTimeParser;
+/// use icu::locale::locale;
+///
+/// let parser = FixedCalendarDateTimeParser::<Gregorian, _>::try_new(
+///     locale!("de").into(),
+///     YMD::long(),
+/// )
+/// .unwrap();
+///
+/// assert_eq!(
+///     parser.parse_date("3. Oktober 1990", Gregorian),
+///     Ok(Date::try_new_gregorian(1990, 10, 3).unwrap())
+/// );
+///
+/// let parser = FixedCalendarDateTimeParser::<(), _>::try_new(
+///     locale!("en").into(),
+///     T::hm(),
+/// )
+/// .unwrap()
+/// .with_leniency(ParseLeniency::Lenient);
+///
+/// assert_eq!(
+///     parser.parse_time("9:30 pm"),
+///     Ok(Time::try_new(21, 30, 0, 0).unwrap())
+/// );
+/// ```
+#[derive(Debug, Clone)]
+pub struct FixedCalendarDateTimeParser<C: CldrCalendar, FSet: DateTimeNamesMarker> {
+    formatter: FixedCalendarDateTimeFormatter<C, FSet>,
+    symbols: NumberSymbols,
+    leniency: ParseLeniency,
+}
+
+impl<C: CldrCalendar, FSet: DateTimeMarkers> FixedCalendarDateTimeParser<C, FSet>
+where
+    FSet::D: TypedDateDataMarkers<C>,
+    FSet::T: TimeMarkers,
+    FSet::Z: ZoneMarkers,
+    FSet: GetField<crate::fieldsets::enums::CompositeFieldSet>,
+{
+    /// Creates a new [`FixedCalendarDateTimeParser`] from compiled data with
+    /// datetime components specified at build time.
+    ///
+    /// This loads the same data as [`FixedCalendarDateTimeFormatter::try_new`]. To use other
+    /// data providers, construct a formatter and use [`FixedCalendarDateTimeParser::from_formatter`].
+    ///
+    /// ✨ *Enabled with the `compiled_data` Cargo feature.*
+    ///
+    /// [📚 Help choosing a constructor](icu_provider::constructors)
+    #[cfg(feature = "compiled_data")]
+    pub fn try_new(
+        prefs: crate::DateTimeFormatterPreferences,
+        field_set_with_options: FSet,
+    ) -> Result<Self, crate::DateTimeFormatterLoadError>
+    where
+        crate::provider::Baked: AllFixedCalendarFormattingDataMarkers<C, FSet>,
+    {
+        FixedCalendarDateTimeFormatter::try_new(prefs, field_set_with_options)
+            .map(Self::from_formatter)
+    }
+}
+
+impl<C: CldrCalendar, FSet: DateTimeMarkers> FixedCalendarDateTimeParser<C, FSet> {
+    /// Creates a parser that accepts the output of the given formatter.
+    pub fn from_formatter(formatter: FixedCalendarDateTimeFormatter<C, FSet>) -> Self {
+        Self {
+            symbols: NumberSymbols::new(formatter.names.as_borrowed().decimal_formatter),
+            formatter,
+            leniency: Default::default(),
+        }
+    }
+
+    /// Sets how strictly the input needs to match the localized pattern.
+    pub fn with_leniency(mut self, leniency: ParseLeniency) -> Self {
+        self.leniency = leniency;
+        self
+    }
+
+    fn parse_fields(&self, input: &str) -> Result<ParsedFields, DateTimeParseError> {
+        parse_with_selection(
+            &self.formatter.selection,
+            self.formatter.names.as_borrowed(),
+            &self.symbols,
+            self.leniency,
+            input,
+        )
+    }
+
+    /// Parses a date in the given calendar.
+    pub fn parse_date<A>(&self, input: &str, calendar: A) -> Result<Date<A>, DateTimeParseError>
+    where
+        A: AsCalendar<Calendar = C>,
+        C: Calendar,
+    {
+        self.parse_fields(input)?.date(calendar, self.leniency)
+    }
+
+    /// Parses a time of day.
+    pub fn parse_time(&self, input: &str) -> Result<Time, DateTimeParseError> {
+        self.parse_fields(input)?.time()
+    }
+
+    /// Parses a date in the given calendar and a time of day.
+    pub fn parse_datetime<A>(
+        &self,
+        input: &str,
+        calendar: A,
+    ) -> Result<DateTime<A>, DateTimeParseError>
+    where
+        A: AsCalendar<Calendar = C>,
+        C: Calendar,
+    {
+        let fields = self.parse_fields(input)?;
+        Ok(DateTime {
+            date: fields.date(calendar, self.leniency)?,
+            time: fields.time()?,
+        })
+    }
+
+    /// Parses a date in the given calendar, a time of day, and a time zone offset.
+    ///
+    /// Time zone names can only be parsed if they were formatted as an offset.
+    pub fn parse_zoned_datetime<A>(
+        &self,
+        input: &str,
+        calendar: A,
+    ) -> Result<ZonedDateTime<A, UtcOffset>, DateTimeParseError>
+    where
+        A: AsCalendar<Calendar = C>,
+        C: Calendar,
+    {
+        let fields = self.parse_fields(input)?;
+        Ok(ZonedDateTime {
+            date: fields.date(calendar, self.leniency)?,
+            time: fields.time()?,
+            zone: fields.offset()?,
+        })
+    }
+}
+
+/// Tries every pattern the selection can produce and returns the first successful parse.
+///
+/// If no pattern matches, the error that got furthest into the input is returned.
+fn parse_with_selection(
+    selection: &DateTimeZonePatternSelectionData,
+    names: RawDateTimeNamesBorrowed,
+    symbols: &NumberSymbols,
+    leniency: ParseLeniency,
+    input: &str,
+) -> Result<ParsedFields, DateTimeParseError> {
+    let parser = RawParser {
+        names,
+        symbols,
+        leniency,
+    };
+    let mut furthest_error: Option<DateTimeParseError> = None;
+    for pattern in selection.select_all() {
+        let items = pattern.iter_items().collect::<Vec<_>>();
+        match parser.parse_items(&items, input) {
+            Ok(fields) => return Ok(fields),
+            Err(e) => {
+                if furthest_error.is_none_or(|f| e.index > f.index) {
+                    furthest_error = Some(e);
+                }
+            }
+        }
+    }
+    // The selection always contains at least one pattern, so this is not reached in practice.
+    Err(furthest_error.unwrap_or(DateTimeParseError::new(
+        DateTimeParseErrorKind::TrailingInput,
+        0,
+    )))
+}
+
+/// The localized number symbols needed to read numeric fields.
+///
+/// These are computed once when a parser is constructed.
+#[derive(Debug, Clone)]
+struct NumberSymbols {
+    digits: [char; 10],
+    decimal_separator: String,
+    plus_sign: String,
+    minus_sign: String,
+}
+
+impl NumberSymbols {
+    fn new(decimal_formatter: Option<&DecimalFormatter>) -> Self {
+        let Some(formatter) = decimal_formatter else {
+            return Self {
+                digits: ['0', '1', '2', '3', '4', '5', '6', '7', '8', '9'],
+                decimal_separator: ".".into(),
+                plus_sign: "+".into(),
+                minus_sign: "-".into(),
+            };
+        };
+        let symbols = formatter.symbols();
+        Self {
+            digits: *formatter.digits(),
+            decimal_separator: symbols.decimal_separator().into(),
+            plus_sign: symbols.plus_sign_affixes().0.into(),
+            minus_sign: symbols.minus_sign_affixes().0.into(),
+        }
+    }
+}
+
+/// The fields read from the input by a single pattern.
+#[derive(Debug, Default, Clone, Copy)]
+struct ParsedFields {
+    era: Option<ParsedEra>,
+    year: Option<i32>,
+    is_two_digit_year: bool,
+    related_iso: Option<i32>,
+    cyclic_year: Option<(u8, Field)>,
+    month: Option<ParsedMonth>,
+    day_of_month: Option<u8>,
+    day_of_year: Option<(u16, Field)>,
+    day_of_week_in_month: Option<(u8, Field)>,
+    weekday: Option<(Weekday, Field)>,
+    hour: Option<(fields::Hour, u8)>,
+    is_pm: Option<bool>,
+    minute: Option<u8>,
+    second: Option<u8>,
+    subsecond: Option<u32>,
+    offset: Option<UtcOffset>,
+    /// Start of the first date field in the input
+    date_index: Option<usize>,
+    /// Start of the first time field in the input
+    time_index: Option<usize>,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum ParsedEra {
+    /// The index of the era name in [`YearNames::FixedEras`]
+    Index(u8),
+    /// The era code from [`YearNames::VariableEras`]
+    Code(TinyStr16),
+}
+
+#[derive(Debug, Clone, Copy)]
+enum ParsedMonth {
+    /// A month from a numeric field, which is the ordinal month in the year
+    Ordinal(u8),
+    /// A month from a name
+    Code(MonthCode),
+}
+
+fn month_code(number: u8, is_leap: bool) -> Option<MonthCode> {
+    let code = MonthCode::new_normal(number)?;
+    if !is_leap {
+        return Some(code);
+    }
+    let mut bytes = *code.0.all_bytes();
+    bytes[3] = b'L';
+    tinystr::TinyAsciiStr::try_from_raw(bytes)
+        .ok()
+        .map(MonthCode)
+}
+
+impl ParsedFields {
+    fn time(&self) -> Result<Time, DateTimeParseError> {
+        let index = self.time_index.unwrap_or_default();
+        let Some((symbol, value)) = self.hour else {
+            return Err(DateTimeParseError::new(
+                DateTimeParseErrorKind::MissingInputField(MissingInputFieldKind::Hour),
+                index,
+            ));
+        };
+        let invalid = DateTimeParseError::new(DateTimeParseErrorKind::InvalidTime, index);
+        let pm_offset = if self.is_pm.unwrap_or(false) { 12 } else { 0 };
+        let hour = match symbol {
+            fields::Hour::H23 => value,
+            fields::Hour::H12 if (1..=12).contains(&value) => value % 12 + pm_offset,
+            fields::Hour::H11 if value <= 11 => value + pm_offset,
+            _ => return Err(invalid),
+        };
+        Time::try_new(
+            hour,
+            self.minute.unwrap_or_default(),
+            self.second.unwrap_or_default(),
+            self.subsecond.unwrap_or_default(),
+        )
+        .map_err(|_| invalid)
+    }
+
+    fn offset(&self) -> Result<UtcOffset, DateTimeParseError> {
+        self.offset.ok_or(DateTimeParseError::new(
+            DateTimeParseErrorKind::MissingTimeZoneOffset,
+            0,
+        ))
+    }
+
+    fn date<A: AsCalendar>(
+        &self,
+        calendar: A,
+        leniency: ParseLeniency,
+    ) -> Result<Date<A>, DateTimeParseError> {
+        let index = self.date_index.unwrap_or_default();
+        let error = |kind| DateTimeParseError::new(kind, index);
+        let missing = |kind| error(DateTimeParseErrorKind::MissingInputField(kind));
+        let cal = calendar.as_calendar();
+
+        let (era, year) = match (self.era, self.year, self.related_iso) {
+            (Some(ParsedEra::Code(code)), Some(year), _) => (Some(code), year),
+            (Some(ParsedEra::Index(era_index)), Some(year), _) => (
+                Some(era_code_for_index(cal, era_index).ok_or(error(
+                    DateTimeParseErrorKind::InvalidDate(DateError::UnknownEra),
+                ))?),
+                year,
+            ),
+            (None, Some(year), _) if self.is_two_digit_year => {
+                (None, expand_two_digit_year(cal, year))
+            }
+            (None, Some(year), _) => (None, year),
+            (_, None, Some(related_iso)) => (None, related_iso),
+            _ => return Err(missing(MissingInputFieldKind::Year)),
+        };
+        let era = era.as_ref().map(|era| era.as_str());
+        let month_code = match self.month {
+            Some(ParsedMonth::Code(code)) => code,
+            Some(ParsedMonth::Ordinal(ordinal)) => month_code_for_ordinal(cal, era, year, ordinal)
+                .ok_or(error(DateTimeParseErrorKind::InvalidDate(
+                    DateError::Range {
+                        field: "month",
+                        value: ordinal.into(),
+                        min: 1,
+                        max: 13,
+                    },
+                )))?,
+            None => return Err(missing(MissingInputFieldKind::Month)),
+        };
+        let day = self
+            .day_of_month
+            .ok_or(missing(MissingInputFieldKind::DayOfMonth))?;
+        let date = Date::try_new_from_codes(era, year, month_code, day, calendar)
+            .map_err(|e| error(DateTimeParseErrorKind::InvalidDate(e)))?;
+
+        if leniency == ParseLeniency::Strict {
+            let inconsistent =
+                |field| error(DateTimeParseErrorKind::InconsistentField(ErrorField(field)));
+            if let Some((weekday, field)) = self.weekday {
+                if date.day_of_week() != weekday {
+                    return Err(inconsistent(field));
+                }
+            }
+            if let Some((day_of_year, field)) = self.day_of_year {
+                if date.day_of_year().0 != day_of_year {
+                    return Err(inconsistent(field));
+                }
+            }
+            if let Some((day_of_week_in_month, field)) = self.day_of_week_in_month {
+                if DayOfWeekInMonth::from(date.day_of_month()).0 != day_of_week_in_month {
+                    return Err(inconsistent(field));
+                }
+            }
+            if let Some((cyclic_year, field)) = self.cyclic_year {
+                if date.year().cyclic().map(|c| c.year) != Some(cyclic_year) {
+                    return Err(inconsistent(field));
+                }
+            }
+        }
+        Ok(date)
+    }
+}
+
+/// Finds the era code for an index into [`YearNames::FixedEras`].
+fn era_code_for_index<C: Calendar>(calendar: &C, era_index: u8) -> Option<TinyStr16> {
+    // Calendars with fixed eras have very few of them, spread over a wide range of years,
+    // so sampling the start of a few far apart years finds all of them.
+    [-10000, -1000, 1, 1000, 1950, 2000]
+        .into_iter()
+        .find_map(|iso_year| {
+            let iso = Date::try_new_iso(iso_year, 1, 1).ok()?;
+            let era_year = Date::new_from_iso(iso, Ref(calendar)).year().era()?;
+            (era_year.era_index == Some(era_index)).then_some(era_year.era)
+        })
+}
+
+/// Resolves a two-digit year to the century closest to the year 2000.
+///
+/// This is the inverse of the formatter, which only drops the century for years in which it
+/// is unambiguous.
+fn expand_two_digit_year<C: Calendar>(calendar: &C, two_digit_year: i32) -> i32 {
+    let reference_year = Date::try_new_iso(2000, 1, 1)
+        .map(|iso| {
+            Date::new_from_iso(iso, Ref(calendar))
+                .year()
+                .era_year_or_related_iso()
+        })
+        .unwrap_or(2000);
+    let window_start = reference_year - 50;
+    let year = window_start - window_start.rem_euclid(100) + two_digit_year;
+    if year < window_start {
+        year + 100
+    } else {
+        year
+    }
+}
+
+/// Finds the month code for an ordinal month, which depends on the year in lunisolar calendars.
+fn month_code_for_ordinal<C: Calendar>(
+    calendar: &C,
+    era: Option<&str>,
+    year: i32,
+    ordinal: u8,
+) -> Option<MonthCode> {
+    (1..=13)
+        .flat_map(|number| [month_code(number, false), month_code(number, true)])
+        .flatten()
+        .find(|&code| {
+            Date::try_new_from_codes(era, year, code, 1, Ref(calendar))
+                .is_ok_and(|date| date.month().ordinal == ordinal)
+        })
+}
+
+/// A position in the input string.
+#[derive(Debug, Clone, Copy)]
+struct Cursor<'a> {
+    input: &'a str,
+    index: usize,
+}
+
+impl<'a> Cursor<'a> {
+    fn rest(&self) -> &'a str {
+        self.input.get(self.index..).unwrap_or_default()
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.rest().chars().next()
+    }
+
+    fn advance(&mut self, len: usize) {
+        self.index += len;
+    }
+
+    fn skip_whitespace(&mut self) {
+        while let Some(c) = self.peek().filter(|c| c.is_whitespace()) {
+            self.advance(c.len_utf8());
+        }
+    }
+}
+
+/// Parses input against the items of a single pattern.
+struct RawParser<'a, 's> {
+    names: RawDateTimeNamesBorrowed<'a>,
+    symbols: &'s NumberSymbols,
+    leniency: ParseLeniency,
+}
+
+impl RawParser<'_, '_> {
+    fn is_lenient(&self) -> bool {
+        self.leniency == ParseLeniency::Lenient
+    }
+
+    fn parse_items(
+        &self,
+        items: &[PatternItem],
+        input: &str,
+    ) -> Result<ParsedFields, DateTimeParseError> {
+        let mut cursor = Cursor { input, index: 0 };
+        let mut parsed = ParsedFields::default();
+        for (i, item) in items.iter().enumerate() {
+            match *item {
+                PatternItem::Literal(ch) => self.parse_literal(&mut cursor, ch)?,
+                PatternItem::Field(field) => {
+                    if self.is_lenient() {
+                        cursor.skip_whitespace();
+                    }
+                    // Numeric fields without a separator, like `HHmm`, are read with their
+                    // exact width so that the first one does not consume the second one.
+                    let is_abutting = matches!(
+                        items.get(i + 1),
+                        Some(PatternItem::Field(next)) if is_numeric(*next)
+                    );
+                    self.parse_field(&mut cursor, field, is_abutting, &mut parsed)?;
+                }
+            }
+        }
+        if self.is_lenient() {
+            cursor.skip_whitespace();
+        }
+        if !cursor.rest().is_empty() {
+            return Err(DateTimeParseError::new(
+                DateTimeParseErrorKind::TrailingInput,
+                cursor.index,
+            ));
+        }
+        Ok(parsed)
+    }
+
+    fn chars_match(&self, actual: char, expected: char) -> bool {
+        actual == expected
+            || (actual.is_whitespace() && expected.is_whitespace())
+            || (self.is_lenient() && actual.to_lowercase().eq(expected.to_lowercase()))
+    }
+
+    fn parse_literal(&self, cursor: &mut Cursor, expected: char) -> Result<(), DateTimeParseError> {
+        if self.is_lenient() {
+            let start = *cursor;
+            cursor.skip_whitespace();
+            if expected.is_whitespace() {
+                return Ok(());
+            }
+            match cursor.peek() {
+                Some(actual) if self.chars_match(actual, expected) => {
+                    cursor.advance(actual.len_utf8());
+                    Ok(())
+                }
+                // Punctuation between fields is optional in lenient mode
+                _ if !expected.is_alphanumeric() => Ok(()),
+                _ => Err(DateTimeParseError::new(
+                    DateTimeParseErrorKind::ExpectedLiteral(expected),
+                    start.index,
+                )),
+            }
+        } else {
+            match cursor.peek() {
+                Some(actual) if self.chars_match(actual, expected) => {
+                    cursor.advance(actual.len_utf8());
+                    Ok(())
+                }
+                _ => Err(DateTimeParseError::new(
+                    DateTimeParseErrorKind::ExpectedLiteral(expected),
+                    cursor.index,
+                )),
+            }
+        }
+    }
+
+    /// Returns the length of the input that matches the name, if any.
+    fn match_name(&self, rest: &str, name: &str) -> Option<usize> {
+        let name = if self.is_lenient() {
+            name.strip_suffix('.').unwrap_or(name)
+        } else {
+            name
+        };
+        if name.is_empty() {
+            return None;
+        }
+        let mut rest_chars = rest.char_indices();
+        let mut end = 0;
+        for expected in name.chars() {
+            let (i, actual) = rest_chars.next()?;
+            if !self.chars_match(actual, expected) {
+                return None;
+            }
+            end = i + actual.len_utf8();
+        }
+        if self.is_lenient() && rest.get(end..).is_some_and(|r| r.starts_with('.')) {
+            end += 1;
+        }
+        Some(end)
+    }
+
+    /// Matches the longest of the given names and returns its value.
+    fn parse_name<'n, T>(
+        &self,
+        cursor: &mut Cursor,
+        field: Field,
+        names: impl Iterator<Item = (&'n str, T)>,
+    ) -> Result<T, DateTimeParseError> {
+        let rest = cursor.rest();
+        let best = names
+            .filter_map(|(name, value)| Some((self.match_name(rest, name)?, value)))
+            .fold(None, |best: Option<(usize, T)>, candidate| match best {
+                Some(best) if best.0 >= candidate.0 => Some(best),
+                _ => Some(candidate),
+            });
+        match best {
+            Some((len, value)) => {
+                cursor.advance(len);
+                Ok(value)
+            }
+            None => Err(DateTimeParseError::new(
+                DateTimeParseErrorKind::UnknownName(ErrorField(field)),
+                cursor.index,
+            )),
+        }
+    }
+
+    fn digit_value(&self, c: char, allow_ascii: bool) -> Option<u8> {
+        if let Some(i) = self.symbols.digits.iter().position(|&d| d == c) {
+            return u8::try_from(i).ok();
+        }
+        if allow_ascii || self.is_lenient() {
+            return c.to_digit(10).and_then(|d| u8::try_from(d).ok());
+        }
+        None
+    }
+
+    /// Reads between `min_digits` and `max_digits` digits.
+    fn parse_digits(
+        &self,
+        cursor: &mut Cursor,
+        field: Field,
+        min_digits: usize,
+        max_digits: usize,
+        allow_ascii: bool,
+    ) -> Result<(u32, usize), DateTimeParseError> {
+        let start = cursor.index;
+        let mut value: u32 = 0;
+        let mut count = 0;
+        while count < max_digits {
+            let Some(c) = cursor.peek() else {
+                break;
+            };
+            let Some(digit) = self.digit_value(c, allow_ascii) else {
+                break;
+            };
+            let Some(next) = value
+                .checked_mul(10)
+                .and_then(|v| v.checked_add(digit.into()))
+            else {
+                break;
+            };
+            value = next;
+            count += 1;
+            cursor.advance(c.len_utf8());
+        }
+        if count < min_digits.max(1) {
+            cursor.index = start;
+            return Err(DateTimeParseError::new(
+                DateTimeParseErrorKind::ExpectedNumber(ErrorField(field)),
+                start,
+            ));
+        }
+        Ok((value, count))
+    }
+
+    /// Reads a numeric field, applying the padding rules of the formatter in strict mode.
+    fn parse_number(
+        &self,
+        cursor: &mut Cursor,
+        field: Field,
+        max_digits: usize,
+        is_abutting: bool,
+    ) -> Result<u32, DateTimeParseError> {
+        let width = match field.length {
+            FieldLength::NumericOverride(_) => 1,
+            length => length.to_len(),
+        };
+        let min_digits = if self.is_lenient() { 1 } else { width };
+        let max_digits = if is_abutting {
+            width
+        } else {
+            max_digits.max(min_digits)
+        };
+        self.parse_digits(cursor, field, min_digits, max_digits, false)
+            .map(|(value, _)| value)
+    }
+
+    fn parse_field(
+        &self,
+        cursor: &mut Cursor,
+        field: Field,
+        is_abutting: bool,
+        parsed: &mut ParsedFields,
+    ) -> Result<(), DateTimeParseError> {
+        let start = cursor.index;
+        let not_loaded = || {
+            DateTimeParseError::new(
+                DateTimeParseErrorKind::NamesNotLoaded(ErrorField(field)),
+                start,
+            )
+        };
+        let narrow = |value: u32| u8::try_from(value).unwrap_or(u8::MAX);
+        match (field.symbol, field.length) {
+            (FieldSymbol::Era, l) => {
+                parsed.date_index.get_or_insert(start);
+                let era = match self.names.get_year_names(l).map_err(|_| not_loaded())? {
+                    YearNames::FixedEras(names) => self.parse_name(
+                        cursor,
+                        field,
+                        names
+                            .iter()
+                            .zip(0u8..)
+                            .map(|(name, i)| (name, ParsedEra::Index(i))),
+                    )?,
+                    YearNames::VariableEras(map) => self.parse_name(
+                        cursor,
+                        field,
+                        map.a()
+                            .iter()
+                            .zip(map.b().iter())
+                            .filter_map(|(code, name)| {
+                                let code = TinyStr16::try_from_utf8(code.as_bytes()).ok()?;
+                                Some((name, ParsedEra::Code(code)))
+                            }),
+                    )?,
+                    YearNames::Cyclic(_) => {
+                        return Err(DateTimeParseError::new(
+                            DateTimeParseErrorKind::UnknownName(ErrorField(field)),
+                            start,
+                        ))
+                    }
+                };
+                parsed.era = Some(era);
+            }
+            (FieldSymbol::Year(Year::Calendar), l) => {
+                parsed.date_index.get_or_insert(start);
+                let value = if l == FieldLength::Two {
+                    // 'yy' is truncated to two digits
+                    parsed.is_two_digit_year = true;
+                    self.parse_digits(cursor, field, 2, 2, false)?.0
+                } else {
+                    self.parse_number(cursor, field, 9, is_abutting)?
+                };
+                parsed.year = i32::try_from(value).ok();
+            }
+            (FieldSymbol::Year(Year::Cyclic), l) => {
+                parsed.date_index.get_or_insert(start);
+                let YearNames::Cyclic(names) =
+                    self.names.get_year_names(l).map_err(|_| not_loaded())?
+                else {
+                    return Err(DateTimeParseError::new(
+                        DateTimeParseErrorKind::UnknownName(ErrorField(field)),
+                        start,
+                    ));
+                };
+                let cyclic = self.parse_name(cursor, field, names.iter().zip(1u8..))?;
+                parsed.cyclic_year = Some((cyclic, field));
+            }
+            (FieldSymbol::Year(Year::RelatedIso), _) => {
+                parsed.date_index.get_or_insert(start);
+                // Always in latin digits according to spec
+                let (value, _) = self.parse_digits(cursor, field, 1, 9, true)?;
+                parsed.related_iso = i32::try_from(value).ok();
+            }
+            (FieldSymbol::Month(_), FieldLength::One | FieldLength::Two) => {
+                parsed.date_index.get_or_insert(start);
+                let value = self.parse_number(cursor, field, 2, is_abutting)?;
+                parsed.month = Some(ParsedMonth::Ordinal(narrow(value)));
+            }
+            (FieldSymbol::Month(symbol), l) => {
+                parsed.date_index.get_or_insert(start);
+                let mut names = Vec::new();
+                let mut has_numeric = false;
+                for code in (1..=13)
+                    .flat_map(|number| [month_code(number, false), month_code(number, true)])
+                    .flatten()
+                {
+                    match self.names.get_name_for_month(symbol, l, code) {
+                        Ok(MonthPlaceholderValue::PlainString(name)) => {
+                            names.push((name, ParsedMonth::Code(code)))
+                        }
+                        // Calendars with numeric leap month names format months by ordinal
+                        Ok(
+                            MonthPlaceholderValue::Numeric
+                            | MonthPlaceholderValue::NumericPattern(_),
+                        ) => has_numeric = true,
+                        Err(GetNameForMonthError::InvalidMonthCode) => (),
+                        Err(
+                            GetNameForMonthError::InvalidFieldLength
+                            | GetNameForMonthError::NotLoaded,
+                        ) => return Err(not_loaded()),
+                    }
+                }
+                let month = match self.parse_name(cursor, field, names.into_iter()) {
+                    Err(_) if has_numeric => {
+                        ParsedMonth::Ordinal(narrow(self.parse_number(cursor, field, 2, false)?))
+                    }
+                    result => result?,
+                };
+                parsed.month = Some(month);
+            }
+            (FieldSymbol::Week(w), _) => match w {},
+            (FieldSymbol::Weekday(symbol), l) => {
+                parsed.date_index.get_or_insert(start);
+                let mut names = Vec::with_capacity(7);
+                for weekday in [
+                    Weekday::Monday,
+                    Weekday::Tuesday,
+                    Weekday::Wednesday,
+                    Weekday::Thursday,
+                    Weekday::Friday,
+                    Weekday::Saturday,
+                    Weekday::Sunday,
+                ] {
+                    match self.names.get_name_for_weekday(symbol, l, weekday) {
+                        Ok(name) => names.push((name, weekday)),
+                        Err(
+                            GetNameForWeekdayError::InvalidFieldLength
+                            | GetNameForWeekdayError::NotLoaded,
+                        ) => return Err(not_loaded()),
+                    }
+                }
+                let weekday = self.parse_name(cursor, field, names.into_iter())?;
+                parsed.weekday = Some((weekday, field));
+            }
+            (FieldSymbol::Day(fields::Day::DayOfMonth), _) => {
+                parsed.date_index.get_or_insert(start);
+                let value = self.parse_number(cursor, field, 2, is_abutting)?;
+                parsed.day_of_month = Some(narrow(value));
+            }
+            (FieldSymbol::Day(fields::Day::DayOfYear), _) => {
+                parsed.date_index.get_or_insert(start);
+                let value = self.parse_number(cursor, field, 3, is_abutting)?;
+                parsed.day_of_year = Some((u16::try_from(value).unwrap_or(u16::MAX), field));
+            }
+            (FieldSymbol::Day(fields::Day::DayOfWeekInMonth), _) => {
+                parsed.date_index.get_or_insert(start);
+                let value = self.parse_number(cursor, field, 1, is_abutting)?;
+                parsed.day_of_week_in_month = Some((narrow(value), field));
+            }
+            (FieldSymbol::Hour(symbol), _) => {
+                parsed.time_index.get_or_insert(start);
+                let value = self.parse_number(cursor, field, 2, is_abutting)?;
+                parsed.hour = Some((symbol, narrow(value)));
+            }
+            (FieldSymbol::Minute, _) => {
+                parsed.time_index.get_or_insert(start);
+                let value = self.parse_number(cursor, field, 2, is_abutting)?;
+                parsed.minute = Some(narrow(value));
+            }
+            (FieldSymbol::Second(Second::Second), _) => {
+                parsed.time_index.get_or_insert(start);
+                let value = self.parse_number(cursor, field, 2, is_abutting)?;
+                parsed.second = Some(narrow(value));
+            }
+            (FieldSymbol::Second(Second::MillisInDay), _) => {
+                parsed.time_index.get_or_insert(start);
+                let millis = self.parse_number(cursor, field, 8, is_abutting)?;
+                parsed.hour = Some((fields::Hour::H23, narrow(millis / 3_600_000)));
+                parsed.minute = Some(narrow(millis / 60_000 % 60));
+                parsed.second = Some(narrow(millis / 1000 % 60));
+                parsed.subsecond = Some(millis % 1000 * 1_000_000);
+            }
+            (FieldSymbol::DecimalSecond(decimal_second), _) => {
+                parsed.time_index.get_or_insert(start);
+                let seconds = self.parse_number(cursor, field, 2, false)?;
+                parsed.second = Some(narrow(seconds));
+                let separator = self.symbols.decimal_separator.as_str();
+                let Some(separator_len) = self.match_name(cursor.rest(), separator) else {
+                    if self.is_lenient() {
+                        return Ok(());
+                    }
+                    return Err(DateTimeParseError::new(
+                        DateTimeParseErrorKind::ExpectedNumber(ErrorField(field)),
+                        cursor.index,
+                    ));
+                };
+                cursor.advance(separator_len);
+                let digits = usize::from(decimal_second.idx());
+                let (fraction, count) = if self.is_lenient() {
+                    self.parse_digits(cursor, field, 1, 9, false)?
+                } else {
+                    self.parse_digits(cursor, field, digits, digits, false)?
+                };
+                let scale = 10u32.pow(9 - u32::try_from(count).unwrap_or(9));
+                parsed.subsecond = Some(fraction * scale);
+            }
+            (FieldSymbol::DayPeriod(period), l) => {
+                parsed.time_index.get_or_insert(start);
+                let mut names = Vec::with_capacity(4);
+                for (hour, is_top_of_hour) in [(0u8, false), (12, false), (0, true), (12, true)] {
+                    let Ok(hour) = Hour::try_from(hour) else {
+                        continue;
+                    };
+                    match self
+                        .names
+                        .get_name_for_day_period(period, l, hour, is_top_of_hour)
+                    {
+                        Ok(name) => names.push((name, u8::from(hour) >= 12)),
+                        Err(
+                            GetNameForDayPeriodError::InvalidFieldLength
+                            | GetNameForDayPeriodError::NotLoaded,
+                        ) => return Err(not_loaded()),
+                    }
+                }
+                parsed.is_pm = Some(self.parse_name(cursor, field, names.into_iter())?);
+            }
+            (FieldSymbol::TimeZone(fields::TimeZone::Iso), _) => {
+                parsed.offset = Some(self.parse_iso_offset(cursor, field, false)?);
+            }
+            (FieldSymbol::TimeZone(fields::TimeZone::IsoWithZ), _) => {
+                parsed.offset = Some(self.parse_iso_offset(cursor, field, true)?);
+            }
+            (FieldSymbol::TimeZone(fields::TimeZone::LocalizedOffset), _) => {
+                parsed.offset = Some(self.parse_localized_offset(cursor, field)?);
+            }
+            (FieldSymbol::TimeZone(_), _) => {
+                // Zone names fall back to the localized offset format when formatting,
+                // which is the only zone format that can be mapped back to a zone.
+                parsed.offset = Some(self.parse_localized_offset(cursor, field).map_err(|_| {
+                    DateTimeParseError::new(
+                        DateTimeParseErrorKind::UnsupportedField(ErrorField(field)),
+                        start,
+                    )
+                })?);
+            }
+        }
+        Ok(())
+    }
+
+    /// Reads a sign, if present, returning whether it is negative.
+    fn parse_sign(&self, cursor: &mut Cursor) -> Option<bool> {
+        let rest = cursor.rest();
+        for (sign, is_negative) in [
+            (self.symbols.minus_sign.as_str(), true),
+            (self.symbols.plus_sign.as_str(), false),
+            ("-", true),
+            ("\u{2212}", true),
+            ("+", false),
+        ] {
+            if !sign.is_empty() && rest.starts_with(sign) {
+                cursor.advance(sign.len());
+                return Some(is_negative);
+            }
+        }
+        None
+    }
+
+    fn to_offset(
+        &self,
+        is_negative: bool,
+        hours: u32,
+        minutes: u32,
+        seconds: u32,
+        index: usize,
+    ) -> Result<UtcOffset, DateTimeParseError> {
+        let total = i32::try_from(hours * 3600 + minutes * 60 + seconds).unwrap_or(i32::MAX);
+        UtcOffset::try_from_seconds(if is_negative { -total } else { total })
+            .map_err(|_| DateTimeParseError::new(DateTimeParseErrorKind::InvalidOffset, index))
+    }
+
+    /// Reads an ISO 8601 offset such as `Z`, `+05`, `-0330`, or `+05:30:15`.
+    fn parse_iso_offset(
+        &self,
+        cursor: &mut Cursor,
+        field: Field,
+        allow_z: bool,
+    ) -> Result<UtcOffset, DateTimeParseError> {
+        let start = cursor.index;
+        if allow_z && self.match_name(cursor.rest(), "Z").is_some() {
+            cursor.advance(1);
+            return Ok(UtcOffset::zero());
+        }
+        let Some(is_negative) = self.parse_sign(cursor) else {
+            return Err(DateTimeParseError::new(
+                DateTimeParseErrorKind::ExpectedNumber(ErrorField(field)),
+                start,
+            ));
+        };
+        let (hours, _) = self.parse_digits(cursor, field, 2, 2, true)?;
+        let mut minutes_and_seconds = [0; 2];
+        for part in minutes_and_seconds.iter_mut() {
+            let mut lookahead = *cursor;
+            if lookahead.rest().starts_with(':') {
+                lookahead.advance(1);
+            }
+            match self.parse_digits(&mut lookahead, field, 2, 2, true) {
+                Ok((value, _)) => {
+                    *part = value;
+                    *cursor = lookahead;
+                }
+                Err(_) => break,
+            }
+        }
+        let [minutes, seconds] = minutes_and_seconds;
+        self.to_offset(is_negative, hours, minutes, seconds, start)
+    }
+
+    /// Reads a localized offset such as `GMT`, `GMT+5`, or `GMT-03:30`.
+    fn parse_localized_offset(
+        &self,
+        cursor: &mut Cursor,
+        field: Field,
+    ) -> Result<UtcOffset, DateTimeParseError> {
+        let start = cursor.index;
+        let not_loaded = DateTimeParseError::new(
+            DateTimeParseErrorKind::NamesNotLoaded(ErrorField(field)),
+            start,
+        );
+        let essentials = self.names.get_payloads().essentials.ok_or(not_loaded)?;
+        let mut offset = UtcOffset::zero();
+        let mut parsed_any = false;
+        for item in essentials.offset_pattern.iter() {
+            match item {
+                OffsetPatternItem::Literal(literal) => {
+                    let len =
+                        self.match_name(cursor.rest(), literal)
+                            .ok_or(DateTimeParseError::new(
+                                DateTimeParseErrorKind::UnknownName(ErrorField(field)),
+                                cursor.index,
+                            ))?;
+                    cursor.advance(len);
+                }
+                OffsetPatternItem::Placeholder(_) => {
+                    // The zero offset is formatted without hours, as in "GMT"
+                    let Some(is_negative) = self.parse_sign(cursor) else {
+                        continue;
+                    };
+                    let index = cursor.index;
+                    let (hours, _) = self.parse_digits(cursor, field, 1, 2, false)?;
+                    let separator = essentials.offset_separator.as_ref();
+                    let mut minutes_and_seconds = [0; 2];
+                    for part in minutes_and_seconds.iter_mut() {
+                        let mut lookahead = *cursor;
+                        let Some(len) = self.match_name(lookahead.rest(), separator) else {
+                            break;
+                        };
+                        lookahead.advance(len);
+                        match self.parse_digits(&mut lookahead, field, 2, 2, false) {
+                            Ok((value, _)) => {
+                                *part = value;
+                                *cursor = lookahead;
+                            }
+                            Err(_) => break,
+                        }
+                    }
+                    let [minutes, seconds] = minutes_and_seconds;
+                    offset = self.to_offset(is_negative, hours, minutes, seconds, index)?;
+                    parsed_any = true;
+                }
+            }
+        }
+        if !parsed_any {
+            // Some locales have a distinct zero format
+            if let Some(len) = self.match_name(cursor.rest(), &essentials.offset_zero) {
+                cursor.advance(len);
+            }
+        }
+        Ok(offset)
+    }
+}
+
+fn is_numeric(field: Field) -> bool {
+    match field.symbol {
+        FieldSymbol::Year(Year::Calendar | Year::RelatedIso)
+        | FieldSymbol::Day(_)
+        | FieldSymbol::Hour(_)
+        | FieldSymbol::Minute
+        | FieldSymbol::Second(_)
+        | FieldSymbol::DecimalSecond(_) => true,
+        FieldSymbol::Month(_) => matches!(field.length, FieldLength::One | FieldLength::Two),
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "compiled_data")]
+mod tests {
+    use super::*;
+    use crate::fieldsets::enums::DateFieldSet;
+    use crate::fieldsets::{self, T, YMD, YMDE, YMDT};
+    use crate::input::{Date, Time};
+    use crate::options::SubsecondDigits;
+    use crate::DateTimeFormatter;
+    use icu_calendar::Gregorian;
+    use icu_locale_core::locale;
+
+    #[test]
+    fn test_round_trip_dates() {
+        let dates = [
+            Date::try_new_iso(2025, 1, 15).unwrap(),
+            Date::try_new_iso(1999, 12, 31).unwrap(),
+            Date::try_new_iso(2024, 2, 29).unwrap(),
+        ];
+        for locale in [
+            locale!("en"),
+            locale!("fr"),
+            locale!("de"),
+            locale!("es"),
+            locale!("ja"),
+            locale!("ar-EG"),
+            locale!("hi"),
+            locale!("th"),
+            locale!("he-u-ca-hebrew"),
+            locale!("en-u-ca-japanese"),
+            locale!("zh-u-ca-chinese"),
+        ] {
+            for field_set in [
+                DateFieldSet::YMD(YMD::short()),
+                DateFieldSet::YMD(YMD::medium()),
+                DateFieldSet::YMD(YMD::long()),
+                DateFieldSet::YMDE(YMDE::medium()),
+            ] {
+                let formatter =
+                    DateTimeFormatter::try_new(locale.clone().into(), field_set).unwrap();
+                let parser = DateTimeParser::from_formatter(formatter.clone());
+                for date in dates {
+                    let formatted = formatter.format(&date).to_string();
+                    assert_eq!(
+                        parser.parse_date(&formatted),
+                        Ok(date),
+                        "{locale} {field_set:?} {formatted}"
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_round_trip_times() {
+        let times = [
+            Time::try_new(0, 0, 0, 0).unwrap(),
+            Time::try_new(12, 5, 9, 0).unwrap(),
+            Time::try_new(23, 59, 30, 0).unwrap(),
+        ];
+        for locale in [
+            locale!("en"),
+            locale!("en-u-hc-h23"),
+            locale!("ja-u-hc-h11"),
+            locale!("ko"),
+            locale!("ar-EG"),
+        ] {
+            let formatter =
+                FixedCalendarDateTimeFormatter::<(), _>::try_new(locale.clone().into(), T::hms())
+                    .unwrap();
+            let parser = FixedCalendarDateTimeParser::from_formatter(formatter.clone());
+            for time in times {
+                let formatted = formatter.format(&time).to_string();
+                assert_eq!(
+                    parser.parse_time(&formatted),
+                    Ok(time),
+                    "{locale} {formatted}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_subseconds() {
+        let parser = FixedCalendarDateTimeParser::<(), _>::try_new(
+            locale!("fr").into(),
+            T::hms().with_time_precision(crate::options::TimePrecision::Subsecond(
+                SubsecondDigits::S3,
+            )),
+        )
+        .unwrap();
+        assert_eq!(
+            parser.parse_time("14:03:09,250"),
+            Ok(Time::try_new(14, 3, 9, 250_000_000).unwrap())
+        );
+        assert_eq!(
+            parser.parse_time("14:03:09,25").unwrap_err().kind,
+            DateTimeParseErrorKind::ExpectedNumber(ErrorField(Field {
+                symbol: FieldSymbol::DecimalSecond(fields::DecimalSecond::Subsecond3),
+                length: FieldLength::Two,
+            }))
+        );
+    }
+
+    #[test]
+    fn test_zoned() {
+        let parser = DateTimeParser::try_new(
+            locale!("en").into(),
+            YMDT::medium().with_zone(fieldsets::zone::LocalizedOffsetLong),
+        )
+        .unwrap();
+        let zoned = parser
+            .parse_zoned_datetime("Jan 15, 2025, 4:09:35\u{202F}PM GMT-05:30")
+            .unwrap();
+        assert_eq!(zoned.zone, UtcOffset::try_from_seconds(-19800).unwrap());
+        assert_eq!(zoned.time, Time::try_new(16, 9, 35, 0).unwrap());
+
+        let zoned = parser
+            .parse_zoned_datetime("Jan 15, 2025, 4:09:35\u{202F}PM GMT")
+            .unwrap();
+        assert_eq!(zoned.zone, UtcOffset::zero());
+    }
+
+    #[test]
+    fn test_strict_and_lenient() {
+        let parser = FixedCalendarDateTimeParser::<Gregorian, _>::try_new(
+            locale!("en").into(),
+            YMDE::medium(),
+        )
+        .unwrap();
+
+        // Wrong weekday
+        assert_eq!(
+            parser
+                .parse_date("Thu, Jan 15, 2025", Gregorian)
+                .unwrap_err()
+                .kind,
+            DateTimeParseErrorKind::InconsistentField(ErrorField(Field {
+                symbol: FieldSymbol::Weekday(fields::Weekday::Format),
+                length: FieldLength::One,
+            }))
+        );
+        // Wrong case and missing punctuation
+        let input = "wed jan 15 2025";
+        let error = parser.parse_date(input, Gregorian).unwrap_err();
+        assert_eq!(error.index, 0);
+        assert_eq!(
+            error.kind,
+            DateTimeParseErrorKind::UnknownName(ErrorField(Field {
+                symbol: FieldSymbol::Weekday(fields::Weekday::Format),
+                length: FieldLength::One,
+            }))
+        );
+
+        let parser = parser.with_leniency(ParseLeniency::Lenient);
+        assert_eq!(
+            parser.parse_date(input, Gregorian),
+            Ok(Date::try_new_gregorian(2025, 1, 15).unwrap())
+        );
+        assert_eq!(
+            parser.parse_date("  Wed,  Jan. 15 ,2025 ", Gregorian),
+            Ok(Date::try_new_gregorian(2025, 1, 15).unwrap())
+        );
+    }
+
+    #[test]
+    fn test_native_digits() {
+        let parser = DateTimeParser::try_new(locale!("ar-EG").into(), YMD::short()).unwrap();
+        let formatted = "١٥‏/١‏/٢٠٢٥";
+        assert_eq!(
+            parser.parse_date(formatted),
+            Ok(Date::try_new_iso(2025, 1, 15).unwrap())
+        );
+        // ASCII digits are only accepted in lenient mode
+        assert!(parser.parse_date("15‏/1‏/2025").is_err());
+        assert_eq!(
+            parser
+                .with_leniency(ParseLeniency::Lenient)
+                .parse_date("15‏/1‏/2025"),
+            Ok(Date::try_new_iso(2025, 1, 15).unwrap())
+        );
+    }
+
+    #[test]
+    fn test_two_digit_years() {
+        let parser = FixedCalendarDateTimeParser::<Gregorian, _>::try_new(
+            locale!("en").into(),
+            YMD::short(),
+        )
+        .unwrap();
+        for (input, year) in [("1/15/25", 2025), ("1/15/49", 2049), ("1/15/50", 1950)] {
+            assert_eq!(
+                parser.parse_date(input, Gregorian),
+                Ok(Date::try_new_gregorian(year, 1, 15).unwrap()),
+                "{input}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_error_index() {
+        let parser =
+            FixedCalendarDateTimeParser::<Gregorian, _>::try_new(locale!("en").into(), YMD::long())
+                .unwrap();
+        let error = parser
+            .parse_date("January 15, 20x5", Gregorian)
+            .unwrap_err();
+        assert_eq!(
+            error,
+            DateTimeParseError::new(DateTimeParseErrorKind::TrailingInput, 14)
+        );
+        let error = parser
+            .parse_date("January 32, 2025", Gregorian)
+            .unwrap_err();
+        assert_eq!(error.index, 0);
+        assert!(matches!(
+            error.kind,
+            DateTimeParseErrorKind::InvalidDate(DateError::Range { field: "day", .. })
+        ));
+        let error = parser
+            .parse_date("January 15, 2025!", Gregorian)
+            .unwrap_err();
+        assert_eq!(
+            error,
+            DateTimeParseError::new(DateTimeParseErrorKind::TrailingInput, 16)
+        );
+    }
+}
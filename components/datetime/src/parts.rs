@@ -127,3 +127,17 @@ pub const TIME_ZONE_NAME: Part = Part {
     category: "datetime",
     value: "timeZoneName",
 };
+
+/// A [`Part`] used by [`FormattedDateTimeInterval`](super::FormattedDateTimeInterval)
+/// for the start of the interval.
+pub const START_RANGE: Part = Part {
+    category: "datetime",
+    value: "startRange",
+};
+
+/// A [`Part`] used by [`FormattedDateTimeInterval`](super::FormattedDateTimeInterval)
+/// for the end of the interval.
+pub const END_RANGE: Part = Part {
+    category: "datetime",
+    value: "endRange",
+};
@@ -3764,6 +3764,20 @@ impl RawDateTimeNamesBorrowed<'_> {
         }
     }
 
+    /// Gets all era or cyclic year names for the given field length.
+    ///
+    /// Used when parsing, where the names need to be searched in reverse.
+    pub(crate) fn get_year_names(
+        &self,
+        field_length: FieldLength,
+    ) -> Result<&YearNames<'_>, GetNameForEraError> {
+        let year_name_length = YearNameLength::from_field_length(field_length)
+            .ok_or(GetNameForEraError::InvalidFieldLength)?;
+        self.year_names
+            .get_with_variables(year_name_length)
+            .ok_or(GetNameForEraError::NotLoaded)
+    }
+
     pub(crate) fn get_name_for_cyclic(
         &self,
         field_length: FieldLength,
@@ -62,6 +62,7 @@ const _: () = {
     impl_timezone_names_standard_long_v1!(Baked);
 
     impl_datetime_patterns_glue_v1!(Baked);
+    impl_datetime_patterns_interval_v1!(Baked);
     impl_datetime_patterns_time_v1!(Baked);
 
     impl_datetime_names_weekday_v1!(Baked);
@@ -143,6 +144,7 @@ pub const MARKERS: &[DataMarkerInfo] = &[
     neo::DatetimeNamesWeekdayV1::INFO,
     neo::DatetimeNamesDayperiodV1::INFO,
     neo::DatetimePatternsGlueV1::INFO,
+    neo::DatetimePatternsIntervalV1::INFO,
     DatetimePatternsTimeV1::INFO,
     neo::DatetimeNamesYearBuddhistV1::INFO,
     neo::DatetimeNamesYearChineseV1::INFO,
@@ -10,7 +10,10 @@ use alloc::borrow::Cow;
 use icu_pattern::SinglePlaceholderPattern;
 use icu_provider::prelude::*;
 use potential_utf::PotentialUtf8;
-use zerovec::{ule::tuplevar::Tuple2VarULE, VarZeroCow, VarZeroSlice, VarZeroVec};
+use zerovec::{
+    ule::{tuplevar::Tuple2VarULE, AsULE},
+    VarZeroCow, VarZeroSlice, VarZeroVec, ZeroVec,
+};
 
 /// Helpers involving the data marker attributes used for date names.
 ///
@@ -519,6 +522,12 @@ icu_provider::data_marker!(
     GluePattern<'static>
 );
 
+icu_provider::data_marker!(
+    /// `DatetimePatternsIntervalV1`
+    DatetimePatternsIntervalV1,
+    IntervalPatterns<'static>
+);
+
 size_test!(YearNames, year_names_v1_size, 32);
 
 /// Names used for representing the year.
@@ -714,6 +723,114 @@ icu_provider::data_struct!(
     #[cfg(feature = "datagen")]
 );
 
+/// The field with the greatest difference between the start and the end of an interval.
+///
+/// <div class="stab unstable">
+/// 🚧 This code is considered unstable; it may change at any time, in breaking or non-breaking ways,
+/// including in SemVer minor releases. While the serde representation of data structs is guaranteed
+/// to be stable, their Rust representation might not be. Use with caution.
+/// </div>
+#[zerovec::make_ule(IntervalFieldULE)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "datagen", derive(serde::Serialize, databake::Bake))]
+#[cfg_attr(feature = "datagen", databake(path = icu_datetime::provider::neo))]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[repr(u8)]
+pub enum IntervalField {
+    /// The era differs (`G`).
+    Era = 0,
+    /// The year differs (`y`).
+    Year = 1,
+    /// The month differs (`M`).
+    Month = 2,
+    /// The day differs (`d`).
+    Day = 3,
+    /// The day period differs (`a` or `B`).
+    DayPeriod = 4,
+    /// The hour differs (`h` or `H`).
+    Hour = 5,
+    /// The minute differs (`m`).
+    Minute = 6,
+}
+
+/// The key of an interval pattern in [`IntervalPatterns`].
+///
+/// <div class="stab unstable">
+/// 🚧 This code is considered unstable; it may change at any time, in breaking or non-breaking ways,
+/// including in SemVer minor releases. While the serde representation of data structs is guaranteed
+/// to be stable, their Rust representation might not be. Use with caution.
+/// </div>
+#[zerovec::make_ule(IntervalPatternKeyULE)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "datagen", derive(serde::Serialize, databake::Bake))]
+#[cfg_attr(feature = "datagen", databake(path = icu_datetime::provider::neo))]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+pub struct IntervalPatternKey {
+    /// The length of the pattern: 0 is long, 1 is medium, and 2 is short.
+    pub length: u8,
+    /// The variant of the pattern, as in [`PackedPatterns`]: 0 is the standard pattern,
+    /// 1 is variant 0, and 2 is variant 1.
+    ///
+    /// [`PackedPatterns`]: crate::provider::PackedPatterns
+    pub variant: u8,
+    /// The greatest difference that the pattern is used for.
+    pub difference: IntervalField,
+}
+
+size_test!(IntervalPatterns, interval_patterns_v1_size, 72);
+
+/// The patterns used for formatting date and time intervals with one field set.
+///
+/// An interval pattern contains the fields of the formatted pattern twice, once for the
+/// start and once for the end of the interval, except for the fields that are the same in
+/// both. The end of the interval starts at the first field that repeats a field from earlier
+/// in the pattern. For example, the English pattern for a day difference in a medium date is
+/// `"MMM d – d, y"`.
+///
+/// The data marker attributes are the same as those of the date and time pattern markers.
+/// Date patterns are only available for the Gregorian calendar.
+#[doc = interval_patterns_v1_size!()]
+///
+/// <div class="stab unstable">
+/// 🚧 This code is considered unstable; it may change at any time, in breaking or non-breaking ways,
+/// including in SemVer minor releases. While the serde representation of data structs is guaranteed
+/// to be stable, their Rust representation might not be. Use with caution.
+/// </div>
+#[derive(Debug, PartialEq, Clone, yoke::Yokeable, zerofrom::ZeroFrom)]
+#[cfg_attr(feature = "datagen", derive(serde::Serialize, databake::Bake))]
+#[cfg_attr(feature = "datagen", databake(path = icu_datetime::provider::neo))]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[yoke(prove_covariance_manually)]
+pub struct IntervalPatterns<'data> {
+    /// The pattern used when there is no interval pattern, where `{0}` is the start
+    /// and `{1}` is the end of the interval.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub fallback: runtime::GenericPattern<'data>,
+    /// The keys of the interval patterns, sorted.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub keys: ZeroVec<'data, IntervalPatternKey>,
+    /// The interval patterns, in the same order as `keys`.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub patterns: VarZeroVec<'data, runtime::PatternULE>,
+}
+
+icu_provider::data_struct!(
+    IntervalPatterns<'_>,
+    #[cfg(feature = "datagen")]
+);
+
+impl IntervalPatterns<'_> {
+    /// Gets the interval pattern for the given key.
+    pub(crate) fn get(&self, key: IntervalPatternKey) -> Option<runtime::PatternBorrowed<'_>> {
+        let index = self.keys.binary_search(&key).ok()?;
+        let pattern = self.patterns.get(index)?;
+        Some(runtime::PatternBorrowed {
+            items: &pattern.items,
+            metadata: runtime::PatternMetadata::from_unaligned(pattern.metadata),
+        })
+    }
+}
+
 /// Calendar-agnostic year name data marker
 #[derive(Debug)]
 pub struct YearNamesV1;
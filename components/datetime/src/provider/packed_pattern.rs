@@ -464,6 +464,7 @@ impl PackedPatternsBuilder<'_> {
 }
 
 /// Which pattern to select. For details, see [`PackedPatterns`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub(crate) enum PackedSkeletonVariant {
     /// Default-precision year OR hours only
     Standard,
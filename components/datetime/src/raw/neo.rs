@@ -14,6 +14,7 @@ use crate::provider::pattern::{
 };
 use crate::provider::{neo::*, ErasedPackedPatterns, PackedSkeletonVariant};
 use crate::DateTimeFormatterPreferences;
+use alloc::vec::Vec;
 use icu_calendar::types::YearAmbiguity;
 use icu_provider::prelude::*;
 use icu_provider::DataPayloadOr;
@@ -179,8 +180,20 @@ impl DatePatternSelectionData {
         options: RawOptions,
     ) -> Option<DatePatternDataBorrowed<'_>> {
         let payload = self.payload.get_option()?;
+        let variant = Self::select_variant(input, options);
+        Some(DatePatternDataBorrowed::Resolved(
+            payload.get(options.length(), variant),
+            options.alignment,
+        ))
+    }
+
+    /// Returns the variant of the pattern that [`Self::select`] chooses for the given datetime
+    fn select_variant(
+        input: &DateTimeInputUnchecked,
+        options: RawOptions,
+    ) -> PackedSkeletonVariant {
         let year_style = options.year_style.unwrap_or_default();
-        let variant = match (
+        match (
             year_style,
             input
                 .year
@@ -200,11 +213,42 @@ impl DatePatternSelectionData {
             (YearStyle::Auto, YearAmbiguity::Unambiguous | YearAmbiguity::EraRequired) => {
                 PackedSkeletonVariant::Standard
             }
+        }
+    }
+}
+
+impl DatePatternSelectionData {
+    /// Borrows every pattern that [`Self::select`] can return for the given options.
+    ///
+    /// Used when parsing, where the input that drives the selection is not known yet.
+    pub(crate) fn select_all(
+        &self,
+        options: RawOptions,
+    ) -> Vec<Option<DatePatternDataBorrowed<'_>>> {
+        let Some(payload) = self.payload.get_option() else {
+            return alloc::vec![None];
         };
-        Some(DatePatternDataBorrowed::Resolved(
-            payload.get(options.length(), variant),
-            options.alignment,
-        ))
+        let variants: &[PackedSkeletonVariant] = match options.year_style.unwrap_or_default() {
+            YearStyle::WithEra => &[PackedSkeletonVariant::Variant1],
+            YearStyle::Full => &[
+                PackedSkeletonVariant::Variant0,
+                PackedSkeletonVariant::Variant1,
+            ],
+            YearStyle::Auto => &[
+                PackedSkeletonVariant::Standard,
+                PackedSkeletonVariant::Variant0,
+                PackedSkeletonVariant::Variant1,
+            ],
+        };
+        variants
+            .iter()
+            .map(|variant| {
+                Some(DatePatternDataBorrowed::Resolved(
+                    payload.get(options.length(), *variant),
+                    options.alignment,
+                ))
+            })
+            .collect()
     }
 }
 
@@ -352,6 +396,43 @@ impl TimePatternSelectionData {
     }
 }
 
+impl TimePatternSelectionData {
+    /// Borrows every pattern that [`Self::select`] can return for the given options.
+    ///
+    /// Used when parsing, where the input that drives the selection is not known yet.
+    pub(crate) fn select_all(
+        &self,
+        options: RawOptions,
+        prefs: RawPreferences,
+    ) -> Vec<Option<TimePatternDataBorrowed<'_>>> {
+        let Some(payload) = self.payload.get_option() else {
+            return alloc::vec![None];
+        };
+        let variants: &[(PackedSkeletonVariant, Option<SubsecondDigits>)] =
+            match options.time_precision.unwrap_or_default() {
+                TimePrecision::Hour => &[(PackedSkeletonVariant::Standard, None)],
+                TimePrecision::Minute => &[(PackedSkeletonVariant::Variant0, None)],
+                TimePrecision::Second => &[(PackedSkeletonVariant::Variant1, None)],
+                TimePrecision::Subsecond(f) => &[(PackedSkeletonVariant::Variant1, Some(f))],
+                TimePrecision::MinuteOptional => &[
+                    (PackedSkeletonVariant::Variant0, None),
+                    (PackedSkeletonVariant::Standard, None),
+                ],
+            };
+        variants
+            .iter()
+            .map(|&(variant, subsecond_digits)| {
+                Some(TimePatternDataBorrowed::Resolved(
+                    payload.get(options.length(), variant),
+                    options.alignment,
+                    prefs.hour_cycle,
+                    subsecond_digits,
+                ))
+            })
+            .collect()
+    }
+}
+
 impl<'a> TimePatternDataBorrowed<'a> {
     pub(crate) fn items_and_options(self) -> ItemsAndOptions<'a> {
         let Self::Resolved(pattern, alignment, hour_cycle, subsecond_digits) = self;
@@ -645,6 +726,54 @@ impl DateTimeZonePatternSelectionData {
         }
     }
 
+    /// Borrows every resolved pattern that [`Self::select`] can return.
+    ///
+    /// Used when parsing, where the input that drives the selection is not known yet.
+    pub(crate) fn select_all(&self) -> Vec<DateTimeZonePatternDataBorrowed<'_>> {
+        let dates = self.date.select_all(self.options);
+        let times = self.time.select_all(self.options, self.prefs);
+        let zone = self.zone.as_ref().map(|zone| {
+            let ZonePatternSelectionData::SinglePatternItem(_, pattern_item) = zone;
+            ZonePatternDataBorrowed::SinglePatternItem(pattern_item)
+        });
+        let glue = self.glue.as_ref().map(|glue| glue.get());
+        dates
+            .iter()
+            .flat_map(|&date| {
+                times
+                    .iter()
+                    .map(move |&time| DateTimeZonePatternDataBorrowed {
+                        date,
+                        time,
+                        zone,
+                        glue,
+                    })
+            })
+            .collect()
+    }
+
+    /// Returns the length and the variant of the date or time pattern that [`Self::select`]
+    /// chooses for the given datetime.
+    ///
+    /// For patterns with both a date and a time, this is the variant of the time pattern.
+    /// Used when formatting intervals, where the interval patterns share these keys.
+    pub(crate) fn select_length_and_variant(
+        &self,
+        input: &DateTimeInputUnchecked,
+    ) -> Option<(Length, PackedSkeletonVariant)> {
+        let length = self.options.length?;
+        let variant = if self.time.payload.is_payload() {
+            input
+                .resolve_time_precision(self.options.time_precision.unwrap_or_default())
+                .0
+        } else if self.date.payload.is_payload() {
+            DatePatternSelectionData::select_variant(input, self.options)
+        } else {
+            return None;
+        };
+        Some((length, variant))
+    }
+
     /// Converts one of these into a corresponding [`builder::FieldSetBuilder`]
     pub(crate) fn to_builder(&self) -> builder::FieldSetBuilder {
         let time_precision = if self.time.payload.is_payload() {
@@ -738,6 +867,41 @@ impl<'a> DateTimeZonePatternDataBorrowed<'a> {
     }
 }
 
+/// The date and time patterns that make up a resolved pattern, used when formatting intervals.
+#[derive(Debug, Copy, Clone)]
+pub(crate) enum IntervalPieces<'a> {
+    /// A date or a time pattern on its own.
+    Single(ItemsAndOptions<'a>),
+    /// A date pattern and a time pattern joined by a glue pattern.
+    Glued {
+        date: ItemsAndOptions<'a>,
+        time: ItemsAndOptions<'a>,
+        glue: &'a ZeroSlice<GenericPatternItem>,
+    },
+}
+
+impl<'a> DateTimeZonePatternDataBorrowed<'a> {
+    /// Splits this pattern into the pieces used for interval formatting.
+    ///
+    /// Returns `None` for patterns with a time zone, which are not formatted as intervals.
+    pub(crate) fn interval_pieces(self) -> Option<IntervalPieces<'a>> {
+        match (self.date, self.time, self.zone, self.glue_pattern()) {
+            (Some(date), None, None, None) => {
+                Some(IntervalPieces::Single(date.items_and_options()))
+            }
+            (None, Some(time), None, None) => {
+                Some(IntervalPieces::Single(time.items_and_options()))
+            }
+            (Some(date), Some(time), None, Some(glue)) => Some(IntervalPieces::Glued {
+                date: date.items_and_options(),
+                time: time.items_and_options(),
+                glue,
+            }),
+            _ => None,
+        }
+    }
+}
+
 impl<'a> ItemsAndOptions<'a> {
     pub(crate) fn iter_items(self) -> impl Iterator<Item = PatternItem> + 'a {
         self.items.iter().map(move |mut pattern_item| {
@@ -96,11 +96,15 @@ extern crate alloc;
 mod format;
 mod grouper;
 pub mod options;
+#[cfg(feature = "alloc")]
+mod parse;
 pub mod parts;
 pub mod provider;
 pub(crate) mod size_test_macro;
 
 pub use format::FormattedDecimal;
+#[cfg(feature = "alloc")]
+pub use parse::{DecimalParseError, DecimalParser};
 
 use fixed_decimal::Decimal;
 use icu_locale_core::locale;
@@ -298,6 +302,23 @@ impl DecimalFormatter {
         use writeable::Writeable;
         self.format(value).write_to_string().into_owned()
     }
+
+    /// Returns the symbols used by this formatter, such as the decimal separator
+    /// and the sign affixes.
+    ///
+    /// <div class="stab unstable">
+    /// 🚧 This code is considered unstable; it may change at any time, in breaking or non-breaking ways,
+    /// including in SemVer minor releases. The returned type is a data struct, whose Rust
+    /// representation might change. Use with caution.
+    /// </div>
+    pub fn symbols(&self) -> &provider::DecimalSymbols<'_> {
+        self.symbols.get()
+    }
+
+    /// Returns the digits used by this formatter, from zero to nine.
+    pub fn digits(&self) -> &[char; 10] {
+        self.digits.get()
+    }
 }
 
 #[test]
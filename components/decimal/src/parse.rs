@@ -0,0 +1,381 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Parsing of localized decimal numbers.
+
+use crate::provider::*;
+use crate::{DecimalFormatter, DecimalFormatterPreferences};
+use alloc::string::String;
+use fixed_decimal::Decimal;
+use icu_provider::prelude::*;
+
+/// An error returned by [`DecimalParser::parse`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq, displaydoc::Display)]
+#[non_exhaustive]
+pub enum DecimalParseError {
+    /// The input does not start with a number.
+    #[displaydoc("The input does not start with a number")]
+    NoDigits,
+    /// The grouping separators in the number are not placed according to the
+    /// grouping sizes of the locale.
+    ///
+    /// The field contains the byte index of the first grouping separator of the number.
+    #[displaydoc("Misplaced grouping separator at index {0}")]
+    InvalidGrouping(usize),
+    /// The number exceeds the magnitude or precision supported by [`Decimal`].
+    #[displaydoc("Magnitude or number of digits exceeded")]
+    Limit,
+}
+
+impl core::error::Error for DecimalParseError {}
+
+/// A parser for localized decimal numbers, the inverse of [`DecimalFormatter`].
+///
+/// [`DecimalParser`] uses the same data as [`DecimalFormatter`] and supports:
+///
+/// 1. Digits of the local numbering system, as well as ASCII digits
+/// 2. Locale-sensitive grouping separators, which are checked against the locale's grouping sizes
+/// 3. Locale-sensitive plus and minus signs, as well as `+`, `-` and `−` (U+2212 MINUS SIGN)
+///
+/// Parsing stops at the first character that cannot continue the number, and the number of
+/// bytes consumed is returned together with the value. This makes it possible to parse numbers
+/// embedded in longer text; to check that the whole input is a number, compare the consumed
+/// length with the input length.
+///
+/// ✨ *Enabled with the `alloc` Cargo feature.*
+///
+/// # Examples
+///
+/// ```
+/// use icu::decimal::input::Decimal;
+/// use icu::decimal::DecimalParser;
+/// use icu::locale::locale;
+///
+/// let parser = DecimalParser::try_new(locale!("de").into()).unwrap();
+///
+/// let (decimal, len) = parser.parse("1.234,56 €").unwrap();
+/// assert_eq!(decimal, "1234.56".parse::<Decimal>().unwrap());
+/// assert_eq!(len, 8);
+///
+/// let parser = DecimalParser::try_new(locale!("ar-EG").into()).unwrap();
+///
+/// let (decimal, len) = parser.parse("١٢٣").unwrap();
+/// assert_eq!(decimal, Decimal::from(123));
+/// assert_eq!(len, "١٢٣".len());
+///
+/// let parser = DecimalParser::try_new(locale!("sv").into()).unwrap();
+///
+/// let (decimal, _) = parser.parse("−5").unwrap();
+/// assert_eq!(decimal, Decimal::from(-5));
+/// ```
+///
+/// Grouping separators must be placed where the formatter would place them:
+///
+/// ```
+/// use icu::decimal::input::Decimal;
+/// use icu::decimal::{DecimalParseError, DecimalParser};
+/// use icu::locale::locale;
+///
+/// let parser = DecimalParser::try_new(locale!("en-IN").into()).unwrap();
+///
+/// let (decimal, _) = parser.parse("12,34,567").unwrap();
+/// assert_eq!(decimal, Decimal::from(1234567));
+///
+/// assert_eq!(
+///     parser.parse("1,234,567"),
+///     Err(DecimalParseError::InvalidGrouping(1))
+/// );
+/// ```
+#[derive(Debug, Clone)]
+pub struct DecimalParser {
+    symbols: DataPayload<DecimalSymbolsV1>,
+    digits: DataPayload<DecimalDigitsV1>,
+}
+
+impl AsRef<DecimalParser> for DecimalParser {
+    fn as_ref(&self) -> &DecimalParser {
+        self
+    }
+}
+
+impl DecimalParser {
+    icu_provider::gen_buffer_data_constructors!(
+        (prefs: DecimalFormatterPreferences) -> error: DataError,
+        /// Creates a new [`DecimalParser`] from compiled data.
+    );
+
+    #[doc = icu_provider::gen_buffer_unstable_docs!(UNSTABLE, Self::try_new)]
+    pub fn try_new_unstable<
+        D: DataProvider<DecimalSymbolsV1> + DataProvider<DecimalDigitsV1> + ?Sized,
+    >(
+        provider: &D,
+        prefs: DecimalFormatterPreferences,
+    ) -> Result<Self, DataError> {
+        // The numbering system resolution is the same as for the formatter
+        let DecimalFormatter {
+            symbols, digits, ..
+        } = DecimalFormatter::try_new_unstable(provider, prefs, Default::default())?;
+        Ok(Self { symbols, digits })
+    }
+
+    /// Parses a number at the start of `input`, returning it together with the number
+    /// of bytes of `input` that it spans.
+    pub fn parse(&self, input: &str) -> Result<(Decimal, usize), DecimalParseError> {
+        let symbols = self.symbols.get();
+        let digits = self.digits.get();
+        let mut cursor = Cursor { input, index: 0 };
+
+        // Locale-specific affixes come first, since they might start with a generic sign
+        let mut suffix = "";
+        let mut sign = None;
+        if let Some(affixes) = [
+            ('-', symbols.minus_sign_affixes()),
+            ('+', symbols.plus_sign_affixes()),
+        ]
+        .into_iter()
+        .find(|(_, (prefix, _))| !prefix.is_empty() && cursor.rest().starts_with(prefix))
+        {
+            let (sign_char, (prefix, affix_suffix)) = affixes;
+            cursor.index += prefix.len();
+            sign = Some(sign_char);
+            suffix = affix_suffix;
+        } else if let Some(c) = cursor.peek().filter(|c| matches!(c, '+' | '-' | '−')) {
+            cursor.index += c.len_utf8();
+            sign = Some(if c == '+' { '+' } else { '-' });
+        }
+
+        let mut ascii = String::new();
+        ascii.extend(sign);
+        let sign_len = ascii.len();
+
+        // Integer digits, with group lengths for validation
+        let grouping_separator = symbols.grouping_separator();
+        let sizes = symbols.grouping_sizes;
+        let mut group_len = 0;
+        let mut separators = 0;
+        let mut first_separator_index = None;
+        loop {
+            if let Some(digit) = cursor.peek().and_then(|c| digit_value(digits, c)) {
+                ascii.push(digit);
+                group_len += 1;
+                cursor.index += cursor.peek().map(char::len_utf8).unwrap_or_default();
+                continue;
+            }
+            // A grouping separator is only part of the number if a digit follows it
+            let Some(separator_len) = match_separator(cursor.rest(), grouping_separator) else {
+                break;
+            };
+            let after = Cursor {
+                input,
+                index: cursor.index + separator_len,
+            };
+            if sizes.primary == 0
+                || group_len == 0
+                || after.peek().and_then(|c| digit_value(digits, c)).is_none()
+            {
+                break;
+            }
+            // The leading group may be shorter, every later one must be a full secondary group
+            let secondary = if sizes.secondary == 0 {
+                sizes.primary
+            } else {
+                sizes.secondary
+            };
+            if group_len > usize::from(secondary)
+                || (separators > 0 && group_len != usize::from(secondary))
+            {
+                return Err(DecimalParseError::InvalidGrouping(
+                    first_separator_index.unwrap_or(cursor.index),
+                ));
+            }
+            first_separator_index.get_or_insert(cursor.index);
+            separators += 1;
+            group_len = 0;
+            cursor = after;
+        }
+        // The last group before the decimal separator is the primary group
+        if separators > 0 && group_len != usize::from(sizes.primary) {
+            return Err(DecimalParseError::InvalidGrouping(
+                first_separator_index.unwrap_or(cursor.index),
+            ));
+        }
+        let has_integer_digits = ascii.len() > sign_len;
+
+        // Fraction digits
+        if let Some(separator_len) = match_separator(cursor.rest(), symbols.decimal_separator()) {
+            let mut fraction = Cursor {
+                input,
+                index: cursor.index + separator_len,
+            };
+            if let Some(first) = fraction.peek().and_then(|c| digit_value(digits, c)) {
+                if !has_integer_digits {
+                    ascii.push('0');
+                }
+                ascii.push('.');
+                ascii.push(first);
+                fraction.index += fraction.peek().map(char::len_utf8).unwrap_or_default();
+                while let Some(c) = fraction.peek() {
+                    let Some(digit) = digit_value(digits, c) else {
+                        break;
+                    };
+                    ascii.push(digit);
+                    fraction.index += c.len_utf8();
+                }
+                cursor = fraction;
+            }
+        }
+        if ascii.len() == sign_len {
+            return Err(DecimalParseError::NoDigits);
+        }
+
+        if !suffix.is_empty() && cursor.rest().starts_with(suffix) {
+            cursor.index += suffix.len();
+        }
+
+        let decimal = Decimal::try_from_str(&ascii).map_err(|_| DecimalParseError::Limit)?;
+        Ok((decimal, cursor.index))
+    }
+}
+
+/// Returns the ASCII digit for a digit of the numbering system or an ASCII digit.
+fn digit_value(digits: &[char; 10], c: char) -> Option<char> {
+    if c.is_ascii_digit() {
+        return Some(c);
+    }
+    digits
+        .iter()
+        .zip('0'..='9')
+        .find_map(|(&digit, ascii)| (digit == c).then_some(ascii))
+}
+
+/// Returns the length of the separator at the start of `input`.
+///
+/// Locales that group with a space character use different kinds of spaces, which are all
+/// accepted in place of each other.
+fn match_separator(input: &str, separator: &str) -> Option<usize> {
+    if !separator.is_empty() && input.starts_with(separator) {
+        return Some(separator.len());
+    }
+    let mut separator_chars = separator.chars();
+    match (
+        separator_chars.next(),
+        separator_chars.next(),
+        input.chars().next(),
+    ) {
+        (Some(s), None, Some(c)) if s.is_whitespace() && c.is_whitespace() => Some(c.len_utf8()),
+        _ => None,
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Cursor<'a> {
+    input: &'a str,
+    index: usize,
+}
+
+impl<'a> Cursor<'a> {
+    fn rest(&self) -> &'a str {
+        self.input.get(self.index..).unwrap_or_default()
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.rest().chars().next()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use icu_locale_core::locale;
+    use writeable::Writeable;
+
+    #[test]
+    fn test_round_trip() {
+        for locale in [
+            locale!("en"),
+            locale!("de"),
+            locale!("fr"),
+            locale!("en-IN"),
+            locale!("es"),
+            locale!("ar-EG"),
+            locale!("fa"),
+            locale!("bn"),
+            locale!("th-u-nu-thai"),
+            locale!("sv"),
+            locale!("he"),
+        ] {
+            let formatter =
+                DecimalFormatter::try_new((&locale).into(), Default::default()).unwrap();
+            let parser = DecimalParser::try_new((&locale).into()).unwrap();
+            for input in [
+                "0",
+                "7",
+                "-7",
+                "+7",
+                "1000",
+                "12345.67",
+                "-1234567.890",
+                "0.05",
+                "-0",
+            ] {
+                let decimal: Decimal = input.parse().unwrap();
+                let formatted = formatter.format(&decimal).write_to_string().into_owned();
+                assert_eq!(
+                    parser.parse(&formatted),
+                    Ok((decimal.clone(), formatted.len())),
+                    "{locale}: {formatted}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_partial_input() {
+        let parser = DecimalParser::try_new(locale!("en").into()).unwrap();
+        // Separators that are not followed by digits end the number
+        assert_eq!(parser.parse("1, 2, 3"), Ok((Decimal::from(1), 1)));
+        assert_eq!(parser.parse("15. Mai"), Ok((Decimal::from(15), 2)));
+        assert_eq!(parser.parse(".5%"), Ok(("0.5".parse().unwrap(), 2)));
+        assert_eq!(parser.parse("abc"), Err(DecimalParseError::NoDigits));
+        assert_eq!(parser.parse("-"), Err(DecimalParseError::NoDigits));
+        assert_eq!(parser.parse(""), Err(DecimalParseError::NoDigits));
+    }
+
+    #[test]
+    fn test_grouping() {
+        let parser = DecimalParser::try_new(locale!("en").into()).unwrap();
+        assert_eq!(parser.parse("1,234"), Ok((Decimal::from(1234), 5)));
+        assert_eq!(
+            parser.parse("1234,567"),
+            Err(DecimalParseError::InvalidGrouping(4))
+        );
+        assert_eq!(
+            parser.parse("1,23"),
+            Err(DecimalParseError::InvalidGrouping(1))
+        );
+        assert_eq!(
+            parser.parse("1,2345"),
+            Err(DecimalParseError::InvalidGrouping(1))
+        );
+        assert_eq!(
+            parser.parse("1,234,56.7"),
+            Err(DecimalParseError::InvalidGrouping(1))
+        );
+
+        // French groups with U+202F NARROW NO-BREAK SPACE, but users type other spaces
+        let parser = DecimalParser::try_new(locale!("fr").into()).unwrap();
+        assert_eq!(
+            parser.parse("1 234 567,5"),
+            Ok(("1234567.5".parse().unwrap(), 11))
+        );
+    }
+
+    #[test]
+    fn test_limit() {
+        let parser = DecimalParser::try_new(locale!("en").into()).unwrap();
+        let mut input = String::from("1");
+        input.extend(core::iter::repeat_n('0', 40000));
+        assert_eq!(parser.parse(&input), Err(DecimalParseError::Limit));
+    }
+}
@@ -0,0 +1,354 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+use icu_collator::options::{CollatorOptions, Strength};
+use icu_collator::preferences::CollationType;
+use icu_collator::provider::{
+    CollationDiacriticsV1, CollationJamoV1, CollationMetadataV1, CollationReorderingV1,
+    CollationRootV1, CollationSpecialPrimariesV1, CollationTailoringV1,
+};
+use icu_collator::{Collator, CollatorBorrowed, CollatorPreferences};
+use icu_collections::codepointinvliststringlist::CodePointInversionListAndStringList;
+use icu_locale::exemplar_chars::ExemplarCharacters;
+use icu_locale::provider::LocaleExemplarCharactersIndexV1;
+use icu_locale_core::preferences::{define_preferences, prefs_convert};
+use icu_normalizer::provider::{NormalizerNfdDataV1, NormalizerNfdTablesV1};
+use icu_properties::props::Script;
+use icu_properties::provider::PropertyEnumScriptV1;
+use icu_properties::CodePointMapData;
+use icu_provider::prelude::*;
+
+define_preferences!(
+    /// The preferences for alphabetic indexes.
+    [Copy]
+    AlphabeticIndexPreferences,
+    {
+        /// The collation type. This corresponds to the `-u-co` BCP-47 tag.
+        collation_type: CollationType
+    }
+);
+
+prefs_convert!(AlphabeticIndexPreferences, CollatorPreferences, {
+    collation_type
+});
+
+/// The label used for the underflow, inflow, and overflow buckets by default.
+const ELLIPSIS: &str = "…";
+
+/// The kind of an alphabetic index bucket.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum BucketKind {
+    /// The bucket for names that sort before the first label, such as numbers and symbols.
+    Underflow,
+    /// The bucket for names that sort at or after a label, and before the next one.
+    Normal,
+    /// The bucket for names between the labels of two scripts, in a script that has no labels.
+    Inflow,
+    /// The bucket for names that sort after the last label, in a script that has no labels.
+    Overflow,
+}
+
+/// A bucket of an [`AlphabeticIndex`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct Bucket<'a> {
+    /// The label to display for the bucket.
+    pub label: &'a str,
+    /// The kind of the bucket.
+    pub kind: BucketKind,
+}
+
+#[derive(Debug)]
+struct Label {
+    text: String,
+    script: Script,
+}
+
+/// An alphabetic index, which assigns names to buckets labeled by the index exemplar characters
+/// of one or more locales.
+///
+/// The buckets are, in order: an underflow bucket, a bucket for each label in collation order,
+/// with an inflow bucket between adjacent labels of different scripts, and an overflow bucket.
+/// Labels that are equal at the primary level, such as Russian "Е" and "Ё", share one bucket.
+///
+/// See the [module-level documentation](super) for an example.
+#[derive(Debug)]
+pub struct AlphabeticIndex {
+    prefs: AlphabeticIndexPreferences,
+    collator: Collator,
+    scripts: CodePointMapData<Script>,
+    labels: Vec<Label>,
+    underflow_label: String,
+    inflow_label: String,
+    overflow_label: String,
+}
+
+impl AlphabeticIndex {
+    icu_provider::gen_buffer_data_constructors!(
+        (prefs: AlphabeticIndexPreferences) -> error: DataError,
+        functions: [
+            try_new: skip,
+            try_new_with_buffer_provider,
+            try_new_unstable,
+            Self
+        ]
+    );
+
+    /// Creates a new [`AlphabeticIndex`] with the labels of the given locale from compiled data.
+    ///
+    /// ✨ *Enabled with the `compiled_data` Cargo feature.*
+    ///
+    /// [📚 Help choosing a constructor](icu_provider::constructors)
+    #[cfg(feature = "compiled_data")]
+    pub fn try_new(prefs: AlphabeticIndexPreferences) -> Result<Self, DataError> {
+        let mut index = Self {
+            prefs,
+            collator: Collator::try_new(
+                (&prefs).into(),
+                Self::collator_options(Strength::Primary),
+            )?
+            .static_to_owned(),
+            scripts: CodePointMapData::<Script>::new().static_to_owned(),
+            labels: Vec::new(),
+            underflow_label: ELLIPSIS.into(),
+            inflow_label: ELLIPSIS.into(),
+            overflow_label: ELLIPSIS.into(),
+        };
+        index.try_add_labels(&LocaleExemplarCharactersIndexV1::make_locale(
+            prefs.locale_preferences,
+        ))?;
+        Ok(index)
+    }
+
+    #[doc = icu_provider::gen_buffer_unstable_docs!(UNSTABLE, Self::try_new)]
+    pub fn try_new_unstable<P>(
+        provider: &P,
+        prefs: AlphabeticIndexPreferences,
+    ) -> Result<Self, DataError>
+    where
+        P: ?Sized
+            + DataProvider<CollationSpecialPrimariesV1>
+            + DataProvider<CollationRootV1>
+            + DataProvider<CollationTailoringV1>
+            + DataProvider<CollationDiacriticsV1>
+            + DataProvider<CollationJamoV1>
+            + DataProvider<CollationMetadataV1>
+            + DataProvider<CollationReorderingV1>
+            + DataProvider<NormalizerNfdDataV1>
+            + DataProvider<NormalizerNfdTablesV1>
+            + DataProvider<LocaleExemplarCharactersIndexV1>
+            + DataProvider<PropertyEnumScriptV1>,
+    {
+        let mut index = Self {
+            prefs,
+            collator: Collator::try_new_unstable(
+                provider,
+                (&prefs).into(),
+                Self::collator_options(Strength::Primary),
+            )?,
+            scripts: CodePointMapData::<Script>::try_new_unstable(provider)?,
+            labels: Vec::new(),
+            underflow_label: ELLIPSIS.into(),
+            inflow_label: ELLIPSIS.into(),
+            overflow_label: ELLIPSIS.into(),
+        };
+        index.try_add_labels_unstable(
+            provider,
+            &LocaleExemplarCharactersIndexV1::make_locale(prefs.locale_preferences),
+        )?;
+        Ok(index)
+    }
+
+    /// Adds the labels of another locale from compiled data, such as "A" to "Z" to a Japanese
+    /// index for names in Latin script.
+    ///
+    /// Labels that are equal at the primary level to an existing label are ignored.
+    ///
+    /// ✨ *Enabled with the `compiled_data` Cargo feature.*
+    #[cfg(feature = "compiled_data")]
+    pub fn try_add_labels(&mut self, locale: &DataLocale) -> Result<(), DataError> {
+        let exemplars = ExemplarCharacters::try_new_index(locale)?;
+        let tertiary = Collator::try_new(
+            (&self.prefs).into(),
+            Self::collator_options(Strength::Tertiary),
+        )?;
+        self.add_labels(&exemplars, &tertiary);
+        Ok(())
+    }
+
+    /// A version of [`Self::try_add_labels()`] that uses custom data provided by a [`DataProvider`].
+    ///
+    /// [📚 Help choosing a constructor](icu_provider::constructors)
+    pub fn try_add_labels_unstable<P>(
+        &mut self,
+        provider: &P,
+        locale: &DataLocale,
+    ) -> Result<(), DataError>
+    where
+        P: ?Sized
+            + DataProvider<CollationSpecialPrimariesV1>
+            + DataProvider<CollationRootV1>
+            + DataProvider<CollationTailoringV1>
+            + DataProvider<CollationDiacriticsV1>
+            + DataProvider<CollationJamoV1>
+            + DataProvider<CollationMetadataV1>
+            + DataProvider<CollationReorderingV1>
+            + DataProvider<NormalizerNfdDataV1>
+            + DataProvider<NormalizerNfdTablesV1>
+            + DataProvider<LocaleExemplarCharactersIndexV1>,
+    {
+        let exemplars = ExemplarCharacters::try_new_index_unstable(provider, locale)?;
+        let tertiary = Collator::try_new_unstable(
+            provider,
+            (&self.prefs).into(),
+            Self::collator_options(Strength::Tertiary),
+        )?;
+        self.add_labels(&exemplars.as_borrowed(), &tertiary.as_borrowed());
+        Ok(())
+    }
+
+    /// Sets the label of the bucket for names that sort before the first label. The default
+    /// is "…".
+    pub fn set_underflow_label(&mut self, label: &str) {
+        self.underflow_label = label.into();
+    }
+
+    /// Sets the label of the buckets for names between the labels of two scripts. The default
+    /// is "…".
+    pub fn set_inflow_label(&mut self, label: &str) {
+        self.inflow_label = label.into();
+    }
+
+    /// Sets the label of the bucket for names that sort after the last label. The default
+    /// is "…".
+    pub fn set_overflow_label(&mut self, label: &str) {
+        self.overflow_label = label.into();
+    }
+
+    /// Returns all buckets of the index in order.
+    pub fn buckets(&self) -> impl Iterator<Item = Bucket<'_>> + '_ {
+        let underflow = Bucket {
+            label: &self.underflow_label,
+            kind: BucketKind::Underflow,
+        };
+        let overflow = Bucket {
+            label: &self.overflow_label,
+            kind: BucketKind::Overflow,
+        };
+        let labels = self.labels.iter().enumerate().flat_map(|(i, label)| {
+            let normal = Bucket {
+                label: &label.text,
+                kind: BucketKind::Normal,
+            };
+            let inflow = self.has_inflow_after(i).then_some(Bucket {
+                label: &self.inflow_label,
+                kind: BucketKind::Inflow,
+            });
+            core::iter::once(normal).chain(inflow)
+        });
+        core::iter::once(underflow)
+            .chain(labels)
+            .chain(core::iter::once(overflow))
+    }
+
+    /// Returns the position in [`Self::buckets()`] of the bucket for `name`.
+    pub fn bucket_index(&self, name: &str) -> usize {
+        let collator = self.collator.as_borrowed();
+        let count = self
+            .labels
+            .partition_point(|label| collator.compare(&label.text, name) != Ordering::Greater);
+        let Some(last) = count.checked_sub(1) else {
+            return 0;
+        };
+        let inflows = (0..last).filter(|&i| self.has_inflow_after(i)).count();
+        let normal = 1 + last + inflows;
+        let script = name
+            .chars()
+            .next()
+            .map(|c| Self::index_script(self.scripts.as_borrowed().get(c)));
+        match script {
+            Some(script)
+                if !matches!(script, Script::Common | Script::Inherited | Script::Unknown)
+                    && self
+                        .labels
+                        .get(last)
+                        .is_some_and(|label| label.script != script) =>
+            {
+                // The name is in a script without labels, so it goes into the following inflow or
+                // overflow bucket if there is one.
+                if count == self.labels.len() || self.has_inflow_after(last) {
+                    normal + 1
+                } else {
+                    normal
+                }
+            }
+            _ => normal,
+        }
+    }
+
+    /// Returns the bucket for `name`.
+    pub fn bucket(&self, name: &str) -> Bucket<'_> {
+        let index = self.bucket_index(name);
+        self.buckets().nth(index).unwrap_or(Bucket {
+            label: &self.overflow_label,
+            kind: BucketKind::Overflow,
+        })
+    }
+
+    fn collator_options(strength: Strength) -> CollatorOptions {
+        let mut options = CollatorOptions::default();
+        options.strength = Some(strength);
+        options
+    }
+
+    /// Whether there is an inflow bucket between the labels at `i` and `i + 1`.
+    fn has_inflow_after(&self, i: usize) -> bool {
+        match (self.labels.get(i), self.labels.get(i + 1)) {
+            (Some(a), Some(b)) => a.script != b.script,
+            _ => false,
+        }
+    }
+
+    /// Treats scripts that share index labels, such as Hiragana and Katakana, as one.
+    fn index_script(script: Script) -> Script {
+        if script == Script::Katakana {
+            Script::Hiragana
+        } else {
+            script
+        }
+    }
+
+    fn add_labels(
+        &mut self,
+        exemplars: &CodePointInversionListAndStringList,
+        tertiary: &CollatorBorrowed,
+    ) {
+        let mut new_labels: Vec<String> = exemplars
+            .code_points()
+            .iter_chars()
+            .map(String::from)
+            .collect();
+        new_labels.extend(exemplars.strings().iter().map(String::from));
+        // Of the labels that are equal at the primary level, keep the first one at full strength.
+        new_labels.sort_by(|a, b| tertiary.compare(a, b));
+        let collator = self.collator.as_borrowed();
+        for text in new_labels {
+            if let Err(i) = self
+                .labels
+                .binary_search_by(|label| collator.compare(&label.text, &text))
+            {
+                let script = text
+                    .chars()
+                    .next()
+                    .map(|c| Self::index_script(self.scripts.as_borrowed().get(c)))
+                    .unwrap_or(Script::Unknown);
+                self.labels.insert(i, Label { text, script });
+            }
+        }
+    }
+}
@@ -0,0 +1,38 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Alphabetic indexes, for grouping sorted lists of names under labels such as "A", "B", …
+//!
+//! The labels come from the index exemplar characters of one or more locales, and names are
+//! assigned to buckets by comparing them to the labels with a primary-strength collator, so that
+//! "Émile" goes into the "E" bucket and, in Japanese, "がっこう" goes into the "か" row.
+//!
+//! ✨ *Enabled with the `alphabeticindex` Cargo feature.*
+//!
+//! # Examples
+//!
+//! ```
+//! use icu::experimental::alphabeticindex::{AlphabeticIndex, BucketKind};
+//! use icu::locale::locale;
+//!
+//! let mut index = AlphabeticIndex::try_new(locale!("en").into()).unwrap();
+//! index.set_underflow_label("#");
+//!
+//! assert_eq!(index.bucket("apple").label, "A");
+//! assert_eq!(index.bucket("Émile").label, "E");
+//! assert_eq!(index.bucket("42").label, "#");
+//! assert_eq!(index.bucket("Ωmega").kind, BucketKind::Overflow);
+//!
+//! index.try_add_labels(&locale!("ja").into()).unwrap();
+//!
+//! assert_eq!(index.bucket("がっこう").label, "か");
+//! assert_eq!(index.bucket("イトウ").label, "あ");
+//! assert_eq!(index.bucket("Ωmega").kind, BucketKind::Inflow);
+//! ```
+
+#![warn(missing_docs)]
+
+mod index;
+
+pub use index::{AlphabeticIndex, AlphabeticIndexPreferences, Bucket, BucketKind};
@@ -0,0 +1,589 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! The paragraph-level rules of UAX #9: P2 and P3, the explicit rules
+//! X1 to X10, the weak rules W1 to W7, the neutral rules N0 to N2, and
+//! the implicit rules I1 and I2.
+
+use super::Level;
+use alloc::vec;
+use alloc::vec::Vec;
+use icu_properties::props::BidiClass;
+use BidiClass as B;
+
+/// The maximum explicit embedding level, per BD2.
+const MAX_DEPTH: u8 = 125;
+
+/// The maximum number of nested brackets that are paired, per BD16.
+const MAX_BRACKET_DEPTH: usize = 63;
+
+/// A paired bracket, per BD14 and BD15.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct Bracket {
+    /// Whether this is an opening bracket.
+    pub(crate) opening: bool,
+    /// The closing bracket of the pair, after canonical equivalence.
+    pub(crate) closing: char,
+}
+
+fn is_isolate_initiator(class: BidiClass) -> bool {
+    matches!(
+        class,
+        B::LeftToRightIsolate | B::RightToLeftIsolate | B::FirstStrongIsolate
+    )
+}
+
+/// Whether the character is removed by X9.
+fn is_removed_by_x9(class: BidiClass) -> bool {
+    matches!(
+        class,
+        B::LeftToRightEmbedding
+            | B::RightToLeftEmbedding
+            | B::LeftToRightOverride
+            | B::RightToLeftOverride
+            | B::PopDirectionalFormat
+            | B::BoundaryNeutral
+    )
+}
+
+/// The strong direction of a resolved type for the neutral rules, which
+/// treat numbers as R.
+fn strong_direction(class: BidiClass) -> Option<BidiClass> {
+    match class {
+        B::LeftToRight => Some(B::LeftToRight),
+        B::RightToLeft | B::ArabicLetter | B::EuropeanNumber | B::ArabicNumber => {
+            Some(B::RightToLeft)
+        }
+        _ => None,
+    }
+}
+
+fn direction_of(level: Level) -> BidiClass {
+    if level.is_rtl() {
+        B::RightToLeft
+    } else {
+        B::LeftToRight
+    }
+}
+
+/// P2 and P3: the level given by the first strong character, skipping
+/// isolates. With `stop_at_pdi`, an unmatched PDI ends the search, as X5c
+/// requires for the content of an FSI.
+pub(crate) fn first_strong_level(classes: &[BidiClass], stop_at_pdi: bool) -> Option<Level> {
+    let mut depth = 0usize;
+    for &class in classes {
+        match class {
+            B::LeftToRight if depth == 0 => return Some(Level::LTR),
+            B::RightToLeft | B::ArabicLetter if depth == 0 => return Some(Level::RTL),
+            c if is_isolate_initiator(c) => depth += 1,
+            B::PopDirectionalIsolate => {
+                if depth > 0 {
+                    depth -= 1;
+                } else if stop_at_pdi {
+                    return None;
+                }
+            }
+            _ => {}
+        }
+    }
+    None
+}
+
+/// An entry of the directional status stack of X1.
+#[derive(Clone, Copy)]
+struct Status {
+    level: u8,
+    override_class: Option<BidiClass>,
+    isolate: bool,
+}
+
+/// Resolves the embedding levels of a paragraph with the given original
+/// bidi classes, writing them into `levels`.
+///
+/// Characters removed by X9 get the level of the preceding character.
+pub(crate) fn resolve_paragraph(
+    original: &[BidiClass],
+    bracket: impl Fn(usize) -> Option<Bracket>,
+    paragraph_level: Level,
+    levels: &mut [Level],
+) {
+    let len = original.len();
+    let mut classes = original.to_vec();
+
+    // BD9: matching isolate initiators and PDIs.
+    let mut matching_pdi = vec![None; len];
+    let mut open = Vec::new();
+    for (i, &class) in original.iter().enumerate() {
+        if is_isolate_initiator(class) {
+            open.push(i);
+        } else if class == B::PopDirectionalIsolate {
+            if let Some(slot) = open
+                .pop()
+                .and_then(|initiator| matching_pdi.get_mut(initiator))
+            {
+                *slot = Some(i);
+            }
+        }
+    }
+
+    // X1 to X8: explicit levels and directions.
+    let mut stack = vec![Status {
+        level: paragraph_level.0,
+        override_class: None,
+        isolate: false,
+    }];
+    let mut overflow_isolates = 0usize;
+    let mut overflow_embeddings = 0usize;
+    let mut valid_isolates = 0usize;
+    let mut raw_levels = vec![paragraph_level.0; len];
+    for (i, ((&original_class, raw_level), class)) in original
+        .iter()
+        .zip(raw_levels.iter_mut())
+        .zip(classes.iter_mut())
+        .enumerate()
+    {
+        let top = stack.last().copied().unwrap_or(Status {
+            level: paragraph_level.0,
+            override_class: None,
+            isolate: false,
+        });
+        let next_level = |rtl: bool| {
+            if rtl {
+                (top.level + 1) | 1
+            } else {
+                (top.level + 2) & !1
+            }
+        };
+        match original_class {
+            B::RightToLeftEmbedding
+            | B::LeftToRightEmbedding
+            | B::RightToLeftOverride
+            | B::LeftToRightOverride => {
+                *raw_level = top.level;
+                let level = next_level(matches!(
+                    original_class,
+                    B::RightToLeftEmbedding | B::RightToLeftOverride
+                ));
+                if level <= MAX_DEPTH && overflow_isolates == 0 && overflow_embeddings == 0 {
+                    stack.push(Status {
+                        level,
+                        override_class: match original_class {
+                            B::RightToLeftOverride => Some(B::RightToLeft),
+                            B::LeftToRightOverride => Some(B::LeftToRight),
+                            _ => None,
+                        },
+                        isolate: false,
+                    });
+                } else if overflow_isolates == 0 {
+                    overflow_embeddings += 1;
+                }
+            }
+            B::RightToLeftIsolate | B::LeftToRightIsolate | B::FirstStrongIsolate => {
+                *raw_level = top.level;
+                if let Some(override_class) = top.override_class {
+                    *class = override_class;
+                }
+                let rtl = match original_class {
+                    B::RightToLeftIsolate => true,
+                    B::LeftToRightIsolate => false,
+                    _ => {
+                        let end = matching_pdi.get(i).copied().flatten().unwrap_or(len);
+                        let content = original.get(i + 1..end).unwrap_or_default();
+                        first_strong_level(content, true) == Some(Level::RTL)
+                    }
+                };
+                let level = next_level(rtl);
+                if level <= MAX_DEPTH && overflow_isolates == 0 && overflow_embeddings == 0 {
+                    valid_isolates += 1;
+                    stack.push(Status {
+                        level,
+                        override_class: None,
+                        isolate: true,
+                    });
+                } else {
+                    overflow_isolates += 1;
+                }
+            }
+            B::PopDirectionalIsolate => {
+                if overflow_isolates > 0 {
+                    overflow_isolates -= 1;
+                } else if valid_isolates > 0 {
+                    overflow_embeddings = 0;
+                    while stack.last().is_some_and(|status| !status.isolate) {
+                        stack.pop();
+                    }
+                    stack.pop();
+                    valid_isolates -= 1;
+                }
+                let top = stack.last().copied().unwrap_or(top);
+                *raw_level = top.level;
+                if let Some(override_class) = top.override_class {
+                    *class = override_class;
+                }
+            }
+            B::PopDirectionalFormat => {
+                *raw_level = top.level;
+                if overflow_isolates > 0 {
+                } else if overflow_embeddings > 0 {
+                    overflow_embeddings -= 1;
+                } else if !top.isolate && stack.len() >= 2 {
+                    stack.pop();
+                }
+            }
+            B::ParagraphSeparator => *raw_level = paragraph_level.0,
+            B::BoundaryNeutral => *raw_level = top.level,
+            _ => {
+                *raw_level = top.level;
+                if let Some(override_class) = top.override_class {
+                    *class = override_class;
+                }
+            }
+        }
+    }
+
+    // X9 and X10: level runs of the characters that are not removed, and
+    // the isolating run sequences that they form.
+    let mut runs: Vec<(u8, Vec<usize>)> = Vec::new();
+    for (i, (_, &level)) in original
+        .iter()
+        .zip(&raw_levels)
+        .enumerate()
+        .filter(|(_, (&class, _))| !is_removed_by_x9(class))
+    {
+        match runs.last_mut() {
+            Some((run_level, run)) if *run_level == level => run.push(i),
+            _ => runs.push((level, vec![i])),
+        }
+    }
+    let mut run_starting_at = vec![None; len];
+    for (r, (_, run)) in runs.iter().enumerate() {
+        if let Some(slot) = run
+            .first()
+            .and_then(|&first| run_starting_at.get_mut(first))
+        {
+            *slot = Some(r);
+        }
+    }
+    // The runs that continue the isolating run sequence of an earlier run.
+    let mut continuation = vec![None; runs.len()];
+    for ((_, run), next) in runs.iter().zip(continuation.iter_mut()) {
+        *next = run
+            .last()
+            .and_then(|&last| matching_pdi.get(last).copied().flatten())
+            .and_then(|pdi| run_starting_at.get(pdi).copied().flatten());
+    }
+    let mut is_continuation = vec![false; runs.len()];
+    for &next in continuation.iter().flatten() {
+        if let Some(slot) = is_continuation.get_mut(next) {
+            *slot = true;
+        }
+    }
+
+    let starts = is_continuation
+        .iter()
+        .enumerate()
+        .filter(|&(_, &is_continuation)| !is_continuation);
+    for (start, _) in starts {
+        let mut sequence = Vec::new();
+        let mut level = paragraph_level.0;
+        let mut r = Some(start);
+        while let Some((run_level, run)) = r.and_then(|current| runs.get(current)) {
+            if sequence.is_empty() {
+                level = *run_level;
+            }
+            sequence.extend_from_slice(run);
+            r = r.and_then(|current| continuation.get(current).copied().flatten());
+        }
+        let (Some(&first), Some(&last)) = (sequence.first(), sequence.last()) else {
+            continue;
+        };
+        let not_removed = |(&class, _): &(&BidiClass, &u8)| !is_removed_by_x9(class);
+        let before = original
+            .iter()
+            .zip(&raw_levels)
+            .take(first)
+            .rev()
+            .find(not_removed)
+            .map_or(paragraph_level.0, |(_, &level)| level);
+        let after = if original
+            .get(last)
+            .copied()
+            .is_some_and(is_isolate_initiator)
+        {
+            paragraph_level.0
+        } else {
+            original
+                .iter()
+                .zip(&raw_levels)
+                .skip(last + 1)
+                .find(not_removed)
+                .map_or(paragraph_level.0, |(_, &level)| level)
+        };
+        let sos = direction_of(Level(level.max(before)));
+        let eos = direction_of(Level(level.max(after)));
+        resolve_sequence(
+            &sequence,
+            original,
+            &mut classes,
+            &bracket,
+            Level(level),
+            sos,
+            eos,
+        );
+
+        // I1 and I2.
+        for &i in &sequence {
+            if let (Some(raw_level), Some(&class)) = (raw_levels.get_mut(i), classes.get(i)) {
+                *raw_level = match (level % 2 == 1, class) {
+                    (false, B::RightToLeft) => level + 1,
+                    (false, B::ArabicNumber | B::EuropeanNumber) => level + 2,
+                    (true, B::LeftToRight | B::EuropeanNumber | B::ArabicNumber) => level + 1,
+                    _ => level,
+                };
+            }
+        }
+    }
+
+    let mut previous = paragraph_level.0;
+    for ((level, &class), &raw_level) in levels.iter_mut().zip(original).zip(&raw_levels) {
+        if !is_removed_by_x9(class) {
+            previous = raw_level;
+        }
+        *level = Level(previous);
+    }
+}
+
+/// Calls `f` with the bounds of each maximal run of positions in `classes`
+/// whose class satisfies `predicate`.
+fn for_each_run(
+    classes: &mut [BidiClass],
+    predicate: impl Fn(BidiClass) -> bool,
+    mut f: impl FnMut(&mut [BidiClass], usize, usize),
+) {
+    let mut k = 0;
+    while k < classes.len() {
+        let start = k;
+        while classes.get(k).is_some_and(|&class| predicate(class)) {
+            k += 1;
+        }
+        if k == start {
+            k += 1;
+        } else {
+            f(classes, start, k);
+        }
+    }
+}
+
+/// The weak and neutral rules for one isolating run sequence.
+fn resolve_sequence(
+    sequence: &[usize],
+    original: &[BidiClass],
+    classes: &mut [BidiClass],
+    bracket: &impl Fn(usize) -> Option<Bracket>,
+    level: Level,
+    sos: BidiClass,
+    eos: BidiClass,
+) {
+    // The classes of the sequence, in order, which are written back at the end.
+    let mut resolved: Vec<BidiClass> = sequence
+        .iter()
+        .map(|&i| classes.get(i).copied().unwrap_or(B::OtherNeutral))
+        .collect();
+
+    // W1
+    let mut previous = sos;
+    for class in resolved.iter_mut() {
+        if *class == B::NonspacingMark {
+            *class = if is_isolate_initiator(previous) || previous == B::PopDirectionalIsolate {
+                B::OtherNeutral
+            } else {
+                previous
+            };
+        }
+        previous = *class;
+    }
+
+    // W2 and W3
+    let mut last_strong = sos;
+    for class in resolved.iter_mut() {
+        match *class {
+            B::LeftToRight | B::RightToLeft => last_strong = *class,
+            B::ArabicLetter => {
+                last_strong = B::ArabicLetter;
+                *class = B::RightToLeft;
+            }
+            B::EuropeanNumber if last_strong == B::ArabicLetter => *class = B::ArabicNumber,
+            _ => {}
+        }
+    }
+
+    // W4
+    for k in 1..resolved.len().saturating_sub(1) {
+        let (Some(&previous), Some(&next)) = (resolved.get(k - 1), resolved.get(k + 1)) else {
+            continue;
+        };
+        let Some(class) = resolved.get_mut(k) else {
+            continue;
+        };
+        match *class {
+            B::EuropeanSeparator if previous == B::EuropeanNumber && next == B::EuropeanNumber => {
+                *class = B::EuropeanNumber
+            }
+            B::CommonSeparator
+                if previous == next && matches!(previous, B::EuropeanNumber | B::ArabicNumber) =>
+            {
+                *class = previous
+            }
+            _ => {}
+        }
+    }
+
+    // W5
+    for_each_run(
+        &mut resolved,
+        |class| class == B::EuropeanTerminator,
+        |resolved, start, end| {
+            let is_number =
+                |k: Option<usize>| k.and_then(|k| resolved.get(k)) == Some(&B::EuropeanNumber);
+            if is_number(start.checked_sub(1)) || is_number(Some(end)) {
+                resolved
+                    .get_mut(start..end)
+                    .unwrap_or_default()
+                    .fill(B::EuropeanNumber);
+            }
+        },
+    );
+
+    // W6
+    for class in resolved.iter_mut() {
+        if matches!(
+            *class,
+            B::EuropeanSeparator | B::EuropeanTerminator | B::CommonSeparator
+        ) {
+            *class = B::OtherNeutral;
+        }
+    }
+
+    // W7
+    let mut last_strong = sos;
+    for class in resolved.iter_mut() {
+        match *class {
+            B::LeftToRight | B::RightToLeft => last_strong = *class,
+            B::EuropeanNumber if last_strong == B::LeftToRight => *class = B::LeftToRight,
+            _ => {}
+        }
+    }
+
+    let embedding_direction = direction_of(level);
+
+    // N0
+    let mut openers: Vec<(char, usize)> = Vec::new();
+    let mut pairs = Vec::new();
+    for (k, (&i, &class)) in sequence.iter().zip(&resolved).enumerate() {
+        if class != B::OtherNeutral {
+            continue;
+        }
+        match bracket(i) {
+            Some(Bracket {
+                opening: true,
+                closing,
+            }) => {
+                if openers.len() == MAX_BRACKET_DEPTH {
+                    break;
+                }
+                openers.push((closing, k));
+            }
+            Some(Bracket {
+                opening: false,
+                closing,
+            }) => {
+                if let Some(position) = openers.iter().rposition(|&(c, _)| c == closing) {
+                    if let Some(&(_, open)) = openers.get(position) {
+                        pairs.push((open, k));
+                    }
+                    openers.truncate(position);
+                }
+            }
+            None => {}
+        }
+    }
+    pairs.sort_unstable();
+    for (open, close) in pairs {
+        let mut found_embedding = false;
+        let mut found_opposite = false;
+        for &class in resolved.get(open + 1..close).unwrap_or_default() {
+            match strong_direction(class) {
+                Some(direction) if direction == embedding_direction => {
+                    found_embedding = true;
+                    break;
+                }
+                Some(_) => found_opposite = true,
+                None => {}
+            }
+        }
+        let direction = if found_embedding {
+            embedding_direction
+        } else if found_opposite {
+            resolved
+                .get(..open)
+                .unwrap_or_default()
+                .iter()
+                .rev()
+                .find_map(|&class| strong_direction(class))
+                .unwrap_or(sos)
+        } else {
+            continue;
+        };
+        for bracket_position in [open, close] {
+            // The bracket and the nonspacing marks that follow it, per N0.
+            let marks = sequence
+                .iter()
+                .skip(bracket_position + 1)
+                .take_while(|&&i| original.get(i) == Some(&B::NonspacingMark))
+                .count();
+            resolved
+                .get_mut(bracket_position..=bracket_position + marks)
+                .unwrap_or_default()
+                .fill(direction);
+        }
+    }
+
+    // N1 and N2
+    let is_neutral = |class| {
+        matches!(
+            class,
+            B::ParagraphSeparator
+                | B::SegmentSeparator
+                | B::WhiteSpace
+                | B::OtherNeutral
+                | B::PopDirectionalIsolate
+        ) || is_isolate_initiator(class)
+    };
+    for_each_run(&mut resolved, is_neutral, |resolved, start, end| {
+        let before = start
+            .checked_sub(1)
+            .and_then(|k| resolved.get(k))
+            .and_then(|&class| strong_direction(class))
+            .unwrap_or(sos);
+        let after = resolved
+            .get(end)
+            .and_then(|&class| strong_direction(class))
+            .unwrap_or(eos);
+        let direction = if before == after {
+            before
+        } else {
+            embedding_direction
+        };
+        resolved
+            .get_mut(start..end)
+            .unwrap_or_default()
+            .fill(direction);
+    });
+
+    for (&i, class) in sequence.iter().zip(resolved) {
+        if let Some(slot) = classes.get_mut(i) {
+            *slot = class;
+        }
+    }
+}
@@ -0,0 +1,77 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! The Unicode Bidirectional Algorithm, as defined in [UAX #9].
+//!
+//! [`BidiResolver`] resolves the embedding levels of UTF-8 or UTF-16 text, using the
+//! Bidi_Class and Bidi_Paired_Bracket_Type properties. The resulting [`BidiInfo`] reorders
+//! each line into visual runs, and lists the characters of each line that are displayed
+//! mirrored.
+//!
+//! Line breaking is not part of the algorithm: lines are given as ranges, for example
+//! from the break opportunities of a `LineSegmenter`.
+//!
+//! [UAX #9]: https://www.unicode.org/reports/tr9/
+//!
+//! # Examples
+//!
+//! ```
+//! use icu::experimental::bidi::{BidiResolver, Level};
+//!
+//! let resolver = BidiResolver::new();
+//!
+//! // "ABC" in Hebrew, followed by "(123) def".
+//! let text = "אבג (123) def";
+//! let info = resolver.resolve_str(text, Some(Level::LTR));
+//!
+//! let visual: Vec<&str> = info
+//!     .visual_runs(0..text.len())
+//!     .iter()
+//!     .map(|run| &text[run.range.clone()])
+//!     .collect();
+//! // The brackets enclose a number after Hebrew, so they are right-to-left as well.
+//! assert_eq!(visual, [")", "123", "אבג (", " def"]);
+//! assert!(info.visual_runs(0..text.len())[0].is_rtl());
+//! ```
+//!
+//! Lines can be taken from a `LineSegmenter`, and mirrored characters such as brackets in
+//! right-to-left runs are reported separately:
+//!
+//! ```
+//! use icu::experimental::bidi::BidiResolver;
+//! use icu::segmenter::LineSegmenter;
+//!
+//! let text = "שלום (עולם)";
+//! let info = BidiResolver::new().resolve_str(text, None);
+//! assert!(info.paragraphs().all(|p| p.level.is_rtl()));
+//!
+//! // Break after every opportunity, one word per line.
+//! let breaks = LineSegmenter::new_auto(Default::default()).segment_str(text);
+//! let lines = info.visual_runs_by_line(breaks);
+//! assert_eq!(lines.len(), 2);
+//!
+//! // Mirroring depends on the levels of each line.
+//! assert_eq!(info.mirrored(0..text.len()), [(9, ')'), (18, '(')]);
+//! ```
+
+// https://github.com/unicode-org/icu4x/blob/main/documents/process/boilerplate.md#library-annotations
+#![cfg_attr(
+    not(test),
+    deny(
+        clippy::indexing_slicing,
+        clippy::unwrap_used,
+        clippy::expect_used,
+        clippy::panic,
+        clippy::exhaustive_structs,
+        clippy::exhaustive_enums,
+        clippy::trivially_copy_pass_by_ref,
+        missing_debug_implementations,
+    )
+)]
+#![warn(missing_docs)]
+
+mod algorithm;
+mod resolver;
+
+pub use resolver::{BidiInfo, BidiParagraph, BidiResolver, BidiRun, Level};
@@ -0,0 +1,466 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use super::algorithm::{self, Bracket};
+use alloc::vec::Vec;
+use core::ops::Range;
+use icu_properties::props::{BidiClass, BidiMirroringGlyph, BidiPairedBracketType};
+use icu_properties::provider::{PropertyEnumBidiClassV1, PropertyEnumBidiMirroringGlyphV1};
+use icu_properties::CodePointMapData;
+use icu_provider::prelude::*;
+
+/// A bidi embedding level, as defined in [UAX #9].
+///
+/// Even levels are left-to-right, odd levels are right-to-left.
+///
+/// [UAX #9]: https://www.unicode.org/reports/tr9/#BD2
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Level(pub(crate) u8);
+
+impl Level {
+    /// The left-to-right paragraph level.
+    pub const LTR: Self = Self(0);
+
+    /// The right-to-left paragraph level.
+    pub const RTL: Self = Self(1);
+
+    /// Creates a level from its number, returning `None` if it is deeper than the maximum
+    /// explicit depth of 125.
+    pub const fn new(number: u8) -> Option<Self> {
+        if number <= 125 {
+            Some(Self(number))
+        } else {
+            None
+        }
+    }
+
+    /// Returns the number of this level.
+    pub const fn number(self) -> u8 {
+        self.0
+    }
+
+    /// Returns whether this level is right-to-left.
+    pub const fn is_rtl(self) -> bool {
+        self.0 % 2 == 1
+    }
+}
+
+/// A paragraph of a [`BidiInfo`], as split by rule P1.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct BidiParagraph {
+    /// The range of the paragraph in code units, including its paragraph separator.
+    pub range: Range<usize>,
+    /// The paragraph embedding level.
+    pub level: Level,
+}
+
+/// A run of characters with the same embedding level, in the visual order of a line.
+///
+/// The characters of a right-to-left run are displayed in reverse order.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct BidiRun {
+    /// The range of the run in code units.
+    pub range: Range<usize>,
+    /// The embedding level of the run.
+    pub level: Level,
+}
+
+impl BidiRun {
+    /// Returns whether the run is displayed right-to-left.
+    pub fn is_rtl(&self) -> bool {
+        self.level.is_rtl()
+    }
+}
+
+/// A resolver of bidi embedding levels, implementing the [Unicode Bidirectional Algorithm].
+///
+/// See the [module-level documentation](super) for an example.
+///
+/// [Unicode Bidirectional Algorithm]: https://www.unicode.org/reports/tr9/
+#[derive(Debug)]
+pub struct BidiResolver {
+    bidi_class: CodePointMapData<BidiClass>,
+    mirroring: CodePointMapData<BidiMirroringGlyph>,
+}
+
+#[cfg(feature = "compiled_data")]
+impl Default for BidiResolver {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl BidiResolver {
+    icu_provider::gen_buffer_data_constructors!(
+        () -> error: DataError,
+        functions: [
+            new: skip,
+            try_new_with_buffer_provider,
+            try_new_unstable,
+            Self
+        ]
+    );
+
+    /// Creates a new [`BidiResolver`] using compiled data.
+    ///
+    /// ✨ *Enabled with the `compiled_data` Cargo feature.*
+    ///
+    /// [📚 Help choosing a constructor](icu_provider::constructors)
+    #[cfg(feature = "compiled_data")]
+    pub const fn new() -> Self {
+        Self {
+            bidi_class: CodePointMapData::new().static_to_owned(),
+            mirroring: CodePointMapData::new().static_to_owned(),
+        }
+    }
+
+    #[doc = icu_provider::gen_buffer_unstable_docs!(UNSTABLE, Self::new)]
+    pub fn try_new_unstable<P>(provider: &P) -> Result<Self, DataError>
+    where
+        P: ?Sized
+            + DataProvider<PropertyEnumBidiClassV1>
+            + DataProvider<PropertyEnumBidiMirroringGlyphV1>,
+    {
+        Ok(Self {
+            bidi_class: CodePointMapData::try_new_unstable(provider)?,
+            mirroring: CodePointMapData::try_new_unstable(provider)?,
+        })
+    }
+
+    /// Resolves the embedding levels of a UTF-8 string.
+    ///
+    /// If `default_level` is `None`, the level of each paragraph is determined by its first
+    /// strong character, as in rules P2 and P3; otherwise all paragraphs have that level.
+    ///
+    /// Indices in the returned [`BidiInfo`] are byte offsets into `text`.
+    pub fn resolve_str(&self, text: &str, default_level: Option<Level>) -> BidiInfo {
+        self.resolve(text.char_indices(), text.len(), default_level)
+    }
+
+    /// Resolves the embedding levels of a potentially ill-formed UTF-16 string.
+    ///
+    /// Unpaired surrogates are treated as U+FFFD REPLACEMENT CHARACTER. See
+    /// [`Self::resolve_str`] for the meaning of `default_level`.
+    ///
+    /// Indices in the returned [`BidiInfo`] are code unit offsets into `text`.
+    pub fn resolve_utf16(&self, text: &[u16], default_level: Option<Level>) -> BidiInfo {
+        let mut offset = 0;
+        let chars = char::decode_utf16(text.iter().copied()).map(|c| {
+            let c = c.unwrap_or(char::REPLACEMENT_CHARACTER);
+            let start = offset;
+            // Unpaired surrogates are a single code unit, like U+FFFD.
+            offset += c.len_utf16();
+            (start, c)
+        });
+        self.resolve(chars, text.len(), default_level)
+    }
+
+    fn resolve(
+        &self,
+        chars: impl Iterator<Item = (usize, char)>,
+        len: usize,
+        default_level: Option<Level>,
+    ) -> BidiInfo {
+        let bidi_class = self.bidi_class.as_borrowed();
+        let mirroring = self.mirroring.as_borrowed();
+
+        let (mut offsets, text): (Vec<usize>, Vec<char>) = chars.unzip();
+        offsets.push(len);
+        let classes: Vec<BidiClass> = text.iter().map(|&c| bidi_class.get(c)).collect();
+
+        // P1
+        let mut paragraphs = Vec::new();
+        let mut start = 0;
+        for (i, &class) in classes.iter().enumerate() {
+            let crlf = text.get(i) == Some(&'\r') && text.get(i + 1) == Some(&'\n');
+            if class == BidiClass::ParagraphSeparator && !crlf {
+                paragraphs.push(start..i + 1);
+                start = i + 1;
+            }
+        }
+        if start < text.len() {
+            paragraphs.push(start..text.len());
+        }
+
+        let bracket = |i: usize| {
+            let c = *text.get(i)?;
+            let glyph = mirroring.get(c);
+            let (opening, closing) = match glyph.paired_bracket_type {
+                BidiPairedBracketType::Open => (true, glyph.mirroring_glyph?),
+                BidiPairedBracketType::Close => (false, c),
+                _ => return None,
+            };
+            // U+2329 and U+232A are canonically equivalent to U+3008 and U+3009.
+            let closing = if closing == '\u{232A}' {
+                '\u{3009}'
+            } else {
+                closing
+            };
+            Some(Bracket { opening, closing })
+        };
+
+        let mut levels = alloc::vec![Level::LTR; text.len()];
+        let paragraphs: Vec<(Range<usize>, Level)> = paragraphs
+            .into_iter()
+            .map(|range| {
+                let paragraph_classes = classes.get(range.clone()).unwrap_or_default();
+                let level = default_level
+                    .or_else(|| algorithm::first_strong_level(paragraph_classes, false))
+                    .unwrap_or(Level::LTR);
+                let start = range.start;
+                algorithm::resolve_paragraph(
+                    paragraph_classes,
+                    |i| bracket(start + i),
+                    level,
+                    levels.get_mut(range.clone()).unwrap_or_default(),
+                );
+                (range, level)
+            })
+            .collect();
+
+        // L4 depends on the levels after L1, which are only known once lines are laid out.
+        let mirror_glyphs = text
+            .iter()
+            .enumerate()
+            .filter_map(|(i, &c)| {
+                let glyph = mirroring.get(c);
+                Some((i, glyph.mirroring_glyph.filter(|_| glyph.mirrored)?))
+            })
+            .collect();
+
+        BidiInfo {
+            offsets,
+            classes,
+            levels,
+            paragraphs,
+            mirror_glyphs,
+        }
+    }
+}
+
+/// The resolved embedding levels of a text, as returned by [`BidiResolver`].
+///
+/// All indices are code unit offsets into the text that was resolved: bytes for UTF-8, and
+/// 16-bit code units for UTF-16.
+#[derive(Debug, Clone)]
+pub struct BidiInfo {
+    /// The offset of each character, followed by the length of the text.
+    offsets: Vec<usize>,
+    /// The original bidi class of each character.
+    classes: Vec<BidiClass>,
+    /// The resolved level of each character, before the line rules are applied.
+    levels: Vec<Level>,
+    /// The paragraphs, as ranges of character indices.
+    paragraphs: Vec<(Range<usize>, Level)>,
+    /// The character indices and mirroring glyphs of the characters that are displayed
+    /// mirrored at a right-to-left level.
+    mirror_glyphs: Vec<(usize, char)>,
+}
+
+impl BidiInfo {
+    /// Returns the paragraphs of the text.
+    pub fn paragraphs(&self) -> impl Iterator<Item = BidiParagraph> + '_ {
+        self.paragraphs.iter().map(|(range, level)| BidiParagraph {
+            range: self.offset(range.start)..self.offset(range.end),
+            level: *level,
+        })
+    }
+
+    /// Returns whether any character of the text has a right-to-left level.
+    ///
+    /// If this returns `false`, the text can be displayed without reordering.
+    pub fn has_rtl(&self) -> bool {
+        self.levels.iter().any(|level| level.is_rtl())
+    }
+
+    /// Returns the resolved embedding level of the character containing the code unit at
+    /// `index`, before any line is laid out, or `None` if `index` is out of bounds.
+    pub fn level_at(&self, index: usize) -> Option<Level> {
+        let i = self
+            .offsets
+            .partition_point(|&offset| offset <= index)
+            .checked_sub(1)?;
+        self.levels.get(i).copied()
+    }
+
+    /// Returns the level of each character of the given line, after rule L1 has reset
+    /// separators and trailing whitespace to the paragraph level.
+    ///
+    /// The line is given as a range of code units, as in [`Self::visual_runs`].
+    pub fn line_levels(&self, line: Range<usize>) -> Vec<Level> {
+        let mut levels = Vec::new();
+        for (range, level) in self.line_paragraphs(line) {
+            levels.extend(self.apply_l1(range, level));
+        }
+        levels
+    }
+
+    /// Returns the characters of the given line that are displayed with a mirrored glyph, as
+    /// defined in rule L4, as pairs of their offset and the glyph to display.
+    ///
+    /// The line is given as a range of code units, as in [`Self::visual_runs`]. Characters
+    /// without a mirroring glyph in the Bidi_Mirroring_Glyph property, such as U+2231
+    /// CLOCKWISE INTEGRAL, are not included and need a mirrored font glyph instead.
+    pub fn mirrored(&self, line: Range<usize>) -> Vec<(usize, char)> {
+        let mut mirrored = Vec::new();
+        for (range, level) in self.line_paragraphs(line) {
+            let levels = self.apply_l1(range.clone(), level);
+            let start = self
+                .mirror_glyphs
+                .partition_point(|&(i, _)| i < range.start);
+            for &(i, glyph) in self.mirror_glyphs.get(start..).unwrap_or_default() {
+                if i >= range.end {
+                    break;
+                }
+                if levels
+                    .get(i - range.start)
+                    .is_some_and(|level| level.is_rtl())
+                {
+                    mirrored.push((self.offset(i), glyph));
+                }
+            }
+        }
+        mirrored
+    }
+
+    /// Returns the runs of the given line in visual order, from left to right.
+    ///
+    /// The line is given as a range of code units, which should start and end at character
+    /// boundaries. Lines that span several paragraphs are laid out paragraph by paragraph.
+    pub fn visual_runs(&self, line: Range<usize>) -> Vec<BidiRun> {
+        let mut runs = Vec::new();
+        for (range, level) in self.line_paragraphs(line) {
+            self.reorder(range, level, &mut runs);
+        }
+        runs
+    }
+
+    /// Returns the runs of each line in visual order, for lines that end at the given
+    /// breaks.
+    ///
+    /// The breaks are offsets in increasing order, such as the line break opportunities
+    /// returned by a [`LineSegmenter`] that were chosen for wrapping. Breaks at offset zero are
+    /// ignored, and text after the last break forms a final line.
+    ///
+    /// [`LineSegmenter`]: https://docs.rs/icu_segmenter/latest/icu_segmenter/struct.LineSegmenter.html
+    pub fn visual_runs_by_line(
+        &self,
+        breaks: impl IntoIterator<Item = usize>,
+    ) -> Vec<Vec<BidiRun>> {
+        let len = self.offset(self.levels.len());
+        let mut lines = Vec::new();
+        let mut start = 0;
+        for end in breaks.into_iter().chain([len]) {
+            let end = end.min(len);
+            if end > start {
+                lines.push(self.visual_runs(start..end));
+                start = end;
+            }
+        }
+        lines
+    }
+
+    fn offset(&self, char_index: usize) -> usize {
+        self.offsets.get(char_index).copied().unwrap_or_default()
+    }
+
+    fn char_index(&self, offset: usize) -> usize {
+        self.offsets
+            .partition_point(|&o| o < offset)
+            .min(self.levels.len())
+    }
+
+    /// Splits a line, given in code units, into its parts in each paragraph, as ranges of
+    /// character indices with the paragraph level.
+    fn line_paragraphs(
+        &self,
+        line: Range<usize>,
+    ) -> impl Iterator<Item = (Range<usize>, Level)> + '_ {
+        let start = self.char_index(line.start);
+        let end = self.char_index(line.end);
+        self.paragraphs
+            .iter()
+            .map(move |(paragraph, level)| {
+                (start.max(paragraph.start)..end.min(paragraph.end), *level)
+            })
+            .filter(|(range, _)| !range.is_empty())
+    }
+
+    /// Applies rule L1 to a line within a paragraph, returning the levels of its characters.
+    fn apply_l1(&self, line: Range<usize>, paragraph_level: Level) -> Vec<Level> {
+        let mut levels = self.levels.get(line.clone()).unwrap_or_default().to_vec();
+        let mut trailing = true;
+        for (level, &class) in levels
+            .iter_mut()
+            .zip(self.classes.get(line).unwrap_or_default())
+            .rev()
+        {
+            match class {
+                BidiClass::SegmentSeparator | BidiClass::ParagraphSeparator => {
+                    *level = paragraph_level;
+                    trailing = true;
+                }
+                BidiClass::WhiteSpace
+                | BidiClass::FirstStrongIsolate
+                | BidiClass::LeftToRightIsolate
+                | BidiClass::RightToLeftIsolate
+                | BidiClass::PopDirectionalIsolate
+                | BidiClass::LeftToRightEmbedding
+                | BidiClass::RightToLeftEmbedding
+                | BidiClass::LeftToRightOverride
+                | BidiClass::RightToLeftOverride
+                | BidiClass::PopDirectionalFormat
+                | BidiClass::BoundaryNeutral => {
+                    if trailing {
+                        *level = paragraph_level;
+                    }
+                }
+                _ => trailing = false,
+            }
+        }
+        levels
+    }
+
+    /// Applies rule L2 to a line within a paragraph.
+    fn reorder(&self, line: Range<usize>, paragraph_level: Level, runs: &mut Vec<BidiRun>) {
+        let levels = self.apply_l1(line.clone(), paragraph_level);
+
+        // Logical runs of characters with the same level.
+        let mut line_runs: Vec<(Range<usize>, Level)> = Vec::new();
+        for (i, &level) in line.clone().zip(&levels) {
+            match line_runs.last_mut() {
+                Some((range, run_level)) if *run_level == level => range.end = i + 1,
+                _ => line_runs.push((i..i + 1, level)),
+            }
+        }
+
+        // L2
+        let highest = levels.iter().copied().max().unwrap_or(paragraph_level);
+        let lowest_odd = levels.iter().copied().min().unwrap_or(paragraph_level).0 | 1;
+        for level in (lowest_odd..=highest.0).rev() {
+            let mut k = 0;
+            while k < line_runs.len() {
+                let start = k;
+                while line_runs
+                    .get(k)
+                    .is_some_and(|(_, run_level)| run_level.0 >= level)
+                {
+                    k += 1;
+                }
+                if k == start {
+                    k += 1;
+                } else {
+                    line_runs.get_mut(start..k).unwrap_or_default().reverse();
+                }
+            }
+        }
+
+        runs.extend(line_runs.into_iter().map(|(range, level)| BidiRun {
+            range: self.offset(range.start)..self.offset(range.end),
+            level,
+        }));
+    }
+}
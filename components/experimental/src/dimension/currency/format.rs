@@ -5,6 +5,7 @@
 use fixed_decimal::Decimal;
 
 use icu_decimal::DecimalFormatter;
+use icu_pattern::DoublePlaceholderPattern;
 use writeable::Writeable;
 
 use crate::dimension::currency::options::CurrencyFormatterOptions;
@@ -23,11 +24,11 @@ pub struct FormattedCurrency<'l> {
 
 writeable::impl_display_with_writeable!(FormattedCurrency<'_>);
 
-impl Writeable for FormattedCurrency<'_> {
-    fn write_to<W>(&self, sink: &mut W) -> core::result::Result<(), core::fmt::Error>
-    where
-        W: core::fmt::Write + ?Sized,
-    {
+impl FormattedCurrency<'_> {
+    /// Returns the pattern and the currency sign that surround the formatted value.
+    pub(crate) fn pattern_and_sign(
+        &self,
+    ) -> Result<(&DoublePlaceholderPattern, &str), core::fmt::Error> {
         let config = self
             .essential
             .pattern_config_map
@@ -60,6 +61,17 @@ impl Writeable for FormattedCurrency<'_> {
         }
         .ok_or(core::fmt::Error)?;
 
+        Ok((pattern, currency_sign_value))
+    }
+}
+
+impl Writeable for FormattedCurrency<'_> {
+    fn write_to<W>(&self, sink: &mut W) -> core::result::Result<(), core::fmt::Error>
+    where
+        W: core::fmt::Write + ?Sized,
+    {
+        let (pattern, currency_sign_value) = self.pattern_and_sign()?;
+
         pattern
             .interpolate((
                 self.decimal_formatter.format(self.value),
@@ -4,4 +4,5 @@
 
 pub mod currency;
 pub mod percent;
+pub mod range;
 pub mod units;
@@ -0,0 +1,42 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+// Provider structs must be stable
+#![allow(clippy::exhaustive_structs, clippy::exhaustive_enums)]
+
+//! Data provider struct definitions for this ICU4X component.
+//!
+//! Read more about data providers: [`icu_provider`]
+
+use icu_pattern::{DoublePlaceholderPattern, SinglePlaceholderPattern};
+use icu_provider::prelude::*;
+use zerovec::VarZeroCow;
+
+#[cfg(feature = "compiled_data")]
+pub use crate::provider::Baked;
+
+icu_provider::data_marker!(
+    /// `NumberRangePatternsV1`
+    NumberRangePatternsV1,
+    NumberRangePatterns<'static>
+);
+
+/// The patterns for formatting number ranges and approximate numbers.
+#[derive(Clone, PartialEq, Debug, yoke::Yokeable, zerofrom::ZeroFrom)]
+#[cfg_attr(feature = "datagen", derive(serde::Serialize, databake::Bake))]
+#[cfg_attr(feature = "datagen", databake(path = icu_experimental::dimension::provider::range))]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+pub struct NumberRangePatterns<'data> {
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    /// Represents the pattern for a range, such as `{0}–{1}`.
+    /// NOTE: place holder 0 is the place of the start of the range.
+    ///       place holder 1 is the place of the end of the range.
+    pub range_pattern: VarZeroCow<'data, DoublePlaceholderPattern>,
+
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    /// Represents the pattern for an approximate value, such as `~{0}`.
+    pub approximately_pattern: VarZeroCow<'data, SinglePlaceholderPattern>,
+}
+
+icu_provider::data_struct!(NumberRangePatterns<'_>, #[cfg(feature = "datagen")]);
@@ -0,0 +1,462 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Experimental.
+
+use fixed_decimal::{Decimal, Sign};
+use icu_decimal::{DecimalFormatter, FormattedDecimal};
+use icu_pattern::{
+    DoublePlaceholderKey, DoublePlaceholderPattern, PatternItem, SinglePlaceholderPattern,
+};
+use icu_plurals::{PluralCategory, PluralRules, PluralRulesWithRanges};
+use writeable::Writeable;
+
+use crate::dimension::currency::format::FormattedCurrency;
+use crate::dimension::provider::range::NumberRangePatterns;
+use crate::dimension::units::format::FormattedUnit;
+
+use super::options::{NumberRangeFormatterOptions, RangeCollapse, RangeIdentityFallback};
+
+/// One end of a range formatted by
+/// [`NumberRangeFormatter`](crate::dimension::range::formatter::NumberRangeFormatter).
+///
+/// This is a plain number, a [`FormattedCurrency`] or a [`FormattedUnit`].
+pub struct RangeEndpoint<'l>(Endpoint<'l>);
+
+enum Endpoint<'l> {
+    Number(&'l Decimal),
+    Currency(FormattedCurrency<'l>),
+    Unit(FormattedUnit<'l>),
+}
+
+impl<'l> From<&'l Decimal> for RangeEndpoint<'l> {
+    fn from(value: &'l Decimal) -> Self {
+        Self(Endpoint::Number(value))
+    }
+}
+
+impl<'l> From<FormattedCurrency<'l>> for RangeEndpoint<'l> {
+    fn from(value: FormattedCurrency<'l>) -> Self {
+        Self(Endpoint::Currency(value))
+    }
+}
+
+impl<'l> From<FormattedUnit<'l>> for RangeEndpoint<'l> {
+    fn from(value: FormattedUnit<'l>) -> Self {
+        Self(Endpoint::Unit(value))
+    }
+}
+
+impl<'l> RangeEndpoint<'l> {
+    fn value(&self) -> &'l Decimal {
+        match self.0 {
+            Endpoint::Number(value) => value,
+            Endpoint::Currency(ref currency) => currency.value,
+            Endpoint::Unit(ref unit) => unit.value,
+        }
+    }
+
+    /// Formats the number of this endpoint, without its currency or unit.
+    fn format_number<'a>(
+        &'a self,
+        decimal_formatter: &'a DecimalFormatter,
+    ) -> FormattedDecimal<'a> {
+        match self.0 {
+            Endpoint::Number(value) => decimal_formatter.format(value),
+            Endpoint::Currency(ref currency) => currency.decimal_formatter.format(currency.value),
+            Endpoint::Unit(ref unit) => unit.decimal_formatter.format(unit.value),
+        }
+    }
+
+    /// Writes the currency or unit of this endpoint around the given number.
+    fn write_around<W>(
+        &self,
+        number: impl Writeable,
+        plural_category: PluralCategory,
+        sink: &mut W,
+    ) -> core::fmt::Result
+    where
+        W: core::fmt::Write + ?Sized,
+    {
+        match self.0 {
+            Endpoint::Number(_) => number.write_to(sink),
+            Endpoint::Currency(ref currency) => {
+                let (pattern, currency_sign_value) = currency.pattern_and_sign()?;
+                pattern
+                    .interpolate((number, currency_sign_value))
+                    .write_to(sink)
+            }
+            Endpoint::Unit(ref unit) => unit
+                .display_name
+                .patterns
+                .get_for_category(plural_category)
+                .interpolate((number,))
+                .write_to(sink),
+        }
+    }
+
+    /// Returns what is written around the number of this endpoint, which is the same for two
+    /// endpoints exactly if they have the same currency or unit.
+    fn affix(&self) -> Result<Affix<'_>, core::fmt::Error> {
+        Ok(match self.0 {
+            Endpoint::Number(_) => Affix::None,
+            Endpoint::Currency(ref currency) => {
+                let (pattern, currency_sign_value) = currency.pattern_and_sign()?;
+                Affix::Currency(pattern, currency_sign_value)
+            }
+            Endpoint::Unit(ref unit) => Affix::Unit(
+                unit.display_name
+                    .patterns
+                    .get_for_category(PluralCategory::Other),
+            ),
+        })
+    }
+
+    /// Returns the whole endpoint, formatted with `decimal_formatter` if it is a plain number.
+    fn formatted<'a>(
+        &'a self,
+        decimal_formatter: &'a DecimalFormatter,
+    ) -> FormattedEndpoint<'a, 'l> {
+        FormattedEndpoint {
+            endpoint: self,
+            decimal_formatter,
+        }
+    }
+}
+
+/// The currency or unit of an endpoint, see [`RangeEndpoint::affix`].
+#[derive(PartialEq)]
+enum Affix<'a> {
+    None,
+    Currency(&'a DoublePlaceholderPattern, &'a str),
+    Unit(&'a SinglePlaceholderPattern),
+}
+
+/// A whole endpoint, see [`RangeEndpoint::formatted`].
+struct FormattedEndpoint<'a, 'l> {
+    endpoint: &'a RangeEndpoint<'l>,
+    decimal_formatter: &'a DecimalFormatter,
+}
+
+impl Writeable for FormattedEndpoint<'_, '_> {
+    fn write_to<W>(&self, sink: &mut W) -> core::fmt::Result
+    where
+        W: core::fmt::Write + ?Sized,
+    {
+        match self.endpoint.0 {
+            Endpoint::Number(value) => self.decimal_formatter.format(value).write_to(sink),
+            Endpoint::Currency(ref currency) => currency.write_to(sink),
+            Endpoint::Unit(ref unit) => unit.write_to(sink),
+        }
+    }
+}
+
+/// Counts the code points written into it.
+struct CharCounter(usize);
+
+impl core::fmt::Write for CharCounter {
+    fn write_str(&mut self, s: &str) -> core::fmt::Result {
+        self.0 += s.chars().count();
+        Ok(())
+    }
+}
+
+/// Writes a range pattern, adding spaces around the separator if needed.
+struct RangePattern<'a, W0, W1> {
+    pattern: &'a DoublePlaceholderPattern,
+    start: W0,
+    end: W1,
+    spaced: bool,
+}
+
+impl<W0: Writeable, W1: Writeable> Writeable for RangePattern<'_, W0, W1> {
+    fn write_to<W>(&self, sink: &mut W) -> core::fmt::Result
+    where
+        W: core::fmt::Write + ?Sized,
+    {
+        let mut placeholders = 0;
+        for item in self.pattern.iter() {
+            match item {
+                // The separator between the start and the end of the range
+                PatternItem::Literal(literal) if self.spaced && placeholders == 1 => {
+                    if !literal.starts_with(char::is_whitespace) {
+                        sink.write_char(' ')?;
+                    }
+                    sink.write_str(literal)?;
+                    if !literal.ends_with(char::is_whitespace) {
+                        sink.write_char(' ')?;
+                    }
+                }
+                PatternItem::Literal(literal) => sink.write_str(literal)?,
+                PatternItem::Placeholder(key) => {
+                    placeholders += 1;
+                    match key {
+                        DoublePlaceholderKey::Place0 => self.start.write_to(sink)?,
+                        DoublePlaceholderKey::Place1 => self.end.write_to(sink)?,
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// A range of numbers, currencies or units formatted by
+/// [`NumberRangeFormatter::format`](crate::dimension::range::formatter::NumberRangeFormatter::format),
+/// such as "3–5" or "$3 – $5".
+///
+/// Use [`Writeable`] or [`Display`](core::fmt::Display) to get the formatted string.
+pub struct FormattedNumberRange<'l> {
+    pub(crate) start: RangeEndpoint<'l>,
+    pub(crate) end: RangeEndpoint<'l>,
+    pub(crate) options: &'l NumberRangeFormatterOptions,
+    pub(crate) patterns: &'l NumberRangePatterns<'l>,
+    pub(crate) decimal_formatter: &'l DecimalFormatter,
+    pub(crate) plural_rules: &'l PluralRulesWithRanges<PluralRules>,
+}
+
+impl Writeable for FormattedNumberRange<'_> {
+    fn write_to<W>(&self, sink: &mut W) -> core::result::Result<(), core::fmt::Error>
+    where
+        W: core::fmt::Write + ?Sized,
+    {
+        let start = self.start.formatted(self.decimal_formatter);
+        let end = self.end.formatted(self.decimal_formatter);
+        let affix = self.start.affix()?;
+        let same_affix = affix == self.end.affix()?;
+
+        // Equal values with the same currency or unit are formatted the same way.
+        if same_affix && self.start.value() == self.end.value() {
+            match self.options.identity_fallback {
+                RangeIdentityFallback::SingleValue => return start.write_to(sink),
+                RangeIdentityFallback::Approximately => {
+                    return self
+                        .patterns
+                        .approximately_pattern
+                        .interpolate([start])
+                        .write_to(sink)
+                }
+                RangeIdentityFallback::Range => (),
+            }
+        }
+
+        let has_negative = self.start.value().sign() == Sign::Negative
+            || self.end.value().sign() == Sign::Negative;
+
+        // Units are always collapsed, but currency symbols only if they are more than one
+        // code point, following the heuristic of ICU4C, so that we get "$3 – $5" and "3–5 €".
+        let collapse = same_affix
+            && match (&self.start.0, self.options.collapse) {
+                (_, RangeCollapse::None) => false,
+                (Endpoint::Number(_) | Endpoint::Unit(_), _) => true,
+                (Endpoint::Currency(_), RangeCollapse::Unit) => !has_negative,
+                (Endpoint::Currency(_), _) => {
+                    let mut affix_length = CharCounter(0);
+                    self.start
+                        .write_around("", PluralCategory::Other, &mut affix_length)?;
+                    !has_negative && affix_length.0 > 1
+                }
+            };
+
+        if collapse {
+            let plural_category = self
+                .plural_rules
+                .category_for_range(self.start.value(), self.end.value());
+            self.start.write_around(
+                RangePattern {
+                    pattern: &self.patterns.range_pattern,
+                    start: self.start.format_number(self.decimal_formatter),
+                    end: self.end.format_number(self.decimal_formatter),
+                    spaced: has_negative,
+                },
+                plural_category,
+                sink,
+            )
+        } else {
+            RangePattern {
+                pattern: &self.patterns.range_pattern,
+                start,
+                end,
+                spaced: has_negative || affix != Affix::None,
+            }
+            .write_to(sink)
+        }
+    }
+}
+
+writeable::impl_display_with_writeable!(FormattedNumberRange<'_>);
+
+/// An approximate number, currency or unit formatted by
+/// [`NumberRangeFormatter::format_approximately`](crate::dimension::range::formatter::NumberRangeFormatter::format_approximately),
+/// such as "~5".
+///
+/// Use [`Writeable`] or [`Display`](core::fmt::Display) to get the formatted string.
+pub struct FormattedApproximateNumber<'l> {
+    pub(crate) value: RangeEndpoint<'l>,
+    pub(crate) patterns: &'l NumberRangePatterns<'l>,
+    pub(crate) decimal_formatter: &'l DecimalFormatter,
+}
+
+impl Writeable for FormattedApproximateNumber<'_> {
+    fn write_to<W>(&self, sink: &mut W) -> core::result::Result<(), core::fmt::Error>
+    where
+        W: core::fmt::Write + ?Sized,
+    {
+        self.patterns
+            .approximately_pattern
+            .interpolate([self.value.formatted(self.decimal_formatter)])
+            .write_to(sink)
+    }
+}
+
+writeable::impl_display_with_writeable!(FormattedApproximateNumber<'_>);
+
+#[cfg(test)]
+mod tests {
+    use icu_locale_core::locale;
+    use tinystr::tinystr;
+    use writeable::assert_writeable_eq;
+
+    use crate::dimension::currency::formatter::CurrencyFormatter;
+    use crate::dimension::currency::CurrencyCode;
+    use crate::dimension::range::formatter::NumberRangeFormatter;
+    use crate::dimension::range::options::{
+        NumberRangeFormatterOptions, RangeCollapse, RangeIdentityFallback,
+    };
+
+    #[test]
+    pub fn test_numbers() {
+        let fmt =
+            NumberRangeFormatter::try_new(locale!("en-US").into(), Default::default()).unwrap();
+        let three = "3".parse().unwrap();
+        let five = "5".parse().unwrap();
+        let minus_five = "-5".parse().unwrap();
+        let large = "12345.67".parse().unwrap();
+
+        assert_writeable_eq!(fmt.format(&three, &five), "3–5");
+        assert_writeable_eq!(fmt.format(&three, &large), "3–12,345.67");
+        assert_writeable_eq!(fmt.format(&minus_five, &three), "-5 – 3");
+        assert_writeable_eq!(fmt.format(&five, &five), "~5");
+        assert_writeable_eq!(fmt.format_approximately(&five), "~5");
+
+        let fmt = NumberRangeFormatter::try_new(locale!("ja").into(), Default::default()).unwrap();
+        assert_writeable_eq!(fmt.format(&three, &five), "3～5");
+        assert_writeable_eq!(fmt.format_approximately(&five), "約 5");
+    }
+
+    #[test]
+    pub fn test_identity_fallback() {
+        let five = "5".parse().unwrap();
+        let five_point_zero = "5.0".parse().unwrap();
+
+        let fmt = NumberRangeFormatter::try_new(
+            locale!("fr").into(),
+            RangeIdentityFallback::Approximately.into(),
+        )
+        .unwrap();
+        assert_writeable_eq!(fmt.format(&five, &five), "≈5");
+        assert_writeable_eq!(fmt.format(&five, &five_point_zero), "5–5,0");
+
+        let fmt = NumberRangeFormatter::try_new(
+            locale!("fr").into(),
+            RangeIdentityFallback::SingleValue.into(),
+        )
+        .unwrap();
+        assert_writeable_eq!(fmt.format(&five, &five), "5");
+
+        let fmt = NumberRangeFormatter::try_new(
+            locale!("fr").into(),
+            RangeIdentityFallback::Range.into(),
+        )
+        .unwrap();
+        assert_writeable_eq!(fmt.format(&five, &five), "5–5");
+    }
+
+    #[test]
+    pub fn test_currencies() {
+        let three = "3".parse().unwrap();
+        let five = "5".parse().unwrap();
+        let minus_five = "-5".parse().unwrap();
+
+        let usd = CurrencyCode(tinystr!(3, "USD"));
+        let currency_fmt =
+            CurrencyFormatter::try_new(locale!("en-US").into(), Default::default()).unwrap();
+        let fmt =
+            NumberRangeFormatter::try_new(locale!("en-US").into(), Default::default()).unwrap();
+
+        // "$" is a single code point, so it is not collapsed by default.
+        assert_writeable_eq!(
+            fmt.format(
+                currency_fmt.format_fixed_decimal(&three, usd),
+                currency_fmt.format_fixed_decimal(&five, usd),
+            ),
+            "$3 – $5"
+        );
+        assert_writeable_eq!(
+            fmt.format(
+                currency_fmt.format_fixed_decimal(&five, usd),
+                currency_fmt.format_fixed_decimal(&five, usd),
+            ),
+            "~$5"
+        );
+        assert_writeable_eq!(
+            fmt.format_approximately(currency_fmt.format_fixed_decimal(&five, usd)),
+            "~$5"
+        );
+
+        let fmt = NumberRangeFormatter::try_new(
+            locale!("en-US").into(),
+            NumberRangeFormatterOptions {
+                collapse: RangeCollapse::Unit,
+                ..Default::default()
+            },
+        )
+        .unwrap();
+        assert_writeable_eq!(
+            fmt.format(
+                currency_fmt.format_fixed_decimal(&three, usd),
+                currency_fmt.format_fixed_decimal(&five, usd),
+            ),
+            "$3–5"
+        );
+        assert_writeable_eq!(
+            fmt.format(
+                currency_fmt.format_fixed_decimal(&minus_five, usd),
+                currency_fmt.format_fixed_decimal(&five, usd),
+            ),
+            "$-5 – $5"
+        );
+
+        let eur = CurrencyCode(tinystr!(3, "EUR"));
+        let currency_fmt =
+            CurrencyFormatter::try_new(locale!("fr-FR").into(), Default::default()).unwrap();
+        let fmt =
+            NumberRangeFormatter::try_new(locale!("fr-FR").into(), Default::default()).unwrap();
+        assert_writeable_eq!(
+            fmt.format(
+                currency_fmt.format_fixed_decimal(&three, eur),
+                currency_fmt.format_fixed_decimal(&five, eur),
+            ),
+            "3–5\u{a0}€"
+        );
+        assert_writeable_eq!(
+            fmt.format(
+                currency_fmt.format_fixed_decimal(&three, eur),
+                currency_fmt.format_fixed_decimal(&five, usd),
+            ),
+            "3\u{a0}€ – 5\u{a0}$US"
+        );
+
+        let fmt =
+            NumberRangeFormatter::try_new(locale!("fr-FR").into(), RangeCollapse::None.into())
+                .unwrap();
+        assert_writeable_eq!(
+            fmt.format(
+                currency_fmt.format_fixed_decimal(&three, eur),
+                currency_fmt.format_fixed_decimal(&five, eur),
+            ),
+            "3\u{a0}€ – 5\u{a0}€"
+        );
+    }
+}
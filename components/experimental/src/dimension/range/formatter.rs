@@ -0,0 +1,207 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Experimental.
+
+use icu_decimal::options::DecimalFormatterOptions;
+use icu_decimal::{DecimalFormatter, DecimalFormatterPreferences};
+use icu_locale_core::preferences::{define_preferences, prefs_convert};
+use icu_plurals::{PluralRules, PluralRulesPreferences, PluralRulesWithRanges};
+use icu_provider::prelude::*;
+
+use super::super::provider::range::NumberRangePatternsV1;
+use super::format::{FormattedApproximateNumber, FormattedNumberRange, RangeEndpoint};
+use super::options::NumberRangeFormatterOptions;
+
+define_preferences!(
+    /// The preferences for number range formatting.
+    [Copy]
+    NumberRangeFormatterPreferences,
+    {
+        /// The user's preferred numbering system.
+        ///
+        /// Corresponds to the `-u-nu` in Unicode Locale Identifier.
+        numbering_system: super::super::preferences::NumberingSystem
+    }
+);
+
+prefs_convert!(
+    NumberRangeFormatterPreferences,
+    DecimalFormatterPreferences,
+    { numbering_system }
+);
+prefs_convert!(NumberRangeFormatterPreferences, PluralRulesPreferences);
+
+/// A formatter for ranges of numbers, such as "3–5", and for approximate numbers, such as "~5".
+///
+/// The ends of a range can be plain [`Decimal`](fixed_decimal::Decimal)s, formatted currencies or
+/// formatted units. A currency or unit shared by both ends of the range is displayed once,
+/// depending on [`RangeCollapse`](super::options::RangeCollapse).
+pub struct NumberRangeFormatter {
+    /// Options bag for the number range formatter to determine the behavior of the formatter.
+    options: NumberRangeFormatterOptions,
+
+    /// The range and approximately patterns.
+    patterns: DataPayload<NumberRangePatternsV1>,
+
+    /// A fixed decimal formatter used to format plain numbers.
+    decimal_formatter: DecimalFormatter,
+
+    /// Plural rules used to select the unit of a collapsed range.
+    plural_rules: PluralRulesWithRanges<PluralRules>,
+}
+
+impl NumberRangeFormatter {
+    icu_provider::gen_buffer_data_constructors!(
+        (prefs: NumberRangeFormatterPreferences, options: NumberRangeFormatterOptions) -> error: DataError,
+        functions: [
+            try_new: skip,
+            try_new_with_buffer_provider,
+            try_new_unstable,
+            Self
+        ]
+    );
+
+    /// Creates a new [`NumberRangeFormatter`] from compiled locale data and an options bag.
+    ///
+    /// ✨ *Enabled with the `compiled_data` Cargo feature.*
+    ///
+    /// [📚 Help choosing a constructor](icu_provider::constructors)
+    #[cfg(feature = "compiled_data")]
+    pub fn try_new(
+        prefs: NumberRangeFormatterPreferences,
+        options: NumberRangeFormatterOptions,
+    ) -> Result<Self, DataError> {
+        let locale = NumberRangePatternsV1::make_locale(prefs.locale_preferences);
+        let patterns = crate::provider::Baked
+            .load(DataRequest {
+                id: DataIdentifierBorrowed::for_locale(&locale),
+                ..Default::default()
+            })?
+            .payload;
+
+        let decimal_formatter =
+            DecimalFormatter::try_new((&prefs).into(), DecimalFormatterOptions::default())?;
+
+        let plural_rules = PluralRulesWithRanges::try_new_cardinal((&prefs).into())?;
+
+        Ok(Self {
+            options,
+            patterns,
+            decimal_formatter,
+            plural_rules,
+        })
+    }
+
+    #[doc = icu_provider::gen_buffer_unstable_docs!(UNSTABLE, Self::try_new)]
+    pub fn try_new_unstable<D>(
+        provider: &D,
+        prefs: NumberRangeFormatterPreferences,
+        options: NumberRangeFormatterOptions,
+    ) -> Result<Self, DataError>
+    where
+        D: ?Sized
+            + DataProvider<NumberRangePatternsV1>
+            + DataProvider<icu_decimal::provider::DecimalSymbolsV1>
+            + DataProvider<icu_decimal::provider::DecimalDigitsV1>
+            + DataProvider<icu_plurals::provider::PluralsCardinalV1>
+            + DataProvider<icu_plurals::provider::PluralsRangesV1>,
+    {
+        let locale = NumberRangePatternsV1::make_locale(prefs.locale_preferences);
+        let patterns = provider
+            .load(DataRequest {
+                id: DataIdentifierBorrowed::for_locale(&locale),
+                ..Default::default()
+            })?
+            .payload;
+
+        let decimal_formatter = DecimalFormatter::try_new_unstable(
+            provider,
+            (&prefs).into(),
+            DecimalFormatterOptions::default(),
+        )?;
+
+        let plural_rules =
+            PluralRulesWithRanges::try_new_cardinal_unstable(provider, (&prefs).into())?;
+
+        Ok(Self {
+            options,
+            patterns,
+            decimal_formatter,
+            plural_rules,
+        })
+    }
+
+    /// Formats the range between two numbers, currencies or units.
+    ///
+    /// If both ends are the same, the range is displayed according to
+    /// [`RangeIdentityFallback`](super::options::RangeIdentityFallback).
+    ///
+    /// # Examples
+    /// ```
+    /// use icu::experimental::dimension::currency::formatter::CurrencyFormatter;
+    /// use icu::experimental::dimension::currency::CurrencyCode;
+    /// use icu::experimental::dimension::range::formatter::NumberRangeFormatter;
+    /// use icu::locale::locale;
+    /// use tinystr::*;
+    /// use writeable::assert_writeable_eq;
+    ///
+    /// let fmt = NumberRangeFormatter::try_new(locale!("en-US").into(), Default::default())
+    ///     .unwrap();
+    /// let start = "3".parse().unwrap();
+    /// let end = "5".parse().unwrap();
+    /// assert_writeable_eq!(fmt.format(&start, &end), "3–5");
+    ///
+    /// let currency_fmt =
+    ///     CurrencyFormatter::try_new(locale!("fr-FR").into(), Default::default()).unwrap();
+    /// let fmt = NumberRangeFormatter::try_new(locale!("fr-FR").into(), Default::default())
+    ///     .unwrap();
+    /// let euro = CurrencyCode(tinystr!(3, "EUR"));
+    /// assert_writeable_eq!(
+    ///     fmt.format(
+    ///         currency_fmt.format_fixed_decimal(&start, euro),
+    ///         currency_fmt.format_fixed_decimal(&end, euro),
+    ///     ),
+    ///     "3–5\u{a0}€"
+    /// );
+    /// ```
+    pub fn format<'l>(
+        &'l self,
+        start: impl Into<RangeEndpoint<'l>>,
+        end: impl Into<RangeEndpoint<'l>>,
+    ) -> FormattedNumberRange<'l> {
+        FormattedNumberRange {
+            start: start.into(),
+            end: end.into(),
+            options: &self.options,
+            patterns: self.patterns.get(),
+            decimal_formatter: &self.decimal_formatter,
+            plural_rules: &self.plural_rules,
+        }
+    }
+
+    /// Formats a number, currency or unit as an approximate value.
+    ///
+    /// # Examples
+    /// ```
+    /// use icu::experimental::dimension::range::formatter::NumberRangeFormatter;
+    /// use icu::locale::locale;
+    /// use writeable::assert_writeable_eq;
+    ///
+    /// let fmt = NumberRangeFormatter::try_new(locale!("en-US").into(), Default::default())
+    ///     .unwrap();
+    /// let value = "5".parse().unwrap();
+    /// assert_writeable_eq!(fmt.format_approximately(&value), "~5");
+    /// ```
+    pub fn format_approximately<'l>(
+        &'l self,
+        value: impl Into<RangeEndpoint<'l>>,
+    ) -> FormattedApproximateNumber<'l> {
+        FormattedApproximateNumber {
+            value: value.into(),
+            patterns: self.patterns.get(),
+            decimal_formatter: &self.decimal_formatter,
+        }
+    }
+}
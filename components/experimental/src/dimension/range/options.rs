@@ -0,0 +1,97 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Options for [`NumberRangeFormatter`](crate::dimension::range::formatter::NumberRangeFormatter).
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// A collection of configuration options that determine the formatting behavior of
+/// [`NumberRangeFormatter`](crate::dimension::range::formatter::NumberRangeFormatter).
+#[derive(Copy, Debug, Eq, PartialEq, Clone, Default, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[non_exhaustive]
+pub struct NumberRangeFormatterOptions {
+    /// Whether units and currencies shared by both ends of the range are displayed once.
+    pub collapse: RangeCollapse,
+
+    /// How a range is displayed if both ends format to the same value.
+    pub identity_fallback: RangeIdentityFallback,
+}
+
+impl From<RangeCollapse> for NumberRangeFormatterOptions {
+    fn from(collapse: RangeCollapse) -> Self {
+        Self {
+            collapse,
+            ..Default::default()
+        }
+    }
+}
+
+impl From<RangeIdentityFallback> for NumberRangeFormatterOptions {
+    fn from(identity_fallback: RangeIdentityFallback) -> Self {
+        Self {
+            identity_fallback,
+            ..Default::default()
+        }
+    }
+}
+
+#[derive(Debug, Eq, PartialEq, Clone, Copy, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[non_exhaustive]
+pub enum RangeCollapse {
+    /// Display units once, and currency symbols once if they are longer than a single character.
+    ///
+    /// For example, "3–5 m" and "$3 – $5" in en-US, and "3–5 €" in de-DE.
+    #[cfg_attr(feature = "serde", serde(rename = "auto"))]
+    Auto,
+
+    /// Display units and currency symbols on both ends of the range.
+    ///
+    /// For example, "3 m – 5 m" and "$3 – $5" in en-US.
+    #[cfg_attr(feature = "serde", serde(rename = "none"))]
+    None,
+
+    /// Display units and currency symbols once.
+    ///
+    /// For example, "3–5 m" and "$3–5" in en-US.
+    #[cfg_attr(feature = "serde", serde(rename = "unit"))]
+    Unit,
+}
+
+impl Default for RangeCollapse {
+    fn default() -> Self {
+        Self::Auto
+    }
+}
+
+#[derive(Debug, Eq, PartialEq, Clone, Copy, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[non_exhaustive]
+pub enum RangeIdentityFallback {
+    /// Display the value once.
+    ///
+    /// For example, "5" in en-US.
+    #[cfg_attr(feature = "serde", serde(rename = "singleValue"))]
+    SingleValue,
+
+    /// Display the value once, marked as approximate.
+    ///
+    /// For example, "~5" in en-US.
+    #[cfg_attr(feature = "serde", serde(rename = "approximately"))]
+    Approximately,
+
+    /// Display the range even though both ends are the same.
+    ///
+    /// For example, "5–5" in en-US.
+    #[cfg_attr(feature = "serde", serde(rename = "range"))]
+    Range,
+}
+
+impl Default for RangeIdentityFallback {
+    fn default() -> Self {
+        Self::Approximately
+    }
+}
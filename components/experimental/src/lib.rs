@@ -17,13 +17,19 @@
 
 extern crate alloc;
 
+#[cfg(feature = "alphabeticindex")]
+pub mod alphabeticindex;
+pub mod bidi;
 pub mod compactdecimal;
 pub mod dimension;
 pub mod displaynames;
 pub mod duration;
 pub mod measure;
+pub mod messageformat;
 pub mod personnames;
 pub mod relativetime;
+pub mod scientificdecimal;
+pub mod spoof;
 pub mod transliterate;
 pub mod unicodeset_parse;
 pub mod units;
@@ -61,6 +67,7 @@ pub mod provider {
         impl_script_display_names_v1!(Baked);
         impl_variant_display_names_v1!(Baked);
         impl_percent_essentials_v1!(Baked);
+        impl_number_range_patterns_v1!(Baked);
         impl_person_names_format_v1!(Baked);
         impl_long_day_relative_v1!(Baked);
         impl_long_hour_relative_v1!(Baked);
@@ -86,6 +93,7 @@ pub mod provider {
         impl_short_second_relative_v1!(Baked);
         impl_short_week_relative_v1!(Baked);
         impl_short_year_relative_v1!(Baked);
+        impl_scientific_decimal_symbols_v1!(Baked);
         impl_transliterator_rules_v1!(Baked);
         impl_units_info_v1!(Baked);
         impl_unit_ids_v1!(Baked);
@@ -107,6 +115,7 @@ pub mod provider {
         super::dimension::provider::currency::patterns::CurrencyPatternsDataV1::INFO,
         super::dimension::provider::currency::extended::CurrencyExtendedDataV1::INFO,
         super::dimension::provider::percent::PercentEssentialsV1::INFO,
+        super::dimension::provider::range::NumberRangePatternsV1::INFO,
         super::dimension::provider::units::essentials::UnitsEssentialsV1::INFO,
         super::dimension::provider::units::display_name::UnitsDisplayNameV1::INFO,
         super::displaynames::provider::LanguageDisplayNamesV1::INFO,
@@ -141,6 +150,8 @@ pub mod provider {
         super::relativetime::provider::ShortSecondRelativeV1::INFO,
         super::relativetime::provider::ShortWeekRelativeV1::INFO,
         super::relativetime::provider::ShortYearRelativeV1::INFO,
+        super::scientificdecimal::provider::ScientificDecimalSymbolsV1::INFO,
+        super::spoof::provider::ConfusablesV1::INFO,
         super::transliterate::provider::TransliteratorRulesV1::INFO,
         super::units::provider::UnitsInfoV1::INFO,
     ];
@@ -0,0 +1,137 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use alloc::borrow::Cow;
+use alloc::string::String;
+use fixed_decimal::Decimal;
+#[cfg(feature = "messageformat_datetime")]
+use icu_calendar::Iso;
+#[cfg(feature = "messageformat_datetime")]
+use icu_datetime::input::{Date, DateTime, Time};
+use litemap::LiteMap;
+
+/// A value passed to a message by name.
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub enum MessageValue<'a> {
+    /// A string, which can also be used as the operand of number and date functions
+    /// if it contains a number or an ISO 8601 date.
+    String(Cow<'a, str>),
+    /// A number.
+    Number(Decimal),
+    /// A date without a time of day.
+    #[cfg(feature = "messageformat_datetime")]
+    Date(Date<Iso>),
+    /// A time of day without a date.
+    #[cfg(feature = "messageformat_datetime")]
+    Time(Time),
+    /// A date with a time of day.
+    #[cfg(feature = "messageformat_datetime")]
+    DateTime(DateTime<Iso>),
+}
+
+impl<'a> From<&'a str> for MessageValue<'a> {
+    fn from(value: &'a str) -> Self {
+        Self::String(Cow::Borrowed(value))
+    }
+}
+
+impl From<String> for MessageValue<'_> {
+    fn from(value: String) -> Self {
+        Self::String(Cow::Owned(value))
+    }
+}
+
+impl From<Decimal> for MessageValue<'_> {
+    fn from(value: Decimal) -> Self {
+        Self::Number(value)
+    }
+}
+
+macro_rules! impl_from_integer {
+    ($($t:ty),+) => {
+        $(
+            impl From<$t> for MessageValue<'_> {
+                fn from(value: $t) -> Self {
+                    Self::Number(Decimal::from(value))
+                }
+            }
+        )+
+    };
+}
+
+impl_from_integer!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+#[cfg(feature = "messageformat_datetime")]
+impl From<Date<Iso>> for MessageValue<'_> {
+    fn from(value: Date<Iso>) -> Self {
+        Self::Date(value)
+    }
+}
+
+#[cfg(feature = "messageformat_datetime")]
+impl From<Time> for MessageValue<'_> {
+    fn from(value: Time) -> Self {
+        Self::Time(value)
+    }
+}
+
+#[cfg(feature = "messageformat_datetime")]
+impl From<DateTime<Iso>> for MessageValue<'_> {
+    fn from(value: DateTime<Iso>) -> Self {
+        Self::DateTime(value)
+    }
+}
+
+/// The named arguments of a message.
+///
+/// # Examples
+///
+/// ```
+/// use icu::experimental::messageformat::{MessageArguments, MessageValue};
+///
+/// let arguments = MessageArguments::new()
+///     .with("name", "Maria")
+///     .with("count", 3);
+///
+/// assert_eq!(arguments.get("name"), Some(&MessageValue::from("Maria")));
+/// assert_eq!(arguments.get("other"), None);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct MessageArguments<'a> {
+    values: LiteMap<&'a str, MessageValue<'a>>,
+}
+
+impl<'a> MessageArguments<'a> {
+    /// Creates an empty set of arguments.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns these arguments with the given argument added.
+    pub fn with(mut self, name: &'a str, value: impl Into<MessageValue<'a>>) -> Self {
+        self.insert(name, value);
+        self
+    }
+
+    /// Adds an argument, replacing any argument with the same name.
+    pub fn insert(&mut self, name: &'a str, value: impl Into<MessageValue<'a>>) {
+        self.values.insert(name, value.into());
+    }
+
+    /// Returns the argument with the given name.
+    pub fn get(&self, name: &str) -> Option<&MessageValue<'a>> {
+        self.values.get(name)
+    }
+}
+
+impl<'a, V: Into<MessageValue<'a>>> FromIterator<(&'a str, V)> for MessageArguments<'a> {
+    fn from_iter<T: IntoIterator<Item = (&'a str, V)>>(iter: T) -> Self {
+        let mut arguments = Self::new();
+        for (name, value) in iter {
+            arguments.insert(name, value);
+        }
+        arguments
+    }
+}
@@ -0,0 +1,196 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! The `:date`, `:time`, and `:datetime` functions of the default function registry.
+//!
+//! See <https://unicode.org/reports/tr35/tr35-messageFormat.html#contents-of-part-9-messageformat>.
+
+use super::errors::MessageFormatErrorKind;
+use icu_calendar::Iso;
+use icu_datetime::fieldsets::enums::{
+    CompositeDateTimeFieldSet, DateAndTimeFieldSet, DateFieldSet, TimeFieldSet,
+};
+use icu_datetime::fieldsets::{T, YMD, YMDE};
+use icu_datetime::input::{Date, DateTime, Time};
+use icu_datetime::options::{Length, TimePrecision};
+
+/// The `style`, `dateStyle`, and `timeStyle` options of the date and time functions.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub(crate) enum DateTimeStyle {
+    Full,
+    Long,
+    Medium,
+    Short,
+}
+
+impl DateTimeStyle {
+    pub(crate) fn parse(value: &str) -> Result<Self, MessageFormatErrorKind> {
+        match value {
+            "full" => Ok(Self::Full),
+            "long" => Ok(Self::Long),
+            "medium" => Ok(Self::Medium),
+            "short" => Ok(Self::Short),
+            _ => Err(MessageFormatErrorKind::BadOption),
+        }
+    }
+
+    fn length(self) -> Length {
+        match self {
+            Self::Full | Self::Long => Length::Long,
+            Self::Medium => Length::Medium,
+            Self::Short => Length::Short,
+        }
+    }
+
+    fn time_precision(self) -> TimePrecision {
+        match self {
+            Self::Full | Self::Long | Self::Medium => TimePrecision::Second,
+            Self::Short => TimePrecision::Minute,
+        }
+    }
+}
+
+/// The styles of a date or time function, each of which is formatted by its own formatter.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub(crate) struct DateTimeStyles {
+    pub(crate) date: Option<DateTimeStyle>,
+    pub(crate) time: Option<DateTimeStyle>,
+}
+
+impl DateTimeStyles {
+    /// Returns the styles of the given date or time function, or `None` if the function is
+    /// not a date or time function.
+    ///
+    /// `option` returns the value of the option with the given name.
+    pub(crate) fn for_function<'s>(
+        name: &str,
+        option: impl Fn(&str) -> Option<&'s str>,
+    ) -> Option<Result<Self, MessageFormatErrorKind>> {
+        let style = |name: &str| option(name).map(DateTimeStyle::parse).transpose();
+        let styles = match name {
+            "date" => style("style").map(|date| Self {
+                date: Some(date.unwrap_or(DateTimeStyle::Medium)),
+                time: None,
+            }),
+            "time" => style("style").map(|time| Self {
+                date: None,
+                time: Some(time.unwrap_or(DateTimeStyle::Short)),
+            }),
+            "datetime" => style("dateStyle").and_then(|date| {
+                let time = style("timeStyle")?;
+                Ok(if date.is_none() && time.is_none() {
+                    Self {
+                        date: Some(DateTimeStyle::Medium),
+                        time: Some(DateTimeStyle::Short),
+                    }
+                } else {
+                    Self { date, time }
+                })
+            }),
+            _ => return None,
+        };
+        Some(styles)
+    }
+
+    /// Returns the field set used to format these styles.
+    pub(crate) fn field_set(self) -> CompositeDateTimeFieldSet {
+        match (self.date, self.time) {
+            (Some(DateTimeStyle::Full), None) => {
+                CompositeDateTimeFieldSet::Date(DateFieldSet::YMDE(YMDE::long()))
+            }
+            (Some(date), None) => {
+                CompositeDateTimeFieldSet::Date(DateFieldSet::YMD(YMD::for_length(date.length())))
+            }
+            (Some(DateTimeStyle::Full), Some(time)) => CompositeDateTimeFieldSet::DateTime(
+                DateAndTimeFieldSet::YMDET(YMDE::long().with_time(time.time_precision())),
+            ),
+            (Some(date), Some(time)) => {
+                CompositeDateTimeFieldSet::DateTime(DateAndTimeFieldSet::YMDT(
+                    YMD::for_length(date.length()).with_time(time.time_precision()),
+                ))
+            }
+            (None, time) => {
+                let time = time.unwrap_or(DateTimeStyle::Short);
+                CompositeDateTimeFieldSet::Time(TimeFieldSet::T(
+                    T::for_length(time.length()).with_time_precision(time.time_precision()),
+                ))
+            }
+        }
+    }
+}
+
+/// The operand of a date or time function, which may be missing some of its fields.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub(crate) struct DateTimeValue {
+    pub(crate) date: Option<Date<Iso>>,
+    pub(crate) time: Option<Time>,
+}
+
+impl DateTimeValue {
+    /// Parses an ISO 8601 date, time, or date-time string, such as `2025-01-15T16:00`.
+    pub(crate) fn parse(value: &str) -> Result<Self, MessageFormatErrorKind> {
+        let (date, time) = match value.split_once('T') {
+            Some((date, time)) => (Some(date), Some(time)),
+            None if value.contains(':') => (None, Some(value)),
+            None => (Some(value), None),
+        };
+        let date = date
+            .map(|date| {
+                let mut fields = date.splitn(3, '-');
+                let year = parse_field(fields.next(), 4)?;
+                let month = parse_field(fields.next(), 2)?;
+                let day = parse_field(fields.next(), 2)?;
+                Date::try_new_iso(year, month, day).map_err(|_| MessageFormatErrorKind::BadOperand)
+            })
+            .transpose()?;
+        let time = time
+            .map(|time| {
+                let mut fields = time.splitn(3, ':');
+                let hour = parse_field(fields.next(), 2)?;
+                let minute = parse_field(fields.next(), 2)?;
+                let second = match fields.next() {
+                    Some(second) => parse_field(Some(second), 2)?,
+                    None => 0,
+                };
+                Time::try_new(hour, minute, second, 0)
+                    .map_err(|_| MessageFormatErrorKind::BadOperand)
+            })
+            .transpose()?;
+        Ok(Self { date, time })
+    }
+
+    /// Returns the value to format, filling in missing fields, if the value has the fields
+    /// required by the styles.
+    pub(crate) fn for_styles(
+        self,
+        styles: DateTimeStyles,
+    ) -> Result<DateTime<Iso>, MessageFormatErrorKind> {
+        let date = match (self.date, styles.date) {
+            (Some(date), _) => date,
+            (None, None) => {
+                Date::try_new_iso(1970, 1, 1).map_err(|_| MessageFormatErrorKind::BadOperand)?
+            }
+            (None, Some(_)) => return Err(MessageFormatErrorKind::BadOperand),
+        };
+        let time = match (self.time, styles.time) {
+            (Some(time), _) => time,
+            (None, Some(_)) if styles.date.is_none() => {
+                return Err(MessageFormatErrorKind::BadOperand)
+            }
+            (None, _) => Time::start_of_day(),
+        };
+        Ok(DateTime { date, time })
+    }
+}
+
+/// Parses a field of exactly `len` ASCII digits.
+fn parse_field<N: core::str::FromStr>(
+    field: Option<&str>,
+    len: usize,
+) -> Result<N, MessageFormatErrorKind> {
+    field
+        .filter(|field| field.len() == len && field.bytes().all(|b| b.is_ascii_digit()))
+        .and_then(|field| field.parse().ok())
+        .ok_or(MessageFormatErrorKind::BadOperand)
+}
@@ -0,0 +1,138 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+#[cfg(feature = "messageformat_datetime")]
+use icu_datetime::DateTimeFormatterLoadError;
+use icu_provider::DataError;
+
+/// The kind of a [`MessageSyntaxError`].
+///
+/// This covers both syntax errors and data model errors of the specification.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, displaydoc::Display)]
+#[non_exhaustive]
+pub enum MessageSyntaxErrorKind {
+    /// The message contains a character that is not allowed at this position.
+    #[displaydoc("Unexpected character")]
+    UnexpectedCharacter,
+    /// The message ended in the middle of a construct.
+    #[displaydoc("Unexpected end of message")]
+    UnexpectedEnd,
+    /// A variable is declared twice, or declared after it was used in a declaration.
+    #[displaydoc("Duplicate declaration")]
+    DuplicateDeclaration,
+    /// An option name occurs twice in the same function or markup.
+    #[displaydoc("Duplicate option name")]
+    DuplicateOptionName,
+    /// Two variants have the same keys.
+    #[displaydoc("Duplicate variant")]
+    DuplicateVariant,
+    /// A variant has a different number of keys than there are selectors.
+    #[displaydoc("Variant key mismatch")]
+    VariantKeyMismatch,
+    /// There is no variant with only catch-all (`*`) keys.
+    #[displaydoc("Missing fallback variant")]
+    MissingFallbackVariant,
+    /// A selector does not reference a declaration with a function.
+    #[displaydoc("Missing selector annotation")]
+    MissingSelectorAnnotation,
+}
+
+/// An error in the source of a message.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, displaydoc::Display)]
+#[displaydoc("{kind} at index {index}")]
+#[non_exhaustive]
+pub struct MessageSyntaxError {
+    /// What went wrong.
+    pub kind: MessageSyntaxErrorKind,
+    /// The UTF-8 byte index into the message at which the error was detected.
+    pub index: usize,
+}
+
+impl core::error::Error for MessageSyntaxError {}
+
+impl MessageSyntaxError {
+    pub(crate) fn new(kind: MessageSyntaxErrorKind, index: usize) -> Self {
+        Self { kind, index }
+    }
+}
+
+/// An error returned when constructing a [`MessageFormatter`](super::MessageFormatter).
+#[derive(Debug, Copy, Clone, PartialEq, displaydoc::Display)]
+#[non_exhaustive]
+pub enum MessageFormatterLoadError {
+    /// The message is not valid.
+    #[displaydoc("{0}")]
+    Syntax(MessageSyntaxError),
+    /// Data for a number formatting or selection function could not be loaded.
+    #[displaydoc("{0}")]
+    Data(DataError),
+    /// Data for a date or time formatting function could not be loaded.
+    #[cfg(feature = "messageformat_datetime")]
+    #[displaydoc("{0}")]
+    DateTime(DateTimeFormatterLoadError),
+}
+
+impl core::error::Error for MessageFormatterLoadError {}
+
+impl From<MessageSyntaxError> for MessageFormatterLoadError {
+    fn from(e: MessageSyntaxError) -> Self {
+        Self::Syntax(e)
+    }
+}
+
+impl From<DataError> for MessageFormatterLoadError {
+    fn from(e: DataError) -> Self {
+        Self::Data(e)
+    }
+}
+
+#[cfg(feature = "messageformat_datetime")]
+impl From<DateTimeFormatterLoadError> for MessageFormatterLoadError {
+    fn from(e: DateTimeFormatterLoadError) -> Self {
+        Self::DateTime(e)
+    }
+}
+
+/// The kind of a [`MessageFormatError`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq, displaydoc::Display)]
+#[non_exhaustive]
+pub enum MessageFormatErrorKind {
+    /// A variable is neither declared nor passed as an argument.
+    #[displaydoc("Unresolved variable")]
+    UnresolvedVariable,
+    /// A function is not in the function registry.
+    #[displaydoc("Unknown function")]
+    UnknownFunction,
+    /// The operand of a function has an unsupported type or value.
+    #[displaydoc("Bad operand")]
+    BadOperand,
+    /// An option of a function has an unsupported value.
+    #[displaydoc("Bad option")]
+    BadOption,
+    /// A selector cannot be used for selection.
+    #[displaydoc("Bad selector")]
+    BadSelector,
+}
+
+/// An error that occurred while formatting a message.
+///
+/// The formatted output replaces the expression that caused the error with a fallback
+/// representation, such as `{$count}`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, displaydoc::Display)]
+#[displaydoc("{kind} in the expression at index {index}")]
+#[non_exhaustive]
+pub struct MessageFormatError {
+    /// What went wrong.
+    pub kind: MessageFormatErrorKind,
+    /// The UTF-8 byte index into the message of the expression that caused the error.
+    pub index: usize,
+}
+
+impl core::error::Error for MessageFormatError {}
+
+impl MessageFormatError {
+    pub(crate) fn new(kind: MessageFormatErrorKind, index: usize) -> Self {
+        Self { kind, index }
+    }
+}
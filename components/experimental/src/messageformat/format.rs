@@ -0,0 +1,539 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use super::arguments::{MessageArguments, MessageValue};
+#[cfg(feature = "messageformat_datetime")]
+use super::datetime::{DateTimeStyles, DateTimeValue};
+use super::errors::{MessageFormatError, MessageFormatErrorKind};
+use super::formatter::MessageFormatter;
+use super::functions::{numerically_equal, parse_number, NumberOptions, NumberSelect};
+use super::model::{Body, Expression, Function, Key, MessagePattern, Operand, Placeholder};
+use alloc::borrow::Cow;
+use alloc::collections::BTreeMap;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::fmt::{self, Write};
+use fixed_decimal::Decimal;
+#[cfg(feature = "messageformat_datetime")]
+use icu_calendar::Iso;
+#[cfg(feature = "messageformat_datetime")]
+use icu_datetime::fieldsets::enums::CompositeDateTimeFieldSet;
+#[cfg(feature = "messageformat_datetime")]
+use icu_datetime::input::DateTime;
+#[cfg(feature = "messageformat_datetime")]
+use icu_datetime::DateTimeFormatter;
+use icu_decimal::DecimalFormatter;
+use icu_pattern::{MultiNamedPlaceholder, Pattern};
+use icu_plurals::PluralCategory;
+use writeable::{PartsWrite, TryWriteable, Writeable};
+
+/// Parts of a formatted message.
+pub mod parts {
+    use writeable::Part;
+
+    /// The [`Part`] used by [`FormattedMessage`](crate::messageformat::FormattedMessage) to mark
+    /// the formatted value of a `:string` function or of a string argument.
+    pub const STRING: Part = Part {
+        category: "messageformat",
+        value: "string",
+    };
+
+    /// The [`Part`] used by [`FormattedMessage`](crate::messageformat::FormattedMessage) to mark
+    /// a formatted number. The number also contains the parts of
+    /// [`FormattedDecimal`](icu_decimal::FormattedDecimal).
+    pub const NUMBER: Part = Part {
+        category: "messageformat",
+        value: "number",
+    };
+
+    /// The [`Part`] used by [`FormattedMessage`](crate::messageformat::FormattedMessage) to mark
+    /// a formatted date or time. The date or time also contains the parts of
+    /// [`FormattedDateTime`](icu_datetime::FormattedDateTime).
+    #[cfg(feature = "messageformat_datetime")]
+    pub const DATETIME: Part = Part {
+        category: "messageformat",
+        value: "datetime",
+    };
+
+    /// The [`Part`] used by [`FormattedMessage`](crate::messageformat::FormattedMessage) to mark
+    /// the fallback representation of an expression that could not be formatted.
+    pub const FALLBACK: Part = Part {
+        category: "messageformat",
+        value: "fallback",
+    };
+}
+
+/// An intermediate structure returned by [`MessageFormatter`].
+///
+/// This structure can be consumed via the [`TryWriteable`] trait. Formatting errors are
+/// reported as the [`TryWriteable::Error`], after the message has been written with a fallback
+/// representation for each expression that could not be formatted. If there are several
+/// errors, the first one is returned.
+///
+/// # Examples
+///
+/// ```
+/// use icu::experimental::messageformat::{
+///     parts, MessageArguments, MessageFormatter,
+/// };
+/// use icu::locale::locale;
+/// use writeable::assert_try_writeable_parts_eq;
+///
+/// let formatter =
+///     MessageFormatter::try_new(locale!("en").into(), "{$name} has {$count} items")
+///         .unwrap();
+///
+/// let arguments = MessageArguments::new()
+///     .with("name", "Maria")
+///     .with("count", 5);
+///
+/// assert_try_writeable_parts_eq!(
+///     formatter.format(&arguments),
+///     "Maria has 5 items",
+///     Ok(()),
+///     [
+///         (0, 5, parts::STRING),
+///         (10, 11, icu::decimal::parts::INTEGER),
+///         (10, 11, parts::NUMBER),
+///     ]
+/// );
+/// ```
+#[derive(Debug)]
+pub struct FormattedMessage<'a> {
+    /// The selected pattern, or `None` if no variant could be selected.
+    pattern: Option<&'a Pattern<MultiNamedPlaceholder>>,
+    values: BTreeMap<String, Output<'a>>,
+    error: Option<MessageFormatError>,
+}
+
+impl TryWriteable for FormattedMessage<'_> {
+    type Error = MessageFormatError;
+
+    fn try_write_to_parts<S: PartsWrite + ?Sized>(
+        &self,
+        sink: &mut S,
+    ) -> Result<Result<(), Self::Error>, fmt::Error> {
+        if let Some(pattern) = self.pattern {
+            // Every placeholder of the pattern has a value, so interpolation does not fail
+            let _ = pattern
+                .try_interpolate(&self.values)
+                .try_write_to_parts(sink)?;
+        }
+        Ok(self.error.map_or(Ok(()), Err))
+    }
+}
+
+/// The formatted output of a placeholder.
+#[derive(Debug)]
+enum Output<'a> {
+    String(Cow<'a, str>),
+    Number(&'a DecimalFormatter, Decimal),
+    #[cfg(feature = "messageformat_datetime")]
+    DateTime(
+        &'a DateTimeFormatter<CompositeDateTimeFieldSet>,
+        DateTime<Iso>,
+    ),
+    Fallback(String),
+    Markup,
+}
+
+impl Writeable for Output<'_> {
+    fn write_to_parts<S: PartsWrite + ?Sized>(&self, sink: &mut S) -> fmt::Result {
+        match self {
+            Output::String(value) => sink.with_part(parts::STRING, |w| w.write_str(value)),
+            Output::Number(formatter, value) => {
+                sink.with_part(parts::NUMBER, |w| formatter.format(value).write_to_parts(w))
+            }
+            #[cfg(feature = "messageformat_datetime")]
+            Output::DateTime(formatter, value) => sink.with_part(parts::DATETIME, |w| {
+                formatter.format(value).write_to_parts(w)
+            }),
+            Output::Fallback(value) => sink.with_part(parts::FALLBACK, |w| {
+                w.write_char('{')?;
+                w.write_str(value)?;
+                w.write_char('}')
+            }),
+            Output::Markup => Ok(()),
+        }
+    }
+}
+
+/// The resolved value of an expression or argument.
+#[derive(Debug, Clone)]
+enum Value<'a> {
+    String(Cow<'a, str>),
+    /// A number with the options of the number function that produced it, which are applied
+    /// when the number is formatted or selected.
+    Number(Decimal, NumberOptions),
+    /// A date or time with the styles of the date or time function that produced it, if any.
+    #[cfg(feature = "messageformat_datetime")]
+    DateTime(DateTimeValue, Option<DateTimeStyles>),
+}
+
+/// Resolves the expressions of a message, collecting the first error.
+pub(crate) struct Resolver<'a> {
+    formatter: &'a MessageFormatter,
+    arguments: &'a MessageArguments<'a>,
+    /// The values of declared variables, or `None` if the declaration could not be resolved.
+    locals: Vec<(&'a str, Option<Value<'a>>)>,
+    error: Option<MessageFormatError>,
+}
+
+impl<'a> Resolver<'a> {
+    pub(crate) fn new(
+        formatter: &'a MessageFormatter,
+        arguments: &'a MessageArguments<'a>,
+    ) -> Self {
+        Self {
+            formatter,
+            arguments,
+            locals: Vec::new(),
+            error: None,
+        }
+    }
+
+    fn report(&mut self, kind: MessageFormatErrorKind, index: usize) {
+        self.error
+            .get_or_insert(MessageFormatError::new(kind, index));
+    }
+
+    pub(crate) fn resolve(mut self) -> FormattedMessage<'a> {
+        let message = &self.formatter.message;
+        for declaration in &message.declarations {
+            let value = self.resolve_expression(declaration.expression());
+            self.locals.push((declaration.name(), value));
+        }
+        let pattern = match &message.body {
+            Body::Pattern(pattern) => Some(pattern),
+            Body::Matcher {
+                selectors,
+                variants,
+            } => self.select(selectors, variants),
+        };
+        let Some(pattern) = pattern else {
+            self.report(MessageFormatErrorKind::BadSelector, 0);
+            return FormattedMessage {
+                pattern: None,
+                values: BTreeMap::new(),
+                error: self.error,
+            };
+        };
+        let mut values = BTreeMap::new();
+        for &id in &pattern.placeholders {
+            let output = match message.placeholders.get(id) {
+                Some(Placeholder::Expression(expression)) => self.format_expression(expression),
+                Some(Placeholder::Markup) | None => Output::Markup,
+            };
+            values.insert(id.to_string(), output);
+        }
+        FormattedMessage {
+            pattern: Some(&pattern.pattern),
+            values,
+            error: self.error,
+        }
+    }
+
+    fn resolve_expression(&mut self, expression: &'a Expression) -> Option<Value<'a>> {
+        match self.try_resolve_expression(expression) {
+            Ok(value) => Some(value),
+            Err(kind) => {
+                self.report(kind, expression.index);
+                None
+            }
+        }
+    }
+
+    fn try_resolve_expression(
+        &self,
+        expression: &'a Expression,
+    ) -> Result<Value<'a>, MessageFormatErrorKind> {
+        let operand = expression
+            .operand
+            .as_ref()
+            .map(|operand| self.resolve_operand(operand))
+            .transpose()?;
+        match &expression.function {
+            Some(function) => self.call(function, operand),
+            // The syntax does not allow expressions without operand and function
+            None => operand.ok_or(MessageFormatErrorKind::BadOperand),
+        }
+    }
+
+    fn resolve_operand(&self, operand: &'a Operand) -> Result<Value<'a>, MessageFormatErrorKind> {
+        let name = match operand {
+            Operand::Literal(literal) => return Ok(Value::String(Cow::Borrowed(literal))),
+            Operand::Variable(name) => name,
+        };
+        if let Some((_, local)) = self.locals.iter().rev().find(|(n, _)| *n == name) {
+            // A failed declaration has already reported its error
+            return local.clone().ok_or(MessageFormatErrorKind::BadOperand);
+        }
+        Ok(match self.arguments.get(name) {
+            Some(MessageValue::String(value)) => Value::String(Cow::Borrowed(value)),
+            Some(MessageValue::Number(value)) => {
+                Value::Number(value.clone(), NumberOptions::default())
+            }
+            #[cfg(feature = "messageformat_datetime")]
+            Some(MessageValue::Date(date)) => Value::DateTime(
+                DateTimeValue {
+                    date: Some(*date),
+                    time: None,
+                },
+                None,
+            ),
+            #[cfg(feature = "messageformat_datetime")]
+            Some(MessageValue::Time(time)) => Value::DateTime(
+                DateTimeValue {
+                    date: None,
+                    time: Some(*time),
+                },
+                None,
+            ),
+            #[cfg(feature = "messageformat_datetime")]
+            Some(MessageValue::DateTime(datetime)) => Value::DateTime(
+                DateTimeValue {
+                    date: Some(datetime.date),
+                    time: Some(datetime.time),
+                },
+                None,
+            ),
+            None => return Err(MessageFormatErrorKind::UnresolvedVariable),
+        })
+    }
+
+    /// Calls a function of the default registry.
+    fn call(
+        &self,
+        function: &'a Function,
+        operand: Option<Value<'a>>,
+    ) -> Result<Value<'a>, MessageFormatErrorKind> {
+        let mut options = Vec::with_capacity(function.options.len());
+        for (name, value) in &function.options {
+            let value = match self.resolve_operand(value)? {
+                Value::String(value) => value,
+                Value::Number(value, _) => Cow::Owned(value.write_to_string().into_owned()),
+                #[cfg(feature = "messageformat_datetime")]
+                Value::DateTime(..) => return Err(MessageFormatErrorKind::BadOption),
+            };
+            options.push((&**name, value));
+        }
+
+        match &*function.name {
+            "string" => match operand {
+                Some(Value::String(value)) => Ok(Value::String(value)),
+                Some(Value::Number(value, _)) => Ok(Value::String(Cow::Owned(
+                    value.write_to_string().into_owned(),
+                ))),
+                _ => Err(MessageFormatErrorKind::BadOperand),
+            },
+            name @ ("number" | "integer") => {
+                let (mut value, mut number_options) = match operand {
+                    Some(Value::Number(value, options)) => (value, options),
+                    Some(Value::String(value)) => (parse_number(&value)?, Default::default()),
+                    _ => return Err(MessageFormatErrorKind::BadOperand),
+                };
+                let is_integer = name == "integer";
+                if is_integer {
+                    value.trunc(0);
+                    number_options.minimum_fraction_digits = None;
+                    number_options.maximum_fraction_digits = None;
+                }
+                for (name, value) in &options {
+                    if is_integer && name.ends_with("FractionDigits") {
+                        continue;
+                    }
+                    number_options.set(name, value)?;
+                }
+                Ok(Value::Number(value, number_options))
+            }
+            #[cfg(feature = "messageformat_datetime")]
+            name => {
+                let styles = DateTimeStyles::for_function(name, |name| {
+                    options
+                        .iter()
+                        .find_map(|(n, value)| (*n == name).then_some(&**value))
+                })
+                .ok_or(MessageFormatErrorKind::UnknownFunction)??;
+                let value = match operand {
+                    Some(Value::DateTime(value, _)) => value,
+                    Some(Value::String(value)) => DateTimeValue::parse(&value)?,
+                    _ => return Err(MessageFormatErrorKind::BadOperand),
+                };
+                // Check that the value has the required fields
+                value.for_styles(styles)?;
+                Ok(Value::DateTime(value, Some(styles)))
+            }
+            #[cfg(not(feature = "messageformat_datetime"))]
+            _ => Err(MessageFormatErrorKind::UnknownFunction),
+        }
+    }
+
+    fn format_expression(&mut self, expression: &'a Expression) -> Output<'a> {
+        let output = self
+            .resolve_expression(expression)
+            .map(|value| self.format_value(value));
+        match output {
+            Some(Ok(output)) => output,
+            Some(Err(kind)) => {
+                self.report(kind, expression.index);
+                Output::Fallback(fallback(expression))
+            }
+            None => Output::Fallback(fallback(expression)),
+        }
+    }
+
+    fn format_value(&self, value: Value<'a>) -> Result<Output<'a>, MessageFormatErrorKind> {
+        match value {
+            Value::String(value) => Ok(Output::String(value)),
+            Value::Number(mut value, options) => {
+                options.apply(&mut value);
+                let (_, formatter) = self
+                    .formatter
+                    .decimal
+                    .iter()
+                    .find(|(grouping, _)| *grouping == options.grouping)
+                    .ok_or(MessageFormatErrorKind::BadOption)?;
+                Ok(Output::Number(formatter, value))
+            }
+            #[cfg(feature = "messageformat_datetime")]
+            Value::DateTime(value, Some(styles)) => {
+                let (_, formatter) = self
+                    .formatter
+                    .datetime
+                    .iter()
+                    .find(|(s, _)| *s == styles)
+                    .ok_or(MessageFormatErrorKind::BadOption)?;
+                Ok(Output::DateTime(formatter, value.for_styles(styles)?))
+            }
+            // Dates and times are only formatted with an explicit style
+            #[cfg(feature = "messageformat_datetime")]
+            Value::DateTime(_, None) => Err(MessageFormatErrorKind::BadOperand),
+        }
+    }
+
+    /// Selects the best-matching variant, following the pattern selection algorithm of the
+    /// specification.
+    ///
+    /// Returns `None` if no variant matches, which the syntax prevents by requiring a fallback
+    /// variant.
+    fn select(
+        &mut self,
+        selectors: &'a [String],
+        variants: &'a [super::model::Variant],
+    ) -> Option<&'a MessagePattern> {
+        // For each selector, the matching keys in order of preference
+        let mut preferences: Vec<Vec<&'a str>> = Vec::with_capacity(selectors.len());
+        for (i, selector) in selectors.iter().enumerate() {
+            let keys = variants
+                .iter()
+                .filter_map(|variant| match variant.keys.get(i) {
+                    Some(Key::Literal(key)) => Some(&**key),
+                    _ => None,
+                });
+            match self.match_keys(selector, keys) {
+                Ok(keys) => preferences.push(keys),
+                Err(kind) => {
+                    let index = self
+                        .formatter
+                        .message
+                        .declarations
+                        .iter()
+                        .rev()
+                        .find(|d| d.name() == selector)
+                        .map(|d| d.expression().index)
+                        .unwrap_or_default();
+                    self.report(kind, index);
+                    preferences.push(Vec::new());
+                }
+            }
+        }
+
+        let mut best: Option<(Vec<usize>, &'a MessagePattern)> = None;
+        'variants: for variant in variants {
+            let mut rank = Vec::with_capacity(variant.keys.len());
+            for (key, preferences) in variant.keys.iter().zip(&preferences) {
+                rank.push(match key {
+                    Key::CatchAll => preferences.len(),
+                    Key::Literal(key) => match preferences.iter().position(|p| p == key) {
+                        Some(position) => position,
+                        None => continue 'variants,
+                    },
+                });
+            }
+            if best.as_ref().is_none_or(|(best, _)| rank < *best) {
+                best = Some((rank, &variant.pattern));
+            }
+        }
+        best.map(|(_, pattern)| pattern)
+    }
+
+    /// Returns the keys that match the value of the selector, in order of preference.
+    fn match_keys(
+        &self,
+        selector: &str,
+        keys: impl Iterator<Item = &'a str>,
+    ) -> Result<Vec<&'a str>, MessageFormatErrorKind> {
+        let value = self
+            .locals
+            .iter()
+            .rev()
+            .find(|(name, _)| *name == selector)
+            .and_then(|(_, value)| value.as_ref())
+            .ok_or(MessageFormatErrorKind::BadSelector)?;
+        match value {
+            Value::String(value) => Ok(keys.filter(|key| key == value).collect()),
+            Value::Number(value, options) => {
+                let mut value = value.clone();
+                options.apply(&mut value);
+                let rules = match options.select {
+                    NumberSelect::Plural => self.formatter.cardinal.as_ref(),
+                    NumberSelect::Ordinal => self.formatter.ordinal.as_ref(),
+                    NumberSelect::Exact => None,
+                };
+                let category = rules.map(|rules| rules.category_for(&value));
+                let (mut exact, mut categories) = (Vec::new(), Vec::new());
+                for key in keys {
+                    if parse_number(key).is_ok_and(|key| numerically_equal(&key, &value)) {
+                        exact.push(key);
+                    } else if category.is_some()
+                        && PluralCategory::get_for_cldr_string(key) == category
+                    {
+                        categories.push(key);
+                    }
+                }
+                exact.append(&mut categories);
+                Ok(exact)
+            }
+            #[cfg(feature = "messageformat_datetime")]
+            Value::DateTime(..) => Err(MessageFormatErrorKind::BadSelector),
+        }
+    }
+}
+
+/// Returns the fallback representation of an expression, without braces.
+fn fallback(expression: &Expression) -> String {
+    let mut fallback = String::new();
+    match (&expression.operand, &expression.function) {
+        (Some(Operand::Variable(name)), _) => {
+            fallback.push('$');
+            fallback.push_str(name);
+        }
+        (Some(Operand::Literal(literal)), _) => {
+            fallback.push('|');
+            for c in literal.chars() {
+                if matches!(c, '\\' | '|') {
+                    fallback.push('\\');
+                }
+                fallback.push(c);
+            }
+            fallback.push('|');
+        }
+        (None, Some(function)) => {
+            fallback.push(':');
+            fallback.push_str(&function.name);
+        }
+        (None, None) => fallback.push('\u{FFFD}'),
+    }
+    fallback
+}
@@ -0,0 +1,396 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use super::arguments::MessageArguments;
+#[cfg(feature = "messageformat_datetime")]
+use super::datetime::DateTimeStyles;
+use super::errors::MessageFormatterLoadError;
+use super::format::{FormattedMessage, Resolver};
+use super::functions::parse_grouping;
+use super::model::{Message, Operand};
+use super::syntax;
+use alloc::vec;
+use alloc::vec::Vec;
+#[cfg(feature = "messageformat_datetime")]
+use icu_datetime::fieldsets::enums::CompositeDateTimeFieldSet;
+#[cfg(feature = "messageformat_datetime")]
+use icu_datetime::scaffold::{
+    AllAnyCalendarExternalDataMarkers, AllAnyCalendarFormattingDataMarkers,
+};
+#[cfg(feature = "messageformat_datetime")]
+use icu_datetime::{DateTimeFormatter, DateTimeFormatterLoadError, DateTimeFormatterPreferences};
+use icu_decimal::options::{DecimalFormatterOptions, GroupingStrategy};
+use icu_decimal::provider::{DecimalDigitsV1, DecimalSymbolsV1};
+use icu_decimal::{DecimalFormatter, DecimalFormatterPreferences};
+use icu_locale_core::preferences::extensions::unicode::keywords::{
+    CalendarAlgorithm, HourCycle, NumberingSystem,
+};
+use icu_locale_core::preferences::{define_preferences, prefs_convert};
+use icu_plurals::provider::{PluralsCardinalV1, PluralsOrdinalV1};
+use icu_plurals::{PluralRules, PluralRulesPreferences};
+use icu_provider::prelude::*;
+
+define_preferences!(
+    /// The preferences for message formatting.
+    [Copy]
+    MessageFormatterPreferences,
+    {
+        /// The user's preferred numbering system.
+        ///
+        /// Corresponds to the `-u-nu` in Unicode Locale Identifier.
+        numbering_system: NumberingSystem,
+        /// The user's preferred hour cycle.
+        ///
+        /// Corresponds to the `-u-hc` in Unicode Locale Identifier.
+        hour_cycle: HourCycle,
+        /// The user's preferred calendar system.
+        ///
+        /// Corresponds to the `-u-ca` in Unicode Locale Identifier.
+        calendar_algorithm: CalendarAlgorithm
+    }
+);
+
+prefs_convert!(MessageFormatterPreferences, DecimalFormatterPreferences, {
+    numbering_system
+});
+prefs_convert!(MessageFormatterPreferences, PluralRulesPreferences);
+#[cfg(feature = "messageformat_datetime")]
+prefs_convert!(MessageFormatterPreferences, DateTimeFormatterPreferences, {
+    numbering_system,
+    hour_cycle,
+    calendar_algorithm
+});
+
+/// A formatter for messages in the [MessageFormat 2.0] syntax.
+///
+/// The message is parsed when the formatter is constructed, and the data for all functions
+/// used by the message is loaded up front. The supported functions are
+///
+/// * `:string`, for formatting and selection on strings,
+/// * `:number` and `:integer`, for formatting numbers and plural, ordinal, or exact selection,
+///   with the options `select`, `signDisplay`, `useGrouping`, `minimumIntegerDigits`,
+///   `minimumFractionDigits`, and `maximumFractionDigits`,
+/// * `:date`, `:time`, and `:datetime`, for formatting dates and times, with the options
+///   `style`, `dateStyle`, and `timeStyle`.
+///
+/// ✨ *The date and time functions are enabled with the `messageformat_datetime` Cargo feature.*
+///
+/// Styles and `useGrouping` given as variables can only select data that is also selected by
+/// a literal option elsewhere in the message.
+///
+/// Markup and attributes are accepted, but have no effect on the formatted output.
+///
+/// [MessageFormat 2.0]: https://unicode.org/reports/tr35/tr35-messageFormat.html
+///
+/// # Examples
+///
+/// ```
+/// use icu::experimental::messageformat::{MessageArguments, MessageFormatter};
+/// use icu::locale::locale;
+/// use writeable::assert_try_writeable_eq;
+///
+/// let formatter = MessageFormatter::try_new(
+///     locale!("en").into(),
+///     ".input {$count :integer}
+///      .match $count
+///      0 {{No new messages}}
+///      one {{{$count} new message}}
+///      * {{{$count} new messages}}",
+/// )
+/// .unwrap();
+///
+/// let arguments = MessageArguments::new().with("count", 1);
+/// assert_try_writeable_eq!(formatter.format(&arguments), "1 new message");
+///
+/// let arguments = MessageArguments::new().with("count", 1200);
+/// assert_try_writeable_eq!(formatter.format(&arguments), "1,200 new messages");
+/// ```
+///
+/// Errors during formatting are reported together with a best-effort output:
+///
+/// ```
+/// use icu::experimental::messageformat::{
+///     MessageArguments, MessageFormatErrorKind, MessageFormatter,
+/// };
+/// use icu::locale::locale;
+/// use writeable::TryWriteable;
+///
+/// let formatter =
+///     MessageFormatter::try_new(locale!("en").into(), "Hello, {$name}!")
+///         .unwrap();
+///
+/// let arguments = MessageArguments::new();
+/// let formatted = formatter.format(&arguments);
+/// let (error, output) = formatted.try_write_to_string().unwrap_err();
+///
+/// assert_eq!(output, "Hello, {$name}!");
+/// assert_eq!(error.kind, MessageFormatErrorKind::UnresolvedVariable);
+/// assert_eq!(error.index, 7);
+/// ```
+#[derive(Debug)]
+pub struct MessageFormatter {
+    pub(crate) message: Message,
+    /// Decimal formatters by grouping strategy; the first one uses [`GroupingStrategy::Auto`].
+    pub(crate) decimal: Vec<(GroupingStrategy, DecimalFormatter)>,
+    pub(crate) cardinal: Option<PluralRules>,
+    pub(crate) ordinal: Option<PluralRules>,
+    #[cfg(feature = "messageformat_datetime")]
+    pub(crate) datetime: Vec<(DateTimeStyles, DateTimeFormatter<CompositeDateTimeFieldSet>)>,
+}
+
+/// Loads the data used by the functions of a message.
+trait FunctionLoader {
+    fn load_decimal(
+        &self,
+        prefs: DecimalFormatterPreferences,
+        grouping: GroupingStrategy,
+    ) -> Result<DecimalFormatter, DataError>;
+    fn load_plurals(
+        &self,
+        prefs: PluralRulesPreferences,
+        ordinal: bool,
+    ) -> Result<PluralRules, DataError>;
+    #[cfg(feature = "messageformat_datetime")]
+    fn load_datetime(
+        &self,
+        prefs: DateTimeFormatterPreferences,
+        field_set: CompositeDateTimeFieldSet,
+    ) -> Result<DateTimeFormatter<CompositeDateTimeFieldSet>, DateTimeFormatterLoadError>;
+}
+
+#[cfg(feature = "compiled_data")]
+struct BakedLoader;
+
+#[cfg(feature = "compiled_data")]
+impl FunctionLoader for BakedLoader {
+    fn load_decimal(
+        &self,
+        prefs: DecimalFormatterPreferences,
+        grouping: GroupingStrategy,
+    ) -> Result<DecimalFormatter, DataError> {
+        let mut options = DecimalFormatterOptions::default();
+        options.grouping_strategy = Some(grouping);
+        DecimalFormatter::try_new(prefs, options)
+    }
+
+    fn load_plurals(
+        &self,
+        prefs: PluralRulesPreferences,
+        ordinal: bool,
+    ) -> Result<PluralRules, DataError> {
+        if ordinal {
+            PluralRules::try_new_ordinal(prefs)
+        } else {
+            PluralRules::try_new_cardinal(prefs)
+        }
+    }
+
+    #[cfg(feature = "messageformat_datetime")]
+    fn load_datetime(
+        &self,
+        prefs: DateTimeFormatterPreferences,
+        field_set: CompositeDateTimeFieldSet,
+    ) -> Result<DateTimeFormatter<CompositeDateTimeFieldSet>, DateTimeFormatterLoadError> {
+        DateTimeFormatter::try_new(prefs, field_set)
+    }
+}
+
+/// The data markers of the date and time functions, which are required by
+/// [`MessageFormatter::try_new_unstable`].
+///
+/// This is implemented for all data providers with the required markers. Without the
+/// `messageformat_datetime` Cargo feature, it has no requirements.
+#[cfg(feature = "messageformat_datetime")]
+pub trait DateTimeFunctionDataMarkers:
+    AllAnyCalendarFormattingDataMarkers<CompositeDateTimeFieldSet> + AllAnyCalendarExternalDataMarkers
+{
+}
+
+#[cfg(feature = "messageformat_datetime")]
+impl<P> DateTimeFunctionDataMarkers for P where
+    P: ?Sized
+        + AllAnyCalendarFormattingDataMarkers<CompositeDateTimeFieldSet>
+        + AllAnyCalendarExternalDataMarkers
+{
+}
+
+/// The data markers of the date and time functions, which are required by
+/// [`MessageFormatter::try_new_unstable`].
+///
+/// This is implemented for all data providers with the required markers. Without the
+/// `messageformat_datetime` Cargo feature, it has no requirements.
+#[cfg(not(feature = "messageformat_datetime"))]
+pub trait DateTimeFunctionDataMarkers {}
+
+#[cfg(not(feature = "messageformat_datetime"))]
+impl<P: ?Sized> DateTimeFunctionDataMarkers for P {}
+
+struct UnstableLoader<'a, P: ?Sized>(&'a P);
+
+impl<P> FunctionLoader for UnstableLoader<'_, P>
+where
+    P: ?Sized
+        + DataProvider<DecimalSymbolsV1>
+        + DataProvider<DecimalDigitsV1>
+        + DataProvider<PluralsCardinalV1>
+        + DataProvider<PluralsOrdinalV1>
+        + DateTimeFunctionDataMarkers,
+{
+    fn load_decimal(
+        &self,
+        prefs: DecimalFormatterPreferences,
+        grouping: GroupingStrategy,
+    ) -> Result<DecimalFormatter, DataError> {
+        let mut options = DecimalFormatterOptions::default();
+        options.grouping_strategy = Some(grouping);
+        DecimalFormatter::try_new_unstable(self.0, prefs, options)
+    }
+
+    fn load_plurals(
+        &self,
+        prefs: PluralRulesPreferences,
+        ordinal: bool,
+    ) -> Result<PluralRules, DataError> {
+        if ordinal {
+            PluralRules::try_new_ordinal_unstable(self.0, prefs)
+        } else {
+            PluralRules::try_new_cardinal_unstable(self.0, prefs)
+        }
+    }
+
+    #[cfg(feature = "messageformat_datetime")]
+    fn load_datetime(
+        &self,
+        prefs: DateTimeFormatterPreferences,
+        field_set: CompositeDateTimeFieldSet,
+    ) -> Result<DateTimeFormatter<CompositeDateTimeFieldSet>, DateTimeFormatterLoadError> {
+        DateTimeFormatter::try_new_unstable(self.0, prefs, field_set)
+    }
+}
+
+impl MessageFormatter {
+    icu_provider::gen_buffer_data_constructors!(
+        (prefs: MessageFormatterPreferences, message: &str) -> error: MessageFormatterLoadError,
+        functions: [
+            try_new: skip,
+            try_new_with_buffer_provider,
+            try_new_unstable,
+            Self
+        ]
+    );
+
+    /// Creates a new [`MessageFormatter`] for the given message from compiled locale data.
+    ///
+    /// Returns an error if the message is not valid, or if data for its functions is missing.
+    ///
+    /// ✨ *Enabled with the `compiled_data` Cargo feature.*
+    ///
+    /// [📚 Help choosing a constructor](icu_provider::constructors)
+    #[cfg(feature = "compiled_data")]
+    pub fn try_new(
+        prefs: MessageFormatterPreferences,
+        message: &str,
+    ) -> Result<Self, MessageFormatterLoadError> {
+        Self::try_new_internal(&BakedLoader, prefs, message)
+    }
+
+    #[doc = icu_provider::gen_buffer_unstable_docs!(UNSTABLE, Self::try_new)]
+    pub fn try_new_unstable<P>(
+        provider: &P,
+        prefs: MessageFormatterPreferences,
+        message: &str,
+    ) -> Result<Self, MessageFormatterLoadError>
+    where
+        P: ?Sized
+            + DataProvider<DecimalSymbolsV1>
+            + DataProvider<DecimalDigitsV1>
+            + DataProvider<PluralsCardinalV1>
+            + DataProvider<PluralsOrdinalV1>
+            + DateTimeFunctionDataMarkers,
+    {
+        Self::try_new_internal(&UnstableLoader(provider), prefs, message)
+    }
+
+    fn try_new_internal(
+        loader: &impl FunctionLoader,
+        prefs: MessageFormatterPreferences,
+        message: &str,
+    ) -> Result<Self, MessageFormatterLoadError> {
+        let message = syntax::parse(message)?;
+
+        // Unannotated numbers are formatted with the default grouping strategy
+        let mut decimal = vec![(
+            GroupingStrategy::Auto,
+            loader.load_decimal((&prefs).into(), GroupingStrategy::Auto)?,
+        )];
+        #[cfg(feature = "messageformat_datetime")]
+        let mut datetime = Vec::new();
+        for function in message.expressions().filter_map(|e| e.function.as_ref()) {
+            if matches!(&*function.name, "number" | "integer") {
+                let grouping = function
+                    .literal_option("useGrouping")
+                    .and_then(|value| parse_grouping(value).ok());
+                if let Some(grouping) = grouping {
+                    if !decimal.iter().any(|(g, _)| *g == grouping) {
+                        decimal.push((grouping, loader.load_decimal((&prefs).into(), grouping)?));
+                    }
+                }
+                continue;
+            }
+            #[cfg(feature = "messageformat_datetime")]
+            if let Some(Ok(styles)) =
+                DateTimeStyles::for_function(&function.name, |name| function.literal_option(name))
+            {
+                if !datetime.iter().any(|(s, _)| *s == styles) {
+                    datetime.push((
+                        styles,
+                        loader.load_datetime((&prefs).into(), styles.field_set())?,
+                    ));
+                }
+            }
+        }
+
+        // Only declared variables can be selectors
+        let (mut needs_cardinal, mut needs_ordinal) = (false, false);
+        for declaration in &message.declarations {
+            let Some(function) = &declaration.expression().function else {
+                continue;
+            };
+            if !matches!(&*function.name, "number" | "integer") {
+                continue;
+            }
+            match function.option("select") {
+                Some(Operand::Literal(select)) if select == "exact" => (),
+                Some(Operand::Literal(select)) if select == "ordinal" => needs_ordinal = true,
+                Some(Operand::Literal(_)) | None => needs_cardinal = true,
+                Some(Operand::Variable(_)) => {
+                    needs_cardinal = true;
+                    needs_ordinal = true;
+                }
+            }
+        }
+        let cardinal = needs_cardinal
+            .then(|| loader.load_plurals((&prefs).into(), false))
+            .transpose()?;
+        let ordinal = needs_ordinal
+            .then(|| loader.load_plurals((&prefs).into(), true))
+            .transpose()?;
+
+        Ok(Self {
+            message,
+            decimal,
+            cardinal,
+            ordinal,
+            #[cfg(feature = "messageformat_datetime")]
+            datetime,
+        })
+    }
+
+    /// Formats the message with the given arguments.
+    ///
+    /// See [`MessageFormatter`] for an example.
+    pub fn format<'a>(&'a self, arguments: &'a MessageArguments<'a>) -> FormattedMessage<'a> {
+        Resolver::new(self, arguments).resolve()
+    }
+}
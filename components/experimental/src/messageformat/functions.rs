@@ -0,0 +1,131 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! The default function registry.
+//!
+//! See <https://unicode.org/reports/tr35/tr35-messageFormat.html#contents-of-part-9-messageformat>.
+
+use super::errors::MessageFormatErrorKind;
+use fixed_decimal::{Decimal, Sign, SignDisplay, SignedRoundingMode, UnsignedRoundingMode};
+use icu_decimal::options::GroupingStrategy;
+
+/// The largest value accepted by the digit options.
+const MAX_DIGITS: u8 = 20;
+
+/// The `select` option of `:number` and `:integer`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+pub(crate) enum NumberSelect {
+    #[default]
+    Plural,
+    Ordinal,
+    Exact,
+}
+
+/// The options of `:number` and `:integer`.
+///
+/// Options of an operand that is itself the result of a number function are inherited.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+pub(crate) struct NumberOptions {
+    pub(crate) select: NumberSelect,
+    pub(crate) sign_display: SignDisplay,
+    pub(crate) grouping: GroupingStrategy,
+    pub(crate) minimum_integer_digits: Option<u8>,
+    pub(crate) minimum_fraction_digits: Option<u8>,
+    pub(crate) maximum_fraction_digits: Option<u8>,
+}
+
+impl NumberOptions {
+    /// Sets the option with the given name, ignoring unknown options.
+    pub(crate) fn set(&mut self, name: &str, value: &str) -> Result<(), MessageFormatErrorKind> {
+        match name {
+            "select" => self.select = parse_select(value)?,
+            "signDisplay" => {
+                self.sign_display = match value {
+                    "auto" => SignDisplay::Auto,
+                    "always" => SignDisplay::Always,
+                    "exceptZero" => SignDisplay::ExceptZero,
+                    "negative" => SignDisplay::Negative,
+                    "never" => SignDisplay::Never,
+                    _ => return Err(MessageFormatErrorKind::BadOption),
+                }
+            }
+            "useGrouping" => self.grouping = parse_grouping(value)?,
+            "minimumIntegerDigits" => self.minimum_integer_digits = Some(parse_digits(value)?),
+            "minimumFractionDigits" => self.minimum_fraction_digits = Some(parse_digits(value)?),
+            "maximumFractionDigits" => self.maximum_fraction_digits = Some(parse_digits(value)?),
+            _ => (),
+        }
+        Ok(())
+    }
+
+    /// Applies the digit and sign options to a number.
+    pub(crate) fn apply(&self, value: &mut Decimal) {
+        if let Some(max) = self.maximum_fraction_digits {
+            value.round_with_mode(
+                -i16::from(max),
+                SignedRoundingMode::Unsigned(UnsignedRoundingMode::HalfExpand),
+            );
+        }
+        if let Some(min) = self.minimum_fraction_digits {
+            value.absolute.pad_end(-i16::from(min));
+        }
+        if let Some(min) = self.minimum_integer_digits {
+            value.absolute.pad_start(i16::from(min));
+        }
+        value.apply_sign_display(self.sign_display);
+    }
+}
+
+pub(crate) fn parse_select(value: &str) -> Result<NumberSelect, MessageFormatErrorKind> {
+    match value {
+        "plural" => Ok(NumberSelect::Plural),
+        "ordinal" => Ok(NumberSelect::Ordinal),
+        "exact" => Ok(NumberSelect::Exact),
+        _ => Err(MessageFormatErrorKind::BadOption),
+    }
+}
+
+pub(crate) fn parse_grouping(value: &str) -> Result<GroupingStrategy, MessageFormatErrorKind> {
+    match value {
+        "auto" => Ok(GroupingStrategy::Auto),
+        "always" => Ok(GroupingStrategy::Always),
+        "never" => Ok(GroupingStrategy::Never),
+        "min2" => Ok(GroupingStrategy::Min2),
+        _ => Err(MessageFormatErrorKind::BadOption),
+    }
+}
+
+fn parse_digits(value: &str) -> Result<u8, MessageFormatErrorKind> {
+    value
+        .parse::<u8>()
+        .ok()
+        .filter(|&digits| digits <= MAX_DIGITS)
+        .ok_or(MessageFormatErrorKind::BadOption)
+}
+
+/// Parses the string operand of a number function, which must be a number literal.
+pub(crate) fn parse_number(value: &str) -> Result<Decimal, MessageFormatErrorKind> {
+    // `Decimal` also accepts a leading plus sign, which is not a number literal
+    if value.starts_with('+') {
+        return Err(MessageFormatErrorKind::BadOperand);
+    }
+    Decimal::try_from_str(value).map_err(|_| MessageFormatErrorKind::BadOperand)
+}
+
+/// Returns whether two numbers are numerically equal, ignoring trailing zeros.
+pub(crate) fn numerically_equal(a: &Decimal, b: &Decimal) -> bool {
+    let normalize = |value: &Decimal| {
+        let mut value = value.clone();
+        value.absolute.trim_start();
+        value.absolute.trim_end();
+        if value.absolute.is_zero() {
+            value.sign = Sign::None;
+        }
+        if value.sign == Sign::Positive {
+            value.sign = Sign::None;
+        }
+        value
+    };
+    normalize(a) == normalize(b)
+}
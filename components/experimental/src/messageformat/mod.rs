@@ -0,0 +1,61 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Formatting of messages in the [MessageFormat 2.0] syntax.
+//!
+//! A message combines text with placeholders for values, which are formatted with functions
+//! such as `:number` or `:date`, and can select between variants of the text based on those
+//! values, for example by plural category.
+//!
+//! The `:date`, `:time`, and `:datetime` functions are enabled with the
+//! `messageformat_datetime` Cargo feature.
+//!
+//! [MessageFormat 2.0]: https://unicode.org/reports/tr35/tr35-messageFormat.html
+//!
+//! # Examples
+//!
+//! ```
+//! use icu::experimental::messageformat::{MessageArguments, MessageFormatter};
+//! use icu::locale::locale;
+//! use writeable::assert_try_writeable_eq;
+//!
+//! let formatter = MessageFormatter::try_new(
+//!     locale!("en").into(),
+//!     ".input {$guests :number}
+//!      .match $guests
+//!      0 {{No guests at {$venue}}}
+//!      one {{{$guests} guest at {$venue}}}
+//!      * {{{$guests} guests at {$venue}}}",
+//! )
+//! .unwrap();
+//!
+//! let arguments = MessageArguments::new()
+//!     .with("venue", "the museum")
+//!     .with("guests", 1200);
+//!
+//! assert_try_writeable_eq!(
+//!     formatter.format(&arguments),
+//!     "1,200 guests at the museum"
+//! );
+//! ```
+
+#![warn(missing_docs)]
+
+mod arguments;
+#[cfg(feature = "messageformat_datetime")]
+mod datetime;
+mod errors;
+mod format;
+mod formatter;
+mod functions;
+mod model;
+mod syntax;
+
+pub use arguments::{MessageArguments, MessageValue};
+pub use errors::{
+    MessageFormatError, MessageFormatErrorKind, MessageFormatterLoadError, MessageSyntaxError,
+    MessageSyntaxErrorKind,
+};
+pub use format::{parts, FormattedMessage};
+pub use formatter::{DateTimeFunctionDataMarkers, MessageFormatter, MessageFormatterPreferences};
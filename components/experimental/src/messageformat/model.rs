@@ -0,0 +1,132 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! The MessageFormat 2.0 data model.
+//!
+//! See <https://unicode.org/reports/tr35/tr35-messageFormat.html#interchange-data-model>.
+
+use alloc::boxed::Box;
+use alloc::string::String;
+use alloc::vec::Vec;
+use icu_pattern::{MultiNamedPlaceholder, Pattern};
+
+/// A parsed message.
+///
+/// Patterns are stored as [`MultiNamedPlaceholder`] patterns whose placeholder names are
+/// indices into [`Message::placeholders`].
+#[derive(Debug)]
+pub(crate) struct Message {
+    pub(crate) declarations: Vec<Declaration>,
+    pub(crate) body: Body,
+    pub(crate) placeholders: Vec<Placeholder>,
+}
+
+#[derive(Debug)]
+pub(crate) enum Declaration {
+    /// `.input {$name ...}`
+    Input(Expression),
+    /// `.local $name = {...}`
+    Local(String, Expression),
+}
+
+impl Declaration {
+    pub(crate) fn name(&self) -> &str {
+        match self {
+            Declaration::Input(expression) => match &expression.operand {
+                Some(Operand::Variable(name)) => name,
+                // The syntax only allows variable operands here
+                _ => "",
+            },
+            Declaration::Local(name, _) => name,
+        }
+    }
+
+    pub(crate) fn expression(&self) -> &Expression {
+        match self {
+            Declaration::Input(expression) | Declaration::Local(_, expression) => expression,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub(crate) enum Body {
+    Pattern(MessagePattern),
+    Matcher {
+        selectors: Vec<String>,
+        variants: Vec<Variant>,
+    },
+}
+
+#[derive(Debug)]
+pub(crate) struct Variant {
+    pub(crate) keys: Vec<Key>,
+    pub(crate) pattern: MessagePattern,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub(crate) enum Key {
+    Literal(String),
+    CatchAll,
+}
+
+#[derive(Debug)]
+pub(crate) struct MessagePattern {
+    pub(crate) pattern: Box<Pattern<MultiNamedPlaceholder>>,
+    /// The indices of the placeholders that occur in the pattern
+    pub(crate) placeholders: Vec<usize>,
+}
+
+#[derive(Debug)]
+pub(crate) enum Placeholder {
+    Expression(Expression),
+    /// Markup has no formatted output of its own.
+    Markup,
+}
+
+#[derive(Debug)]
+pub(crate) struct Expression {
+    pub(crate) operand: Option<Operand>,
+    pub(crate) function: Option<Function>,
+    /// The byte index of the expression in the message source
+    pub(crate) index: usize,
+}
+
+#[derive(Debug, Clone)]
+pub(crate) enum Operand {
+    Literal(String),
+    Variable(String),
+}
+
+#[derive(Debug)]
+pub(crate) struct Function {
+    pub(crate) name: String,
+    pub(crate) options: Vec<(String, Operand)>,
+}
+
+impl Function {
+    pub(crate) fn option(&self, name: &str) -> Option<&Operand> {
+        self.options
+            .iter()
+            .find_map(|(key, value)| (key == name).then_some(value))
+    }
+
+    pub(crate) fn literal_option(&self, name: &str) -> Option<&str> {
+        match self.option(name) {
+            Some(Operand::Literal(value)) => Some(value),
+            _ => None,
+        }
+    }
+}
+
+impl Message {
+    /// Returns all expressions in the message, in declarations and placeholders.
+    pub(crate) fn expressions(&self) -> impl Iterator<Item = &Expression> + '_ {
+        self.declarations.iter().map(Declaration::expression).chain(
+            self.placeholders.iter().filter_map(|p| match p {
+                Placeholder::Expression(expression) => Some(expression),
+                Placeholder::Markup => None,
+            }),
+        )
+    }
+}
@@ -0,0 +1,699 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Parser for the MessageFormat 2.0 syntax.
+//!
+//! See <https://unicode.org/reports/tr35/tr35-messageFormat.html#syntax>.
+
+use super::errors::{MessageSyntaxError, MessageSyntaxErrorKind};
+use super::model::*;
+use alloc::borrow::Cow;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use icu_pattern::{MultiNamedPlaceholderKeyCow, Pattern, PatternItemCow};
+
+/// Parses a message into its data model, checking for data model errors.
+pub(crate) fn parse(source: &str) -> Result<Message, MessageSyntaxError> {
+    let mut parser = Parser {
+        source,
+        index: 0,
+        placeholders: Vec::new(),
+    };
+    parser.skip_whitespace();
+    let is_complex = parser.rest().starts_with('.') || parser.rest().starts_with("{{");
+    let (declarations, body) = if is_complex {
+        parser.parse_complex_body()?
+    } else {
+        // Leading whitespace is part of a simple message
+        parser.index = 0;
+        let pattern = parser.parse_pattern(false)?;
+        (Vec::new(), Body::Pattern(pattern))
+    };
+    Ok(Message {
+        declarations,
+        body,
+        placeholders: parser.placeholders,
+    })
+}
+
+fn is_whitespace(c: char) -> bool {
+    matches!(c, ' ' | '\t' | '\r' | '\n' | '\u{3000}')
+}
+
+fn is_bidi(c: char) -> bool {
+    matches!(
+        c,
+        '\u{061C}' | '\u{200E}' | '\u{200F}' | '\u{2066}'..='\u{2069}'
+    )
+}
+
+fn is_name_start(c: char) -> bool {
+    matches!(c,
+        'a'..='z'
+        | 'A'..='Z'
+        | '_'
+        | '\u{C0}'..='\u{D6}'
+        | '\u{D8}'..='\u{F6}'
+        | '\u{F8}'..='\u{2FF}'
+        | '\u{370}'..='\u{37D}'
+        | '\u{37F}'..='\u{61B}'
+        | '\u{61D}'..='\u{1FFF}'
+        | '\u{200C}'..='\u{200D}'
+        | '\u{2070}'..='\u{218F}'
+        | '\u{2C00}'..='\u{2FEF}'
+        | '\u{3001}'..='\u{D7FF}'
+        | '\u{F900}'..='\u{FDCF}'
+        | '\u{FDF0}'..='\u{FFFC}'
+        | '\u{10000}'..='\u{EFFFF}'
+    )
+}
+
+fn is_name_char(c: char) -> bool {
+    is_name_start(c)
+        || matches!(c,
+            '0'..='9'
+            | '-'
+            | '.'
+            | '\u{B7}'
+            | '\u{300}'..='\u{36F}'
+            | '\u{203F}'..='\u{2040}'
+        )
+}
+
+struct Parser<'a> {
+    source: &'a str,
+    index: usize,
+    placeholders: Vec<Placeholder>,
+}
+
+impl<'a> Parser<'a> {
+    fn rest(&self) -> &'a str {
+        self.source.get(self.index..).unwrap_or_default()
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.rest().chars().next()
+    }
+
+    fn bump(&mut self) -> Option<char> {
+        let c = self.peek()?;
+        self.index += c.len_utf8();
+        Some(c)
+    }
+
+    fn error(&self, kind: MessageSyntaxErrorKind) -> MessageSyntaxError {
+        MessageSyntaxError::new(kind, self.index)
+    }
+
+    /// Returns a syntax error at the current position.
+    fn unexpected(&self) -> MessageSyntaxError {
+        self.error(if self.rest().is_empty() {
+            MessageSyntaxErrorKind::UnexpectedEnd
+        } else {
+            MessageSyntaxErrorKind::UnexpectedCharacter
+        })
+    }
+
+    fn expect(&mut self, expected: char) -> Result<(), MessageSyntaxError> {
+        if self.peek() == Some(expected) {
+            self.index += expected.len_utf8();
+            Ok(())
+        } else {
+            Err(self.unexpected())
+        }
+    }
+
+    /// Skips optional whitespace and bidi marks, returning whether any whitespace was skipped.
+    fn skip_whitespace(&mut self) -> bool {
+        let mut has_whitespace = false;
+        while let Some(c) = self.peek().filter(|&c| is_whitespace(c) || is_bidi(c)) {
+            has_whitespace |= is_whitespace(c);
+            self.index += c.len_utf8();
+        }
+        has_whitespace
+    }
+
+    /// Skips required whitespace.
+    fn require_whitespace(&mut self) -> Result<(), MessageSyntaxError> {
+        if self.skip_whitespace() {
+            Ok(())
+        } else {
+            Err(self.unexpected())
+        }
+    }
+
+    fn skip_bidi(&mut self) {
+        while let Some(c) = self.peek().filter(|&c| is_bidi(c)) {
+            self.index += c.len_utf8();
+        }
+    }
+
+    fn parse_name(&mut self) -> Result<String, MessageSyntaxError> {
+        self.skip_bidi();
+        if !self.peek().is_some_and(is_name_start) {
+            return Err(self.unexpected());
+        }
+        let start = self.index;
+        while self.peek().is_some_and(is_name_char) {
+            self.bump();
+        }
+        let name = self.source.get(start..self.index).unwrap_or_default();
+        self.skip_bidi();
+        Ok(name.into())
+    }
+
+    /// Parses a name, optionally preceded by a namespace.
+    fn parse_identifier(&mut self) -> Result<String, MessageSyntaxError> {
+        let mut identifier = self.parse_name()?;
+        if self.peek() == Some(':') {
+            self.bump();
+            identifier.push(':');
+            identifier.push_str(&self.parse_name()?);
+        }
+        Ok(identifier)
+    }
+
+    fn parse_variable(&mut self) -> Result<String, MessageSyntaxError> {
+        self.expect('$')?;
+        self.parse_name()
+    }
+
+    fn parse_escape(&mut self) -> Result<char, MessageSyntaxError> {
+        self.expect('\\')?;
+        match self.peek() {
+            Some(c @ ('\\' | '{' | '|' | '}')) => {
+                self.bump();
+                Ok(c)
+            }
+            _ => Err(self.unexpected()),
+        }
+    }
+
+    fn parse_literal(&mut self) -> Result<String, MessageSyntaxError> {
+        if self.peek() == Some('|') {
+            self.bump();
+            let mut literal = String::new();
+            loop {
+                match self.peek() {
+                    Some('|') => {
+                        self.bump();
+                        return Ok(literal);
+                    }
+                    Some('\\') => literal.push(self.parse_escape()?),
+                    Some(c) if c != '\0' => {
+                        self.bump();
+                        literal.push(c);
+                    }
+                    _ => return Err(self.unexpected()),
+                }
+            }
+        }
+        let start = self.index;
+        while self.peek().is_some_and(is_name_char) {
+            self.bump();
+        }
+        if start == self.index {
+            return Err(self.unexpected());
+        }
+        Ok(self
+            .source
+            .get(start..self.index)
+            .unwrap_or_default()
+            .into())
+    }
+
+    fn parse_literal_or_variable(&mut self) -> Result<Operand, MessageSyntaxError> {
+        if self.peek() == Some('$') {
+            self.parse_variable().map(Operand::Variable)
+        } else {
+            self.parse_literal().map(Operand::Literal)
+        }
+    }
+
+    /// Parses options after a function or markup name, stopping before attributes or the end.
+    fn parse_options(&mut self) -> Result<Vec<(String, Operand)>, MessageSyntaxError> {
+        let mut options: Vec<(String, Operand)> = Vec::new();
+        loop {
+            let start = self.index;
+            let has_whitespace = self.skip_whitespace();
+            if !has_whitespace || !self.peek().is_some_and(|c| is_name_start(c) || is_bidi(c)) {
+                self.index = start;
+                return Ok(options);
+            }
+            let name_index = self.index;
+            let name = self.parse_identifier()?;
+            self.skip_whitespace();
+            self.expect('=')?;
+            self.skip_whitespace();
+            let value = self.parse_literal_or_variable()?;
+            if options.iter().any(|(existing, _)| *existing == name) {
+                return Err(MessageSyntaxError::new(
+                    MessageSyntaxErrorKind::DuplicateOptionName,
+                    name_index,
+                ));
+            }
+            options.push((name, value));
+        }
+    }
+
+    /// Parses attributes, which have no effect on formatting, and the closing brace.
+    fn parse_attributes_and_close(&mut self) -> Result<(), MessageSyntaxError> {
+        loop {
+            let has_whitespace = self.skip_whitespace();
+            match self.peek() {
+                Some('}') => {
+                    self.bump();
+                    return Ok(());
+                }
+                Some('@') if has_whitespace => {
+                    self.bump();
+                    self.parse_identifier()?;
+                    let start = self.index;
+                    self.skip_whitespace();
+                    if self.peek() == Some('=') {
+                        self.bump();
+                        self.skip_whitespace();
+                        self.parse_literal()?;
+                    } else {
+                        self.index = start;
+                    }
+                }
+                _ => return Err(self.unexpected()),
+            }
+        }
+    }
+
+    /// Parses an expression or markup after its opening brace.
+    fn parse_placeholder(&mut self, index: usize) -> Result<Placeholder, MessageSyntaxError> {
+        self.skip_whitespace();
+        match self.peek() {
+            Some('#' | '/') => {
+                self.bump();
+                self.parse_identifier()?;
+                self.parse_options()?;
+                // Attributes, an optional self-closing slash, and the closing brace
+                loop {
+                    let has_whitespace = self.skip_whitespace();
+                    match self.peek() {
+                        Some('@') if has_whitespace => {
+                            self.bump();
+                            self.parse_identifier()?;
+                            let start = self.index;
+                            self.skip_whitespace();
+                            if self.peek() == Some('=') {
+                                self.bump();
+                                self.skip_whitespace();
+                                self.parse_literal()?;
+                            } else {
+                                self.index = start;
+                            }
+                        }
+                        Some('/') => {
+                            self.bump();
+                            self.expect('}')?;
+                            return Ok(Placeholder::Markup);
+                        }
+                        Some('}') => {
+                            self.bump();
+                            return Ok(Placeholder::Markup);
+                        }
+                        _ => return Err(self.unexpected()),
+                    }
+                }
+            }
+            _ => self
+                .parse_expression_body(index)
+                .map(Placeholder::Expression),
+        }
+    }
+
+    /// Parses an expression after its opening brace.
+    fn parse_expression_body(&mut self, index: usize) -> Result<Expression, MessageSyntaxError> {
+        self.skip_whitespace();
+        let operand = if self.peek() == Some(':') {
+            None
+        } else {
+            Some(self.parse_literal_or_variable()?)
+        };
+        let start = self.index;
+        let has_whitespace = self.skip_whitespace();
+        let function = if self.peek() == Some(':') && (operand.is_none() || has_whitespace) {
+            self.bump();
+            let name = self.parse_identifier()?;
+            let options = self.parse_options()?;
+            Some(Function { name, options })
+        } else {
+            self.index = start;
+            None
+        };
+        self.parse_attributes_and_close()?;
+        Ok(Expression {
+            operand,
+            function,
+            index,
+        })
+    }
+
+    fn parse_expression(&mut self) -> Result<Expression, MessageSyntaxError> {
+        let index = self.index;
+        self.expect('{')?;
+        self.parse_expression_body(index)
+    }
+
+    /// Parses a pattern, either until the end of input or until the closing `}}` of
+    /// a quoted pattern.
+    fn parse_pattern(&mut self, is_quoted: bool) -> Result<MessagePattern, MessageSyntaxError> {
+        let start = self.index;
+        let mut items: Vec<PatternItemCow<MultiNamedPlaceholderKeyCow>> = Vec::new();
+        let mut placeholders = Vec::new();
+        let mut text = String::new();
+        loop {
+            match self.peek() {
+                None if !is_quoted => break,
+                Some('}') if is_quoted && self.rest().starts_with("}}") => {
+                    self.index += 2;
+                    break;
+                }
+                Some('\\') => text.push(self.parse_escape()?),
+                Some('{') => {
+                    let index = self.index;
+                    self.bump();
+                    let placeholder = self.parse_placeholder(index)?;
+                    if !text.is_empty() {
+                        items.push(PatternItemCow::Literal(Cow::Owned(core::mem::take(
+                            &mut text,
+                        ))));
+                    }
+                    let id = self.placeholders.len();
+                    self.placeholders.push(placeholder);
+                    placeholders.push(id);
+                    items.push(PatternItemCow::Placeholder(MultiNamedPlaceholderKeyCow(
+                        Cow::Owned(id.to_string()),
+                    )));
+                }
+                // The pattern store reserves the lowest code points for placeholder headers
+                Some(c) if c != '}' && c > '\u{7}' => {
+                    self.bump();
+                    text.push(c);
+                }
+                _ => return Err(self.unexpected()),
+            }
+        }
+        if !text.is_empty() {
+            items.push(PatternItemCow::Literal(Cow::Owned(text)));
+        }
+        let pattern = Pattern::try_from_items(items.into_iter()).map_err(|_| {
+            MessageSyntaxError::new(MessageSyntaxErrorKind::UnexpectedCharacter, start)
+        })?;
+        Ok(MessagePattern {
+            pattern,
+            placeholders,
+        })
+    }
+
+    fn parse_quoted_pattern(&mut self) -> Result<MessagePattern, MessageSyntaxError> {
+        if !self.rest().starts_with("{{") {
+            return Err(self.unexpected());
+        }
+        self.index += 2;
+        self.parse_pattern(true)
+    }
+
+    fn parse_keyword(&mut self) -> Option<&'static str> {
+        for keyword in [".input", ".local", ".match"] {
+            if self.rest().starts_with(keyword) {
+                self.index += keyword.len();
+                return Some(keyword);
+            }
+        }
+        None
+    }
+
+    fn parse_complex_body(&mut self) -> Result<(Vec<Declaration>, Body), MessageSyntaxError> {
+        let mut declarations: Vec<Declaration> = Vec::new();
+        // Variables referenced by earlier declarations, which must not be declared later
+        let mut referenced: Vec<String> = Vec::new();
+        loop {
+            self.skip_whitespace();
+            if self.rest().starts_with("{{") {
+                let pattern = self.parse_quoted_pattern()?;
+                self.finish()?;
+                return Ok((declarations, Body::Pattern(pattern)));
+            }
+            let declaration_index = self.index;
+            let declaration = match self.parse_keyword() {
+                Some(".input") => {
+                    self.skip_whitespace();
+                    let expression_index = self.index;
+                    let expression = self.parse_expression()?;
+                    if !matches!(expression.operand, Some(Operand::Variable(_))) {
+                        return Err(MessageSyntaxError::new(
+                            MessageSyntaxErrorKind::UnexpectedCharacter,
+                            expression_index + 1,
+                        ));
+                    }
+                    Declaration::Input(expression)
+                }
+                Some(".local") => {
+                    self.require_whitespace()?;
+                    let name = self.parse_variable()?;
+                    self.skip_whitespace();
+                    self.expect('=')?;
+                    self.skip_whitespace();
+                    let expression = self.parse_expression()?;
+                    if let Some(Operand::Variable(operand)) = &expression.operand {
+                        referenced.push(operand.clone());
+                    }
+                    Declaration::Local(name, expression)
+                }
+                Some(_) => {
+                    self.index = declaration_index;
+                    let body = self.parse_matcher(&declarations)?;
+                    self.finish()?;
+                    return Ok((declarations, body));
+                }
+                None => return Err(self.unexpected()),
+            };
+            let name = declaration.name();
+            if declarations.iter().any(|d| d.name() == name) || referenced.iter().any(|r| r == name)
+            {
+                return Err(MessageSyntaxError::new(
+                    MessageSyntaxErrorKind::DuplicateDeclaration,
+                    declaration_index,
+                ));
+            }
+            if let Some(function) = &declaration.expression().function {
+                referenced.extend(
+                    function
+                        .options
+                        .iter()
+                        .filter_map(|(_, value)| match value {
+                            Operand::Variable(name) => Some(name.clone()),
+                            Operand::Literal(_) => None,
+                        }),
+                );
+            }
+            declarations.push(declaration);
+        }
+    }
+
+    fn parse_matcher(&mut self, declarations: &[Declaration]) -> Result<Body, MessageSyntaxError> {
+        let match_index = self.index;
+        if self.parse_keyword() != Some(".match") {
+            return Err(self.unexpected());
+        }
+        let mut selectors = Vec::new();
+        loop {
+            let start = self.index;
+            if !self.skip_whitespace() || self.peek() != Some('$') {
+                if selectors.is_empty() {
+                    return Err(self.unexpected());
+                }
+                self.index = start;
+                break;
+            }
+            let selector_index = self.index;
+            let selector = self.parse_variable()?;
+            if !is_annotated(declarations, &selector) {
+                return Err(MessageSyntaxError::new(
+                    MessageSyntaxErrorKind::MissingSelectorAnnotation,
+                    selector_index,
+                ));
+            }
+            selectors.push(selector);
+        }
+
+        let mut variants: Vec<Variant> = Vec::new();
+        loop {
+            self.skip_whitespace();
+            if self.rest().is_empty() {
+                break;
+            }
+            let variant_index = self.index;
+            let mut keys = Vec::new();
+            loop {
+                if self.peek() == Some('*') {
+                    self.bump();
+                    keys.push(Key::CatchAll);
+                } else {
+                    keys.push(Key::Literal(self.parse_literal()?));
+                }
+                let has_whitespace = self.skip_whitespace();
+                if self.rest().starts_with("{{") {
+                    break;
+                }
+                if !has_whitespace {
+                    return Err(self.unexpected());
+                }
+            }
+            let pattern = self.parse_quoted_pattern()?;
+            if keys.len() != selectors.len() {
+                return Err(MessageSyntaxError::new(
+                    MessageSyntaxErrorKind::VariantKeyMismatch,
+                    variant_index,
+                ));
+            }
+            if variants.iter().any(|v| v.keys == keys) {
+                return Err(MessageSyntaxError::new(
+                    MessageSyntaxErrorKind::DuplicateVariant,
+                    variant_index,
+                ));
+            }
+            variants.push(Variant { keys, pattern });
+        }
+        if !variants
+            .iter()
+            .any(|v| v.keys.iter().all(|k| *k == Key::CatchAll))
+        {
+            return Err(MessageSyntaxError::new(
+                MessageSyntaxErrorKind::MissingFallbackVariant,
+                match_index,
+            ));
+        }
+        Ok(Body::Matcher {
+            selectors,
+            variants,
+        })
+    }
+
+    /// Checks that only whitespace remains.
+    fn finish(&mut self) -> Result<(), MessageSyntaxError> {
+        self.skip_whitespace();
+        if self.rest().is_empty() {
+            Ok(())
+        } else {
+            Err(self.unexpected())
+        }
+    }
+}
+
+/// Returns whether the variable is declared with a function, directly or through other
+/// declarations.
+fn is_annotated(declarations: &[Declaration], variable: &str) -> bool {
+    let Some(position) = declarations.iter().rposition(|d| d.name() == variable) else {
+        return false;
+    };
+    let Some((declaration, earlier)) = declarations.get(..=position).and_then(|d| d.split_last())
+    else {
+        return false;
+    };
+    let expression = declaration.expression();
+    if expression.function.is_some() {
+        return true;
+    }
+    match (&expression.operand, declaration) {
+        (Some(Operand::Variable(operand)), Declaration::Local(..)) => {
+            is_annotated(earlier, operand)
+        }
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn error(source: &str) -> MessageSyntaxError {
+        parse(source).unwrap_err()
+    }
+
+    #[test]
+    fn test_valid() {
+        for source in [
+            "",
+            "Hello, world!",
+            "  leading and trailing whitespace  ",
+            "Hello, {$name}!",
+            "{|literal|} {42} {-1.5e3 :number}",
+            "{$x :number minimumFractionDigits=2 signDisplay=$sign @attr}",
+            "{:ns:function opt=|value|}",
+            "Escapes: \\{ \\} \\| \\\\",
+            "{#b}bold{/b} {#img src=|a.png| /}",
+            "{{quoted}}",
+            ".local $x = {1 :number} {{{$x}}}",
+            ".input {$n :number} .local $m = {$n} .match $m 1 {{one}} * {{other}}",
+            ".input {$a :string} .input {$b :string}\n.match $a $b\na b {{ab}}\n* |*| {{x}}\n* * {{*}}",
+            "\u{2068}.input {$x :number}\u{2069} {{{$x}}}",
+        ] {
+            assert!(parse(source).is_ok(), "{source}");
+        }
+    }
+
+    #[test]
+    fn test_syntax_errors() {
+        use MessageSyntaxErrorKind::*;
+        for (source, kind, index) in [
+            ("Hello {$name", UnexpectedEnd, 12),
+            ("Hello }", UnexpectedCharacter, 6),
+            ("{$x:number}", UnexpectedCharacter, 3),
+            ("{$x :number a=1 a=2}", DuplicateOptionName, 16),
+            ("\\n", UnexpectedCharacter, 1),
+            (
+                ".local $x = {1} .local $x = {2} {{}}",
+                DuplicateDeclaration,
+                16,
+            ),
+            (
+                ".local $x = {$y} .input {$y} {{}}",
+                DuplicateDeclaration,
+                17,
+            ),
+            (".local $x = {$x} {{}}", DuplicateDeclaration, 0),
+            (".match $x * {{}}", MissingSelectorAnnotation, 7),
+            (
+                ".local $x = {1} .match $x * {{}}",
+                MissingSelectorAnnotation,
+                23,
+            ),
+            (
+                ".input {$x :string} .match $x a {{}}",
+                MissingFallbackVariant,
+                20,
+            ),
+            (
+                ".input {$x :string} .match $x a b {{}} * {{}}",
+                VariantKeyMismatch,
+                30,
+            ),
+            (
+                ".input {$x :string} .match $x * {{}} * {{}}",
+                DuplicateVariant,
+                37,
+            ),
+            (
+                ".input {$x :string} .match $x * {{}} trailing",
+                UnexpectedEnd,
+                45,
+            ),
+            ("{{a}} b", UnexpectedCharacter, 6),
+            (".unknown {{}}", UnexpectedCharacter, 0),
+        ] {
+            assert_eq!(
+                error(source),
+                MessageSyntaxError::new(kind, index),
+                "{source}"
+            );
+        }
+    }
+}
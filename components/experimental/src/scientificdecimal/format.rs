@@ -0,0 +1,236 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use alloc::borrow::Cow;
+use core::fmt::{self, Write};
+use fixed_decimal::{Decimal, ScientificDecimal, Sign};
+use writeable::{PartsWrite, Writeable};
+
+use crate::scientificdecimal::formatter::ScientificDecimalFormatter;
+use crate::scientificdecimal::options::ExponentStyle;
+use crate::scientificdecimal::parts;
+
+/// An intermediate structure returned by [`ScientificDecimalFormatter`].
+/// Use [`Writeable`][Writeable] to render the formatted decimal to a string or buffer.
+#[derive(Debug)]
+pub struct FormattedScientificDecimal<'l> {
+    pub(crate) formatter: &'l ScientificDecimalFormatter,
+    pub(crate) value: Cow<'l, ScientificDecimal>,
+}
+
+impl FormattedScientificDecimal<'_> {
+    /// Access the resolved [`ScientificDecimal`] after formatting.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu::experimental::scientificdecimal::ScientificDecimalFormatter;
+    /// use icu::locale::locale;
+    /// use writeable::assert_writeable_eq;
+    ///
+    /// let formatter =
+    ///     ScientificDecimalFormatter::try_new(locale!("en").into(), Default::default())
+    ///         .unwrap();
+    ///
+    /// let formatted_scientific_decimal =
+    ///     formatter.format_fixed_decimal(&"2207".parse().unwrap());
+    ///
+    /// assert_writeable_eq!(formatted_scientific_decimal, "2.207E3");
+    /// assert_eq!(
+    ///     formatted_scientific_decimal
+    ///         .get_scientific_decimal()
+    ///         .to_string(),
+    ///     "2.207e3"
+    /// );
+    /// ```
+    pub fn get_scientific_decimal(&self) -> &ScientificDecimal {
+        &self.value
+    }
+}
+
+/// Writes digits and signs as superscript characters.
+struct SuperscriptWrite<'a, W: ?Sized>(&'a mut W);
+
+impl<W: fmt::Write + ?Sized> fmt::Write for SuperscriptWrite<'_, W> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        s.chars().try_for_each(|c| self.write_char(c))
+    }
+
+    fn write_char(&mut self, c: char) -> fmt::Result {
+        self.0.write_char(match c {
+            '0' => '⁰',
+            '1' => '¹',
+            '2' => '²',
+            '3' => '³',
+            '4' => '⁴',
+            '5' => '⁵',
+            '6' => '⁶',
+            '7' => '⁷',
+            '8' => '⁸',
+            '9' => '⁹',
+            '+' => '⁺',
+            '-' | '\u{2212}' => '⁻',
+            _ => c,
+        })
+    }
+}
+
+impl Writeable for FormattedScientificDecimal<'_> {
+    fn write_to_parts<S>(&self, sink: &mut S) -> fmt::Result
+    where
+        S: PartsWrite + ?Sized,
+    {
+        let symbols = self.formatter.symbols.get();
+
+        sink.with_part(parts::SIGNIFICAND, |s| {
+            self.formatter
+                .decimal_formatter
+                .format(self.value.significand())
+                .write_to_parts(s)
+        })?;
+
+        let exponent = Decimal::from(self.value.exponent().clone());
+        let exponent_sign = match exponent.sign() {
+            Sign::Negative => Some(&symbols.minus_sign),
+            Sign::Positive => Some(&symbols.plus_sign),
+            Sign::None => None,
+        };
+        let exponent = exponent.with_sign(Sign::None);
+        let exponent = self.formatter.exponent_formatter.format(&exponent);
+
+        match self.formatter.options.exponent_style {
+            ExponentStyle::Symbol => {
+                sink.with_part(parts::EXPONENT_SYMBOL, |s| {
+                    s.write_str(&symbols.exponential)
+                })?;
+                if let Some(exponent_sign) = exponent_sign {
+                    sink.with_part(parts::EXPONENT_SIGN, |s| s.write_str(exponent_sign))?;
+                }
+                sink.with_part(parts::EXPONENT_INTEGER, |s| exponent.write_to_parts(s))
+            }
+            ExponentStyle::Superscript => {
+                sink.with_part(parts::EXPONENT_SYMBOL, |s| {
+                    s.write_str(&symbols.superscripting_exponent)?;
+                    self.formatter
+                        .exponent_formatter
+                        .format(&Decimal::from(10))
+                        .write_to(s)
+                })?;
+                if let Some(exponent_sign) = exponent_sign {
+                    sink.with_part(parts::EXPONENT_SIGN, |s| {
+                        SuperscriptWrite(s).write_str(exponent_sign)
+                    })?;
+                }
+                sink.with_part(parts::EXPONENT_INTEGER, |s| {
+                    exponent.write_to(&mut SuperscriptWrite(s))
+                })
+            }
+        }
+    }
+}
+
+writeable::impl_display_with_writeable!(FormattedScientificDecimal<'_>);
+
+#[cfg(test)]
+mod tests {
+    use icu_locale_core::locale;
+    use writeable::assert_writeable_eq;
+
+    use crate::scientificdecimal::{
+        ExponentStyle, Notation, ScientificDecimalFormatter, ScientificDecimalFormatterOptions,
+    };
+
+    #[test]
+    pub fn test_scientific() {
+        let formatter =
+            ScientificDecimalFormatter::try_new(locale!("en").into(), Default::default()).unwrap();
+
+        for (value, expected) in [
+            ("1", "1E0"),
+            ("12345", "1.2345E4"),
+            ("12000", "1.2E4"),
+            ("12000.0", "1.20000E4"),
+            ("-0.00042", "-4.2E-4"),
+            ("0.000420", "4.20E-4"),
+            ("123456789012", "1.23456789012E11"),
+            ("0", "0E0"),
+        ] {
+            assert_writeable_eq!(
+                formatter.format_fixed_decimal(&value.parse().unwrap()),
+                expected,
+                "{value}"
+            );
+        }
+    }
+
+    #[test]
+    pub fn test_engineering() {
+        let formatter =
+            ScientificDecimalFormatter::try_new(locale!("en").into(), Notation::Engineering.into())
+                .unwrap();
+
+        for (value, expected) in [
+            ("1", "1E0"),
+            ("12345", "12.345E3"),
+            ("999", "999E0"),
+            ("1000", "1E3"),
+            ("-123456", "-123.456E3"),
+            ("0.00042", "420E-6"),
+            ("0.0042", "4.2E-3"),
+            ("12345678901234", "12.345678901234E12"),
+        ] {
+            assert_writeable_eq!(
+                formatter.format_fixed_decimal(&value.parse().unwrap()),
+                expected,
+                "{value}"
+            );
+        }
+    }
+
+    #[test]
+    pub fn test_superscript() {
+        let formatter = ScientificDecimalFormatter::try_new(
+            locale!("en").into(),
+            ScientificDecimalFormatterOptions {
+                notation: Notation::Engineering,
+                exponent_style: ExponentStyle::Superscript,
+                ..Default::default()
+            },
+        )
+        .unwrap();
+
+        assert_writeable_eq!(
+            formatter.format_fixed_decimal(&"12345".parse().unwrap()),
+            "12.345×10³"
+        );
+        assert_writeable_eq!(
+            formatter.format_fixed_decimal(&"0.00042".parse().unwrap()),
+            "420×10⁻⁶"
+        );
+        let value = "1.5e+10".parse().unwrap();
+        assert_writeable_eq!(formatter.format_scientific_decimal(&value), "1.5×10⁺¹⁰");
+    }
+
+    #[test]
+    pub fn test_locales() {
+        let formatter =
+            ScientificDecimalFormatter::try_new(locale!("fr").into(), Default::default()).unwrap();
+        assert_writeable_eq!(
+            formatter.format_fixed_decimal(&"-0.00042".parse().unwrap()),
+            "-4,2E-4"
+        );
+
+        let formatter =
+            ScientificDecimalFormatter::try_new(locale!("ar-EG").into(), Default::default())
+                .unwrap();
+        assert_writeable_eq!(
+            formatter.format_fixed_decimal(&"12345".parse().unwrap()),
+            "١٫٢٣٤٥أس٤"
+        );
+        assert_writeable_eq!(
+            formatter.format_fixed_decimal(&"0.0012".parse().unwrap()),
+            "١٫٢أس\u{61c}-٣"
+        );
+    }
+}
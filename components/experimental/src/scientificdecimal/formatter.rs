@@ -0,0 +1,232 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use crate::scientificdecimal::{
+    format::FormattedScientificDecimal,
+    options::{Notation, ScientificDecimalFormatterOptions},
+    provider::ScientificDecimalSymbolsV1,
+};
+use alloc::borrow::Cow;
+use fixed_decimal::{Decimal, FixedInteger, ScientificDecimal};
+use icu_decimal::options::{DecimalFormatterOptions, GroupingStrategy};
+use icu_decimal::{DecimalFormatter, DecimalFormatterPreferences};
+use icu_locale_core::preferences::{define_preferences, prefs_convert};
+use icu_provider::prelude::*;
+
+define_preferences!(
+    /// The preferences for scientific decimal formatting.
+    [Copy]
+    ScientificDecimalFormatterPreferences,
+    {
+        /// The user's preferred numbering system.
+        ///
+        /// Corresponds to the `-u-nu` in Unicode Locale Identifier.
+        numbering_system: super::preferences::NumberingSystem
+    }
+);
+
+prefs_convert!(
+    ScientificDecimalFormatterPreferences,
+    DecimalFormatterPreferences,
+    { numbering_system }
+);
+
+/// A formatter that renders locale-sensitive numbers in scientific or engineering notation.
+///
+/// # Examples
+///
+/// ```
+/// use icu::experimental::scientificdecimal::{
+///     ExponentStyle, Notation, ScientificDecimalFormatter,
+/// };
+/// use icu::locale::locale;
+/// use writeable::assert_writeable_eq;
+///
+/// let scientific =
+///     ScientificDecimalFormatter::try_new(locale!("en").into(), Default::default())
+///         .unwrap();
+/// let engineering = ScientificDecimalFormatter::try_new(
+///     locale!("en").into(),
+///     Notation::Engineering.into(),
+/// )
+/// .unwrap();
+/// let superscript = ScientificDecimalFormatter::try_new(
+///     locale!("en").into(),
+///     ExponentStyle::Superscript.into(),
+/// )
+/// .unwrap();
+///
+/// let value = "12345".parse().unwrap();
+/// assert_writeable_eq!(scientific.format_fixed_decimal(&value), "1.2345E4");
+/// assert_writeable_eq!(engineering.format_fixed_decimal(&value), "12.345E3");
+/// assert_writeable_eq!(superscript.format_fixed_decimal(&value), "1.2345×10⁴");
+/// ```
+#[derive(Debug)]
+pub struct ScientificDecimalFormatter {
+    pub(crate) options: ScientificDecimalFormatterOptions,
+    pub(crate) decimal_formatter: DecimalFormatter,
+    /// Formats the digits of the exponent, which are never grouped.
+    pub(crate) exponent_formatter: DecimalFormatter,
+    pub(crate) symbols: DataPayload<ScientificDecimalSymbolsV1>,
+}
+
+impl ScientificDecimalFormatter {
+    /// Constructor that takes a selected locale and a list of preferences,
+    /// then collects all compiled data necessary to format numbers in scientific
+    /// notation for the given locale.
+    ///
+    /// ✨ *Enabled with the `compiled_data` Cargo feature.*
+    ///
+    /// [📚 Help choosing a constructor](icu_provider::constructors)
+    #[cfg(feature = "compiled_data")]
+    pub fn try_new(
+        prefs: ScientificDecimalFormatterPreferences,
+        options: ScientificDecimalFormatterOptions,
+    ) -> Result<Self, DataError> {
+        let locale = ScientificDecimalSymbolsV1::make_locale(prefs.locale_preferences);
+        Ok(Self {
+            decimal_formatter: DecimalFormatter::try_new(
+                (&prefs).into(),
+                options.decimal_formatter_options,
+            )?,
+            exponent_formatter: DecimalFormatter::try_new(
+                (&prefs).into(),
+                DecimalFormatterOptions::from(GroupingStrategy::Never),
+            )?,
+            symbols: crate::provider::Baked
+                .load(DataRequest {
+                    id: DataIdentifierBorrowed::for_locale(&locale),
+                    ..Default::default()
+                })?
+                .payload,
+            options,
+        })
+    }
+
+    icu_provider::gen_buffer_data_constructors!(
+        (prefs: ScientificDecimalFormatterPreferences, options: ScientificDecimalFormatterOptions) -> error: DataError,
+        functions: [
+            try_new: skip,
+            try_new_with_buffer_provider,
+            try_new_unstable,
+            Self,
+        ]
+    );
+
+    #[doc = icu_provider::gen_buffer_unstable_docs!(UNSTABLE, Self::try_new)]
+    pub fn try_new_unstable<D>(
+        provider: &D,
+        prefs: ScientificDecimalFormatterPreferences,
+        options: ScientificDecimalFormatterOptions,
+    ) -> Result<Self, DataError>
+    where
+        D: DataProvider<ScientificDecimalSymbolsV1>
+            + DataProvider<icu_decimal::provider::DecimalSymbolsV1>
+            + DataProvider<icu_decimal::provider::DecimalDigitsV1>
+            + ?Sized,
+    {
+        let locale = ScientificDecimalSymbolsV1::make_locale(prefs.locale_preferences);
+        Ok(Self {
+            decimal_formatter: DecimalFormatter::try_new_unstable(
+                provider,
+                (&prefs).into(),
+                options.decimal_formatter_options,
+            )?,
+            exponent_formatter: DecimalFormatter::try_new_unstable(
+                provider,
+                (&prefs).into(),
+                DecimalFormatterOptions::from(GroupingStrategy::Never),
+            )?,
+            symbols: provider
+                .load(DataRequest {
+                    id: DataIdentifierBorrowed::for_locale(&locale),
+                    ..Default::default()
+                })?
+                .payload,
+            options,
+        })
+    }
+
+    /// Formats a [`Decimal`] by scaling it to the exponent selected by the
+    /// [`Notation`] of this formatter.
+    ///
+    /// Zeros of the integer part of the value are not displayed in the significand,
+    /// but zeros of its fraction part are. Zero is formatted with the exponent 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_decimal::Decimal;
+    /// use icu::experimental::scientificdecimal::ScientificDecimalFormatter;
+    /// use icu::locale::locale;
+    /// use writeable::assert_writeable_eq;
+    ///
+    /// let formatter =
+    ///     ScientificDecimalFormatter::try_new(locale!("fr").into(), Default::default())
+    ///         .unwrap();
+    ///
+    /// assert_writeable_eq!(
+    ///     formatter.format_fixed_decimal(&Decimal::from(-1200)),
+    ///     "-1,2E3"
+    /// );
+    /// assert_writeable_eq!(
+    ///     formatter.format_fixed_decimal(&"0.00120".parse().unwrap()),
+    ///     "1,20E-3"
+    /// );
+    /// assert_writeable_eq!(formatter.format_fixed_decimal(&Decimal::from(0)), "0E0");
+    /// ```
+    pub fn format_fixed_decimal(&self, value: &Decimal) -> FormattedScientificDecimal<'_> {
+        let exponent = if value.is_zero() {
+            0
+        } else {
+            let magnitude = value.nonzero_magnitude_start();
+            match self.options.notation {
+                Notation::Scientific => magnitude,
+                Notation::Engineering => magnitude.saturating_sub(magnitude.rem_euclid(3)),
+            }
+        };
+
+        let mut significand = value.clone();
+        significand.multiply_pow10(exponent.saturating_neg());
+        significand.trim_start();
+        // Trailing zeros of the integer part are absorbed by the exponent.
+        if *value.magnitude_range().start() >= 0 {
+            significand.trim_end();
+        }
+
+        FormattedScientificDecimal {
+            formatter: self,
+            value: Cow::Owned(ScientificDecimal::from(
+                significand,
+                FixedInteger::from(exponent),
+            )),
+        }
+    }
+
+    /// Formats a [`ScientificDecimal`] with its significand and exponent as given.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu::experimental::scientificdecimal::ScientificDecimalFormatter;
+    /// use icu::locale::locale;
+    /// use writeable::assert_writeable_eq;
+    ///
+    /// let formatter =
+    ///     ScientificDecimalFormatter::try_new(locale!("en").into(), Default::default())
+    ///         .unwrap();
+    ///
+    /// let value = "12.5e+06".parse().unwrap();
+    /// assert_writeable_eq!(formatter.format_scientific_decimal(&value), "12.5E+06");
+    /// ```
+    pub fn format_scientific_decimal<'l>(
+        &'l self,
+        value: &'l ScientificDecimal,
+    ) -> FormattedScientificDecimal<'l> {
+        FormattedScientificDecimal {
+            formatter: self,
+            value: Cow::Borrowed(value),
+        }
+    }
+}
@@ -0,0 +1,42 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Scientific decimal
+
+// https://github.com/unicode-org/icu4x/blob/main/documents/process/boilerplate.md#library-annotations
+#![cfg_attr(
+    not(test),
+    deny(
+        clippy::indexing_slicing,
+        clippy::unwrap_used,
+        clippy::expect_used,
+        clippy::panic,
+        clippy::exhaustive_structs,
+        clippy::exhaustive_enums,
+        clippy::trivially_copy_pass_by_ref,
+        missing_debug_implementations,
+    )
+)]
+#![warn(missing_docs)]
+
+mod format;
+mod formatter;
+mod options;
+pub mod parts;
+pub mod provider;
+
+pub use format::FormattedScientificDecimal;
+pub use formatter::ScientificDecimalFormatter;
+pub use formatter::ScientificDecimalFormatterPreferences;
+pub use options::ExponentStyle;
+pub use options::Notation;
+pub use options::ScientificDecimalFormatterOptions;
+
+/// Locale preferences used by this crate
+pub mod preferences {
+    #[doc(inline)]
+    /// **This is a reexport of a type in [`icu::locale`](icu_locale_core::preferences::extensions::unicode::keywords)**.
+    #[doc = "\n"] // prevent autoformatting
+    pub use icu_locale_core::preferences::extensions::unicode::keywords::NumberingSystem;
+}
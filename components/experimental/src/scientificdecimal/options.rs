@@ -0,0 +1,97 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Options for [`ScientificDecimalFormatter`](super::ScientificDecimalFormatter).
+
+use icu_decimal::options::{DecimalFormatterOptions, GroupingStrategy};
+
+/// A collection of configuration options that determine the formatting behavior of
+/// [`ScientificDecimalFormatter`](super::ScientificDecimalFormatter).
+#[derive(Debug, Eq, PartialEq, Clone, Default)]
+#[non_exhaustive]
+pub struct ScientificDecimalFormatterOptions {
+    /// Options to configure the inner [`icu_decimal::DecimalFormatter`] used for the significand.
+    pub decimal_formatter_options: DecimalFormatterOptions,
+    /// Which exponents a [`Decimal`](fixed_decimal::Decimal) is scaled to.
+    pub notation: Notation,
+    /// How the exponent is displayed.
+    pub exponent_style: ExponentStyle,
+}
+
+impl From<DecimalFormatterOptions> for ScientificDecimalFormatterOptions {
+    fn from(decimal_formatter_options: DecimalFormatterOptions) -> Self {
+        Self {
+            decimal_formatter_options,
+            ..Default::default()
+        }
+    }
+}
+
+impl From<GroupingStrategy> for ScientificDecimalFormatterOptions {
+    fn from(grouping_strategy: GroupingStrategy) -> Self {
+        Self {
+            decimal_formatter_options: grouping_strategy.into(),
+            ..Default::default()
+        }
+    }
+}
+
+impl From<Notation> for ScientificDecimalFormatterOptions {
+    fn from(notation: Notation) -> Self {
+        Self {
+            notation,
+            ..Default::default()
+        }
+    }
+}
+
+impl From<ExponentStyle> for ScientificDecimalFormatterOptions {
+    fn from(exponent_style: ExponentStyle) -> Self {
+        Self {
+            exponent_style,
+            ..Default::default()
+        }
+    }
+}
+
+/// Which exponents a [`Decimal`](fixed_decimal::Decimal) is scaled to.
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+#[non_exhaustive]
+pub enum Notation {
+    /// Scale to a significand of at least 1 and less than 10.
+    ///
+    /// For example, 12345 is formatted as "1.2345E4" in en.
+    Scientific,
+    /// Scale to an exponent that is a multiple of 3, and a significand of at least 1 and less
+    /// than 1000.
+    ///
+    /// For example, 12345 is formatted as "12.345E3" in en.
+    Engineering,
+}
+
+impl Default for Notation {
+    fn default() -> Self {
+        Self::Scientific
+    }
+}
+
+/// How the exponent is displayed.
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+#[non_exhaustive]
+pub enum ExponentStyle {
+    /// Use the exponential symbol of the locale.
+    ///
+    /// For example, "1.2345E4" in en.
+    Symbol,
+    /// Use the superscripting symbol of the locale and a superscript exponent.
+    ///
+    /// For example, "1.2345×10⁴" in en.
+    Superscript,
+}
+
+impl Default for ExponentStyle {
+    fn default() -> Self {
+        Self::Symbol
+    }
+}
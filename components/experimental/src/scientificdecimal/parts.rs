@@ -0,0 +1,68 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Parts of a formatted scientific decimal.
+//!
+//! The significand additionally contains the parts of
+//! [`icu_decimal::parts`], and the exponent contains its digits as an
+//! [`icu_decimal::parts::INTEGER`].
+//!
+//! # Examples
+//!
+//! ```
+//! use icu::decimal::parts as decimal_parts;
+//! use icu::experimental::scientificdecimal::parts;
+//! use icu::experimental::scientificdecimal::ScientificDecimalFormatter;
+//! use icu::locale::locale;
+//! use writeable::assert_writeable_parts_eq;
+//!
+//! let formatter = ScientificDecimalFormatter::try_new(
+//!     locale!("en").into(),
+//!     Default::default(),
+//! )
+//! .unwrap();
+//!
+//! let decimal = "0.0123".parse().unwrap();
+//!
+//! assert_writeable_parts_eq!(
+//!     formatter.format_fixed_decimal(&decimal),
+//!     "1.23E-2",
+//!     [
+//!         (0, 4, parts::SIGNIFICAND),
+//!         (0, 1, decimal_parts::INTEGER),
+//!         (1, 2, decimal_parts::DECIMAL),
+//!         (2, 4, decimal_parts::FRACTION),
+//!         (4, 5, parts::EXPONENT_SYMBOL),
+//!         (5, 6, parts::EXPONENT_SIGN),
+//!         (6, 7, decimal_parts::INTEGER),
+//!         (6, 7, parts::EXPONENT_INTEGER),
+//!     ]
+//! );
+//! ```
+
+use writeable::Part;
+
+/// A [`Part`] used by [`FormattedScientificDecimal`](super::FormattedScientificDecimal).
+pub const SIGNIFICAND: Part = Part {
+    category: "scientificDecimal",
+    value: "significand",
+};
+
+/// A [`Part`] used by [`FormattedScientificDecimal`](super::FormattedScientificDecimal).
+pub const EXPONENT_SYMBOL: Part = Part {
+    category: "scientificDecimal",
+    value: "exponentSymbol",
+};
+
+/// A [`Part`] used by [`FormattedScientificDecimal`](super::FormattedScientificDecimal).
+pub const EXPONENT_SIGN: Part = Part {
+    category: "scientificDecimal",
+    value: "exponentSign",
+};
+
+/// A [`Part`] used by [`FormattedScientificDecimal`](super::FormattedScientificDecimal).
+pub const EXPONENT_INTEGER: Part = Part {
+    category: "scientificDecimal",
+    value: "exponentInteger",
+};
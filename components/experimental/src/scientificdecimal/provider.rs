@@ -0,0 +1,54 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+// Provider structs must be stable.
+#![allow(clippy::exhaustive_structs, clippy::exhaustive_enums)]
+
+//! Data provider struct definitions for this ICU4X component.
+//!
+//! Read more about data providers: [`icu_provider`]
+
+use alloc::borrow::Cow;
+use icu_provider::prelude::*;
+
+#[cfg(feature = "compiled_data")]
+/// Baked data
+///
+/// <div class="stab unstable">
+/// 🚧 This code is considered unstable; it may change at any time, in breaking or non-breaking ways,
+/// including in SemVer minor releases. In particular, the `DataProvider` implementations are only
+/// guaranteed to match with this version's `*_unstable` providers. Use with caution.
+/// </div>
+pub use crate::provider::Baked;
+
+icu_provider::data_marker!(
+    /// `ScientificDecimalSymbolsV1`
+    ScientificDecimalSymbolsV1,
+    ScientificDecimalSymbols<'static>,
+);
+
+/// The symbols used in numbers in scientific notation.
+///
+/// The significand and the digits of the exponent are formatted with the
+/// symbols of [`icu_decimal`].
+#[derive(Debug, Clone, PartialEq, yoke::Yokeable, zerofrom::ZeroFrom)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[cfg_attr(feature = "datagen", derive(serde::Serialize, databake::Bake))]
+#[cfg_attr(feature = "datagen", databake(path = icu_experimental::scientificdecimal::provider))]
+pub struct ScientificDecimalSymbols<'data> {
+    /// The symbol between the significand and the exponent, such as `E` in `1.2E3`.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub exponential: Cow<'data, str>,
+    /// The symbol between the significand and a superscript power of ten, such as `×` in `1.2×10³`.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub superscripting_exponent: Cow<'data, str>,
+    /// The sign of negative exponents.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub minus_sign: Cow<'data, str>,
+    /// The sign of positive exponents, if they are displayed with a sign.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub plus_sign: Cow<'data, str>,
+}
+
+icu_provider::data_struct!(ScientificDecimalSymbols<'_>, #[cfg(feature = "datagen")]);
@@ -0,0 +1,239 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use super::provider::ConfusablesV1;
+use alloc::string::String;
+use icu_normalizer::provider::{NormalizerNfdDataV1, NormalizerNfdTablesV1};
+use icu_normalizer::DecomposingNormalizer;
+use icu_properties::props::{DefaultIgnorableCodePoint, Script};
+use icu_properties::provider::{
+    PropertyBinaryDefaultIgnorableCodePointV1, PropertyScriptWithExtensionsV1,
+};
+use icu_properties::script::ScriptWithExtensions;
+use icu_properties::CodePointSetData;
+use icu_provider::prelude::*;
+
+/// ICU's `USCRIPT_JAPANESE`, which is not a value of the Script property.
+const JAPANESE: Script = Script::from_icu4c_value(105);
+/// ICU's `USCRIPT_KOREAN`, which is not a value of the Script property.
+const KOREAN: Script = Script::from_icu4c_value(119);
+/// ICU's `USCRIPT_HAN_WITH_BOPOMOFO`, which is not a value of the Script property.
+const HAN_WITH_BOPOMOFO: Script = Script::from_icu4c_value(172);
+
+/// The restriction level of a string, as defined in [UTS #39].
+///
+/// Levels are ordered from most to least restrictive, so a string can be checked against a
+/// maximum level with `<=`.
+///
+/// The levels do not take the identifier profile into account; a string that contains
+/// characters that are not allowed in identifiers is reported at the level of its scripts.
+///
+/// [UTS #39]: https://www.unicode.org/reports/tr39/#Restriction_Level_Detection
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[non_exhaustive]
+pub enum RestrictionLevel {
+    /// All characters in the string are in the ASCII range.
+    Ascii,
+    /// The string is single-script: its resolved script set is not empty.
+    SingleScript,
+    /// The string is covered by Latin together with Han, Hiragana, and Katakana; with Han and
+    /// Bopomofo; or with Han and Hangul.
+    HighlyRestrictive,
+    /// The string is covered by Latin and one other script, which is not Cyrillic or Greek.
+    ModeratelyRestrictive,
+    /// The string uses any other combination of scripts.
+    MinimallyRestrictive,
+}
+
+/// A set of scripts, including ICU's script codes for writing systems that combine several
+/// scripts.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct ScriptSet([u64; 4]);
+
+impl ScriptSet {
+    const EMPTY: Self = Self([0; 4]);
+    const ALL: Self = Self([u64::MAX; 4]);
+
+    fn insert(&mut self, script: Script) {
+        let value = usize::from(script.to_icu4c_value());
+        if let Some(word) = self.0.get_mut(value / 64) {
+            *word |= 1 << (value % 64);
+        }
+    }
+
+    fn contains(&self, script: Script) -> bool {
+        let value = usize::from(script.to_icu4c_value());
+        self.0
+            .get(value / 64)
+            .is_some_and(|word| word & (1 << (value % 64)) != 0)
+    }
+
+    fn intersect(&mut self, other: &Self) {
+        for (word, other) in self.0.iter_mut().zip(other.0) {
+            *word &= other;
+        }
+    }
+
+    fn is_empty(&self) -> bool {
+        *self == Self::EMPTY
+    }
+}
+
+/// A checker for strings that could be used to spoof others, following [UTS #39].
+///
+/// It computes confusable skeletons from the `confusables.txt` data file, and determines the
+/// scripts and restriction level of a string from the Script_Extensions property.
+///
+/// See the [module-level documentation](super) for an example.
+///
+/// [UTS #39]: https://www.unicode.org/reports/tr39/
+#[derive(Debug)]
+pub struct SpoofChecker {
+    confusables: DataPayload<ConfusablesV1>,
+    nfd: DecomposingNormalizer,
+    default_ignorable: CodePointSetData,
+    script_extensions: ScriptWithExtensions,
+}
+
+impl SpoofChecker {
+    icu_provider::gen_buffer_data_constructors!(
+        () -> error: DataError,
+        functions: [
+            try_new: skip,
+            try_new_with_buffer_provider,
+            try_new_unstable,
+            Self
+        ]
+    );
+
+    /// Creates a new [`SpoofChecker`] using data from the given provider.
+    ///
+    /// The confusables data is not included in compiled data; it can be generated from the
+    /// Unicode security data files with `icu4x-datagen`.
+    ///
+    /// [📚 Help choosing a constructor](icu_provider::constructors)
+    pub fn try_new_unstable<P>(provider: &P) -> Result<Self, DataError>
+    where
+        P: ?Sized
+            + DataProvider<ConfusablesV1>
+            + DataProvider<NormalizerNfdDataV1>
+            + DataProvider<NormalizerNfdTablesV1>
+            + DataProvider<PropertyBinaryDefaultIgnorableCodePointV1>
+            + DataProvider<PropertyScriptWithExtensionsV1>,
+    {
+        Ok(Self {
+            confusables: provider.load(Default::default())?.payload,
+            nfd: DecomposingNormalizer::try_new_nfd_unstable(provider)?,
+            default_ignorable: CodePointSetData::try_new_unstable::<DefaultIgnorableCodePoint>(
+                provider,
+            )?,
+            script_extensions: ScriptWithExtensions::try_new_unstable(provider)?,
+        })
+    }
+
+    /// Returns the skeleton of `s`.
+    ///
+    /// Two strings are confusable if they have the same skeleton. The skeleton is only meant
+    /// for comparisons and should not be displayed.
+    pub fn skeleton(&self, s: &str) -> String {
+        let nfd = self.nfd.as_borrowed();
+        let default_ignorable = self.default_ignorable.as_borrowed();
+        let prototypes = &self.confusables.get().prototypes;
+        let mut mapped = String::with_capacity(s.len());
+        for c in nfd.normalize_iter(s.chars()) {
+            if default_ignorable.contains(c) {
+                continue;
+            }
+            match prototypes.get(&c) {
+                Some(prototype) => mapped.push_str(prototype),
+                None => mapped.push(c),
+            }
+        }
+        nfd.normalize_iter(mapped.chars()).collect()
+    }
+
+    /// Returns whether `a` and `b` are visually confusable, that is, whether they have the same
+    /// skeleton.
+    pub fn are_confusable(&self, a: &str, b: &str) -> bool {
+        self.skeleton(a) == self.skeleton(b)
+    }
+
+    /// Returns whether `s` is mixed-script, that is, whether no single script covers all of its
+    /// characters.
+    ///
+    /// Characters that are used with several scripts, such as digits and punctuation, and
+    /// writing systems that combine scripts, such as Japanese, do not make a string mixed-script.
+    pub fn is_mixed_script(&self, s: &str) -> bool {
+        self.resolved_script_set(s, None).is_empty()
+    }
+
+    /// Returns the restriction level of `s`.
+    pub fn restriction_level(&self, s: &str) -> RestrictionLevel {
+        if s.is_ascii() {
+            return RestrictionLevel::Ascii;
+        }
+        if !self.resolved_script_set(s, None).is_empty() {
+            return RestrictionLevel::SingleScript;
+        }
+        let without_latin = self.resolved_script_set(s, Some(Script::Latin));
+        if without_latin.contains(HAN_WITH_BOPOMOFO)
+            || without_latin.contains(JAPANESE)
+            || without_latin.contains(KOREAN)
+        {
+            RestrictionLevel::HighlyRestrictive
+        } else if !without_latin.is_empty()
+            && !without_latin.contains(Script::Cyrillic)
+            && !without_latin.contains(Script::Greek)
+        {
+            RestrictionLevel::ModeratelyRestrictive
+        } else {
+            RestrictionLevel::MinimallyRestrictive
+        }
+    }
+
+    /// Returns the intersection of the augmented script sets of the characters in `s`, ignoring
+    /// the characters that can be used with `without`.
+    fn resolved_script_set(&self, s: &str, without: Option<Script>) -> ScriptSet {
+        let mut resolved = ScriptSet::ALL;
+        for c in s.chars() {
+            let scripts = self.augmented_script_set(c);
+            if without.is_some_and(|script| scripts.contains(script)) {
+                continue;
+            }
+            resolved.intersect(&scripts);
+        }
+        resolved
+    }
+
+    /// Returns the Script_Extensions of `c`, with the writing systems that use them added.
+    fn augmented_script_set(&self, c: char) -> ScriptSet {
+        let mut scripts = ScriptSet::EMPTY;
+        for script in self
+            .script_extensions
+            .as_borrowed()
+            .get_script_extensions_val(c)
+            .iter()
+        {
+            if script == Script::Common || script == Script::Inherited {
+                return ScriptSet::ALL;
+            }
+            scripts.insert(script);
+        }
+        if scripts.contains(Script::Han) {
+            scripts.insert(HAN_WITH_BOPOMOFO);
+            scripts.insert(JAPANESE);
+            scripts.insert(KOREAN);
+        }
+        if scripts.contains(Script::Hiragana) || scripts.contains(Script::Katakana) {
+            scripts.insert(JAPANESE);
+        }
+        if scripts.contains(Script::Hangul) {
+            scripts.insert(KOREAN);
+        }
+        if scripts.contains(Script::Bopomofo) {
+            scripts.insert(HAN_WITH_BOPOMOFO);
+        }
+        scripts
+    }
+}
@@ -0,0 +1,45 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Detection of confusable and mixed-script strings, as defined in [UTS #39].
+//!
+//! [`SpoofChecker`] computes skeletons of strings, so that strings that look alike, such as
+//! "paypal" and "раураl" (with Cyrillic letters), can be detected, and determines how many
+//! scripts a string mixes.
+//!
+//! The confusables data is generated from `confusables.txt` in the [Unicode security data],
+//! and is not included in compiled data.
+//!
+//! [UTS #39]: https://www.unicode.org/reports/tr39/
+//! [Unicode security data]: https://www.unicode.org/Public/security/
+//!
+//! # Examples
+//!
+//! ```no_run
+//! use icu::experimental::spoof::{RestrictionLevel, SpoofChecker};
+//! # let provider = icu_provider_adapters::empty::EmptyDataProvider::new();
+//!
+//! // Confusables data is not included in compiled data, so this example is not run as a
+//! // test. `provider` needs data generated by `icu4x-datagen` with the `ConfusablesV1`
+//! // marker, and the normalizer and properties markers used by the checker.
+//! let checker = SpoofChecker::try_new_unstable(&provider).unwrap();
+//!
+//! assert!(checker.are_confusable("paypal", "раураl"));
+//! assert!(checker.are_confusable("Illinois", "llIinois"));
+//! assert!(checker.are_confusable("paypal", "paypa1"));
+//! assert!(!checker.are_confusable("paypal", "paypai"));
+//!
+//! assert!(checker.is_mixed_script("раураl"));
+//! assert_eq!(checker.restriction_level("paypal"), RestrictionLevel::Ascii);
+//! assert_eq!(checker.restriction_level("раура"), RestrictionLevel::SingleScript);
+//! assert_eq!(checker.restriction_level("Tokyo東京"), RestrictionLevel::HighlyRestrictive);
+//! assert_eq!(checker.restriction_level("раураl"), RestrictionLevel::MinimallyRestrictive);
+//! ```
+
+#![warn(missing_docs)]
+
+mod checker;
+pub mod provider;
+
+pub use checker::{RestrictionLevel, SpoofChecker};
@@ -0,0 +1,49 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+// Provider structs must be stable
+#![allow(clippy::exhaustive_structs, clippy::exhaustive_enums)]
+
+//! 🚧 \[Unstable\] Data provider struct definitions for this ICU4X component.
+//!
+//! <div class="stab unstable">
+//! 🚧 This code is considered unstable; it may change at any time, in breaking or non-breaking ways,
+//! including in SemVer minor releases. While the serde representation of data structs is guaranteed
+//! to be stable, their Rust representation might not be. Use with caution.
+//! </div>
+//!
+//! Read more about data providers: [`icu_provider`]
+
+use icu_provider::prelude::*;
+use zerovec::ZeroMap;
+
+icu_provider::data_marker!(
+    /// `ConfusablesV1`
+    ConfusablesV1,
+    Confusables<'static>,
+    is_singleton = true
+);
+
+/// The confusable mappings of [UTS #39], from the `confusables.txt` data file.
+///
+/// [UTS #39]: https://www.unicode.org/reports/tr39/#Confusable_Detection
+///
+/// <div class="stab unstable">
+/// 🚧 This code is considered unstable; it may change at any time, in breaking or non-breaking ways,
+/// including in SemVer minor releases. While the serde representation of data structs is guaranteed
+/// to be stable, their Rust representation might not be. Use with caution.
+/// </div>
+#[derive(Debug, Clone, PartialEq, yoke::Yokeable, zerofrom::ZeroFrom)]
+#[cfg_attr(feature = "datagen", derive(serde::Serialize, databake::Bake))]
+#[cfg_attr(feature = "datagen", databake(path = icu_experimental::spoof::provider))]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[yoke(prove_covariance_manually)]
+pub struct Confusables<'data> {
+    /// Maps each confusable code point to its prototype, the string that it is visually
+    /// confusable with. Code points that are not in the map are their own prototypes.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub prototypes: ZeroMap<'data, char, str>,
+}
+
+icu_provider::data_struct!(Confusables<'_>, #[cfg(feature = "datagen")]);
@@ -0,0 +1,121 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use icu_experimental::alphabeticindex::{AlphabeticIndex, BucketKind};
+use icu_locale_core::locale;
+
+fn labels(index: &AlphabeticIndex) -> Vec<&str> {
+    index.buckets().map(|bucket| bucket.label).collect()
+}
+
+#[test]
+fn test_english() {
+    let index = AlphabeticIndex::try_new(locale!("en").into()).unwrap();
+    let labels = labels(&index);
+    assert_eq!(labels.len(), 28);
+    assert_eq!(labels[..4], ["…", "A", "B", "C"]);
+    assert_eq!(labels[26..], ["Z", "…"]);
+
+    for (name, label, kind) in [
+        ("apple", "A", BucketKind::Normal),
+        ("Äpfel", "A", BucketKind::Normal),
+        ("banana", "B", BucketKind::Normal),
+        ("zz", "Z", BucketKind::Normal),
+        ("123", "…", BucketKind::Underflow),
+        ("!", "…", BucketKind::Underflow),
+        ("", "…", BucketKind::Underflow),
+        ("Ωmega", "…", BucketKind::Overflow),
+        ("東京", "…", BucketKind::Overflow),
+    ] {
+        let bucket = index.bucket(name);
+        assert_eq!((bucket.label, bucket.kind), (label, kind), "{name}");
+    }
+}
+
+#[test]
+fn test_bucket_index() {
+    let index = AlphabeticIndex::try_new(locale!("en").into()).unwrap();
+    assert_eq!(index.bucket_index("1"), 0);
+    assert_eq!(index.bucket_index("Anna"), 1);
+    assert_eq!(index.bucket_index("Zoe"), 26);
+    assert_eq!(index.bucket_index("Ωmega"), 27);
+}
+
+#[test]
+fn test_custom_labels() {
+    let mut index = AlphabeticIndex::try_new(locale!("en").into()).unwrap();
+    index.set_underflow_label("#");
+    index.set_overflow_label("Other");
+    assert_eq!(index.bucket("42").label, "#");
+    assert_eq!(index.bucket("Ωmega").label, "Other");
+}
+
+#[test]
+fn test_tailored_labels() {
+    let index = AlphabeticIndex::try_new(locale!("sv").into()).unwrap();
+    assert_eq!(labels(&index)[26..], ["Z", "Å", "Ä", "Ö", "…"]);
+    assert_eq!(index.bucket("Östen").label, "Ö");
+    assert_eq!(index.bucket("Åsa").label, "Å");
+
+    // "Ё" is equal to "Е" at the primary level, so they share a bucket.
+    let index = AlphabeticIndex::try_new(locale!("ru").into()).unwrap();
+    assert!(labels(&index).contains(&"Е"));
+    assert!(!labels(&index).contains(&"Ё"));
+    assert_eq!(index.bucket("Ёлка").label, "Е");
+    assert_eq!(index.bucket("Zoe").kind, BucketKind::Overflow);
+}
+
+#[test]
+fn test_japanese() {
+    let index = AlphabeticIndex::try_new(locale!("ja").into()).unwrap();
+    assert_eq!(
+        labels(&index),
+        ["…", "あ", "か", "さ", "た", "な", "は", "ま", "や", "ら", "わ", "…"]
+    );
+    for (name, label) in [
+        ("いとう", "あ"),
+        ("イトウ", "あ"),
+        ("がっこう", "か"),
+        ("ぱん", "は"),
+        ("ワタナベ", "わ"),
+        ("ん", "わ"),
+    ] {
+        assert_eq!(index.bucket(name).label, label, "{name}");
+    }
+}
+
+#[test]
+fn test_multiple_locales() {
+    let mut index = AlphabeticIndex::try_new(locale!("ja").into()).unwrap();
+    index.try_add_labels(&locale!("en").into()).unwrap();
+    assert_eq!(index.buckets().count(), 1 + 26 + 1 + 10 + 1);
+    assert_eq!(labels(&index)[26..29], ["Z", "…", "あ"]);
+    assert_eq!(index.bucket("Smith").label, "S");
+    assert_eq!(index.bucket("さとう").label, "さ");
+
+    // Japanese collation orders other scripts after Latin, kana, and Han.
+    assert_eq!(index.bucket("Ωmega").kind, BucketKind::Overflow);
+
+    // Adding the same labels again doesn't change anything.
+    index.try_add_labels(&locale!("en").into()).unwrap();
+    assert_eq!(index.buckets().count(), 39);
+
+    // Root collation orders Greek between Latin and kana, so Greek names go into the inflow
+    // bucket between the scripts.
+    let mut index = AlphabeticIndex::try_new(locale!("en").into()).unwrap();
+    index.try_add_labels(&locale!("ja").into()).unwrap();
+    let bucket = index.bucket("Ωmega");
+    assert_eq!((bucket.label, bucket.kind), ("…", BucketKind::Inflow));
+    assert_eq!(index.bucket_index("Ωmega"), 27);
+    assert_eq!(index.bucket_index("あい"), 28);
+    assert_eq!(index.bucket("東京").kind, BucketKind::Overflow);
+}
+
+#[test]
+fn test_korean() {
+    let index = AlphabeticIndex::try_new(locale!("ko").into()).unwrap();
+    assert_eq!(index.buckets().count(), 16);
+    assert_eq!(index.bucket("가나다").label, "ㄱ");
+    assert_eq!(index.bucket("한국").label, "ㅎ");
+}
@@ -0,0 +1,224 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Conformance tests against `BidiTest.txt` and `BidiCharacterTest.txt`.
+//!
+//! The test files are read from `tests/bidi/data`, see the README there. The tests are ignored
+//! until the files are added, and fail if they are run without them. Test cases with a
+//! paragraph separator before the end of the input are skipped: the test data treats the input
+//! as a single paragraph, while [`BidiResolver`] splits paragraphs as in rule P1.
+
+use icu_experimental::bidi::{BidiInfo, BidiResolver, Level};
+use std::path::PathBuf;
+
+/// Reads a test file, failing if it has not been added to the test data.
+fn read(name: &str) -> String {
+    let path: PathBuf = [env!("CARGO_MANIFEST_DIR"), "tests", "bidi", "data", name]
+        .iter()
+        .collect();
+    std::fs::read_to_string(&path).unwrap_or_else(|e| panic!("{}: {e}", path.display()))
+}
+
+/// A representative character of each bidi class in `BidiTest.txt`.
+fn char_for_class(class: &str) -> char {
+    match class {
+        "L" => 'a',
+        "R" => '\u{05D0}',
+        "AL" => '\u{0627}',
+        "EN" => '0',
+        "ES" => '+',
+        "ET" => '$',
+        "AN" => '\u{0660}',
+        "CS" => ',',
+        "NSM" => '\u{0300}',
+        "BN" => '\u{00AD}',
+        "B" => '\u{2029}',
+        "S" => '\t',
+        "WS" => ' ',
+        "ON" => '!',
+        "LRE" => '\u{202A}',
+        "LRO" => '\u{202D}',
+        "RLE" => '\u{202B}',
+        "RLO" => '\u{202E}',
+        "PDF" => '\u{202C}',
+        "LRI" => '\u{2066}',
+        "RLI" => '\u{2067}',
+        "FSI" => '\u{2068}',
+        "PDI" => '\u{2069}',
+        _ => panic!("unknown bidi class {class}"),
+    }
+}
+
+/// Parses a list of levels, where `x` marks a character removed by rule X9.
+fn parse_levels(field: &str) -> Vec<Option<u8>> {
+    field
+        .split_ascii_whitespace()
+        .map(|level| level.parse().ok())
+        .collect()
+}
+
+fn parse_indices(field: &str) -> Vec<usize> {
+    field
+        .split_ascii_whitespace()
+        .map(|index| index.parse().unwrap())
+        .collect()
+}
+
+/// Checks the levels and visual order of `text`, laid out as a single line, and returns a
+/// description of the difference if they do not match.
+fn check(
+    info: &BidiInfo,
+    text: &str,
+    expected_levels: &[Option<u8>],
+    expected_order: &[usize],
+) -> Result<(), String> {
+    let levels = actual_levels(info, text, expected_levels);
+    let order = actual_order(info, text, expected_levels);
+    if levels != expected_levels || order != expected_order {
+        return Err(format!(
+            "{text:?}: levels {levels:?} (expected {expected_levels:?}), \
+             order {order:?} (expected {expected_order:?})"
+        ));
+    }
+    Ok(())
+}
+
+/// The levels of the characters of `text` after rule L1, with `None` where the expected
+/// levels have characters removed by rule X9.
+fn actual_levels(info: &BidiInfo, text: &str, expected: &[Option<u8>]) -> Vec<Option<u8>> {
+    info.line_levels(0..text.len())
+        .iter()
+        .zip(expected)
+        .map(|(level, expected)| expected.map(|_| level.number()))
+        .collect()
+}
+
+/// The character indices of `text` in visual order, without the characters removed by rule
+/// X9.
+fn actual_order(info: &BidiInfo, text: &str, expected_levels: &[Option<u8>]) -> Vec<usize> {
+    let offsets: Vec<usize> = text.char_indices().map(|(offset, _)| offset).collect();
+    let mut order = Vec::new();
+    for run in info.visual_runs(0..text.len()) {
+        let start = offsets.partition_point(|&offset| offset < run.range.start);
+        let end = offsets.partition_point(|&offset| offset < run.range.end);
+        if run.is_rtl() {
+            order.extend((start..end).rev());
+        } else {
+            order.extend(start..end);
+        }
+    }
+    order.retain(|&i| expected_levels.get(i).is_some_and(Option::is_some));
+    order
+}
+
+/// Whether `text` has a paragraph separator before its end.
+fn has_inner_paragraph_separator(text: &str) -> bool {
+    text.char_indices()
+        .any(|(i, c)| c == '\u{2029}' && i + c.len_utf8() < text.len())
+}
+
+fn report(name: &str, failures: Vec<String>, count: usize) {
+    assert!(
+        failures.is_empty(),
+        "{name}: {} of {count} test cases failed, for example:\n{}",
+        failures.len(),
+        failures
+            .iter()
+            .take(10)
+            .cloned()
+            .collect::<Vec<_>>()
+            .join("\n")
+    );
+}
+
+#[test]
+#[ignore = "needs BidiTest.txt in tests/bidi/data, see the README there"]
+fn test_bidi_test() {
+    let data = read("BidiTest.txt");
+    let resolver = BidiResolver::new();
+    let mut levels = Vec::new();
+    let mut order = Vec::new();
+    let mut failures = Vec::new();
+    let mut count = 0;
+    for line in data.lines() {
+        let line = line.split('#').next().unwrap_or_default().trim();
+        if line.is_empty() {
+            continue;
+        }
+        if let Some(field) = line.strip_prefix("@Levels:") {
+            levels = parse_levels(field);
+            continue;
+        }
+        if let Some(field) = line.strip_prefix("@Reorder:") {
+            order = parse_indices(field);
+            continue;
+        }
+        let (classes, bitset) = line.split_once(';').unwrap();
+        let text: String = classes
+            .split_ascii_whitespace()
+            .map(char_for_class)
+            .collect();
+        if has_inner_paragraph_separator(&text) {
+            continue;
+        }
+        let bitset: u8 = bitset.trim().parse().unwrap();
+        for (bit, default_level) in [(1, None), (2, Some(Level::LTR)), (4, Some(Level::RTL))] {
+            if bitset & bit != 0 {
+                count += 1;
+                let info = resolver.resolve_str(&text, default_level);
+                if let Err(failure) = check(&info, &text, &levels, &order) {
+                    failures.push(format!("{failure} at {default_level:?}"));
+                }
+            }
+        }
+    }
+    report("BidiTest.txt", failures, count);
+}
+
+#[test]
+#[ignore = "needs BidiCharacterTest.txt in tests/bidi/data, see the README there"]
+fn test_bidi_character_test() {
+    let data = read("BidiCharacterTest.txt");
+    let resolver = BidiResolver::new();
+    let mut failures = Vec::new();
+    let mut count = 0;
+    for line in data.lines() {
+        let line = line.split('#').next().unwrap_or_default().trim();
+        if line.is_empty() {
+            continue;
+        }
+        let fields: Vec<&str> = line.split(';').collect();
+        let [code_points, direction, paragraph_level, levels, order] = fields[..] else {
+            panic!("invalid test case {line:?}");
+        };
+        let text: String = code_points
+            .split_ascii_whitespace()
+            .map(|c| char::from_u32(u32::from_str_radix(c, 16).unwrap()).unwrap())
+            .collect();
+        if has_inner_paragraph_separator(&text) {
+            continue;
+        }
+        let default_level = match direction.trim() {
+            "0" => Some(Level::LTR),
+            "1" => Some(Level::RTL),
+            _ => None,
+        };
+        count += 1;
+        let info = resolver.resolve_str(&text, default_level);
+        let expected_paragraph_level: u8 = paragraph_level.trim().parse().unwrap();
+        if let Some(paragraph) = info.paragraphs().next() {
+            if paragraph.level.number() != expected_paragraph_level {
+                failures.push(format!(
+                    "{text:?}: paragraph level {} (expected {expected_paragraph_level})",
+                    paragraph.level.number()
+                ));
+                continue;
+            }
+        }
+        if let Err(failure) = check(&info, &text, &parse_levels(levels), &parse_indices(order)) {
+            failures.push(failure);
+        }
+    }
+    report("BidiCharacterTest.txt", failures, count);
+}
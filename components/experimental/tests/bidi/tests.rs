@@ -0,0 +1,189 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+mod conformance;
+
+use icu_experimental::bidi::{BidiInfo, BidiResolver, Level};
+
+/// The levels of each character of `text`, before lines are laid out.
+fn levels(text: &str, info: &BidiInfo) -> Vec<u8> {
+    text.char_indices()
+        .map(|(i, _)| info.level_at(i).unwrap().number())
+        .collect()
+}
+
+/// The visual order of a line of `text`, with right-to-left runs reversed.
+fn visual(text: &str, info: &BidiInfo, line: core::ops::Range<usize>) -> String {
+    info.visual_runs(line)
+        .into_iter()
+        .map(|run| {
+            let run_text = &text[run.range.clone()];
+            if run.is_rtl() {
+                run_text.chars().rev().collect()
+            } else {
+                run_text.to_string()
+            }
+        })
+        .collect()
+}
+
+#[track_caller]
+fn check(text: &str, default_level: Option<Level>, expected_levels: &[u8], expected_visual: &str) {
+    let info = BidiResolver::new().resolve_str(text, default_level);
+    assert_eq!(levels(text, &info), expected_levels, "{text:?}");
+    assert_eq!(
+        visual(text, &info, 0..text.len()),
+        expected_visual,
+        "{text:?}"
+    );
+}
+
+#[test]
+fn test_implicit() {
+    check("abc", None, &[0, 0, 0], "abc");
+    check("אבג abc", None, &[1, 1, 1, 1, 2, 2, 2], "abc גבא");
+    check("abc אבג", None, &[0, 0, 0, 0, 1, 1, 1], "abc גבא");
+    check("abc", Some(Level::RTL), &[2, 2, 2], "abc");
+    // W2: European digits after Arabic letters are Arabic numbers.
+    check("ا 12", None, &[1, 1, 2, 2], "12 ا");
+    // W4 and W5: separators and terminators within numbers.
+    check("א 1.5$", Some(Level::LTR), &[1, 1, 2, 2, 2, 2], "1.5$ א");
+}
+
+#[test]
+fn test_explicit() {
+    // RLE ... PDF raises "b" to level 2; removed characters take the preceding level.
+    check(
+        "a\u{202B}b\u{202C}c",
+        None,
+        &[0, 0, 2, 2, 0],
+        "a\u{202B}b\u{202C}c",
+    );
+    // RLO overrides the direction of Latin letters.
+    check(
+        "\u{202E}abc\u{202C}",
+        Some(Level::LTR),
+        &[0, 1, 1, 1, 1],
+        "\u{202E}cba\u{202C}",
+    );
+    // The content of an isolate does not affect its surroundings.
+    check(
+        "a \u{2067}אב\u{2069} c",
+        None,
+        &[0, 0, 0, 1, 1, 0, 0, 0],
+        "a \u{2067}בא\u{2069} c",
+    );
+    // FSI takes the direction of its first strong character.
+    check(
+        "\u{2068}א\u{2069}",
+        Some(Level::LTR),
+        &[0, 1, 0],
+        "\u{2068}א\u{2069}",
+    );
+    // Isolates are ignored when determining the paragraph level.
+    check(
+        "\u{2067}אב\u{2069}c",
+        None,
+        &[0, 1, 1, 0, 0],
+        "\u{2067}בא\u{2069}c",
+    );
+}
+
+#[test]
+fn test_overflow() {
+    let text: String = core::iter::repeat_n("\u{202B}\u{202A}", 70)
+        .chain(["א"])
+        .collect();
+    let info = BidiResolver::new().resolve_str(&text, Some(Level::LTR));
+    assert_eq!(
+        info.level_at(text.len() - 1),
+        Some(Level::new(125).unwrap())
+    );
+}
+
+#[test]
+fn test_brackets() {
+    // N0: brackets take the embedding direction when they contain it.
+    // Reordering does not mirror the brackets, see `BidiInfo::mirrored`.
+    check("א(ב)", None, &[1, 1, 1, 1], ")ב(א");
+    // N0: otherwise they take the direction of the preceding context.
+    check("a(א)", None, &[0, 0, 1, 0], "a(א)");
+    check("א(a)", Some(Level::LTR), &[1, 0, 0, 0], "א(a)");
+
+    let info = BidiResolver::new().resolve_str("א(ב)", None);
+    assert_eq!(info.mirrored(0..8), [(2, ')'), (5, '(')]);
+    // Mirroring is resolved per line.
+    assert_eq!(info.mirrored(0..3), [(2, ')')]);
+    assert_eq!(info.mirrored(3..8), [(5, '(')]);
+    // Brackets at a left-to-right level are not mirrored.
+    let info = BidiResolver::new().resolve_str("a(b)", None);
+    assert_eq!(info.mirrored(0..4), []);
+}
+
+#[test]
+fn test_line_rules() {
+    // L1: segment separators are reset to the paragraph level.
+    check("אב\tגד", Some(Level::LTR), &[1, 1, 1, 1, 1], "בא\tדג");
+    // L1: trailing whitespace is reset to the paragraph level.
+    let text = "a \u{202B}ב \u{202C}";
+    let info = BidiResolver::new().resolve_str(text, None);
+    assert_eq!(info.level_at(7), Some(Level::RTL));
+    let levels: Vec<u8> = info
+        .line_levels(0..text.len())
+        .iter()
+        .map(|level| level.number())
+        .collect();
+    assert_eq!(levels, [0, 0, 0, 1, 0, 0]);
+    let runs = info.visual_runs(0..text.len());
+    assert_eq!(runs.len(), 3);
+    assert_eq!(runs[2].range, 7..11);
+    assert_eq!(runs[2].level, Level::LTR);
+}
+
+#[test]
+fn test_lines() {
+    let text = "אבג דהו abc";
+    let info = BidiResolver::new().resolve_str(text, None);
+    let lines = info.visual_runs_by_line([0, 7, 14]);
+    assert_eq!(lines.len(), 3);
+    assert_eq!(visual(text, &info, 0..7), " גבא");
+    assert_eq!(visual(text, &info, 7..14), " והד");
+    assert_eq!(visual(text, &info, 14..text.len()), "abc");
+    assert_eq!(lines[2].len(), 1);
+    assert_eq!(lines[2][0].level.number(), 2);
+}
+
+#[test]
+fn test_paragraphs() {
+    let text = "abc\nאבג\r\nd";
+    let info = BidiResolver::new().resolve_str(text, None);
+    let paragraphs: Vec<_> = info.paragraphs().map(|p| (p.range, p.level)).collect();
+    assert_eq!(
+        paragraphs,
+        [
+            (0..4, Level::LTR),
+            (4..12, Level::RTL),
+            (12..13, Level::LTR)
+        ]
+    );
+    // A line across paragraphs is laid out paragraph by paragraph.
+    assert_eq!(visual(text, &info, 0..text.len()), "abc\n\n\rגבאd");
+}
+
+#[test]
+fn test_utf16() {
+    let text = "abc אבג 😀";
+    let utf16: Vec<u16> = text.encode_utf16().collect();
+    let info = BidiResolver::new().resolve_utf16(&utf16, None);
+    let runs: Vec<_> = info
+        .visual_runs(0..utf16.len())
+        .into_iter()
+        .map(|run| (run.range, run.level.number()))
+        .collect();
+    assert_eq!(runs, [(0..4, 0), (4..7, 1), (7..10, 0)]);
+
+    // Unpaired surrogates are neutral.
+    let info = BidiResolver::new().resolve_utf16(&[0x05D0, 0xD800, 0x05D1], None);
+    assert_eq!(info.level_at(1), Some(Level::RTL));
+}
@@ -0,0 +1,323 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use fixed_decimal::Decimal;
+#[cfg(feature = "messageformat_datetime")]
+use icu_calendar::Date;
+use icu_experimental::messageformat::{
+    MessageArguments, MessageFormatErrorKind, MessageFormatter, MessageFormatterLoadError,
+    MessageSyntaxErrorKind,
+};
+use icu_locale_core::locale;
+#[cfg(feature = "messageformat_datetime")]
+use icu_time::{DateTime, Time};
+use writeable::TryWriteable;
+
+fn format(message: &str, arguments: &MessageArguments) -> String {
+    let formatter = MessageFormatter::try_new(locale!("en").into(), message).unwrap();
+    let formatted = formatter.format(arguments);
+    match formatted.try_write_to_string() {
+        Ok(output) => output.into_owned(),
+        Err((error, output)) => panic!("{message}: {error} in {output:?}"),
+    }
+}
+
+fn format_error(message: &str, arguments: &MessageArguments) -> (String, MessageFormatErrorKind) {
+    let formatter = MessageFormatter::try_new(locale!("en").into(), message).unwrap();
+    let formatted = formatter.format(arguments);
+    match formatted.try_write_to_string() {
+        Ok(output) => panic!("{message}: expected an error, got {output:?}"),
+        Err((error, output)) => (output.into_owned(), error.kind),
+    }
+}
+
+#[test]
+fn test_patterns() {
+    let arguments = MessageArguments::new().with("name", "Maria").with("n", 42);
+    for (message, expected) in [
+        ("", ""),
+        ("Hello, world!", "Hello, world!"),
+        ("  Hello  ", "  Hello  "),
+        ("Hello, {$name}!", "Hello, Maria!"),
+        ("{|literal|} and {literal}", "literal and literal"),
+        ("Escaped \\{braces\\}", "Escaped {braces}"),
+        ("{{  Quoted  }}", "  Quoted  "),
+        ("{#b}Bold{/b} {#br/}text", "Bold text"),
+        ("{$name :string @attribute=|x|}", "Maria"),
+        (
+            ".local $greeting = {|Hi|} {{{$greeting}, {$name}}}",
+            "Hi, Maria",
+        ),
+        ("{$n}", "42"),
+        ("{$n :string}", "42"),
+    ] {
+        assert_eq!(format(message, &arguments), expected, "{message}");
+    }
+}
+
+#[test]
+fn test_numbers() {
+    let arguments = MessageArguments::new()
+        .with("big", 1234567)
+        .with("pi", Decimal::try_from_str("3.14159").unwrap())
+        .with("negative", -5)
+        .with("string", "1234.5");
+    for (message, expected) in [
+        ("{$big :number}", "1,234,567"),
+        ("{$big :number useGrouping=never}", "1234567"),
+        ("{$pi :number}", "3.14159"),
+        ("{$pi :number maximumFractionDigits=2}", "3.14"),
+        ("{$pi :integer}", "3"),
+        ("{$big :number minimumFractionDigits=2}", "1,234,567.00"),
+        ("{$negative :number minimumIntegerDigits=3}", "-005"),
+        ("{$big :number signDisplay=always}", "+1,234,567"),
+        ("{$negative :number signDisplay=never}", "5"),
+        ("{$string :number}", "1,234.5"),
+        ("{42.50 :number}", "42.50"),
+        (
+            ".local $x = {$pi :number maximumFractionDigits=3} {{{$x :number minimumFractionDigits=4}}}",
+            "3.1420",
+        ),
+        (
+            ".local $x = {$big :number useGrouping=never} {{{$x}}}",
+            "1234567",
+        ),
+    ] {
+        assert_eq!(format(message, &arguments), expected, "{message}");
+    }
+}
+
+#[test]
+fn test_selection() {
+    let message = ".input {$n :number}
+        .match $n
+        0 {{zero}}
+        one {{one {$n}}}
+        1 {{exactly one}}
+        * {{other {$n}}}";
+    for (n, expected) in [(0, "zero"), (1, "exactly one"), (2, "other 2")] {
+        assert_eq!(
+            format(message, &MessageArguments::new().with("n", n)),
+            expected
+        );
+    }
+    assert_eq!(
+        format(
+            message,
+            &MessageArguments::new().with("n", Decimal::try_from_str("1.0").unwrap())
+        ),
+        "exactly one"
+    );
+
+    let message = ".input {$n :integer select=ordinal}
+        .match $n
+        one {{{$n}st}}
+        two {{{$n}nd}}
+        few {{{$n}rd}}
+        * {{{$n}th}}";
+    for (n, expected) in [(1, "1st"), (22, "22nd"), (103, "103rd"), (11, "11th")] {
+        assert_eq!(
+            format(message, &MessageArguments::new().with("n", n)),
+            expected
+        );
+    }
+
+    let message = ".input {$n :number select=exact}
+        .match $n
+        one {{plural}}
+        1 {{exact}}
+        * {{other}}";
+    for (n, expected) in [(1, "exact"), (2, "other")] {
+        assert_eq!(
+            format(message, &MessageArguments::new().with("n", n)),
+            expected
+        );
+    }
+
+    let message = ".input {$gender :string}
+        .input {$count :integer}
+        .match $gender $count
+        female one {{She has one item}}
+        female * {{She has {$count} items}}
+        * one {{They have one item}}
+        * * {{They have {$count} items}}";
+    for (gender, count, expected) in [
+        ("female", 1, "She has one item"),
+        ("female", 3, "She has 3 items"),
+        ("male", 1, "They have one item"),
+        ("other", 7, "They have 7 items"),
+    ] {
+        let arguments = MessageArguments::new()
+            .with("gender", gender)
+            .with("count", count);
+        assert_eq!(format(message, &arguments), expected);
+    }
+}
+
+#[test]
+#[cfg(feature = "messageformat_datetime")]
+fn test_dates_and_times() {
+    let date = Date::try_new_iso(2025, 1, 15).unwrap();
+    let time = Time::try_new(16, 5, 30, 0).unwrap();
+    let arguments = MessageArguments::new()
+        .with("date", date)
+        .with("time", time)
+        .with("datetime", DateTime { date, time })
+        .with("string", "2025-01-15T16:05");
+    for (message, expected) in [
+        ("{$date :date}", "Jan 15, 2025"),
+        ("{$date :date style=long}", "January 15, 2025"),
+        ("{$date :date style=full}", "Wednesday, January 15, 2025"),
+        ("{$time :time}", "4:05\u{202f}PM"),
+        ("{$time :time style=medium}", "4:05:30\u{202f}PM"),
+        ("{$datetime :datetime}", "Jan 15, 2025, 4:05\u{202f}PM"),
+        ("{$datetime :time}", "4:05\u{202f}PM"),
+        ("{$datetime :datetime dateStyle=short}", "1/15/25"),
+        ("{$string :date}", "Jan 15, 2025"),
+        ("{|2025-01-15| :date style=short}", "1/15/25"),
+        (
+            ".local $d = {$date :date style=long} {{On {$d}}}",
+            "On January 15, 2025",
+        ),
+    ] {
+        assert_eq!(format(message, &arguments), expected, "{message}");
+    }
+
+    let arguments = MessageArguments::new().with("date", date);
+    for (message, expected, kind) in [
+        ("{$date}", "{$date}", MessageFormatErrorKind::BadOperand),
+        (
+            "{$date :time}",
+            "{$date}",
+            MessageFormatErrorKind::BadOperand,
+        ),
+        (
+            ".input {$date :date} .match $date * {{fallback}}",
+            "fallback",
+            MessageFormatErrorKind::BadSelector,
+        ),
+    ] {
+        assert_eq!(
+            format_error(message, &arguments),
+            (expected.to_string(), kind),
+            "{message}"
+        );
+    }
+
+    let formatter =
+        MessageFormatter::try_new(locale!("de").into(), "{$n :number} {$d :date}").unwrap();
+    let arguments = MessageArguments::new()
+        .with("n", Decimal::try_from_str("1234.5").unwrap())
+        .with("d", date);
+    let formatted = formatter.format(&arguments);
+    assert_eq!(
+        formatted.try_write_to_string().unwrap(),
+        "1.234,5 15.01.2025"
+    );
+}
+
+#[test]
+#[cfg(not(feature = "messageformat_datetime"))]
+fn test_dates_and_times_disabled() {
+    let arguments = MessageArguments::new().with("string", "2025-01-15");
+    assert_eq!(
+        format_error("{$string :date}", &arguments),
+        (
+            "{$string}".to_string(),
+            MessageFormatErrorKind::UnknownFunction
+        )
+    );
+}
+
+#[test]
+fn test_format_errors() {
+    let arguments = MessageArguments::new().with("n", 1).with("text", "abc");
+    for (message, expected, kind) in [
+        (
+            "Hello, {$name}!",
+            "Hello, {$name}!",
+            MessageFormatErrorKind::UnresolvedVariable,
+        ),
+        (
+            "{$n :unknown}",
+            "{$n}",
+            MessageFormatErrorKind::UnknownFunction,
+        ),
+        (
+            "{:unknown}",
+            "{:unknown}",
+            MessageFormatErrorKind::UnknownFunction,
+        ),
+        (
+            "{|a\\|b| :number}",
+            "{|a\\|b|}",
+            MessageFormatErrorKind::BadOperand,
+        ),
+        (
+            "{$text :number}",
+            "{$text}",
+            MessageFormatErrorKind::BadOperand,
+        ),
+        (
+            "{$n :number minimumFractionDigits=many}",
+            "{$n}",
+            MessageFormatErrorKind::BadOption,
+        ),
+        (
+            ".input {$missing :number} .match $missing 1 {{one}} * {{other}}",
+            "other",
+            MessageFormatErrorKind::UnresolvedVariable,
+        ),
+    ] {
+        assert_eq!(
+            format_error(message, &arguments),
+            (expected.to_string(), kind),
+            "{message}"
+        );
+    }
+}
+
+#[test]
+fn test_syntax_errors() {
+    for (message, kind) in [
+        ("{$x", MessageSyntaxErrorKind::UnexpectedEnd),
+        ("}", MessageSyntaxErrorKind::UnexpectedCharacter),
+        (
+            ".input {$x :number} .input {$x :number} {{}}",
+            MessageSyntaxErrorKind::DuplicateDeclaration,
+        ),
+        (
+            ".input {$x :number} .match $x 1 {{one}}",
+            MessageSyntaxErrorKind::MissingFallbackVariant,
+        ),
+        (
+            ".local $x = {1} .match $x * {{}}",
+            MessageSyntaxErrorKind::MissingSelectorAnnotation,
+        ),
+    ] {
+        match MessageFormatter::try_new(locale!("en").into(), message) {
+            Err(MessageFormatterLoadError::Syntax(error)) => {
+                assert_eq!(error.kind, kind, "{message}")
+            }
+            other => panic!("{message}: {other:?}"),
+        }
+    }
+}
+
+#[test]
+fn test_locales() {
+    let formatter = MessageFormatter::try_new(
+        locale!("ar-EG").into(),
+        ".input {$n :number} .match $n few {{{$n} few}} * {{{$n} other}}",
+    )
+    .unwrap();
+    let arguments = MessageArguments::new().with("n", 3);
+    let formatted = formatter.format(&arguments);
+    assert_eq!(formatted.try_write_to_string().unwrap(), "٣ few");
+
+    let formatter = MessageFormatter::try_new(locale!("de").into(), "{$n :number}").unwrap();
+    let arguments = MessageArguments::new().with("n", Decimal::try_from_str("1234.5").unwrap());
+    let formatted = formatter.format(&arguments);
+    assert_eq!(formatted.try_write_to_string().unwrap(), "1.234,5");
+}
@@ -0,0 +1,534 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use crate::provider::*;
+use crate::LocaleExpander;
+use alloc::vec::Vec;
+use icu_locale_core::subtags::{Language, Region, Script};
+use icu_locale_core::{langid, LanguageIdentifier};
+use icu_provider::prelude::*;
+
+/// Which subtag differences matter most when matching locales.
+///
+/// See [`LocaleMatcherOptions::favor_subtag`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+#[non_exhaustive]
+pub enum FavorSubtag {
+    /// Language differences matter most, then script differences, then region differences.
+    ///
+    /// This is the default.
+    #[default]
+    Language,
+    /// Script differences matter more than language differences, so that a locale in the
+    /// desired script is preferred over a locale in a related language.
+    Script,
+    /// Region differences matter more than script differences, so that a locale for the
+    /// desired region is preferred over a locale in the desired script.
+    Region,
+}
+
+/// Options for [`LocaleMatcher`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+#[non_exhaustive]
+pub struct LocaleMatcherOptions {
+    /// Which subtag differences matter most.
+    ///
+    /// Default is [`FavorSubtag::Language`].
+    pub favor_subtag: Option<FavorSubtag>,
+}
+
+impl From<FavorSubtag> for LocaleMatcherOptions {
+    fn from(favor_subtag: FavorSubtag) -> Self {
+        Self {
+            favor_subtag: Some(favor_subtag),
+        }
+    }
+}
+
+/// The result of [`LocaleMatcher::find_best`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct LocaleMatch<'a> {
+    /// The supported locale that matched.
+    pub supported: &'a LanguageIdentifier,
+    /// The index of the matched locale in the supported locales.
+    pub supported_index: usize,
+    /// The index of the desired locale that won.
+    pub desired_index: usize,
+    /// The distance between the desired and the supported locale, where `0` is an exact
+    /// match after adding likely subtags, and larger values are worse matches.
+    pub distance: u8,
+}
+
+/// Finds the best supported locale for a list of desired locales, using the
+/// *[Enhanced Language Matching]* algorithm and CLDR language matching data.
+///
+/// Locales are compared after adding likely subtags, and the distance between them is the
+/// sum of the distances between their languages, scripts, and regions. Later desired locales
+/// are demoted by a small distance, so that a desired locale is only passed over for a much
+/// better match of a later desired locale. Locales that differ by at least a script are
+/// never matched.
+///
+/// The `Expander` generic parameter wraps a [`LocaleExpander`].
+///
+/// # Examples
+///
+/// Unlike locale fallback, which would choose `en` for a user in the United Kingdom,
+/// language matching prefers the regional variant closest to the desired one:
+///
+/// ```
+/// use icu::locale::{langid, LocaleMatcher};
+///
+/// let matcher = LocaleMatcher::new_common(Default::default());
+///
+/// let supported = [langid!("en"), langid!("en-001"), langid!("de")];
+///
+/// let result = matcher
+///     .find_best([&langid!("en-GB")], &supported)
+///     .expect("en-GB matches en-001");
+///
+/// assert_eq!(result.supported, &langid!("en-001"));
+/// assert_eq!(result.supported_index, 1);
+/// assert_eq!(result.desired_index, 0);
+/// assert_eq!(result.distance, 3);
+/// ```
+///
+/// A later desired locale wins if the earlier ones have no match:
+///
+/// ```
+/// use icu::locale::{langid, LocaleMatcher};
+///
+/// let matcher = LocaleMatcher::new_common(Default::default());
+///
+/// let supported = [langid!("en"), langid!("de-CH")];
+///
+/// let result = matcher
+///     .find_best([&langid!("fr-CH"), &langid!("de")], &supported)
+///     .expect("de matches de-CH");
+///
+/// assert_eq!(result.supported, &langid!("de-CH"));
+/// assert_eq!(result.desired_index, 1);
+///
+/// assert_eq!(matcher.find_best([&langid!("ja")], &supported), None);
+/// ```
+///
+/// [Enhanced Language Matching]: https://www.unicode.org/reports/tr35/tr35-info.html#EnhancedLanguageMatching
+#[derive(Debug)]
+pub struct LocaleMatcher<Expander = LocaleExpander> {
+    matching: DataPayload<LocaleLanguageMatchingV1>,
+    expander: Expander,
+    options: LocaleMatcherOptions,
+}
+
+impl LocaleMatcher<LocaleExpander> {
+    /// Creates a [`LocaleMatcher`] from compiled data, using [`LocaleExpander`]
+    /// data for common locales.
+    ///
+    /// This includes limited likely subtags data, see [`LocaleExpander::new_common()`].
+    #[cfg(feature = "compiled_data")]
+    pub const fn new_common(options: LocaleMatcherOptions) -> Self {
+        Self::new_with_expander(LocaleExpander::new_common(), options)
+    }
+
+    // Note: This is a custom impl because the bounds on `try_new_unstable` don't suffice
+    #[doc = icu_provider::gen_buffer_unstable_docs!(BUFFER, Self::new_common)]
+    #[cfg(feature = "serde")]
+    pub fn try_new_common_with_buffer_provider(
+        provider: &(impl BufferProvider + ?Sized),
+        options: LocaleMatcherOptions,
+    ) -> Result<Self, DataError> {
+        let expander = LocaleExpander::try_new_common_with_buffer_provider(provider)?;
+        Self::try_new_with_expander_unstable(&provider.as_deserializing(), expander, options)
+    }
+    #[doc = icu_provider::gen_buffer_unstable_docs!(UNSTABLE, Self::new_common)]
+    pub fn try_new_common_unstable<P>(
+        provider: &P,
+        options: LocaleMatcherOptions,
+    ) -> Result<LocaleMatcher, DataError>
+    where
+        P: DataProvider<LocaleLanguageMatchingV1>
+            + DataProvider<LocaleLikelySubtagsLanguageV1>
+            + DataProvider<LocaleLikelySubtagsScriptRegionV1>
+            + ?Sized,
+    {
+        let expander = LocaleExpander::try_new_common_unstable(provider)?;
+        Self::try_new_with_expander_unstable(provider, expander, options)
+    }
+
+    /// Creates a [`LocaleMatcher`] from compiled data, using [`LocaleExpander`]
+    /// data for all locales.
+    ///
+    /// This includes all likely subtags data, see [`LocaleExpander::new_extended()`].
+    #[cfg(feature = "compiled_data")]
+    pub const fn new_extended(options: LocaleMatcherOptions) -> Self {
+        Self::new_with_expander(LocaleExpander::new_extended(), options)
+    }
+
+    // Note: This is a custom impl because the bounds on `try_new_unstable` don't suffice
+    #[doc = icu_provider::gen_buffer_unstable_docs!(BUFFER, Self::new_extended)]
+    #[cfg(feature = "serde")]
+    pub fn try_new_extended_with_buffer_provider(
+        provider: &(impl BufferProvider + ?Sized),
+        options: LocaleMatcherOptions,
+    ) -> Result<Self, DataError> {
+        let expander = LocaleExpander::try_new_extended_with_buffer_provider(provider)?;
+        Self::try_new_with_expander_unstable(&provider.as_deserializing(), expander, options)
+    }
+    #[doc = icu_provider::gen_buffer_unstable_docs!(UNSTABLE, Self::new_extended)]
+    pub fn try_new_extended_unstable<P>(
+        provider: &P,
+        options: LocaleMatcherOptions,
+    ) -> Result<LocaleMatcher, DataError>
+    where
+        P: DataProvider<LocaleLanguageMatchingV1>
+            + DataProvider<LocaleLikelySubtagsLanguageV1>
+            + DataProvider<LocaleLikelySubtagsScriptRegionV1>
+            + DataProvider<LocaleLikelySubtagsExtendedV1>
+            + ?Sized,
+    {
+        let expander = LocaleExpander::try_new_extended_unstable(provider)?;
+        Self::try_new_with_expander_unstable(provider, expander, options)
+    }
+}
+
+impl<Expander: AsRef<LocaleExpander>> LocaleMatcher<Expander> {
+    /// Creates a [`LocaleMatcher`] with a custom [`LocaleExpander`] and compiled data.
+    ///
+    /// This allows using [`LocaleExpander::new_extended()`] with data for all locales.
+    #[cfg(feature = "compiled_data")]
+    pub const fn new_with_expander(expander: Expander, options: LocaleMatcherOptions) -> Self {
+        LocaleMatcher {
+            matching: DataPayload::from_static_ref(
+                crate::provider::Baked::SINGLETON_LOCALE_LANGUAGE_MATCHING_V1,
+            ),
+            expander,
+            options,
+        }
+    }
+
+    #[doc = icu_provider::gen_buffer_unstable_docs!(UNSTABLE, Self::new_with_expander)]
+    pub fn try_new_with_expander_unstable<P>(
+        provider: &P,
+        expander: Expander,
+        options: LocaleMatcherOptions,
+    ) -> Result<Self, DataError>
+    where
+        P: DataProvider<LocaleLanguageMatchingV1> + ?Sized,
+    {
+        let matching = provider.load(Default::default())?.payload;
+
+        Ok(LocaleMatcher {
+            matching,
+            expander,
+            options,
+        })
+    }
+
+    /// Returns the best supported locale for the desired locales, which are given in
+    /// order of preference, or `None` if no supported locale is close enough.
+    ///
+    /// If several supported locales are equally good matches, the first one is returned.
+    ///
+    /// See [`LocaleMatcher`] for an example.
+    pub fn find_best<'a, 's>(
+        &self,
+        desired: impl IntoIterator<Item = &'a LanguageIdentifier>,
+        supported: &'s [LanguageIdentifier],
+    ) -> Option<LocaleMatch<'s>> {
+        const EN_US: LanguageIdentifier = langid!("en-Latn-US");
+        const EN_GB: LanguageIdentifier = langid!("en-Latn-GB");
+
+        // Each desired locale is demoted by the distance between two regional variants
+        // relative to the previous one.
+        let demotion = u32::from(self.maximized_distance(&(&EN_US).into(), &(&EN_GB).into()));
+        let threshold = self.matching.get().default_script_distance;
+
+        let supported_maximized = supported
+            .iter()
+            .map(|langid| self.maximize(langid))
+            .collect::<Vec<_>>();
+
+        let mut best: Option<(u32, LocaleMatch<'s>)> = None;
+        for (desired_index, desired) in desired.into_iter().enumerate() {
+            let demoted = demotion.saturating_mul(desired_index.try_into().unwrap_or(u32::MAX));
+            if best.is_some_and(|(score, _)| score <= demoted) {
+                // Neither this nor any later desired locale can be a better match
+                break;
+            }
+            let desired = self.maximize(desired);
+            for (supported_index, (langid, maximized)) in
+                supported.iter().zip(&supported_maximized).enumerate()
+            {
+                let distance = self.maximized_distance(&desired, maximized);
+                let score = u32::from(distance).saturating_add(demoted);
+                if distance < threshold && best.is_none_or(|(best, _)| score < best) {
+                    best = Some((
+                        score,
+                        LocaleMatch {
+                            supported: langid,
+                            supported_index,
+                            desired_index,
+                            distance,
+                        },
+                    ));
+                }
+            }
+        }
+        best.map(|(_, result)| result)
+    }
+
+    /// Returns the distance between a desired and a supported locale, where `0` is an
+    /// exact match after adding likely subtags, and larger values are worse matches.
+    ///
+    /// The distance is asymmetric: a user who reads Afrikaans will likely accept
+    /// Dutch, but not the other way around.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu::locale::{langid, LocaleMatcher};
+    ///
+    /// let matcher = LocaleMatcher::new_common(Default::default());
+    ///
+    /// assert_eq!(matcher.distance(&langid!("en-US"), &langid!("en")), 0);
+    /// assert_eq!(matcher.distance(&langid!("en-GB"), &langid!("en")), 5);
+    /// assert_eq!(matcher.distance(&langid!("af"), &langid!("nl")), 24);
+    /// assert_eq!(matcher.distance(&langid!("nl"), &langid!("af")), 84);
+    /// ```
+    pub fn distance(&self, desired: &LanguageIdentifier, supported: &LanguageIdentifier) -> u8 {
+        self.maximized_distance(&self.maximize(desired), &self.maximize(supported))
+    }
+
+    fn maximize(&self, langid: &LanguageIdentifier) -> Subtags {
+        let mut maximized = LanguageIdentifier {
+            language: langid.language,
+            script: langid.script,
+            region: langid.region,
+            variants: Default::default(),
+        };
+        self.expander.as_ref().maximize(&mut maximized);
+        (&maximized).into()
+    }
+
+    fn maximized_distance(&self, desired: &Subtags, supported: &Subtags) -> u8 {
+        let data = self.matching.get();
+        let language = language_distance(data, desired, supported);
+        let script = script_distance(data, desired, supported);
+        let region = region_distance(data, desired, supported);
+        let (language, region) = match self.options.favor_subtag.unwrap_or_default() {
+            FavorSubtag::Language => (language, region),
+            FavorSubtag::Script => (language / 4, region),
+            FavorSubtag::Region => (language, region.saturating_mul(4)),
+        };
+        language.saturating_add(script).saturating_add(region)
+    }
+}
+
+/// The subtags of a maximized locale that are used for matching.
+struct Subtags {
+    language: Language,
+    script: Option<Script>,
+    region: Option<Region>,
+}
+
+impl From<&LanguageIdentifier> for Subtags {
+    fn from(langid: &LanguageIdentifier) -> Self {
+        Self {
+            language: langid.language,
+            script: langid.script,
+            region: langid.region,
+        }
+    }
+}
+
+/// Returns whether a language pattern from the data matches a language.
+fn language_matches(pattern: Language, language: Language) -> bool {
+    pattern.is_unknown() || pattern == language
+}
+
+/// Returns whether a script pattern from the data matches a script.
+fn script_matches(pattern: Option<Script>, script: Option<Script>) -> bool {
+    pattern.is_none() || pattern == script
+}
+
+fn language_distance(data: &LanguageMatching, desired: &Subtags, supported: &Subtags) -> u8 {
+    if desired.language == supported.language {
+        return 0;
+    }
+    let matches = |(d, s): (Language, Language)| {
+        language_matches(d, desired.language) && language_matches(s, supported.language)
+    };
+    data.languages
+        .iter()
+        .find(|&(d, s, _, oneway)| matches((d, s)) || (!oneway && matches((s, d))))
+        .map(|(_, _, distance, _)| distance)
+        .unwrap_or(data.default_language_distance)
+}
+
+fn script_distance(data: &LanguageMatching, desired: &Subtags, supported: &Subtags) -> u8 {
+    if desired.script == supported.script {
+        return 0;
+    }
+    let matches = |(dl, ds, sl, ss): (Language, Option<Script>, Language, Option<Script>)| {
+        language_matches(dl, desired.language)
+            && script_matches(ds, desired.script)
+            && language_matches(sl, supported.language)
+            && script_matches(ss, supported.script)
+    };
+    data.scripts
+        .iter()
+        .find(|&(dl, ds, sl, ss, _, oneway)| {
+            matches((dl, ds, sl, ss)) || (!oneway && matches((sl, ss, dl, ds)))
+        })
+        .map(|(_, _, _, _, distance, _)| distance)
+        .unwrap_or(data.default_script_distance)
+}
+
+fn region_distance(data: &LanguageMatching, desired: &Subtags, supported: &Subtags) -> u8 {
+    if desired.region == supported.region {
+        return 0;
+    }
+    let in_set = |set: u8, negated: bool, region: Option<Region>| {
+        let contained = region.is_some_and(|region| {
+            data.region_sets
+                .get(set.into())
+                .is_some_and(|set| set.binary_search(&region).is_ok())
+        });
+        contained != negated
+    };
+    data.regions
+        .iter()
+        .find(|rule| {
+            language_matches(rule.language, desired.language)
+                && language_matches(rule.language, supported.language)
+                && script_matches(rule.script, desired.script)
+                && script_matches(rule.script, supported.script)
+                && ((in_set(rule.desired_set, rule.desired_negated, desired.region)
+                    && in_set(rule.supported_set, rule.supported_negated, supported.region))
+                    || (!rule.oneway
+                        && in_set(rule.desired_set, rule.desired_negated, supported.region)
+                        && in_set(rule.supported_set, rule.supported_negated, desired.region)))
+        })
+        .map(|rule| rule.distance)
+        .unwrap_or(data.default_region_distance)
+}
+
+#[cfg(feature = "compiled_data")]
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_region_variants() {
+        let matcher = LocaleMatcher::new_common(Default::default());
+        let supported = [langid!("en"), langid!("en-001"), langid!("en-IN")];
+
+        for (desired, expected) in [
+            (langid!("en-GB"), langid!("en-001")),
+            (langid!("en-AU"), langid!("en-001")),
+            (langid!("en-CA"), langid!("en")),
+            (langid!("en-PR"), langid!("en")),
+            (langid!("en-IN"), langid!("en-IN")),
+        ] {
+            assert_eq!(
+                matcher
+                    .find_best([&desired], &supported)
+                    .map(|result| result.supported),
+                Some(&expected),
+                "{desired}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_macroregions() {
+        let matcher = LocaleMatcher::new_common(Default::default());
+        let supported = [langid!("es"), langid!("es-419")];
+
+        let result = matcher.find_best([&langid!("es-MX")], &supported).unwrap();
+        assert_eq!(result.supported, &langid!("es-419"));
+        assert_eq!(result.distance, 4);
+
+        let result = matcher.find_best([&langid!("es-AD")], &supported).unwrap();
+        assert_eq!(result.supported, &langid!("es"));
+    }
+
+    #[test]
+    fn test_scripts() {
+        let matcher = LocaleMatcher::new_common(Default::default());
+
+        assert_eq!(
+            matcher.distance(&langid!("zh-TW"), &langid!("zh-Hant-HK")),
+            5
+        );
+        assert_eq!(matcher.distance(&langid!("zh-TW"), &langid!("zh")), 23);
+        assert_eq!(matcher.distance(&langid!("zh"), &langid!("zh-TW")), 19);
+        assert_eq!(matcher.distance(&langid!("sr-Latn"), &langid!("sr")), 5);
+
+        // Different scripts don't match
+        assert_eq!(matcher.find_best([&langid!("ru")], &[langid!("en")]), None);
+        assert_eq!(
+            matcher.find_best([&langid!("en-Cyrl")], &[langid!("en")]),
+            None
+        );
+    }
+
+    #[test]
+    fn test_demotion() {
+        let matcher = LocaleMatcher::new_common(Default::default());
+        let supported = [langid!("en"), langid!("nl"), langid!("fr")];
+
+        // A regional variant of the first desired locale beats an exact match of the second
+        let result = matcher
+            .find_best([&langid!("en-GB"), &langid!("fr")], &supported)
+            .unwrap();
+        assert_eq!(result.supported, &langid!("en"));
+        assert_eq!(result.desired_index, 0);
+
+        // But a related language does not
+        let result = matcher
+            .find_best([&langid!("af"), &langid!("fr")], &supported)
+            .unwrap();
+        assert_eq!(result.supported, &langid!("fr"));
+        assert_eq!(result.desired_index, 1);
+        assert_eq!(result.distance, 0);
+
+        assert_eq!(
+            matcher.find_best(core::iter::empty(), &[langid!("en")]),
+            None
+        );
+        assert_eq!(matcher.find_best([&langid!("en")], &[]), None);
+    }
+
+    #[test]
+    fn test_favor_subtag() {
+        let supported = [langid!("zh-Hant-HK"), langid!("zh-Hans-TW")];
+
+        let matcher = LocaleMatcher::new_common(Default::default());
+        let result = matcher.find_best([&langid!("zh-TW")], &supported).unwrap();
+        assert_eq!(result.supported, &langid!("zh-Hant-HK"));
+
+        let matcher = LocaleMatcher::new_common(FavorSubtag::Region.into());
+        let result = matcher.find_best([&langid!("zh-TW")], &supported).unwrap();
+        assert_eq!(result.supported, &langid!("zh-Hans-TW"));
+        assert_eq!(
+            matcher.distance(&langid!("zh-TW"), &langid!("zh-Hant-HK")),
+            20
+        );
+        assert_eq!(
+            matcher.distance(&langid!("zh-TW"), &langid!("zh-Hans-TW")),
+            19
+        );
+
+        let supported = [langid!("ja"), langid!("en")];
+
+        let matcher = LocaleMatcher::new_common(Default::default());
+        assert_eq!(matcher.find_best([&langid!("it")], &supported), None);
+
+        let matcher = LocaleMatcher::new_common(FavorSubtag::Script.into());
+        let result = matcher.find_best([&langid!("it")], &supported).unwrap();
+        assert_eq!(result.supported, &langid!("en"));
+        assert_eq!(result.distance, 24);
+    }
+}
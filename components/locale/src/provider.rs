@@ -36,6 +36,7 @@ const _: () = {
     }
     make_provider!(Baked);
     impl_locale_aliases_v1!(Baked);
+    impl_locale_language_matching_v1!(Baked);
     impl_locale_likely_subtags_extended_v1!(Baked);
     impl_locale_likely_subtags_language_v1!(Baked);
     impl_locale_likely_subtags_script_region_v1!(Baked);
@@ -85,6 +86,14 @@ icu_provider::data_marker!(
     is_singleton = true
 );
 
+icu_provider::data_marker!(
+    /// Marker for language matching data.
+    LocaleLanguageMatchingV1,
+    "locale/language/matching/v1",
+    LanguageMatching<'static>,
+    is_singleton = true
+);
+
 icu_provider::data_marker!(
     /// Marker for script direction data.
     LocaleScriptDirectionV1,
@@ -133,6 +142,7 @@ pub const MARKERS: &[DataMarkerInfo] = &[
     LocaleExemplarCharactersMainV1::INFO,
     LocaleExemplarCharactersNumbersV1::INFO,
     LocaleExemplarCharactersPunctuationV1::INFO,
+    LocaleLanguageMatchingV1::INFO,
     LocaleLikelySubtagsExtendedV1::INFO,
     LocaleLikelySubtagsLanguageV1::INFO,
     LocaleLikelySubtagsScriptRegionV1::INFO,
@@ -165,6 +175,8 @@ type UnvalidatedLanguageIdentifier = str;
 type UnvalidatedLanguageIdentifierPair = StrStrPairVarULE;
 type UnvalidatedLanguageVariantsPair = LanguageStrStrPairVarULE;
 
+type ScriptMatchRule = (Language, Option<Script>, Language, Option<Script>, u8, bool);
+
 #[zerovec::make_varule(StrStrPairVarULE)]
 #[zerovec::derive(Debug)]
 #[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Debug)]
@@ -462,6 +474,87 @@ icu_provider::data_struct!(
     #[cfg(feature = "datagen")]
 );
 
+#[derive(Debug, PartialEq, Clone, yoke::Yokeable, zerofrom::ZeroFrom)]
+#[cfg_attr(feature = "datagen", derive(serde::Serialize, databake::Bake))]
+#[cfg_attr(feature = "datagen", databake(path = icu_locale::provider))]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+/// Language matching data derived from CLDR `languageMatching` data, used to compute
+/// the distance between two maximized locales.
+///
+/// Rules are stored in order of precedence; the first matching rule determines the
+/// distance. A subtag difference that no rule matches has the default distance for
+/// its level.
+///
+/// <div class="stab unstable">
+/// 🚧 This code is considered unstable; it may change at any time, in breaking or non-breaking ways,
+/// including in SemVer minor releases. While the serde representation of data structs is guaranteed
+/// to be stable, their Rust representation might not be. Use with caution.
+/// </div>
+#[yoke(prove_covariance_manually)]
+pub struct LanguageMatching<'data> {
+    /// Distances between languages, as (desired, supported, distance, one-way).
+    ///
+    /// [`Language::UNKNOWN`] matches any language.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub languages: ZeroVec<'data, (Language, Language, u8, bool)>,
+    /// Distances between scripts, as (desired language, desired script, supported
+    /// language, supported script, distance, one-way).
+    ///
+    /// [`Language::UNKNOWN`] matches any language, and `None` matches any script.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub scripts: ZeroVec<'data, ScriptMatchRule>,
+    /// Distances between regions.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub regions: ZeroVec<'data, RegionMatchRule>,
+    /// Sorted sets of regions, referenced by [`RegionMatchRule`]s.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub region_sets: VarZeroVec<'data, ZeroSlice<Region>>,
+    /// The distance between two languages that no rule matches.
+    pub default_language_distance: u8,
+    /// The distance between two scripts that no rule matches.
+    pub default_script_distance: u8,
+    /// The distance between two regions that no rule matches.
+    pub default_region_distance: u8,
+}
+
+icu_provider::data_struct!(
+    LanguageMatching<'_>,
+    #[cfg(feature = "datagen")]
+);
+
+/// A rule for the distance between two regions of the same language and script.
+///
+/// <div class="stab unstable">
+/// 🚧 This code is considered unstable; it may change at any time, in breaking or non-breaking ways,
+/// including in SemVer minor releases. While the serde representation of data structs is guaranteed
+/// to be stable, their Rust representation might not be. Use with caution.
+/// </div>
+#[zerovec::make_ule(RegionMatchRuleULE)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "datagen", derive(serde::Serialize, databake::Bake))]
+#[cfg_attr(feature = "datagen", databake(path = icu_locale::provider))]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+pub struct RegionMatchRule {
+    /// The language, or [`Language::UNKNOWN`] for any language.
+    pub language: Language,
+    /// The script, or `None` for any script.
+    pub script: Option<Script>,
+    /// The index of the set in [`LanguageMatching::region_sets`] that the desired region
+    /// has to be in.
+    pub desired_set: u8,
+    /// Whether the desired region has to be outside of its set instead.
+    pub desired_negated: bool,
+    /// The index of the set in [`LanguageMatching::region_sets`] that the supported region
+    /// has to be in.
+    pub supported_set: u8,
+    /// Whether the supported region has to be outside of its set instead.
+    pub supported_negated: bool,
+    /// The distance between the regions.
+    pub distance: u8,
+    /// Whether the rule only applies in the given direction.
+    pub oneway: bool,
+}
+
 /// A set of characters and strings which share a particular property value.
 ///
 /// <div class="stab unstable">
@@ -3,7 +3,7 @@
 // (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
 
 // https://github.com/unicode-org/icu4x/blob/main/documents/process/boilerplate.md#library-annotations
-#![cfg_attr(not(any(test, doc)), no_std)]
+#![cfg_attr(not(any(test, doc, feature = "std")), no_std)]
 #![cfg_attr(
     not(test),
     deny(
@@ -31,11 +31,14 @@
 //!
 //! Three kinds of contiguous inputs are supported: known-well-formed UTF-8 (`&str`), potentially-not-well-formed UTF-8,
 //! and potentially-not-well-formed UTF-16. Additionally, an iterator over `char` can be wrapped in a normalizing iterator.
+//! Text that is given in chunks, such as a large file, can be normalized with [`Utf8NormalizerStream`] and
+//! [`Utf16NormalizerStream`], which keep only the text after the last stable starter.
 //!
 //! The `uts46` module provides the combination of mapping and normalization operations for [UTS #46: Unicode IDNA
 //! Compatibility Processing](https://www.unicode.org/reports/tr46/). This functionality is not meant to be used by
 //! applications directly. Instead, it is meant as a building block for a full implementation of UTS #46, such as the
-//! [`idna`](https://docs.rs/idna/latest/idna/) crate.
+//! [`idna`](https://docs.rs/idna/latest/idna/) crate. With the `icu_properties` Cargo feature, the module also
+//! provides such a full implementation of the ToASCII and ToUnicode operations.
 //!
 //! The `properties` module provides the non-recursive canonical decomposition operation on a per `char` basis and
 //! the canonical compositon operation given two `char`s. It also provides access to the Canonical Combining Class
@@ -103,6 +106,14 @@ pub mod properties;
 pub mod provider;
 pub mod uts46;
 
+mod stream;
+
+pub use crate::stream::StreamingNormalizer;
+#[cfg(feature = "utf16_iter")]
+pub use crate::stream::Utf16NormalizerStream;
+#[cfg(feature = "utf8_iter")]
+pub use crate::stream::Utf8NormalizerStream;
+
 use crate::provider::CanonicalCompositions;
 use crate::provider::DecompositionData;
 use crate::provider::NormalizerNfdDataV1;
@@ -1044,6 +1055,8 @@ where
     /// 2. Decomposition starts with a non-starter
     /// 3. Is not a backward-combining starter
     composition_passthrough_bound: u32,
+    /// Whether only contiguous compositions are performed (FCC).
+    only_contiguous: bool,
 }
 
 impl<'data, I> Composition<'data, I>
@@ -1054,12 +1067,14 @@ where
         decomposition: Decomposition<'data, I>,
         canonical_compositions: Char16Trie<'data>,
         composition_passthrough_bound: u16,
+        only_contiguous: bool,
     ) -> Self {
         Self {
             decomposition,
             canonical_compositions,
             unprocessed_starter: None,
             composition_passthrough_bound: u32::from(composition_passthrough_bound),
+            only_contiguous,
         }
     }
 
@@ -1213,7 +1228,8 @@ where
                         return Some(starter);
                     }
                     debug_assert!(ccc >= most_recent_skipped_ccc);
-                    if ccc != most_recent_skipped_ccc {
+                    // In FCC, the skipped non-starter blocks all the following ones.
+                    if ccc != most_recent_skipped_ccc && !self.only_contiguous {
                         // Using the non-Hangul version as a micro-optimization, since
                         // we already rejected the case where `second` is a starter
                         // above, and conjoining jamo are starters.
@@ -1389,7 +1405,8 @@ macro_rules! composing_normalize_to {
                                 continue 'bufferloop;
                             }
                             debug_assert!(ccc >= most_recent_skipped_ccc);
-                            if ccc != most_recent_skipped_ccc {
+                            // In FCC, the skipped non-starter blocks all the following ones.
+                            if ccc != most_recent_skipped_ccc && !$composition.only_contiguous {
                                 // Using the non-Hangul version as a micro-optimization, since
                                 // we already rejected the case where `second` is a starter
                                 // above, and conjoining jamo are starters.
@@ -1850,6 +1867,74 @@ impl<'data> DecomposingNormalizerBorrowed<'data> {
 
     normalizer_methods!();
 
+    /// Checks whether a string slice is in FCD form.
+    ///
+    /// Text is in FCD ("Fast C or D") form if the canonical decomposition of each
+    /// character ends with a combining class that is not greater than the one that
+    /// the canonical decomposition of the next character starts with, unless the
+    /// latter is zero. Such text does not need canonical reordering after decomposing
+    /// each character on its own, which allows skipping full normalization.
+    ///
+    /// This check uses the decompositions of this normalizer, so the standard FCD check
+    /// requires an NFD normalizer.
+    ///
+    /// ```
+    /// use icu::normalizer::DecomposingNormalizerBorrowed;
+    ///
+    /// let nfd = DecomposingNormalizerBorrowed::new_nfd();
+    /// assert!(nfd.is_fcd("a\u{0323}\u{0302}"));
+    /// assert!(nfd.is_fcd("\u{1EA1}\u{0302}"));
+    /// assert!(!nfd.is_fcd("\u{00E2}\u{0323}"));
+    /// ```
+    pub fn is_fcd(&self, text: &str) -> bool {
+        self.is_fcd_iter(text.chars())
+    }
+
+    /// Checks whether a slice of potentially-invalid UTF-16 is in FCD form.
+    ///
+    /// Unpaired surrogates are treated as the REPLACEMENT CHARACTER.
+    ///
+    /// ✨ *Enabled with the `utf16_iter` Cargo feature.*
+    #[cfg(feature = "utf16_iter")]
+    pub fn is_fcd_utf16(&self, text: &[u16]) -> bool {
+        self.is_fcd_iter(text.chars())
+    }
+
+    /// Checks whether a slice of potentially-invalid UTF-8 is in FCD form.
+    ///
+    /// Ill-formed byte sequences are mapped to the REPLACEMENT CHARACTER
+    /// according to the WHATWG Encoding Standard before checking.
+    ///
+    /// ✨ *Enabled with the `utf8_iter` Cargo feature.*
+    #[cfg(feature = "utf8_iter")]
+    pub fn is_fcd_utf8(&self, text: &[u8]) -> bool {
+        self.is_fcd_iter(text.chars())
+    }
+
+    fn is_fcd_iter<I: Iterator<Item = char>>(&self, iter: I) -> bool {
+        let mut previous_trail_ccc = CCC_NOT_REORDERED;
+        for c in iter {
+            if u32::from(c) < u32::from(self.decomposition_passthrough_bound) {
+                previous_trail_ccc = CCC_NOT_REORDERED;
+                continue;
+            }
+            let mut decomposition = self.normalize_iter(core::iter::once(c));
+            let trie = decomposition.trie;
+            let Some(lead) = decomposition.next() else {
+                // Not supposed to happen, since ignorables are unsupported here.
+                debug_assert!(false);
+                continue;
+            };
+            let trail = decomposition.last().unwrap_or(lead);
+            let lead_ccc = ccc_from_trie_value(trie.get(lead));
+            if lead_ccc != CCC_NOT_REORDERED && lead_ccc < previous_trail_ccc {
+                return false;
+            }
+            previous_trail_ccc = ccc_from_trie_value(trie.get(trail));
+        }
+        true
+    }
+
     decomposing_normalize_to!(
         /// Normalize a string slice into a `Write` sink.
         ,
@@ -2422,6 +2507,7 @@ impl DecomposingNormalizer {
 pub struct ComposingNormalizerBorrowed<'a> {
     decomposing_normalizer: DecomposingNormalizerBorrowed<'a>,
     canonical_compositions: &'a CanonicalCompositions<'a>,
+    only_contiguous: bool,
 }
 
 impl ComposingNormalizerBorrowed<'static> {
@@ -2433,6 +2519,7 @@ impl ComposingNormalizerBorrowed<'static> {
         ComposingNormalizer {
             decomposing_normalizer: self.decomposing_normalizer.static_to_owned(),
             canonical_compositions: DataPayload::from_static_ref(self.canonical_compositions),
+            only_contiguous: self.only_contiguous,
         }
     }
 
@@ -2446,6 +2533,25 @@ impl ComposingNormalizerBorrowed<'static> {
         ComposingNormalizerBorrowed {
             decomposing_normalizer: DecomposingNormalizerBorrowed::new_nfd(),
             canonical_compositions: crate::provider::Baked::SINGLETON_NORMALIZER_NFC_V1,
+            only_contiguous: false,
+        }
+    }
+
+    /// FCC constructor using compiled data.
+    ///
+    /// FCC (Fast C Contiguous) is like NFC, except that a combining mark only composes with
+    /// the preceding starter if no other combining mark is between them. The output is
+    /// in FCD form (see [`DecomposingNormalizerBorrowed::is_fcd`]), but not necessarily NFC.
+    ///
+    /// ✨ *Enabled with the `compiled_data` Cargo feature.*
+    ///
+    /// [📚 Help choosing a constructor](icu_provider::constructors)
+    #[cfg(feature = "compiled_data")]
+    pub const fn new_fcc() -> Self {
+        ComposingNormalizerBorrowed {
+            decomposing_normalizer: DecomposingNormalizerBorrowed::new_nfd(),
+            canonical_compositions: crate::provider::Baked::SINGLETON_NORMALIZER_NFC_V1,
+            only_contiguous: true,
         }
     }
 
@@ -2459,6 +2565,7 @@ impl ComposingNormalizerBorrowed<'static> {
         ComposingNormalizerBorrowed {
             decomposing_normalizer: DecomposingNormalizerBorrowed::new_nfkd(),
             canonical_compositions: crate::provider::Baked::SINGLETON_NORMALIZER_NFC_V1,
+            only_contiguous: false,
         }
     }
 
@@ -2476,6 +2583,7 @@ impl ComposingNormalizerBorrowed<'static> {
         ComposingNormalizerBorrowed {
             decomposing_normalizer: DecomposingNormalizerBorrowed::new_uts46_decomposed(),
             canonical_compositions: crate::provider::Baked::SINGLETON_NORMALIZER_NFC_V1,
+            only_contiguous: false,
         }
     }
 }
@@ -2503,6 +2611,7 @@ impl<'data> ComposingNormalizerBorrowed<'data> {
             ),
             self.canonical_compositions.canonical_compositions.clone(),
             self.decomposing_normalizer.composition_passthrough_bound,
+            self.only_contiguous,
         )
     }
 
@@ -2861,6 +2970,7 @@ impl<'data> ComposingNormalizerBorrowed<'data> {
 pub struct ComposingNormalizer {
     decomposing_normalizer: DecomposingNormalizer,
     canonical_compositions: DataPayload<NormalizerNfcV1>,
+    only_contiguous: bool,
 }
 
 impl ComposingNormalizer {
@@ -2869,6 +2979,7 @@ impl ComposingNormalizer {
         ComposingNormalizerBorrowed {
             decomposing_normalizer: self.decomposing_normalizer.as_borrowed(),
             canonical_compositions: self.canonical_compositions.get(),
+            only_contiguous: self.only_contiguous,
         }
     }
 
@@ -2908,6 +3019,51 @@ impl ComposingNormalizer {
         Ok(ComposingNormalizer {
             decomposing_normalizer,
             canonical_compositions,
+            only_contiguous: false,
+        })
+    }
+
+    /// FCC constructor using compiled data.
+    ///
+    /// FCC (Fast C Contiguous) is like NFC, except that a combining mark only composes with
+    /// the preceding starter if no other combining mark is between them. The output is
+    /// in FCD form (see [`DecomposingNormalizerBorrowed::is_fcd`]), but not necessarily NFC.
+    ///
+    /// ✨ *Enabled with the `compiled_data` Cargo feature.*
+    ///
+    /// [📚 Help choosing a constructor](icu_provider::constructors)
+    #[cfg(feature = "compiled_data")]
+    pub const fn new_fcc() -> ComposingNormalizerBorrowed<'static> {
+        ComposingNormalizerBorrowed::new_fcc()
+    }
+
+    icu_provider::gen_buffer_data_constructors!(
+        () -> error: DataError,
+        functions: [
+            new_fcc: skip,
+            try_new_fcc_with_buffer_provider,
+            try_new_fcc_unstable,
+            Self,
+        ]
+    );
+
+    #[doc = icu_provider::gen_buffer_unstable_docs!(UNSTABLE, Self::new_fcc)]
+    pub fn try_new_fcc_unstable<D>(provider: &D) -> Result<Self, DataError>
+    where
+        D: DataProvider<NormalizerNfdDataV1>
+            + DataProvider<NormalizerNfdTablesV1>
+            + DataProvider<NormalizerNfcV1>
+            + ?Sized,
+    {
+        let decomposing_normalizer = DecomposingNormalizer::try_new_nfd_unstable(provider)?;
+
+        let canonical_compositions: DataPayload<NormalizerNfcV1> =
+            provider.load(Default::default())?.payload;
+
+        Ok(ComposingNormalizer {
+            decomposing_normalizer,
+            canonical_compositions,
+            only_contiguous: true,
         })
     }
 
@@ -2948,6 +3104,7 @@ impl ComposingNormalizer {
         Ok(ComposingNormalizer {
             decomposing_normalizer,
             canonical_compositions,
+            only_contiguous: false,
         })
     }
 
@@ -2970,6 +3127,7 @@ impl ComposingNormalizer {
         Ok(ComposingNormalizer {
             decomposing_normalizer,
             canonical_compositions,
+            only_contiguous: false,
         })
     }
 }
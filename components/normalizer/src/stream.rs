@@ -0,0 +1,378 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use crate::{
+    decomposition_starts_with_non_starter, ComposingNormalizerBorrowed,
+    DecomposingNormalizerBorrowed, BACKWARD_COMBINING_MARKER, IGNORABLE_MARKER,
+};
+#[cfg(any(feature = "utf8_iter", feature = "utf16_iter"))]
+use alloc::vec::Vec;
+use core::char::REPLACEMENT_CHARACTER;
+#[cfg(feature = "utf16_iter")]
+use utf16_iter::Utf16CharsEx;
+#[cfg(feature = "utf8_iter")]
+use utf8_iter::Utf8CharsEx;
+
+mod private {
+    pub trait Sealed {}
+}
+
+/// A normalizer that can normalize text given in chunks, see [`Utf8NormalizerStream`] and
+/// [`Utf16NormalizerStream`].
+///
+/// <div class="stab unstable">
+/// 🚫 This trait is sealed; it cannot be implemented by user code. If an API requests an item that implements this
+/// trait, please consider using a type from the implementors listed below.
+/// </div>
+pub trait StreamingNormalizer: private::Sealed {
+    /// Whether the text before `c` is normalized the same way regardless of `c` and the text
+    /// after it.
+    #[doc(hidden)]
+    fn is_stable_boundary(&self, c: char) -> bool;
+
+    /// Normalizes a slice of potentially-invalid UTF-8 into a `Write` sink.
+    #[doc(hidden)]
+    #[cfg(feature = "utf8_iter")]
+    fn write_normalized_utf8<W: core::fmt::Write + ?Sized>(
+        &self,
+        text: &[u8],
+        sink: &mut W,
+    ) -> core::fmt::Result;
+
+    /// Normalizes a slice of potentially-invalid UTF-16 into a `Write16` sink.
+    #[doc(hidden)]
+    #[cfg(feature = "utf16_iter")]
+    fn write_normalized_utf16<W: write16::Write16 + ?Sized>(
+        &self,
+        text: &[u16],
+        sink: &mut W,
+    ) -> core::fmt::Result;
+}
+
+/// Whether the text can be split before a character with the given trie value in the
+/// decomposition forms.
+///
+/// Canonical reordering does not move characters across a starter, so this is the case when
+/// the decomposition starts with a starter, as for Hangul syllables and for characters such as
+/// "é" that decompose. Ignorables are removed in UTS 46 processing, and the REPLACEMENT
+/// CHARACTER may be the start of a character that is cut off at the end of a chunk.
+fn decomposes_to_starter(c: char, trie_value: u32) -> bool {
+    c != REPLACEMENT_CHARACTER
+        && trie_value != IGNORABLE_MARKER
+        && !decomposition_starts_with_non_starter(trie_value)
+}
+
+impl<N: StreamingNormalizer + ?Sized> private::Sealed for &N {}
+
+impl<N: StreamingNormalizer + ?Sized> StreamingNormalizer for &N {
+    fn is_stable_boundary(&self, c: char) -> bool {
+        (**self).is_stable_boundary(c)
+    }
+
+    #[cfg(feature = "utf8_iter")]
+    fn write_normalized_utf8<W: core::fmt::Write + ?Sized>(
+        &self,
+        text: &[u8],
+        sink: &mut W,
+    ) -> core::fmt::Result {
+        (**self).write_normalized_utf8(text, sink)
+    }
+
+    #[cfg(feature = "utf16_iter")]
+    fn write_normalized_utf16<W: write16::Write16 + ?Sized>(
+        &self,
+        text: &[u16],
+        sink: &mut W,
+    ) -> core::fmt::Result {
+        (**self).write_normalized_utf16(text, sink)
+    }
+}
+
+impl private::Sealed for DecomposingNormalizerBorrowed<'_> {}
+
+impl StreamingNormalizer for DecomposingNormalizerBorrowed<'_> {
+    fn is_stable_boundary(&self, c: char) -> bool {
+        decomposes_to_starter(c, self.decompositions.trie.get32(u32::from(c)))
+    }
+
+    #[cfg(feature = "utf8_iter")]
+    fn write_normalized_utf8<W: core::fmt::Write + ?Sized>(
+        &self,
+        text: &[u8],
+        sink: &mut W,
+    ) -> core::fmt::Result {
+        self.normalize_utf8_to(text, sink)
+    }
+
+    #[cfg(feature = "utf16_iter")]
+    fn write_normalized_utf16<W: write16::Write16 + ?Sized>(
+        &self,
+        text: &[u16],
+        sink: &mut W,
+    ) -> core::fmt::Result {
+        self.normalize_utf16_to(text, sink)
+    }
+}
+
+impl private::Sealed for ComposingNormalizerBorrowed<'_> {}
+
+impl StreamingNormalizer for ComposingNormalizerBorrowed<'_> {
+    fn is_stable_boundary(&self, c: char) -> bool {
+        // In addition, the starter must not combine with a preceding starter. It then blocks
+        // the characters after it from combining with the text before it.
+        let trie_value = self
+            .decomposing_normalizer
+            .decompositions
+            .trie
+            .get32(u32::from(c));
+        decomposes_to_starter(c, trie_value) && trie_value & BACKWARD_COMBINING_MARKER == 0
+    }
+
+    #[cfg(feature = "utf8_iter")]
+    fn write_normalized_utf8<W: core::fmt::Write + ?Sized>(
+        &self,
+        text: &[u8],
+        sink: &mut W,
+    ) -> core::fmt::Result {
+        self.normalize_utf8_to(text, sink)
+    }
+
+    #[cfg(feature = "utf16_iter")]
+    fn write_normalized_utf16<W: write16::Write16 + ?Sized>(
+        &self,
+        text: &[u16],
+        sink: &mut W,
+    ) -> core::fmt::Result {
+        self.normalize_utf16_to(text, sink)
+    }
+}
+
+/// Normalizes potentially-invalid UTF-8 that is given in chunks.
+///
+/// The output is the same as when normalizing the concatenation of the chunks. It is written as
+/// soon as it no longer depends on the text that follows, so the stream only keeps the text
+/// after the last character whose decomposition starts with a starter that does not combine
+/// with the preceding text, which is usually a few bytes. A chunk may end in the middle of a
+/// character.
+///
+/// The pending text is not limited in length: a long run of combining marks, or of
+/// characters such as conjoining jamo that combine with the preceding character, is kept
+/// until it ends. Text in the Stream-Safe Text Format of UAX #15 has at most 30 combining
+/// marks in a row.
+///
+/// Ill-formed byte sequences are mapped to the REPLACEMENT CHARACTER according to the WHATWG
+/// Encoding Standard.
+///
+/// ✨ *Enabled with the `utf8_iter` Cargo feature.*
+///
+/// # Examples
+///
+/// ```
+/// use icu::normalizer::ComposingNormalizerBorrowed;
+/// use icu::normalizer::Utf8NormalizerStream;
+///
+/// let mut stream = Utf8NormalizerStream::new(ComposingNormalizerBorrowed::new_nfc());
+/// let mut normalized = String::new();
+///
+/// // The chunks split "ä" between the letter and the combining diaeresis, and split the
+/// // UTF-8 bytes of "ö".
+/// for chunk in [b"Ba".as_slice(), b"\xCC\x88r F", b"\xC3", b"\xB6o"] {
+///     stream.push(chunk, &mut normalized).unwrap();
+/// }
+/// stream.finish(&mut normalized).unwrap();
+///
+/// assert_eq!(normalized, "Bär Föo");
+/// ```
+#[cfg(feature = "utf8_iter")]
+#[derive(Debug)]
+pub struct Utf8NormalizerStream<N> {
+    normalizer: N,
+    pending: Vec<u8>,
+}
+
+#[cfg(feature = "utf8_iter")]
+impl<N: StreamingNormalizer> Utf8NormalizerStream<N> {
+    /// Creates a stream that normalizes text with the given normalizer.
+    pub fn new(normalizer: N) -> Self {
+        Self {
+            normalizer,
+            pending: Vec::new(),
+        }
+    }
+
+    /// Appends a chunk to the text, and writes the normalized text that is known after it into
+    /// the sink.
+    pub fn push<W: core::fmt::Write + ?Sized>(
+        &mut self,
+        chunk: &[u8],
+        sink: &mut W,
+    ) -> core::fmt::Result {
+        // The pending text has no stable boundary after its start, except possibly before a
+        // character that was cut off at its end, which has at most 3 bytes. Bytes of a complete
+        // character that are scanned on their own decode as the REPLACEMENT CHARACTER, which
+        // is never a stable boundary.
+        let start = self.pending.len().saturating_sub(3);
+        self.pending.extend_from_slice(chunk);
+
+        let mut chars = self.pending.get(start..).unwrap_or_default().chars();
+        let mut stable = 0;
+        loop {
+            let index = self.pending.len() - chars.as_slice().len();
+            let Some(c) = chars.next() else {
+                break;
+            };
+            if index != 0 && self.normalizer.is_stable_boundary(c) {
+                stable = index;
+            }
+        }
+
+        let (done, _) = self.pending.split_at(stable);
+        self.normalizer.write_normalized_utf8(done, sink)?;
+        self.pending.drain(..stable);
+        Ok(())
+    }
+
+    /// Ends the text, and writes the rest of the normalized text into the sink.
+    pub fn finish<W: core::fmt::Write + ?Sized>(self, sink: &mut W) -> core::fmt::Result {
+        self.normalizer.write_normalized_utf8(&self.pending, sink)
+    }
+
+    /// Returns the text that has been kept because its normalization depends on the text that
+    /// follows.
+    pub fn pending(&self) -> &[u8] {
+        &self.pending
+    }
+
+    /// Normalizes the rest of the text from a reader into a writer.
+    ///
+    /// The text is read in chunks, so the whole text is never kept in memory.
+    ///
+    /// ✨ *Enabled with the `std` Cargo feature.*
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu::normalizer::DecomposingNormalizerBorrowed;
+    /// use icu::normalizer::Utf8NormalizerStream;
+    ///
+    /// let mut normalized = Vec::new();
+    /// Utf8NormalizerStream::new(DecomposingNormalizerBorrowed::new_nfd())
+    ///     .normalize_read_to(&mut "Bär".as_bytes(), &mut normalized)
+    ///     .unwrap();
+    ///
+    /// assert_eq!(normalized, "Ba\u{0308}r".as_bytes());
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn normalize_read_to<R: std::io::Read + ?Sized, W: std::io::Write + ?Sized>(
+        mut self,
+        reader: &mut R,
+        sink: &mut W,
+    ) -> std::io::Result<()> {
+        let mut buffer = [0; 8192];
+        let mut normalized = alloc::string::String::new();
+        loop {
+            let chunk = match reader.read(&mut buffer) {
+                Ok(0) => break,
+                Ok(len) => buffer.get(..len).unwrap_or_default(),
+                Err(e) if e.kind() == std::io::ErrorKind::Interrupted => continue,
+                Err(e) => return Err(e),
+            };
+            self.push(chunk, &mut normalized).map_err(fmt_error)?;
+            sink.write_all(normalized.as_bytes())?;
+            normalized.clear();
+        }
+        self.finish(&mut normalized).map_err(fmt_error)?;
+        sink.write_all(normalized.as_bytes())
+    }
+}
+
+/// Converts an error of a `fmt::Write` sink to an I/O error.
+#[cfg(all(feature = "utf8_iter", feature = "std"))]
+fn fmt_error(_: core::fmt::Error) -> std::io::Error {
+    std::io::Error::other("normalization failed")
+}
+
+/// Normalizes potentially-invalid UTF-16 that is given in chunks.
+///
+/// This is the same as [`Utf8NormalizerStream`], except that a chunk may end between the two
+/// halves of a surrogate pair. Unpaired surrogates are mapped to the REPLACEMENT CHARACTER.
+///
+/// ✨ *Enabled with the `utf16_iter` Cargo feature.*
+///
+/// # Examples
+///
+/// ```
+/// use icu::normalizer::ComposingNormalizerBorrowed;
+/// use icu::normalizer::Utf16NormalizerStream;
+///
+/// let mut stream = Utf16NormalizerStream::new(ComposingNormalizerBorrowed::new_nfc());
+/// let mut normalized: Vec<u16> = Vec::new();
+///
+/// let text: Vec<u16> = "Ba\u{0308}r".encode_utf16().collect();
+/// for chunk in text.chunks(2) {
+///     stream.push(chunk, &mut normalized).unwrap();
+/// }
+/// stream.finish(&mut normalized).unwrap();
+///
+/// assert_eq!(String::from_utf16(&normalized).unwrap(), "Bär");
+/// ```
+#[cfg(feature = "utf16_iter")]
+#[derive(Debug)]
+pub struct Utf16NormalizerStream<N> {
+    normalizer: N,
+    pending: Vec<u16>,
+}
+
+#[cfg(feature = "utf16_iter")]
+impl<N: StreamingNormalizer> Utf16NormalizerStream<N> {
+    /// Creates a stream that normalizes text with the given normalizer.
+    pub fn new(normalizer: N) -> Self {
+        Self {
+            normalizer,
+            pending: Vec::new(),
+        }
+    }
+
+    /// Appends a chunk to the text, and writes the normalized text that is known after it into
+    /// the sink.
+    pub fn push<W: write16::Write16 + ?Sized>(
+        &mut self,
+        chunk: &[u16],
+        sink: &mut W,
+    ) -> core::fmt::Result {
+        // A trailing lead surrogate is decoded as the REPLACEMENT CHARACTER, which is never a
+        // stable boundary, so it is kept until the next chunk. Apart from it, the pending text
+        // has no stable boundary after its start.
+        let start = self.pending.len().saturating_sub(1);
+        self.pending.extend_from_slice(chunk);
+
+        let mut chars = self.pending.get(start..).unwrap_or_default().chars();
+        let mut stable = 0;
+        loop {
+            let index = self.pending.len() - chars.as_slice().len();
+            let Some(c) = chars.next() else {
+                break;
+            };
+            if index != 0 && self.normalizer.is_stable_boundary(c) {
+                stable = index;
+            }
+        }
+
+        let (done, _) = self.pending.split_at(stable);
+        self.normalizer.write_normalized_utf16(done, sink)?;
+        self.pending.drain(..stable);
+        Ok(())
+    }
+
+    /// Ends the text, and writes the rest of the normalized text into the sink.
+    pub fn finish<W: write16::Write16 + ?Sized>(self, sink: &mut W) -> core::fmt::Result {
+        self.normalizer.write_normalized_utf16(&self.pending, sink)
+    }
+
+    /// Returns the text that has been kept because its normalization depends on the text that
+    /// follows.
+    pub fn pending(&self) -> &[u16] {
+        &self.pending
+    }
+}
@@ -5,8 +5,12 @@
 //! Bundles the part of UTS 46 that makes sense to implement as a
 //! normalization.
 //!
-//! This is meant to be used as a building block of an UTS 46
+//! [`Uts46Mapper`] is meant to be used as a building block of an UTS 46
 //! implementation, such as the `idna` crate.
+//!
+//! With the `icu_properties` Cargo feature, `Uts46Processor` builds the
+//! complete ToASCII and ToUnicode operations on top of it: label splitting,
+//! Punycode, and the hyphen, CONTEXTJ, CONTEXTO, and Bidi checks.
 
 use crate::ComposingNormalizer;
 use crate::ComposingNormalizerBorrowed;
@@ -17,6 +21,16 @@ use crate::NormalizerUts46DataV1;
 use icu_provider::DataError;
 use icu_provider::DataProvider;
 
+#[cfg(feature = "icu_properties")]
+mod processing;
+#[cfg(feature = "icu_properties")]
+mod punycode;
+
+#[cfg(feature = "icu_properties")]
+pub use processing::{
+    Uts46Error, Uts46Errors, Uts46Options, Uts46Processor, Uts46ProcessorBorrowed,
+};
+
 // Implementation note: Despite merely wrapping a `ComposingNormalizer`,
 // having a `Uts46Mapper` serves two purposes:
 //
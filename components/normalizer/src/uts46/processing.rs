@@ -0,0 +1,630 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! The complete UTS 46 processing of domain names: the "Processing",
+//! "ToASCII", and "ToUnicode" sections of UTS 46, built on top of the
+//! mapping and normalization of [`Uts46MapperBorrowed`].
+
+use super::punycode;
+use super::{Uts46Mapper, Uts46MapperBorrowed};
+use crate::provider::{
+    NormalizerNfcV1, NormalizerNfdTablesV1, NormalizerNfkdTablesV1, NormalizerUts46DataV1,
+};
+use alloc::string::String;
+use alloc::vec::Vec;
+use icu_properties::props::{
+    BidiClass, CanonicalCombiningClass, GeneralCategory, GeneralCategoryGroup, JoiningType, Script,
+};
+use icu_properties::provider::{
+    PropertyEnumBidiClassV1, PropertyEnumCanonicalCombiningClassV1, PropertyEnumGeneralCategoryV1,
+    PropertyEnumJoiningTypeV1, PropertyEnumScriptV1,
+};
+use icu_properties::{CodePointMapData, CodePointMapDataBorrowed};
+use icu_provider::prelude::*;
+
+const ZWNJ: char = '\u{200C}';
+const ZWJ: char = '\u{200D}';
+
+/// The maximum length of a label in bytes, per RFC 1034.
+const MAX_LABEL_LENGTH: usize = 63;
+/// The maximum length of a domain name in bytes, without the root label.
+const MAX_DOMAIN_LENGTH: usize = 253;
+
+/// The options of UTS 46 processing.
+///
+/// The defaults are the options that the UTS 46 conformance tests use:
+/// nontransitional processing with all checks enabled except for the
+/// CONTEXTO rules, which are not part of UTS 46. The WHATWG URL Standard
+/// disables `check_hyphens`, `use_std3_ascii_rules`, and
+/// `verify_dns_length`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct Uts46Options {
+    /// Whether to map the deviation characters ß, ς, ZWJ, and ZWNJ as
+    /// in IDNA2003.
+    ///
+    /// Transitional processing is deprecated, and none of Firefox, Safari,
+    /// or Chrome use it.
+    ///
+    /// Default is `false`.
+    pub transitional: bool,
+    /// Whether labels may not begin or end with a hyphen or contain
+    /// hyphens in both the third and fourth positions. When `false`,
+    /// decoded labels may still not begin with `xn--`.
+    ///
+    /// Default is `true`.
+    pub check_hyphens: bool,
+    /// Whether labels of domain names that contain right-to-left characters
+    /// have to satisfy the Bidi rule of RFC 5893.
+    ///
+    /// Default is `true`.
+    pub check_bidi: bool,
+    /// Whether ZWJ and ZWNJ have to satisfy the CONTEXTJ rules of
+    /// RFC 5892, Appendix A.
+    ///
+    /// Default is `true`.
+    pub check_joiners: bool,
+    /// Whether the characters with CONTEXTO rules in RFC 5892, Appendix A,
+    /// such as U+00B7 MIDDLE DOT, have to satisfy them.
+    ///
+    /// Default is `false`.
+    pub check_context_o: bool,
+    /// Whether labels may only contain the ASCII characters a-z, 0-9,
+    /// and hyphen.
+    ///
+    /// Default is `true`.
+    pub use_std3_ascii_rules: bool,
+    /// Whether [`Uts46ProcessorBorrowed::to_ascii`] checks the lengths of the
+    /// domain name and its labels against the limits of DNS.
+    ///
+    /// Default is `true`.
+    pub verify_dns_length: bool,
+}
+
+impl Default for Uts46Options {
+    fn default() -> Self {
+        Self {
+            transitional: false,
+            check_hyphens: true,
+            check_bidi: true,
+            check_joiners: true,
+            check_context_o: false,
+            use_std3_ascii_rules: true,
+            verify_dns_length: true,
+        }
+    }
+}
+
+/// An error found in a label or a domain name by UTS 46 processing.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, displaydoc::Display)]
+#[non_exhaustive]
+pub enum Uts46Error {
+    /// A label that starts with `xn--` is not valid Punycode, or decodes to
+    /// an empty or all-ASCII label.
+    #[displaydoc("Invalid Punycode")]
+    Punycode,
+    /// A label contains a character that is not valid: a disallowed
+    /// character, a full stop in a decoded label, a decoded label that is
+    /// not in mapped and normalized form, or, with
+    /// [`use_std3_ascii_rules`](Uts46Options::use_std3_ascii_rules),
+    /// an ASCII character other than a-z, 0-9, and hyphen.
+    #[displaydoc("Invalid character")]
+    InvalidCharacter,
+    /// A label contains hyphens in both the third and fourth positions.
+    #[displaydoc("Hyphens in the third and fourth positions")]
+    HyphenInThirdAndFourthPositions,
+    /// A label begins or ends with a hyphen.
+    #[displaydoc("Leading or trailing hyphen")]
+    LeadingOrTrailingHyphen,
+    /// A decoded label begins with `xn--`.
+    #[displaydoc("Decoded label begins with xn--")]
+    AcePrefix,
+    /// A label begins with a combining mark.
+    #[displaydoc("Leading combining mark")]
+    LeadingCombiningMark,
+    /// A ZWJ or ZWNJ does not satisfy the CONTEXTJ rules.
+    #[displaydoc("Invalid joiner context")]
+    ContextJ,
+    /// A character does not satisfy its CONTEXTO rule.
+    #[displaydoc("Invalid character context")]
+    ContextO,
+    /// A label does not satisfy the Bidi rule.
+    #[displaydoc("Bidi rule violation")]
+    Bidi,
+    /// A label or the domain name is empty or too long for DNS.
+    #[displaydoc("Invalid DNS length")]
+    DnsLength,
+}
+
+impl core::error::Error for Uts46Error {}
+
+/// The errors found by UTS 46 processing of a domain name, by label.
+#[derive(Debug, Clone, PartialEq, Eq, displaydoc::Display)]
+#[displaydoc("Invalid domain name")]
+#[non_exhaustive]
+pub struct Uts46Errors {
+    /// The first error found in each label, in order, or `None` for the
+    /// labels that are valid.
+    pub labels: Vec<Option<Uts46Error>>,
+    /// The error that concerns the domain name as a whole, if any.
+    pub domain: Option<Uts46Error>,
+}
+
+impl core::error::Error for Uts46Errors {}
+
+impl Uts46Errors {
+    fn into_result(self) -> Result<(), Self> {
+        if self.domain.is_none() && self.labels.iter().all(Option::is_none) {
+            Ok(())
+        } else {
+            Err(self)
+        }
+    }
+}
+
+/// A borrowed version of a processor that converts domain names with
+/// the ToASCII and ToUnicode operations of [UTS 46].
+///
+/// In addition to the mapping and normalization of [`Uts46MapperBorrowed`],
+/// this splits the domain name into labels, decodes and encodes Punycode,
+/// and checks the validity criteria of UTS 46, including the CONTEXTJ
+/// and Bidi rules.
+///
+/// Disallowed characters are replaced with U+FFFD in the output of
+/// [`Self::to_unicode`].
+///
+/// ✨ *Enabled with the `icu_properties` Cargo feature.*
+///
+/// # Examples
+///
+/// ```
+/// use icu::normalizer::uts46::{Uts46Error, Uts46ProcessorBorrowed};
+///
+/// let processor = Uts46ProcessorBorrowed::new();
+///
+/// assert_eq!(
+///     processor.to_ascii("Bücher.example", Default::default()),
+///     Ok("xn--bcher-kva.example".into())
+/// );
+///
+/// let (unicode, result) =
+///     processor.to_unicode("xn--bcher-kva.example", Default::default());
+/// assert_eq!(unicode, "bücher.example");
+/// assert!(result.is_ok());
+///
+/// let errors = processor
+///     .to_ascii("-bücher.xn--ab!.example", Default::default())
+///     .unwrap_err();
+/// assert_eq!(
+///     errors.labels,
+///     [
+///         Some(Uts46Error::LeadingOrTrailingHyphen),
+///         Some(Uts46Error::Punycode),
+///         None
+///     ]
+/// );
+/// ```
+///
+/// [UTS 46]: https://www.unicode.org/reports/tr46/
+#[derive(Debug)]
+pub struct Uts46ProcessorBorrowed<'a> {
+    mapper: Uts46MapperBorrowed<'a>,
+    general_category: CodePointMapDataBorrowed<'a, GeneralCategory>,
+    canonical_combining_class: CodePointMapDataBorrowed<'a, CanonicalCombiningClass>,
+    bidi_class: CodePointMapDataBorrowed<'a, BidiClass>,
+    joining_type: CodePointMapDataBorrowed<'a, JoiningType>,
+    script: CodePointMapDataBorrowed<'a, Script>,
+}
+
+#[cfg(feature = "compiled_data")]
+impl Default for Uts46ProcessorBorrowed<'static> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Uts46ProcessorBorrowed<'static> {
+    /// Cheaply converts a [`Uts46ProcessorBorrowed<'static>`] into a [`Uts46Processor`].
+    ///
+    /// Note: Due to branching and indirection, using [`Uts46Processor`] might inhibit some
+    /// compile-time optimizations that are possible with [`Uts46ProcessorBorrowed`].
+    pub const fn static_to_owned(self) -> Uts46Processor {
+        Uts46Processor {
+            mapper: self.mapper.static_to_owned(),
+            general_category: self.general_category.static_to_owned(),
+            canonical_combining_class: self.canonical_combining_class.static_to_owned(),
+            bidi_class: self.bidi_class.static_to_owned(),
+            joining_type: self.joining_type.static_to_owned(),
+            script: self.script.static_to_owned(),
+        }
+    }
+
+    /// Construct with compiled data.
+    ///
+    /// ✨ *Enabled with the `compiled_data` Cargo feature.*
+    ///
+    /// [📚 Help choosing a constructor](icu_provider::constructors)
+    #[cfg(feature = "compiled_data")]
+    pub const fn new() -> Self {
+        Self {
+            mapper: Uts46MapperBorrowed::new(),
+            general_category: CodePointMapData::new(),
+            canonical_combining_class: CodePointMapData::new(),
+            bidi_class: CodePointMapData::new(),
+            joining_type: CodePointMapData::new(),
+            script: CodePointMapData::new(),
+        }
+    }
+}
+
+impl Uts46ProcessorBorrowed<'_> {
+    /// Converts a domain name to its ASCII form with the ToASCII operation
+    /// of UTS 46, returning the errors by label if any label is invalid.
+    pub fn to_ascii(&self, domain: &str, options: Uts46Options) -> Result<String, Uts46Errors> {
+        let (labels, mut errors) = self.process(domain, options);
+        let mut ascii_labels = Vec::with_capacity(labels.len());
+        for (label, error) in labels.into_iter().zip(errors.labels.iter_mut()) {
+            if label.is_ascii() {
+                ascii_labels.push(label);
+            } else if let Some(encoded) = punycode::encode(&label) {
+                ascii_labels.push(alloc::format!("xn--{encoded}"));
+            } else {
+                error.get_or_insert(Uts46Error::Punycode);
+                ascii_labels.push(label);
+            }
+        }
+        if options.verify_dns_length {
+            // The root label and its dot don't count.
+            let dns_labels = match ascii_labels.split_last() {
+                Some((root, rest)) if root.is_empty() && !rest.is_empty() => rest,
+                _ => ascii_labels.as_slice(),
+            };
+            for (label, error) in dns_labels.iter().zip(errors.labels.iter_mut()) {
+                if label.is_empty() || label.len() > MAX_LABEL_LENGTH {
+                    error.get_or_insert(Uts46Error::DnsLength);
+                }
+            }
+            let domain_length = dns_labels.iter().map(String::len).sum::<usize>()
+                + dns_labels.len().saturating_sub(1);
+            if domain_length == 0 || domain_length > MAX_DOMAIN_LENGTH {
+                errors.domain = Some(Uts46Error::DnsLength);
+            }
+        }
+        errors.into_result()?;
+        Ok(ascii_labels.join("."))
+    }
+
+    /// Converts a domain name to its Unicode form with the ToUnicode
+    /// operation of UTS 46.
+    ///
+    /// The converted domain name is returned even if some labels are
+    /// invalid, together with the errors by label.
+    pub fn to_unicode(
+        &self,
+        domain: &str,
+        options: Uts46Options,
+    ) -> (String, Result<(), Uts46Errors>) {
+        let (labels, errors) = self.process(domain, options);
+        (labels.join("."), errors.into_result())
+    }
+
+    /// The "Processing" section of UTS 46: maps, normalizes, and splits
+    /// the domain name, then decodes and validates each label.
+    fn process(&self, domain: &str, options: Uts46Options) -> (Vec<String>, Uts46Errors) {
+        let mapped: String = if options.transitional {
+            let mut deviations_mapped = String::with_capacity(domain.len());
+            for c in domain.chars() {
+                match c {
+                    'ß' => deviations_mapped.push_str("ss"),
+                    'ς' => deviations_mapped.push('σ'),
+                    ZWJ | ZWNJ => {}
+                    c => deviations_mapped.push(c),
+                }
+            }
+            self.mapper
+                .map_normalize(deviations_mapped.chars())
+                .collect()
+        } else {
+            self.mapper.map_normalize(domain.chars()).collect()
+        };
+
+        let mut labels = Vec::new();
+        let mut errors = Uts46Errors {
+            labels: Vec::new(),
+            domain: None,
+        };
+        for label in mapped.split('.') {
+            let (label, error) = match label.strip_prefix("xn--") {
+                Some(encoded) => match punycode::decode(encoded) {
+                    Some(decoded) if !decoded.is_ascii() => {
+                        let error = self.check_label(&decoded, true, options);
+                        (decoded, error)
+                    }
+                    _ => (String::from(label), Some(Uts46Error::Punycode)),
+                },
+                None => (String::from(label), self.check_label(label, false, options)),
+            };
+            labels.push(label);
+            errors.labels.push(error);
+        }
+
+        if options.check_bidi && labels.iter().any(|label| self.is_rtl(label)) {
+            for (label, error) in labels.iter().zip(errors.labels.iter_mut()) {
+                if error.is_none() && !self.check_bidi(label) {
+                    *error = Some(Uts46Error::Bidi);
+                }
+            }
+        }
+
+        (labels, errors)
+    }
+
+    /// The "Validity Criteria" section of UTS 46, except for the Bidi rule,
+    /// which depends on the other labels.
+    fn check_label(&self, label: &str, decoded: bool, options: Uts46Options) -> Option<Uts46Error> {
+        // Empty labels are checked by VerifyDnsLength, if at all.
+        let first = label.chars().next()?;
+        let valid = if decoded {
+            !label.contains('.')
+                && self
+                    .mapper
+                    .normalize_validate(label.chars())
+                    .eq(label.chars())
+        } else {
+            !label.contains('\u{FFFD}')
+        };
+        if !valid
+            || options.use_std3_ascii_rules
+                && label.chars().any(|c| {
+                    c.is_ascii() && !(c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-')
+                })
+        {
+            return Some(Uts46Error::InvalidCharacter);
+        }
+        if options.check_hyphens {
+            let mut chars = label.chars();
+            if chars.nth(2) == Some('-') && chars.next() == Some('-') {
+                return Some(Uts46Error::HyphenInThirdAndFourthPositions);
+            }
+            if label.starts_with('-') || label.ends_with('-') {
+                return Some(Uts46Error::LeadingOrTrailingHyphen);
+            }
+        } else if label.starts_with("xn--") {
+            return Some(Uts46Error::AcePrefix);
+        }
+        if GeneralCategoryGroup::Mark.contains(self.general_category.get(first)) {
+            return Some(Uts46Error::LeadingCombiningMark);
+        }
+        if options.check_joiners && !self.check_joiners(label) {
+            return Some(Uts46Error::ContextJ);
+        }
+        if options.check_context_o && !self.check_context_o(label) {
+            return Some(Uts46Error::ContextO);
+        }
+        None
+    }
+
+    /// The CONTEXTJ rules of RFC 5892, Appendix A.1 and A.2.
+    fn check_joiners(&self, label: &str) -> bool {
+        for (index, c) in label.char_indices() {
+            if c != ZWNJ && c != ZWJ {
+                continue;
+            }
+            let before = label.get(..index).unwrap_or_default();
+            let after = label.get(index + c.len_utf8()..).unwrap_or_default();
+            if before.chars().next_back().is_some_and(|b| {
+                self.canonical_combining_class.get(b) == CanonicalCombiningClass::Virama
+            }) {
+                continue;
+            }
+            if c == ZWJ {
+                return false;
+            }
+            let joining_type = |c| self.joining_type.get(c);
+            let joins_before = before
+                .chars()
+                .rev()
+                .map(joining_type)
+                .find(|&t| t != JoiningType::Transparent)
+                .is_some_and(|t| t == JoiningType::LeftJoining || t == JoiningType::DualJoining);
+            let joins_after = after
+                .chars()
+                .map(joining_type)
+                .find(|&t| t != JoiningType::Transparent)
+                .is_some_and(|t| t == JoiningType::RightJoining || t == JoiningType::DualJoining);
+            if !(joins_before && joins_after) {
+                return false;
+            }
+        }
+        true
+    }
+
+    /// The CONTEXTO rules of RFC 5892, Appendix A.3 to A.9.
+    fn check_context_o(&self, label: &str) -> bool {
+        const ARABIC_INDIC_DIGITS: core::ops::RangeInclusive<char> = '\u{0660}'..='\u{0669}';
+        const EXTENDED_ARABIC_INDIC_DIGITS: core::ops::RangeInclusive<char> =
+            '\u{06F0}'..='\u{06F9}';
+        label.char_indices().all(|(index, c)| {
+            let before = label.get(..index).unwrap_or_default();
+            let after = label.get(index + c.len_utf8()..).unwrap_or_default();
+            match c {
+                // MIDDLE DOT
+                '\u{00B7}' => before.ends_with('l') && after.starts_with('l'),
+                // GREEK LOWER NUMERAL SIGN (KERAIA)
+                '\u{0375}' => after
+                    .chars()
+                    .next()
+                    .is_some_and(|a| self.script.get(a) == Script::Greek),
+                // HEBREW PUNCTUATION GERESH and GERSHAYIM
+                '\u{05F3}' | '\u{05F4}' => before
+                    .chars()
+                    .next_back()
+                    .is_some_and(|b| self.script.get(b) == Script::Hebrew),
+                // KATAKANA MIDDLE DOT
+                '\u{30FB}' => label.chars().any(|c| {
+                    matches!(
+                        self.script.get(c),
+                        Script::Hiragana | Script::Katakana | Script::Han
+                    )
+                }),
+                c if ARABIC_INDIC_DIGITS.contains(&c) => label
+                    .chars()
+                    .all(|c| !EXTENDED_ARABIC_INDIC_DIGITS.contains(&c)),
+                c if EXTENDED_ARABIC_INDIC_DIGITS.contains(&c) => {
+                    label.chars().all(|c| !ARABIC_INDIC_DIGITS.contains(&c))
+                }
+                _ => true,
+            }
+        })
+    }
+
+    /// Whether the label makes the domain name a Bidi domain name.
+    fn is_rtl(&self, label: &str) -> bool {
+        label.chars().any(|c| {
+            matches!(
+                self.bidi_class.get(c),
+                BidiClass::RightToLeft | BidiClass::ArabicLetter | BidiClass::ArabicNumber
+            )
+        })
+    }
+
+    /// The Bidi rule of RFC 5893, Section 2.
+    fn check_bidi(&self, label: &str) -> bool {
+        use BidiClass as B;
+        let mut classes = label.chars().map(|c| self.bidi_class.get(c));
+        let Some(first) = classes.next() else {
+            return true;
+        };
+        let last = classes
+            .clone()
+            .rev()
+            .find(|&class| class != B::NonspacingMark)
+            .unwrap_or(first);
+        match first {
+            B::RightToLeft | B::ArabicLetter => {
+                let mut has_en = false;
+                let mut has_an = false;
+                for class in classes {
+                    match class {
+                        B::EuropeanNumber => has_en = true,
+                        B::ArabicNumber => has_an = true,
+                        B::RightToLeft
+                        | B::ArabicLetter
+                        | B::EuropeanSeparator
+                        | B::CommonSeparator
+                        | B::EuropeanTerminator
+                        | B::OtherNeutral
+                        | B::BoundaryNeutral
+                        | B::NonspacingMark => {}
+                        _ => return false,
+                    }
+                }
+                !(has_en && has_an)
+                    && matches!(
+                        last,
+                        B::RightToLeft | B::ArabicLetter | B::EuropeanNumber | B::ArabicNumber
+                    )
+            }
+            B::LeftToRight => {
+                classes.all(|class| {
+                    matches!(
+                        class,
+                        B::LeftToRight
+                            | B::EuropeanNumber
+                            | B::EuropeanSeparator
+                            | B::CommonSeparator
+                            | B::EuropeanTerminator
+                            | B::OtherNeutral
+                            | B::BoundaryNeutral
+                            | B::NonspacingMark
+                    )
+                }) && matches!(last, B::LeftToRight | B::EuropeanNumber)
+            }
+            _ => false,
+        }
+    }
+}
+
+/// A processor that converts domain names with the ToASCII and ToUnicode
+/// operations of [UTS 46].
+///
+/// See [`Uts46ProcessorBorrowed`] for details.
+///
+/// ✨ *Enabled with the `icu_properties` Cargo feature.*
+///
+/// [UTS 46]: https://www.unicode.org/reports/tr46/
+#[derive(Debug)]
+pub struct Uts46Processor {
+    mapper: Uts46Mapper,
+    general_category: CodePointMapData<GeneralCategory>,
+    canonical_combining_class: CodePointMapData<CanonicalCombiningClass>,
+    bidi_class: CodePointMapData<BidiClass>,
+    joining_type: CodePointMapData<JoiningType>,
+    script: CodePointMapData<Script>,
+}
+
+#[cfg(feature = "compiled_data")]
+impl Default for Uts46Processor {
+    fn default() -> Self {
+        Self::new().static_to_owned()
+    }
+}
+
+impl Uts46Processor {
+    /// Constructs a borrowed version of this type for more efficient querying.
+    pub fn as_borrowed(&self) -> Uts46ProcessorBorrowed<'_> {
+        Uts46ProcessorBorrowed {
+            mapper: self.mapper.as_borrowed(),
+            general_category: self.general_category.as_borrowed(),
+            canonical_combining_class: self.canonical_combining_class.as_borrowed(),
+            bidi_class: self.bidi_class.as_borrowed(),
+            joining_type: self.joining_type.as_borrowed(),
+            script: self.script.as_borrowed(),
+        }
+    }
+
+    /// Construct with compiled data.
+    ///
+    /// ✨ *Enabled with the `compiled_data` Cargo feature.*
+    ///
+    /// [📚 Help choosing a constructor](icu_provider::constructors)
+    #[cfg(feature = "compiled_data")]
+    #[expect(clippy::new_ret_no_self)]
+    pub const fn new() -> Uts46ProcessorBorrowed<'static> {
+        Uts46ProcessorBorrowed::new()
+    }
+
+    icu_provider::gen_buffer_data_constructors!(() -> error: DataError,
+        functions: [
+            new: skip,
+            try_new_with_buffer_provider,
+            try_new_unstable,
+            Self,
+        ]
+    );
+
+    #[doc = icu_provider::gen_buffer_unstable_docs!(UNSTABLE, Self::new)]
+    pub fn try_new_unstable<D>(provider: &D) -> Result<Self, DataError>
+    where
+        D: DataProvider<NormalizerUts46DataV1>
+            + DataProvider<NormalizerNfdTablesV1>
+            + DataProvider<NormalizerNfkdTablesV1>
+            + DataProvider<NormalizerNfcV1>
+            + DataProvider<PropertyEnumGeneralCategoryV1>
+            + DataProvider<PropertyEnumCanonicalCombiningClassV1>
+            + DataProvider<PropertyEnumBidiClassV1>
+            + DataProvider<PropertyEnumJoiningTypeV1>
+            + DataProvider<PropertyEnumScriptV1>
+            + ?Sized,
+    {
+        Ok(Self {
+            mapper: Uts46Mapper::try_new(provider)?,
+            general_category: CodePointMapData::try_new_unstable(provider)?,
+            canonical_combining_class: CodePointMapData::try_new_unstable(provider)?,
+            bidi_class: CodePointMapData::try_new_unstable(provider)?,
+            joining_type: CodePointMapData::try_new_unstable(provider)?,
+            script: CodePointMapData::try_new_unstable(provider)?,
+        })
+    }
+}
@@ -11,10 +11,15 @@ use icu_normalizer::properties::CanonicalDecompositionBorrowed;
 use icu_normalizer::properties::Decomposed;
 use icu_normalizer::uts46::Uts46Mapper;
 use icu_normalizer::uts46::Uts46MapperBorrowed;
+#[cfg(feature = "icu_properties")]
+use icu_normalizer::uts46::{Uts46Error, Uts46Options, Uts46Processor, Uts46ProcessorBorrowed};
 use icu_normalizer::ComposingNormalizer;
 use icu_normalizer::ComposingNormalizerBorrowed;
 use icu_normalizer::DecomposingNormalizer;
 use icu_normalizer::DecomposingNormalizerBorrowed;
+use icu_normalizer::StreamingNormalizer;
+use icu_normalizer::Utf16NormalizerStream;
+use icu_normalizer::Utf8NormalizerStream;
 
 #[test]
 fn test_nfd_basic() {
@@ -168,6 +173,90 @@ fn test_nfkc_owned() {
     assert_eq!(normalizer.normalize("\u{0345}"), "\u{0345}"); // Iota subscript
 }
 
+#[test]
+fn test_fcc_basic() {
+    let normalizer = ComposingNormalizerBorrowed::new_fcc();
+    assert_eq!(normalizer.normalize("a\u{0308}"), "ä");
+    assert_eq!(normalizer.normalize("e\u{0323}\u{0302}"), "ệ");
+    assert_eq!(normalizer.normalize("e\u{0302}\u{0323}"), "ệ"); // reordered first
+    assert_eq!(normalizer.normalize("\u{2126}"), "Ω"); // ohm sign
+    assert_eq!(normalizer.normalize("ﬁ"), "ﬁ"); // ligature unchanged
+
+    // Discontiguous composition
+    assert_eq!(
+        ComposingNormalizerBorrowed::new_nfc().normalize("a\u{0327}\u{0301}"),
+        "á\u{0327}"
+    );
+    assert_eq!(
+        normalizer.normalize("a\u{0327}\u{0301}"),
+        "a\u{0327}\u{0301}"
+    );
+    assert_eq!(normalizer.normalize("á\u{0327}"), "a\u{0327}\u{0301}");
+    assert_eq!(
+        normalizer
+            .normalize_iter("á\u{0327}".chars())
+            .collect::<String>(),
+        "a\u{0327}\u{0301}"
+    );
+    assert!(normalizer.is_normalized("a\u{0327}\u{0301}"));
+    assert!(!normalizer.is_normalized("á\u{0327}"));
+}
+
+#[test]
+fn test_fcc_owned() {
+    let owned =
+        ComposingNormalizer::try_new_fcc_unstable(&icu_normalizer::provider::Baked).unwrap();
+    let normalizer = owned.as_borrowed();
+    assert_eq!(normalizer.normalize("a\u{0308}"), "ä");
+    assert_eq!(normalizer.normalize("e\u{0323}\u{0302}"), "ệ");
+    assert_eq!(normalizer.normalize("á\u{0327}"), "a\u{0327}\u{0301}");
+    assert_eq!(
+        normalizer.normalize_utf16(&"á\u{0327}".encode_utf16().collect::<Vec<_>>()),
+        "a\u{0327}\u{0301}".encode_utf16().collect::<Vec<_>>()
+    );
+    assert_eq!(
+        normalizer.normalize_utf8("á\u{0327}".as_bytes()),
+        "a\u{0327}\u{0301}"
+    );
+}
+
+#[test]
+fn test_is_fcd() {
+    let normalizer = DecomposingNormalizerBorrowed::new_nfd();
+    assert!(normalizer.is_fcd(""));
+    assert!(normalizer.is_fcd("abc"));
+    assert!(normalizer.is_fcd("a\u{0323}\u{0301}"));
+    assert!(!normalizer.is_fcd("a\u{0301}\u{0323}"));
+    assert!(normalizer.is_fcd("ạ\u{0301}"));
+    assert!(!normalizer.is_fcd("á\u{0323}")); // trailing acute before dot below
+    assert!(normalizer.is_fcd("\u{0F73}\u{0F72}")); // decomposes to U+0F71 U+0F72
+    assert!(!normalizer.is_fcd("\u{0F73}\u{0F71}"));
+    assert!(normalizer.is_fcd("가\u{0301}"));
+    assert!(!normalizer.is_fcd_utf16(&[0x0301, 0x0323]));
+    assert!(normalizer.is_fcd_utf16(&[0x0323, 0xD800, 0x0301]));
+    assert!(!normalizer.is_fcd_utf8("a\u{0301}\u{0323}".as_bytes()));
+    assert!(normalizer.is_fcd_utf8(b"a\xFF\xCC\x81"));
+
+    // NFD and FCC output is always FCD.
+    let nfc = ComposingNormalizerBorrowed::new_nfc();
+    let fcc = ComposingNormalizerBorrowed::new_fcc();
+    for c in (0..0x3000)
+        .chain(0x1D100..0x1D200)
+        .filter_map(char::from_u32)
+    {
+        let text = [c, '\u{0327}', '\u{0301}'].iter().collect::<String>();
+        assert!(normalizer.is_fcd(&normalizer.normalize(&text)));
+        let composed = fcc.normalize(&text);
+        assert!(normalizer.is_fcd(&composed), "{c:?}");
+        assert_eq!(
+            fcc.normalize_iter(text.chars()).collect::<String>(),
+            composed
+        );
+        assert_eq!(normalizer.normalize(&composed), normalizer.normalize(&text));
+        assert_eq!(nfc.normalize(&composed), nfc.normalize(&text));
+    }
+}
+
 #[test]
 fn test_uts46_map_normalize() {
     let mapper = Uts46MapperBorrowed::new();
@@ -2081,3 +2170,450 @@ fn test_is_normalized_up_to() {
         0
     );
 }
+
+#[test]
+#[cfg(feature = "icu_properties")]
+fn test_uts46_to_ascii() {
+    let processor = Uts46ProcessorBorrowed::new();
+    let options = Uts46Options::default();
+    let to_ascii = |domain| processor.to_ascii(domain, options).unwrap();
+
+    assert_eq!(to_ascii("Bücher.example"), "xn--bcher-kva.example");
+    assert_eq!(
+        to_ascii("他们为什么不说中文"),
+        "xn--ihqwcrb4cv8a8dqg056pqjye"
+    );
+    assert_eq!(
+        to_ascii("ليهمابتكلموشعربي.com"),
+        "xn--mgbcah9ar9a4efegftvvn.com"
+    );
+    assert_eq!(to_ascii("xn--BCHER-KVA.Example"), "xn--bcher-kva.example");
+    assert_eq!(to_ascii("example。com"), "example.com"); // ideographic full stop
+    assert_eq!(to_ascii("example.com."), "example.com."); // root label
+    assert_eq!(to_ascii("\u{0915}\u{094D}\u{200D}"), "xn--11b6iy14e"); // ZWJ after virama
+
+    // Deviations
+    assert_eq!(to_ascii("faß.de"), "xn--fa-hia.de");
+    let mut transitional = options;
+    transitional.transitional = true;
+    assert_eq!(
+        processor.to_ascii("faß.de", transitional).unwrap(),
+        "fass.de"
+    );
+    assert_eq!(
+        processor.to_ascii("a\u{200C}b", transitional).unwrap(),
+        "ab"
+    );
+}
+
+#[test]
+#[cfg(feature = "icu_properties")]
+fn test_uts46_to_ascii_errors() {
+    let processor = Uts46ProcessorBorrowed::new();
+    let options = Uts46Options::default();
+    let mut lenient = options;
+    lenient.check_hyphens = false;
+    lenient.use_std3_ascii_rules = false;
+    lenient.verify_dns_length = false;
+    let errors = |domain, options| processor.to_ascii(domain, options).unwrap_err().labels;
+
+    assert_eq!(
+        errors("a_b.com", options),
+        [Some(Uts46Error::InvalidCharacter), None]
+    );
+    assert_eq!(processor.to_ascii("a_b.com", lenient).unwrap(), "a_b.com");
+    assert_eq!(
+        errors("a\u{061C}b", options),
+        [Some(Uts46Error::InvalidCharacter)]
+    );
+    assert_eq!(
+        errors("ab--c", options),
+        [Some(Uts46Error::HyphenInThirdAndFourthPositions)]
+    );
+    assert_eq!(
+        errors("-abc.def-", options),
+        [
+            Some(Uts46Error::LeadingOrTrailingHyphen),
+            Some(Uts46Error::LeadingOrTrailingHyphen)
+        ]
+    );
+    assert_eq!(processor.to_ascii("-abc", lenient).unwrap(), "-abc");
+    assert_eq!(
+        errors("xn--xn---ooa", lenient),
+        [Some(Uts46Error::AcePrefix)]
+    );
+    assert_eq!(
+        errors("\u{0308}a", options),
+        [Some(Uts46Error::LeadingCombiningMark)]
+    );
+    assert_eq!(errors("a\u{200D}b", options), [Some(Uts46Error::ContextJ)]);
+
+    // Punycode
+    assert_eq!(errors("xn--ab!", options), [Some(Uts46Error::Punycode)]);
+    assert_eq!(errors("xn--abc-", options), [Some(Uts46Error::Punycode)]);
+    assert_eq!(errors("xn--", options), [Some(Uts46Error::Punycode)]);
+    assert_eq!(
+        errors("xn--a-ccb", options),
+        [Some(Uts46Error::InvalidCharacter)]
+    ); // not NFC
+
+    // Bidi
+    assert_eq!(errors("\u{0627}b", options), [Some(Uts46Error::Bidi)]);
+    assert_eq!(
+        errors("0a.\u{05D0}", options),
+        [Some(Uts46Error::Bidi), None]
+    );
+    assert_eq!(processor.to_ascii("0a.b", options).unwrap(), "0a.b");
+
+    // CONTEXTO
+    let mut context_o = options;
+    context_o.check_context_o = true;
+    assert_eq!(
+        errors("a\u{00B7}b", context_o),
+        [Some(Uts46Error::ContextO)]
+    );
+    assert_eq!(
+        processor.to_ascii("l\u{00B7}l", context_o).unwrap(),
+        "xn--ll-0ea"
+    );
+    assert_eq!(
+        errors("\u{0661}\u{06F1}", context_o),
+        [Some(Uts46Error::ContextO)]
+    );
+
+    // DNS length
+    let long_label = "a".repeat(64);
+    assert_eq!(errors(&long_label, options), [Some(Uts46Error::DnsLength)]);
+    assert_eq!(
+        errors("a..b", options),
+        [None, Some(Uts46Error::DnsLength), None]
+    );
+    let long_domain = ["a".repeat(63).as_str(); 4].join(".");
+    let error = processor.to_ascii(&long_domain, options).unwrap_err();
+    assert_eq!(error.labels, [None; 4]);
+    assert_eq!(error.domain, Some(Uts46Error::DnsLength));
+    assert_eq!(
+        processor.to_ascii("", options).unwrap_err().domain,
+        Some(Uts46Error::DnsLength)
+    );
+}
+
+#[test]
+#[cfg(feature = "icu_properties")]
+fn test_uts46_to_unicode() {
+    let processor = Uts46Processor::default();
+    let processor = processor.as_borrowed();
+    let options = Uts46Options::default();
+
+    assert_eq!(
+        processor.to_unicode("xn--bcher-kva.xn--ihqwcrb4cv8a8dqg056pqjye", options),
+        ("bücher.他们为什么不说中文".into(), Ok(()))
+    );
+    assert_eq!(
+        processor.to_unicode("Bücher.example.", options),
+        ("bücher.example.".into(), Ok(()))
+    );
+
+    let (unicode, result) = processor.to_unicode("xn--ab!.Bücher.a\u{061C}", options);
+    assert_eq!(unicode, "xn--ab!.bücher.a\u{FFFD}");
+    assert_eq!(
+        result.unwrap_err().labels,
+        [
+            Some(Uts46Error::Punycode),
+            None,
+            Some(Uts46Error::InvalidCharacter)
+        ]
+    );
+}
+
+/// Decodes the `\uXXXX` and `\x{XXXX}` escapes of IdnaTestV2.txt.
+#[cfg(feature = "icu_properties")]
+fn unescape_idna_test(field: &str) -> String {
+    let mut unescaped = String::new();
+    let mut rest = field;
+    while let Some(index) = rest.find('\\') {
+        unescaped.push_str(&rest[..index]);
+        rest = &rest[index..];
+        let (hex, len) = if let Some(hex) = rest.strip_prefix("\\u") {
+            (&hex[..4], 6)
+        } else if let Some(hex) = rest.strip_prefix("\\x{") {
+            let end = hex.find('}').unwrap();
+            (&hex[..end], end + 4)
+        } else {
+            unescaped.push('\\');
+            rest = &rest[1..];
+            continue;
+        };
+        let c = char::from_u32(u32::from_str_radix(hex, 16).unwrap()).unwrap();
+        unescaped.push(c);
+        rest = &rest[len..];
+    }
+    unescaped.push_str(rest);
+    unescaped
+}
+
+/// Returns the errors that the status codes of IdnaTestV2.txt allow, or
+/// `None` if the field is blank.
+#[cfg(feature = "icu_properties")]
+fn parse_idna_test_status(field: &str) -> Option<Vec<Uts46Error>> {
+    let codes = field.strip_prefix('[')?.strip_suffix(']').unwrap();
+    Some(
+        codes
+            .split(',')
+            .map(str::trim)
+            .filter(|code| !code.is_empty())
+            // The validity criteria as numbered in UTS 46 for Unicode 16.0.0
+            .map(|code| match code {
+                "P1" | "V1" | "V5" | "V7" | "U1" => Uts46Error::InvalidCharacter,
+                "V2" => Uts46Error::HyphenInThirdAndFourthPositions,
+                "V3" => Uts46Error::LeadingOrTrailingHyphen,
+                "V4" => Uts46Error::AcePrefix,
+                "V6" => Uts46Error::LeadingCombiningMark,
+                "P4" | "A3" => Uts46Error::Punycode,
+                "A4_1" | "A4_2" | "X4_2" => Uts46Error::DnsLength,
+                "C1" | "C2" => Uts46Error::ContextJ,
+                "B1" | "B2" | "B3" | "B4" | "B5" | "B6" => Uts46Error::Bidi,
+                _ => panic!("Unknown status code {code}"),
+            })
+            .collect(),
+    )
+}
+
+/// Checks that the errors of a result are the ones that the expected status
+/// codes allow, by label.
+#[cfg(feature = "icu_properties")]
+fn check_idna_test_errors(
+    result: Result<(), icu_normalizer::uts46::Uts46Errors>,
+    expected: &[Uts46Error],
+    line: &str,
+) {
+    match result {
+        Ok(()) => assert!(expected.is_empty(), "expected {expected:?}: {line}"),
+        Err(errors) => {
+            assert!(!expected.is_empty(), "unexpected {errors:?}: {line}");
+            for error in errors.labels.iter().flatten().chain(&errors.domain) {
+                assert!(expected.contains(error), "unexpected {error:?}: {line}");
+            }
+        }
+    }
+}
+
+/// Compares a result of ToUnicode, in which disallowed characters are
+/// replaced with U+FFFD, to the expected value.
+#[cfg(feature = "icu_properties")]
+fn matches_idna_test_unicode(actual: &str, expected: &str) -> bool {
+    actual.chars().count() == expected.chars().count()
+        && actual
+            .chars()
+            .zip(expected.chars())
+            .all(|(a, e)| a == e || a == '\u{FFFD}')
+}
+
+#[test]
+#[cfg(feature = "icu_properties")]
+#[ignore = "needs IdnaTestV2.txt for Unicode 16.0.0 in tests/data, see tests/data/README.md"]
+fn test_uts46_conformance() {
+    let processor = Uts46ProcessorBorrowed::new();
+    let nontransitional = Uts46Options::default();
+    let mut transitional = nontransitional;
+    transitional.transitional = true;
+
+    let path = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/data/IdnaTestV2.txt");
+    let data = std::fs::read_to_string(path).unwrap_or_else(|e| panic!("{path}: {e}"));
+    let mut count = 0;
+    for line in data.lines() {
+        let fields = line.split('#').next().unwrap();
+        if fields.trim().is_empty() {
+            continue;
+        }
+        let fields: Vec<&str> = fields.split(';').map(str::trim).collect();
+        let [source, to_unicode, to_unicode_status, to_ascii_n, to_ascii_n_status, to_ascii_t, to_ascii_t_status] =
+            fields[..]
+        else {
+            panic!("Wrong number of fields: {line}");
+        };
+
+        let source = unescape_idna_test(source);
+        let expected_unicode = match to_unicode {
+            "" => source.clone(),
+            unicode => unescape_idna_test(unicode),
+        };
+        let unicode_status = parse_idna_test_status(to_unicode_status).unwrap_or_default();
+        let expected_ascii_n = match to_ascii_n {
+            "" => expected_unicode.clone(),
+            ascii => unescape_idna_test(ascii),
+        };
+        let ascii_n_status =
+            parse_idna_test_status(to_ascii_n_status).unwrap_or_else(|| unicode_status.clone());
+        let expected_ascii_t = match to_ascii_t {
+            "" => expected_ascii_n.clone(),
+            ascii => unescape_idna_test(ascii),
+        };
+        let ascii_t_status =
+            parse_idna_test_status(to_ascii_t_status).unwrap_or_else(|| ascii_n_status.clone());
+
+        let (unicode, result) = processor.to_unicode(&source, nontransitional);
+        assert!(
+            matches_idna_test_unicode(&unicode, &expected_unicode),
+            "{unicode}: {line}"
+        );
+        check_idna_test_errors(result, &unicode_status, line);
+
+        for (options, expected, status) in [
+            (nontransitional, &expected_ascii_n, &ascii_n_status),
+            (transitional, &expected_ascii_t, &ascii_t_status),
+        ] {
+            match processor.to_ascii(&source, options) {
+                Ok(ascii) => {
+                    assert_eq!(&ascii, expected, "{line}");
+                    check_idna_test_errors(Ok(()), status, line);
+                }
+                Err(errors) => check_idna_test_errors(Err(errors), status, line),
+            }
+        }
+        count += 1;
+    }
+    assert!(count > 6000);
+}
+
+const STREAM_TEXT: &str = "Ba\u{0308}r \u{1E0B}\u{0323}\u{0307}o\u{0301} \u{1100}\u{1161}\u{11A8}\u{2126} \u{FB01}\u{1D15E} \u{0345}e\u{0300}\u{0323}. \u{D55C}\u{AD6D}\u{C5B4}\u{D14D}\u{C2A4}\u{D2B8}\u{11A8} \u{E9}\u{E0}\u{FC}\u{E7}\u{F1}\u{F6}\u{E2}\u{EB}.";
+
+fn check_utf8_stream<N: StreamingNormalizer + Copy>(normalizer: N, text: &[u8], expected: &str) {
+    for split in 0..=text.len() {
+        let mut stream = Utf8NormalizerStream::new(normalizer);
+        let mut normalized = String::new();
+        let (first, second) = text.split_at(split);
+        stream.push(first, &mut normalized).unwrap();
+        stream.push(second, &mut normalized).unwrap();
+        stream.finish(&mut normalized).unwrap();
+        assert_eq!(normalized, expected, "split at {split}");
+    }
+
+    for size in 1..=4 {
+        let mut stream = Utf8NormalizerStream::new(normalizer);
+        let mut normalized = String::new();
+        for chunk in text.chunks(size) {
+            stream.push(chunk, &mut normalized).unwrap();
+            assert!(stream.pending().len() < 16);
+        }
+        stream.finish(&mut normalized).unwrap();
+        assert_eq!(normalized, expected, "chunks of {size}");
+    }
+}
+
+fn check_utf16_stream<N: StreamingNormalizer + Copy>(
+    normalizer: N,
+    text: &[u16],
+    expected: &[u16],
+) {
+    for split in 0..=text.len() {
+        let mut stream = Utf16NormalizerStream::new(normalizer);
+        let mut normalized = Vec::new();
+        let (first, second) = text.split_at(split);
+        stream.push(first, &mut normalized).unwrap();
+        stream.push(second, &mut normalized).unwrap();
+        stream.finish(&mut normalized).unwrap();
+        assert_eq!(normalized, expected, "split at {split}");
+    }
+
+    for size in 1..=3 {
+        let mut stream = Utf16NormalizerStream::new(normalizer);
+        let mut normalized = Vec::new();
+        for chunk in text.chunks(size) {
+            stream.push(chunk, &mut normalized).unwrap();
+            assert!(stream.pending().len() < 8);
+        }
+        stream.finish(&mut normalized).unwrap();
+        assert_eq!(normalized, expected, "chunks of {size}");
+    }
+}
+
+#[test]
+fn test_utf8_stream() {
+    let mut text = STREAM_TEXT.as_bytes().to_vec();
+    // Ill-formed sequences, including a truncated one at the end
+    text.extend_from_slice(b"\xE0\x80 \xF0\x9F\x98 a\xCC");
+
+    let nfc = ComposingNormalizerBorrowed::new_nfc();
+    check_utf8_stream(&nfc, &text, &nfc.normalize_utf8(&text));
+    let nfkc = ComposingNormalizerBorrowed::new_nfkc();
+    check_utf8_stream(&nfkc, &text, &nfkc.normalize_utf8(&text));
+    let nfd = DecomposingNormalizerBorrowed::new_nfd();
+    check_utf8_stream(&nfd, &text, &nfd.normalize_utf8(&text));
+    let nfkd = DecomposingNormalizerBorrowed::new_nfkd();
+    check_utf8_stream(&nfkd, &text, &nfkd.normalize_utf8(&text));
+
+    // A stable starter that is split between chunks is found once it is complete.
+    let mut stream = Utf8NormalizerStream::new(&nfc);
+    let mut normalized = String::new();
+    stream.push(b"a\xE4\xB8", &mut normalized).unwrap();
+    assert_eq!(stream.pending(), b"a\xE4\xB8");
+    stream.push(b"\xAD", &mut normalized).unwrap();
+    assert_eq!(normalized, "a");
+    assert_eq!(stream.pending(), "\u{4E2D}".as_bytes());
+}
+
+#[test]
+fn test_utf16_stream() {
+    let mut text: Vec<u16> = STREAM_TEXT.encode_utf16().collect();
+    // Unpaired surrogates
+    text.extend_from_slice(&[0xDC00, 0x61, 0xD800]);
+
+    let nfc = ComposingNormalizerBorrowed::new_nfc();
+    check_utf16_stream(&nfc, &text, &nfc.normalize_utf16(&text));
+    let nfkc = ComposingNormalizerBorrowed::new_nfkc();
+    check_utf16_stream(&nfkc, &text, &nfkc.normalize_utf16(&text));
+    let nfd = DecomposingNormalizerBorrowed::new_nfd();
+    check_utf16_stream(&nfd, &text, &nfd.normalize_utf16(&text));
+    let nfkd = DecomposingNormalizerBorrowed::new_nfkd();
+    check_utf16_stream(&nfkd, &text, &nfkd.normalize_utf16(&text));
+}
+
+/// Checks that splitting text before a character that decomposes, if the stream treats it as
+/// a stable boundary, does not change its normalization.
+fn check_stable_boundaries<N: StreamingNormalizer>(
+    normalizer: N,
+    normalize: impl Fn(&str) -> String,
+) {
+    let nfkd = DecomposingNormalizerBorrowed::new_nfkd();
+    let decomposes = |c: &char| nfkd.normalize(c.encode_utf8(&mut [0; 4])) != c.to_string();
+    for c in ('\0'..=char::MAX)
+        .filter(decomposes)
+        .filter(|&c| normalizer.is_stable_boundary(c))
+    {
+        for prefix in ["a", "\u{1100}", "\u{AC00}", "e\u{0301}"] {
+            for suffix in ["", "\u{0301}", "\u{1161}", "\u{11A8}"] {
+                let rest = format!("{c}{suffix}");
+                assert_eq!(
+                    normalize(&format!("{prefix}{rest}")),
+                    normalize(prefix) + &normalize(&rest),
+                    "{prefix:?} + {rest:?}"
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn test_stream_boundaries() {
+    let nfc = ComposingNormalizerBorrowed::new_nfc();
+    check_stable_boundaries(&nfc, |s| nfc.normalize(s).into_owned());
+    let nfkc = ComposingNormalizerBorrowed::new_nfkc();
+    check_stable_boundaries(&nfkc, |s| nfkc.normalize(s).into_owned());
+    let nfd = DecomposingNormalizerBorrowed::new_nfd();
+    check_stable_boundaries(&nfd, |s| nfd.normalize(s).into_owned());
+    let nfkd = DecomposingNormalizerBorrowed::new_nfkd();
+    check_stable_boundaries(&nfkd, |s| nfkd.normalize(s).into_owned());
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_normalize_read_to() {
+    let text = STREAM_TEXT.repeat(1000);
+    let nfc = ComposingNormalizerBorrowed::new_nfc();
+    let mut normalized = Vec::new();
+    Utf8NormalizerStream::new(&nfc)
+        .normalize_read_to(&mut text.as_bytes(), &mut normalized)
+        .unwrap();
+    assert_eq!(normalized, nfc.normalize(&text).as_bytes());
+}
@@ -546,21 +546,25 @@ where
                         return value;
                     }
                 }
-                match category {
-                    PluralCategory::Zero => Some(PluralElementsKeys::Zero),
-                    PluralCategory::One => Some(PluralElementsKeys::One),
-                    PluralCategory::Two => Some(PluralElementsKeys::Two),
-                    PluralCategory::Few => Some(PluralElementsKeys::Few),
-                    PluralCategory::Many => Some(PluralElementsKeys::Many),
-                    PluralCategory::Other => None,
-                }
-                .and_then(|key| get_special(specials, key))
+                get_special_for_category(specials, category)
             }
             None => None,
         }
         .unwrap_or(parts.default)
     }
 
+    /// Returns the value for the given [`PluralCategory`].
+    ///
+    /// Unlike [`Self::get`], this does not take explicit values for `0` and `1` into account,
+    /// which makes it suitable for values that are not a single number, such as ranges.
+    pub fn get_for_category(&self, category: PluralCategory) -> (FourBitMetadata, &V) {
+        let parts = self.as_parts();
+        parts
+            .specials
+            .and_then(|specials| get_special_for_category(specials, category))
+            .unwrap_or(parts.default)
+    }
+
     /// Recovers the [`PluralElements`] corresponding to this packed structure.
     #[cfg(feature = "datagen")]
     pub fn decode(&self) -> PluralElements<(FourBitMetadata, &V)> {
@@ -794,6 +798,21 @@ fn get_special<V: VarULE + ?Sized>(
         .next()
 }
 
+fn get_special_for_category<V: VarULE + ?Sized>(
+    data: &PluralElementsTupleSliceVarULE<V>,
+    category: PluralCategory,
+) -> Option<(FourBitMetadata, &V)> {
+    match category {
+        PluralCategory::Zero => Some(PluralElementsKeys::Zero),
+        PluralCategory::One => Some(PluralElementsKeys::One),
+        PluralCategory::Two => Some(PluralElementsKeys::Two),
+        PluralCategory::Few => Some(PluralElementsKeys::Few),
+        PluralCategory::Many => Some(PluralElementsKeys::Many),
+        PluralCategory::Other => None,
+    }
+    .and_then(|key| get_special(data, key))
+}
+
 impl<T> PluralElementsInner<(FourBitMetadata, T)>
 where
     T: PartialEq,
@@ -1080,6 +1099,13 @@ where
     pub fn get<'a>(&'a self, op: PluralOperands, rules: &PluralRules) -> &'a V {
         self.elements.get(op, rules).1
     }
+
+    /// Returns the value for the given [`PluralCategory`].
+    ///
+    /// See [`PluralElementsPackedULE::get_for_category`].
+    pub fn get_for_category(&self, category: PluralCategory) -> &V {
+        self.elements.get_for_category(category).1
+    }
 }
 
 #[test]
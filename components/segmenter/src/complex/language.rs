@@ -2,122 +2,188 @@
 // called LICENSE at the top level of the ICU4X source tree
 // (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
 
-#[derive(PartialEq, Debug, Copy, Clone)]
-pub(super) enum Language {
-    Burmese,
-    ChineseOrJapanese,
-    Khmer,
-    Lao,
-    Thai,
-    Unknown,
+use crate::provider::ComplexScripts;
+
+/// The code points that are segmented with a model, and the attributes of those models.
+#[derive(Debug, Clone, Copy)]
+pub(super) enum ScriptTable<'data> {
+    /// The table from [`SegmenterComplexScriptsV1`](crate::provider::SegmenterComplexScriptsV1).
+    Data(&'data ComplexScripts<'data>),
+    /// The table used by data that predates
+    /// [`SegmenterComplexScriptsV1`](crate::provider::SegmenterComplexScriptsV1).
+    BuiltIn,
 }
 
-// TODO: Use data provider
-fn get_language(codepoint: u32) -> Language {
+/// The dictionary and LSTM attributes of the models of [`ScriptTable::BuiltIn`].
+const BUILT_IN_MODELS: [(&str, &str); 5] = [
+    ("burmesedict", "Burmese_"),
+    ("cjdict", ""),
+    ("khmerdict", "Khmer_"),
+    ("laodict", "Lao_"),
+    ("thaidict", "Thai_"),
+];
+
+/// Returns the index into [`BUILT_IN_MODELS`] of the model for the given code point.
+fn get_built_in_model(codepoint: u32) -> Option<usize> {
+    const BURMESE: Option<usize> = Some(0);
+    const CHINESE_OR_JAPANESE: Option<usize> = Some(1);
+    const KHMER: Option<usize> = Some(2);
+    const LAO: Option<usize> = Some(3);
+    const THAI: Option<usize> = Some(4);
     match codepoint {
-        0xe01..=0xe7f => Language::Thai,
-        0x0E80..=0x0EFF => Language::Lao,
-        0x1000..=0x109f => Language::Burmese,
-        0x1780..=0x17FF => Language::Khmer,
-        0x19E0..=0x19FF => Language::Khmer,
-        0x2E80..=0x2EFF => Language::ChineseOrJapanese,
-        0x2F00..=0x2FDF => Language::ChineseOrJapanese,
-        0x3040..=0x30FF => Language::ChineseOrJapanese,
-        0x31F0..=0x31FF => Language::ChineseOrJapanese,
-        0x32D0..=0x32FE => Language::ChineseOrJapanese,
-        0x3400..=0x4DBF => Language::ChineseOrJapanese,
-        0x4E00..=0x9FFF => Language::ChineseOrJapanese,
-        0xa9e0..=0xa9ff => Language::Burmese,
-        0xaa60..=0xaa7f => Language::Burmese,
-        0xF900..=0xFAFF => Language::ChineseOrJapanese,
-        0xFF66..=0xFF9D => Language::ChineseOrJapanese,
-        0x16FE2..=0x16FE3 => Language::ChineseOrJapanese,
-        0x16FF0..=0x16FF1 => Language::ChineseOrJapanese,
-        0x1AFF0..=0x1B16F => Language::ChineseOrJapanese,
-        0x1F200 => Language::ChineseOrJapanese,
-        0x20000..=0x2FA1F => Language::ChineseOrJapanese,
-        0x30000..=0x3134F => Language::ChineseOrJapanese,
-        _ => Language::Unknown,
+        0xe01..=0xe7f => THAI,
+        0x0E80..=0x0EFF => LAO,
+        0x1000..=0x109f => BURMESE,
+        0x1780..=0x17FF => KHMER,
+        0x19E0..=0x19FF => KHMER,
+        0x2E80..=0x2EFF => CHINESE_OR_JAPANESE,
+        0x2F00..=0x2FDF => CHINESE_OR_JAPANESE,
+        0x3040..=0x30FF => CHINESE_OR_JAPANESE,
+        0x31F0..=0x31FF => CHINESE_OR_JAPANESE,
+        0x32D0..=0x32FE => CHINESE_OR_JAPANESE,
+        0x3400..=0x4DBF => CHINESE_OR_JAPANESE,
+        0x4E00..=0x9FFF => CHINESE_OR_JAPANESE,
+        0xa9e0..=0xa9ff => BURMESE,
+        0xaa60..=0xaa7f => BURMESE,
+        0xF900..=0xFAFF => CHINESE_OR_JAPANESE,
+        0xFF66..=0xFF9D => CHINESE_OR_JAPANESE,
+        0x16FE2..=0x16FE3 => CHINESE_OR_JAPANESE,
+        0x16FF0..=0x16FF1 => CHINESE_OR_JAPANESE,
+        0x1AFF0..=0x1B16F => CHINESE_OR_JAPANESE,
+        0x1F200 => CHINESE_OR_JAPANESE,
+        0x20000..=0x2FA1F => CHINESE_OR_JAPANESE,
+        0x30000..=0x3134F => CHINESE_OR_JAPANESE,
+        _ => None,
+    }
+}
+
+impl<'data> ScriptTable<'data> {
+    /// Returns the number of models.
+    pub(super) fn len(self) -> usize {
+        match self {
+            Self::Data(scripts) => scripts.dictionaries.len(),
+            Self::BuiltIn => BUILT_IN_MODELS.len(),
+        }
+    }
+
+    /// Returns the dictionary and LSTM attributes of the model with the given index.
+    pub(super) fn attributes(self, index: usize) -> Option<(&'data str, &'data str)> {
+        match self {
+            Self::Data(scripts) => scripts
+                .dictionaries
+                .get(index)
+                .zip(scripts.lstms.get(index)),
+            Self::BuiltIn => BUILT_IN_MODELS.get(index).copied(),
+        }
+    }
+
+    /// Returns the index of the model for the given code point, or `None` if it is not
+    /// segmented with a model.
+    fn get_model(self, codepoint: u32) -> Option<usize> {
+        match self {
+            Self::Data(scripts) => {
+                usize::from(scripts.models_by_code_point.get32(codepoint)).checked_sub(1)
+            }
+            Self::BuiltIn => get_built_in_model(codepoint),
+        }
     }
 }
 
-/// This struct is an iterator that returns the string per language from the
+/// This struct is an iterator that returns the string per model from the
 /// given string.
-pub(super) struct LanguageIterator<'s> {
+pub(super) struct LanguageIterator<'s, 'data> {
     rest: &'s str,
+    scripts: ScriptTable<'data>,
 }
 
-impl<'s> LanguageIterator<'s> {
-    pub(super) fn new(input: &'s str) -> Self {
-        Self { rest: input }
+impl<'s, 'data> LanguageIterator<'s, 'data> {
+    pub(super) fn new(input: &'s str, scripts: ScriptTable<'data>) -> Self {
+        Self {
+            rest: input,
+            scripts,
+        }
     }
 }
 
-impl<'s> Iterator for LanguageIterator<'s> {
-    type Item = (&'s str, Language);
+impl<'s> Iterator for LanguageIterator<'s, '_> {
+    type Item = (&'s str, Option<usize>);
 
     fn next(&mut self) -> Option<Self::Item> {
         let mut indices = self.rest.char_indices();
-        let lang = get_language(indices.next()?.1 as u32);
-        match indices.find(|&(_, ch)| get_language(ch as u32) != lang) {
+        let model = self.scripts.get_model(indices.next()?.1 as u32);
+        match indices.find(|&(_, ch)| self.scripts.get_model(ch as u32) != model) {
             Some((i, _)) => {
                 let (result, rest) = self.rest.split_at(i);
                 self.rest = rest;
-                Some((result, lang))
+                Some((result, model))
             }
-            None => Some((core::mem::take(&mut self.rest), lang)),
+            None => Some((core::mem::take(&mut self.rest), model)),
         }
     }
 }
 
-pub(super) struct LanguageIteratorUtf16<'s> {
+pub(super) struct LanguageIteratorUtf16<'s, 'data> {
     rest: &'s [u16],
+    scripts: ScriptTable<'data>,
 }
 
-impl<'s> LanguageIteratorUtf16<'s> {
-    pub(super) fn new(input: &'s [u16]) -> Self {
-        Self { rest: input }
+impl<'s, 'data> LanguageIteratorUtf16<'s, 'data> {
+    pub(super) fn new(input: &'s [u16], scripts: ScriptTable<'data>) -> Self {
+        Self {
+            rest: input,
+            scripts,
+        }
     }
 }
 
-impl<'s> Iterator for LanguageIteratorUtf16<'s> {
-    type Item = (&'s [u16], Language);
+impl<'s> Iterator for LanguageIteratorUtf16<'s, '_> {
+    type Item = (&'s [u16], Option<usize>);
 
     fn next(&mut self) -> Option<Self::Item> {
-        let lang = get_language(*self.rest.first()? as u32);
-        match self
-            .rest
-            .iter()
-            .position(|&ch| get_language(ch as u32) != lang)
-        {
-            Some(i) => {
+        let mut offset = 0;
+        let mut chars = char::decode_utf16(self.rest.iter().copied()).map(|ch| {
+            // Unpaired surrogates are a single code unit.
+            let ch = ch.unwrap_or(char::REPLACEMENT_CHARACTER);
+            let start = offset;
+            offset += ch.len_utf16();
+            (start, ch as u32)
+        });
+        let model = self.scripts.get_model(chars.next()?.1);
+        match chars.find(|&(_, ch)| self.scripts.get_model(ch) != model) {
+            Some((i, _)) => {
                 let (result, rest) = self.rest.split_at(i);
                 self.rest = rest;
-                Some((result, lang))
+                Some((result, model))
             }
-            None => Some((core::mem::take(&mut self.rest), lang)),
+            None => Some((core::mem::take(&mut self.rest), model)),
         }
     }
 }
 
 #[cfg(test)]
+#[cfg(feature = "compiled_data")]
 mod tests {
     use super::*;
 
+    const SCRIPTS: ScriptTable =
+        ScriptTable::Data(crate::provider::Baked::SINGLETON_SEGMENTER_COMPLEX_SCRIPTS_V1);
+
     #[test]
     fn test_thai_only() {
         let s = "ภาษาไทยภาษาไทย";
+        let thai = SCRIPTS.get_model('ภ' as u32);
+        assert!(thai.is_some());
         let utf16: Vec<u16> = s.encode_utf16().collect();
-        let mut iter = LanguageIteratorUtf16::new(&utf16);
+        let mut iter = LanguageIteratorUtf16::new(&utf16, SCRIPTS);
         assert_eq!(
             iter.next(),
-            Some((utf16.as_slice(), Language::Thai)),
+            Some((utf16.as_slice(), thai)),
             "Thai language only with UTF-16"
         );
-        let mut iter = LanguageIterator::new(s);
+        let mut iter = LanguageIterator::new(s, SCRIPTS);
         assert_eq!(
             iter.next(),
-            Some((s, Language::Thai)),
+            Some((s, thai)),
             "Thai language only with UTF-8"
         );
         assert_eq!(iter.next(), None, "Iterator for UTF-8 is finished");
@@ -127,35 +193,69 @@ mod tests {
     fn test_combine() {
         const TEST_STR_THAI: &str = "ภาษาไทยภาษาไทย";
         const TEST_STR_BURMESE: &str = "ဗမာနွယ်ဘာသာစကားမျာ";
+        let thai = SCRIPTS.get_model('ภ' as u32);
+        let burmese = SCRIPTS.get_model('ဗ' as u32);
+        assert_ne!(thai, burmese);
         let s = format!("{TEST_STR_THAI}{TEST_STR_BURMESE}");
         let utf16: Vec<u16> = s.encode_utf16().collect();
         let thai_utf16: Vec<u16> = TEST_STR_THAI.encode_utf16().collect();
         let burmese_utf16: Vec<u16> = TEST_STR_BURMESE.encode_utf16().collect();
 
-        let mut iter = LanguageIteratorUtf16::new(&utf16);
+        let mut iter = LanguageIteratorUtf16::new(&utf16, SCRIPTS);
         assert_eq!(
             iter.next(),
-            Some((thai_utf16.as_slice(), Language::Thai)),
+            Some((thai_utf16.as_slice(), thai)),
             "Thai language with UTF-16 at first"
         );
         assert_eq!(
             iter.next(),
-            Some((burmese_utf16.as_slice(), Language::Burmese)),
+            Some((burmese_utf16.as_slice(), burmese)),
             "Burmese language with UTF-16 at second"
         );
         assert_eq!(iter.next(), None, "Iterator for UTF-16 is finished");
 
-        let mut iter = LanguageIterator::new(&s);
+        let mut iter = LanguageIterator::new(&s, SCRIPTS);
         assert_eq!(
             iter.next(),
-            Some((TEST_STR_THAI, Language::Thai)),
+            Some((TEST_STR_THAI, thai)),
             "Thai language with UTF-8 at first"
         );
         assert_eq!(
             iter.next(),
-            Some((TEST_STR_BURMESE, Language::Burmese)),
+            Some((TEST_STR_BURMESE, burmese)),
             "Burmese language with UTF-8 at second"
         );
         assert_eq!(iter.next(), None, "Iterator for UTF-8 is finished");
     }
+
+    #[test]
+    fn test_supplementary_utf16() {
+        // Supplementary Han characters are segmented with the same model as BMP ones.
+        let s = "中𠀀文";
+        let cj = SCRIPTS.get_model('中' as u32);
+        assert!(cj.is_some());
+        let utf16: Vec<u16> = s.encode_utf16().collect();
+        let mut iter = LanguageIteratorUtf16::new(&utf16, SCRIPTS);
+        assert_eq!(iter.next(), Some((utf16.as_slice(), cj)));
+    }
+
+    #[test]
+    fn test_built_in_matches_data() {
+        // The built-in table assigns code points to the same models as the compiled data,
+        // apart from scripts that were added to the data later.
+        for (c, model) in [
+            ('ภ', "thaidict"),
+            ('ກ', "laodict"),
+            ('ဗ', "burmesedict"),
+            ('ក', "khmerdict"),
+            ('中', "cjdict"),
+            ('あ', "cjdict"),
+        ] {
+            for table in [SCRIPTS, ScriptTable::BuiltIn] {
+                let index = table.get_model(c as u32).unwrap();
+                assert_eq!(table.attributes(index).unwrap().0, model, "{c}");
+            }
+        }
+        assert_eq!(ScriptTable::BuiltIn.get_model('a' as u32), None);
+    }
 }
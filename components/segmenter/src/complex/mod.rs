@@ -39,6 +39,7 @@ fn borrow_dictor(dict_or: &DictOrLstm) -> DictOrLstmBorrowed<'_> {
     }
 }
 
+#[cfg(feature = "compiled_data")]
 fn fromstatic_dictor(dict_or: DictOrLstmBorrowed<'static>) -> DictOrLstm {
     match dict_or {
         DictOrLstmBorrowed::Dict(dict) => DictOrLstm::Dict(DataPayload::from_static_ref(dict)),
@@ -50,83 +51,158 @@ fn fromstatic_dictor(dict_or: DictOrLstmBorrowed<'static>) -> DictOrLstm {
 #[derive(Debug)]
 pub(crate) struct ComplexPayloads {
     grapheme: GraphemeClusterSegmenter,
-    my: Option<DictOrLstm>,
-    km: Option<DictOrLstm>,
-    lo: Option<DictOrLstm>,
-    th: Option<DictOrLstm>,
-    ja: Option<DataPayload<UCharDictionaryBreakDataV1>>,
+    /// `None` for data that predates [`SegmenterComplexScriptsV1`], in which case the built-in
+    /// script table is used.
+    scripts: Option<DataPayload<SegmenterComplexScriptsV1>>,
+    models: Vec<Option<DictOrLstm>>,
 }
 
 #[derive(Debug, Clone, Copy)]
 pub(crate) struct ComplexPayloadsBorrowed<'data> {
     grapheme: GraphemeClusterSegmenterBorrowed<'data>,
-    my: Option<DictOrLstmBorrowed<'data>>,
-    km: Option<DictOrLstmBorrowed<'data>>,
-    lo: Option<DictOrLstmBorrowed<'data>>,
-    th: Option<DictOrLstmBorrowed<'data>>,
-    ja: Option<&'data UCharDictionaryBreakData<'data>>,
+    scripts: ScriptTable<'data>,
+    models: Models<'data>,
 }
 
-#[cfg(feature = "lstm")]
-const MY_LSTM: &DataMarkerAttributes = DataMarkerAttributes::from_str_or_panic("Burmese_");
-#[cfg(feature = "lstm")]
-const KM_LSTM: &DataMarkerAttributes = DataMarkerAttributes::from_str_or_panic("Khmer_");
-#[cfg(feature = "lstm")]
-const LO_LSTM: &DataMarkerAttributes = DataMarkerAttributes::from_str_or_panic("Lao_");
-#[cfg(feature = "lstm")]
-const TH_LSTM: &DataMarkerAttributes = DataMarkerAttributes::from_str_or_panic("Thai_");
+/// The maximum number of models in compiled data.
+#[cfg(feature = "compiled_data")]
+const COMPILED_MODELS: usize = 8;
+
+/// The models of a [`ComplexPayloadsBorrowed`], indexed like the models of its
+/// [`ScriptTable`].
+#[derive(Debug, Clone, Copy)]
+enum Models<'data> {
+    Loaded(&'data [Option<DictOrLstm>]),
+    /// Models from compiled data, resolved when the segmenter is constructed.
+    #[cfg(feature = "compiled_data")]
+    Compiled([Option<DictOrLstmBorrowed<'static>>; COMPILED_MODELS]),
+}
+
+/// The kind of models that a constructor loads.
+#[derive(Debug, Clone, Copy)]
+#[cfg(feature = "compiled_data")]
+enum ModelKind {
+    /// LSTM models.
+    #[cfg(feature = "lstm")]
+    Lstm,
+    /// LSTM models, or else dictionaries from [`SegmenterDictionaryAutoV1`].
+    #[cfg(feature = "auto")]
+    Auto,
+    /// Dictionaries from [`SegmenterDictionaryExtendedV1`] or [`SegmenterDictionaryAutoV1`].
+    Dict,
+    /// Dictionaries from [`SegmenterDictionaryExtendedV1`].
+    SoutheastAsian,
+}
+
+#[cfg(feature = "compiled_data")]
+impl ModelKind {
+    #[expect(clippy::unwrap_used)]
+    // try_load is infallible if the provider only returns `MissingLocale`.
+    fn load_static(self, dictionary: &str, lstm: &str) -> Option<DictOrLstmBorrowed<'static>> {
+        let dict = |marker_is_extended: bool| {
+            let dictionary = attributes(dictionary)?;
+            if marker_is_extended {
+                try_load_static::<SegmenterDictionaryExtendedV1, _>(
+                    &crate::provider::Baked,
+                    dictionary,
+                )
+                .unwrap()
+            } else {
+                try_load_static::<SegmenterDictionaryAutoV1, _>(&crate::provider::Baked, dictionary)
+                    .unwrap()
+            }
+            .map(DictOrLstmBorrowed::Dict)
+        };
+        #[cfg(feature = "lstm")]
+        let lstm = || {
+            try_load_static::<SegmenterLstmAutoV1, _>(&crate::provider::Baked, attributes(lstm)?)
+                .unwrap()
+                .map(DictOrLstmBorrowed::Lstm)
+        };
+        #[cfg(not(feature = "lstm"))]
+        let _ = lstm;
+        match self {
+            #[cfg(feature = "lstm")]
+            Self::Lstm => lstm(),
+            #[cfg(feature = "auto")]
+            Self::Auto => lstm().or_else(|| dict(false)),
+            Self::Dict => dict(true).or_else(|| dict(false)),
+            Self::SoutheastAsian => dict(true),
+        }
+    }
+}
 
-const MY_DICT: &DataMarkerAttributes = DataMarkerAttributes::from_str_or_panic("burmesedict");
-const KM_DICT: &DataMarkerAttributes = DataMarkerAttributes::from_str_or_panic("khmerdict");
-const LO_DICT: &DataMarkerAttributes = DataMarkerAttributes::from_str_or_panic("laodict");
-const TH_DICT: &DataMarkerAttributes = DataMarkerAttributes::from_str_or_panic("thaidict");
-const CJ_DICT: &DataMarkerAttributes = DataMarkerAttributes::from_str_or_panic("cjdict");
+/// Parses the attributes of a model, returning `None` for the empty string, which stands for
+/// a missing model.
+fn attributes(model: &str) -> Option<&DataMarkerAttributes> {
+    if model.is_empty() {
+        return None;
+    }
+    DataMarkerAttributes::try_from_str(model).ok()
+}
 
 impl<'data> ComplexPayloadsBorrowed<'data> {
-    fn select(&self, language: Language) -> Option<DictOrLstmBorrowed<'data>> {
+    /// Calls `f` with the selected model and a grapheme segmenter of the same lifetime.
+    ///
+    /// Compiled models are `'static`, so they are paired with the compiled grapheme data.
+    fn with_model<R>(
+        &self,
+        model: Option<usize>,
+        f: impl for<'l> FnOnce(
+            Option<DictOrLstmBorrowed<'l>>,
+            GraphemeClusterSegmenterBorrowed<'l>,
+        ) -> R,
+    ) -> R {
         const ERR: DataError = DataError::custom("No segmentation model for language");
-        match language {
-            Language::Burmese => self.my.or_else(|| {
-                ERR.with_display_context("my");
-                None
-            }),
-            Language::Khmer => self.km.or_else(|| {
-                ERR.with_display_context("km");
-                None
-            }),
-            Language::Lao => self.lo.or_else(|| {
-                ERR.with_display_context("lo");
-                None
-            }),
-            Language::Thai => self.th.or_else(|| {
-                ERR.with_display_context("th");
-                None
-            }),
-            Language::ChineseOrJapanese => self.ja.map(DictOrLstmBorrowed::Dict).or_else(|| {
-                ERR.with_display_context("ja");
-                None
-            }),
-            Language::Unknown => None,
+        let Some(index) = model else {
+            return f(None, self.grapheme);
+        };
+        let log_missing = |missing: bool| {
+            if missing {
+                ERR.with_display_context(
+                    self.scripts
+                        .attributes(index)
+                        .map(|(dictionary, _)| dictionary)
+                        .unwrap_or_default(),
+                );
+            }
+        };
+        match self.models {
+            Models::Loaded(models) => {
+                let result = models
+                    .get(index)
+                    .and_then(|m| m.as_ref())
+                    .map(borrow_dictor);
+                log_missing(result.is_none());
+                f(result, self.grapheme)
+            }
+            #[cfg(feature = "compiled_data")]
+            Models::Compiled(models) => {
+                let result = models.get(index).copied().flatten();
+                log_missing(result.is_none());
+                f(result, GraphemeClusterSegmenter::new())
+            }
         }
     }
+
     pub(crate) fn complex_language_segment_str(&self, input: &str) -> Vec<usize> {
         let mut result = Vec::new();
         let mut offset = 0;
-        for (slice, lang) in LanguageIterator::new(input) {
-            match self.select(lang) {
+        for (slice, model) in LanguageIterator::new(input, self.scripts) {
+            self.with_model(model, |model, grapheme| match model {
                 Some(DictOrLstmBorrowed::Dict(dict)) => {
-                    let seg = DictionarySegmenter::new(dict, self.grapheme);
+                    let seg = DictionarySegmenter::new(dict, grapheme);
                     result.extend(seg.segment_str(slice).map(|n| offset + n));
                 }
                 #[cfg(feature = "lstm")]
                 Some(DictOrLstmBorrowed::Lstm(lstm)) => {
-                    let seg = LstmSegmenter::new(lstm, self.grapheme);
+                    let seg = LstmSegmenter::new(lstm, grapheme);
                     result.extend(seg.segment_str(slice).map(|n| offset + n));
                 }
                 None => {
                     result.push(offset + slice.len());
                 }
-            }
+            });
             offset += slice.len();
         }
         result
@@ -135,135 +211,76 @@ impl<'data> ComplexPayloadsBorrowed<'data> {
     pub(crate) fn complex_language_segment_utf16(&self, input: &[u16]) -> Vec<usize> {
         let mut result = Vec::new();
         let mut offset = 0;
-        for (slice, lang) in LanguageIteratorUtf16::new(input) {
-            match self.select(lang) {
+        for (slice, model) in LanguageIteratorUtf16::new(input, self.scripts) {
+            self.with_model(model, |model, grapheme| match model {
                 Some(DictOrLstmBorrowed::Dict(dict)) => {
-                    let seg = DictionarySegmenter::new(dict, self.grapheme);
+                    let seg = DictionarySegmenter::new(dict, grapheme);
                     result.extend(seg.segment_utf16(slice).map(|n| offset + n));
                 }
                 #[cfg(feature = "lstm")]
                 Some(DictOrLstmBorrowed::Lstm(lstm)) => {
-                    let seg = LstmSegmenter::new(lstm, self.grapheme);
+                    let seg = LstmSegmenter::new(lstm, grapheme);
                     result.extend(seg.segment_utf16(slice).map(|n| offset + n));
                 }
                 None => {
                     result.push(offset + slice.len());
                 }
-            }
+            });
             offset += slice.len();
         }
         result
     }
 }
+
+#[cfg(feature = "compiled_data")]
 impl ComplexPayloadsBorrowed<'static> {
-    #[cfg(feature = "lstm")]
-    #[cfg(feature = "compiled_data")]
-    pub(crate) fn new_lstm() -> Self {
-        #[expect(clippy::unwrap_used)]
-        // try_load is infallible if the provider only returns `MissingLocale`.
+    fn new_compiled(kind: ModelKind) -> Self {
+        let scripts =
+            ScriptTable::Data(crate::provider::Baked::SINGLETON_SEGMENTER_COMPLEX_SCRIPTS_V1);
+        debug_assert!(scripts.len() <= COMPILED_MODELS);
         Self {
             grapheme: GraphemeClusterSegmenter::new(),
-            my: try_load_static::<SegmenterLstmAutoV1, _>(&crate::provider::Baked, MY_LSTM)
-                .unwrap()
-                .map(DictOrLstmBorrowed::Lstm),
-            km: try_load_static::<SegmenterLstmAutoV1, _>(&crate::provider::Baked, KM_LSTM)
-                .unwrap()
-                .map(DictOrLstmBorrowed::Lstm),
-            lo: try_load_static::<SegmenterLstmAutoV1, _>(&crate::provider::Baked, LO_LSTM)
-                .unwrap()
-                .map(DictOrLstmBorrowed::Lstm),
-            th: try_load_static::<SegmenterLstmAutoV1, _>(&crate::provider::Baked, TH_LSTM)
-                .unwrap()
-                .map(DictOrLstmBorrowed::Lstm),
-            ja: None,
+            scripts,
+            models: Models::Compiled(core::array::from_fn(|index| {
+                let (dictionary, lstm) = scripts.attributes(index)?;
+                kind.load_static(dictionary, lstm)
+            })),
         }
     }
+    #[cfg(feature = "lstm")]
+    pub(crate) fn new_lstm() -> Self {
+        Self::new_compiled(ModelKind::Lstm)
+    }
     #[cfg(feature = "auto")]
-    #[cfg(feature = "compiled_data")]
-    #[expect(clippy::unwrap_used)]
     pub(crate) fn new_auto() -> Self {
-        let mut this = Self::new_lstm();
-        this.ja = try_load_static::<SegmenterDictionaryAutoV1, _>(&crate::provider::Baked, CJ_DICT)
-            .unwrap();
-        this
+        Self::new_compiled(ModelKind::Auto)
     }
-    #[cfg(feature = "compiled_data")]
     pub(crate) fn new_dict() -> Self {
-        #[expect(clippy::unwrap_used)]
-        // try_load is infallible if the provider only returns `MissingLocale`.
-        Self {
-            grapheme: GraphemeClusterSegmenter::new(),
-            my: try_load_static::<SegmenterDictionaryExtendedV1, _>(
-                &crate::provider::Baked,
-                MY_DICT,
-            )
-            .unwrap()
-            .map(DictOrLstmBorrowed::Dict),
-            km: try_load_static::<SegmenterDictionaryExtendedV1, _>(
-                &crate::provider::Baked,
-                KM_DICT,
-            )
-            .unwrap()
-            .map(DictOrLstmBorrowed::Dict),
-            lo: try_load_static::<SegmenterDictionaryExtendedV1, _>(
-                &crate::provider::Baked,
-                LO_DICT,
-            )
-            .unwrap()
-            .map(DictOrLstmBorrowed::Dict),
-            th: try_load_static::<SegmenterDictionaryExtendedV1, _>(
-                &crate::provider::Baked,
-                TH_DICT,
-            )
-            .unwrap()
-            .map(DictOrLstmBorrowed::Dict),
-            ja: try_load_static::<SegmenterDictionaryAutoV1, _>(&crate::provider::Baked, CJ_DICT)
-                .unwrap(),
-        }
+        Self::new_compiled(ModelKind::Dict)
     }
-
-    #[cfg(feature = "compiled_data")]
     pub(crate) fn new_southeast_asian() -> Self {
-        #[expect(clippy::unwrap_used)]
-        // try_load is infallible if the provider only returns `MissingLocale`.
-        Self {
-            grapheme: GraphemeClusterSegmenter::new(),
-            my: try_load_static::<SegmenterDictionaryExtendedV1, _>(
-                &crate::provider::Baked,
-                MY_DICT,
-            )
-            .unwrap()
-            .map(DictOrLstmBorrowed::Dict),
-            km: try_load_static::<SegmenterDictionaryExtendedV1, _>(
-                &crate::provider::Baked,
-                KM_DICT,
-            )
-            .unwrap()
-            .map(DictOrLstmBorrowed::Dict),
-            lo: try_load_static::<SegmenterDictionaryExtendedV1, _>(
-                &crate::provider::Baked,
-                LO_DICT,
-            )
-            .unwrap()
-            .map(DictOrLstmBorrowed::Dict),
-            th: try_load_static::<SegmenterDictionaryExtendedV1, _>(
-                &crate::provider::Baked,
-                TH_DICT,
-            )
-            .unwrap()
-            .map(DictOrLstmBorrowed::Dict),
-            ja: None,
-        }
+        Self::new_compiled(ModelKind::SoutheastAsian)
     }
+}
 
+impl ComplexPayloadsBorrowed<'static> {
     pub(crate) fn static_to_owned(self) -> ComplexPayloads {
+        let models = match self.models {
+            Models::Loaded(models) => models.to_vec(),
+            #[cfg(feature = "compiled_data")]
+            Models::Compiled(models) => models
+                .iter()
+                .take(self.scripts.len())
+                .map(|model| model.map(fromstatic_dictor))
+                .collect(),
+        };
         ComplexPayloads {
             grapheme: self.grapheme.static_to_owned(),
-            my: self.my.map(fromstatic_dictor),
-            km: self.km.map(fromstatic_dictor),
-            lo: self.lo.map(fromstatic_dictor),
-            th: self.th.map(fromstatic_dictor),
-            ja: self.ja.map(DataPayload::from_static_ref),
+            scripts: match self.scripts {
+                ScriptTable::Data(scripts) => Some(DataPayload::from_static_ref(scripts)),
+                ScriptTable::BuiltIn => None,
+            },
+            models,
         }
     }
 }
@@ -272,61 +289,76 @@ impl ComplexPayloads {
     pub(crate) fn as_borrowed(&self) -> ComplexPayloadsBorrowed<'_> {
         ComplexPayloadsBorrowed {
             grapheme: self.grapheme.as_borrowed(),
-            my: self.my.as_ref().map(borrow_dictor),
-            km: self.km.as_ref().map(borrow_dictor),
-            lo: self.lo.as_ref().map(borrow_dictor),
-            th: self.th.as_ref().map(borrow_dictor),
-            ja: self.ja.as_ref().map(|p| p.get()),
+            scripts: match &self.scripts {
+                Some(scripts) => ScriptTable::Data(scripts.get()),
+                None => ScriptTable::BuiltIn,
+            },
+            models: Models::Loaded(&self.models),
         }
     }
 
-    #[cfg(feature = "lstm")]
-    pub(crate) fn try_new_lstm<D>(provider: &D) -> Result<Self, DataError>
+    /// Loads the scripts data, and a model for each of its entries with `load`, which is
+    /// given the dictionary and LSTM attributes of the entry.
+    ///
+    /// Providers without [`SegmenterComplexScriptsV1`], such as data generated before it was
+    /// added, fall back to the built-in script table.
+    fn try_new_with<D>(
+        provider: &D,
+        load: impl Fn(&str, &str) -> Result<Option<DictOrLstm>, DataError>,
+    ) -> Result<Self, DataError>
     where
         D: DataProvider<SegmenterBreakGraphemeClusterV1>
-            + DataProvider<SegmenterLstmAutoV1>
+            + DataProvider<SegmenterComplexScriptsV1>
             + ?Sized,
     {
+        let scripts: Option<DataPayload<SegmenterComplexScriptsV1>> =
+            match provider.load(Default::default()) {
+                Ok(response) => Some(response.payload),
+                Err(DataError {
+                    kind: DataErrorKind::MarkerNotFound,
+                    ..
+                }) => None,
+                Err(e) => return Err(e),
+            };
+        let table = match &scripts {
+            Some(scripts) => ScriptTable::Data(scripts.get()),
+            None => ScriptTable::BuiltIn,
+        };
+        let models = (0..table.len())
+            .filter_map(|index| table.attributes(index))
+            .map(|(dictionary, lstm)| load(dictionary, lstm))
+            .collect::<Result<_, _>>()?;
         Ok(Self {
             grapheme: GraphemeClusterSegmenter::try_new_unstable(provider)?,
-            my: try_load::<SegmenterLstmAutoV1, D>(provider, MY_LSTM)?
-                .map(DataPayload::cast)
-                .map(DictOrLstm::Lstm),
-            km: try_load::<SegmenterLstmAutoV1, D>(provider, KM_LSTM)?
-                .map(DataPayload::cast)
-                .map(DictOrLstm::Lstm),
-            lo: try_load::<SegmenterLstmAutoV1, D>(provider, LO_LSTM)?
-                .map(DataPayload::cast)
-                .map(DictOrLstm::Lstm),
-            th: try_load::<SegmenterLstmAutoV1, D>(provider, TH_LSTM)?
-                .map(DataPayload::cast)
-                .map(DictOrLstm::Lstm),
-            ja: None,
+            scripts,
+            models,
         })
     }
 
+    #[cfg(feature = "lstm")]
+    pub(crate) fn try_new_lstm<D>(provider: &D) -> Result<Self, DataError>
+    where
+        D: DataProvider<SegmenterBreakGraphemeClusterV1>
+            + DataProvider<SegmenterComplexScriptsV1>
+            + DataProvider<SegmenterLstmAutoV1>
+            + ?Sized,
+    {
+        Self::try_new_with(provider, |_, lstm| try_load_lstm(provider, lstm))
+    }
+
     pub(crate) fn try_new_dict<D>(provider: &D) -> Result<Self, DataError>
     where
         D: DataProvider<SegmenterBreakGraphemeClusterV1>
+            + DataProvider<SegmenterComplexScriptsV1>
             + DataProvider<SegmenterDictionaryExtendedV1>
             + DataProvider<SegmenterDictionaryAutoV1>
             + ?Sized,
     {
-        Ok(Self {
-            grapheme: GraphemeClusterSegmenter::try_new_unstable(provider)?,
-            my: try_load::<SegmenterDictionaryExtendedV1, D>(provider, MY_DICT)?
-                .map(DataPayload::cast)
-                .map(DictOrLstm::Dict),
-            km: try_load::<SegmenterDictionaryExtendedV1, D>(provider, KM_DICT)?
-                .map(DataPayload::cast)
-                .map(DictOrLstm::Dict),
-            lo: try_load::<SegmenterDictionaryExtendedV1, D>(provider, LO_DICT)?
-                .map(DataPayload::cast)
-                .map(DictOrLstm::Dict),
-            th: try_load::<SegmenterDictionaryExtendedV1, D>(provider, TH_DICT)?
-                .map(DataPayload::cast)
-                .map(DictOrLstm::Dict),
-            ja: try_load::<SegmenterDictionaryAutoV1, D>(provider, CJ_DICT)?.map(DataPayload::cast),
+        Self::try_new_with(provider, |dictionary, _| {
+            match try_load_dict::<SegmenterDictionaryExtendedV1, D>(provider, dictionary)? {
+                Some(dict) => Ok(Some(dict)),
+                None => try_load_dict::<SegmenterDictionaryAutoV1, D>(provider, dictionary),
+            }
         })
     }
 
@@ -334,55 +366,61 @@ impl ComplexPayloads {
     pub(crate) fn try_new_auto<D>(provider: &D) -> Result<Self, DataError>
     where
         D: DataProvider<SegmenterBreakGraphemeClusterV1>
+            + DataProvider<SegmenterComplexScriptsV1>
             + DataProvider<SegmenterLstmAutoV1>
             + DataProvider<SegmenterDictionaryAutoV1>
             + ?Sized,
     {
-        Ok(Self {
-            grapheme: GraphemeClusterSegmenter::try_new_unstable(provider)?,
-            my: try_load::<SegmenterLstmAutoV1, D>(provider, MY_LSTM)?
-                .map(DataPayload::cast)
-                .map(DictOrLstm::Lstm),
-            km: try_load::<SegmenterLstmAutoV1, D>(provider, KM_LSTM)?
-                .map(DataPayload::cast)
-                .map(DictOrLstm::Lstm),
-            lo: try_load::<SegmenterLstmAutoV1, D>(provider, LO_LSTM)?
-                .map(DataPayload::cast)
-                .map(DictOrLstm::Lstm),
-            th: try_load::<SegmenterLstmAutoV1, D>(provider, TH_LSTM)?
-                .map(DataPayload::cast)
-                .map(DictOrLstm::Lstm),
-            ja: try_load::<SegmenterDictionaryAutoV1, D>(provider, CJ_DICT)?.map(DataPayload::cast),
+        Self::try_new_with(provider, |dictionary, lstm| {
+            match try_load_lstm(provider, lstm)? {
+                Some(lstm) => Ok(Some(lstm)),
+                None => try_load_dict::<SegmenterDictionaryAutoV1, D>(provider, dictionary),
+            }
         })
     }
 
     pub(crate) fn try_new_southeast_asian<D>(provider: &D) -> Result<Self, DataError>
     where
         D: DataProvider<SegmenterDictionaryExtendedV1>
+            + DataProvider<SegmenterComplexScriptsV1>
             + DataProvider<SegmenterBreakGraphemeClusterV1>
             + ?Sized,
     {
-        Ok(Self {
-            grapheme: GraphemeClusterSegmenter::try_new_unstable(provider)?,
-            my: try_load::<SegmenterDictionaryExtendedV1, _>(provider, MY_DICT)?
-                .map(DataPayload::cast)
-                .map(DictOrLstm::Dict),
-            km: try_load::<SegmenterDictionaryExtendedV1, _>(provider, KM_DICT)?
-                .map(DataPayload::cast)
-                .map(DictOrLstm::Dict),
-            lo: try_load::<SegmenterDictionaryExtendedV1, _>(provider, LO_DICT)?
-                .map(DataPayload::cast)
-                .map(DictOrLstm::Dict),
-            th: try_load::<SegmenterDictionaryExtendedV1, _>(provider, TH_DICT)?
-                .map(DataPayload::cast)
-                .map(DictOrLstm::Dict),
-            ja: None,
+        Self::try_new_with(provider, |dictionary, _| {
+            try_load_dict::<SegmenterDictionaryExtendedV1, D>(provider, dictionary)
         })
     }
 }
+
+fn try_load_dict<M, P>(provider: &P, dictionary: &str) -> Result<Option<DictOrLstm>, DataError>
+where
+    M: DataMarker<DataStruct = UCharDictionaryBreakData<'static>>,
+    P: DataProvider<M> + ?Sized,
+{
+    let Some(dictionary) = attributes(dictionary) else {
+        return Ok(None);
+    };
+    Ok(try_load::<M, P>(provider, dictionary)?
+        .map(DataPayload::cast)
+        .map(DictOrLstm::Dict))
+}
+
+#[cfg(feature = "lstm")]
+fn try_load_lstm<P>(provider: &P, lstm: &str) -> Result<Option<DictOrLstm>, DataError>
+where
+    P: DataProvider<SegmenterLstmAutoV1> + ?Sized,
+{
+    let Some(lstm) = attributes(lstm) else {
+        return Ok(None);
+    };
+    Ok(try_load::<SegmenterLstmAutoV1, P>(provider, lstm)?
+        .map(DataPayload::cast)
+        .map(DictOrLstm::Lstm))
+}
+
 fn try_load<M: DataMarker, P: DataProvider<M> + ?Sized>(
     provider: &P,
-    model: &'static DataMarkerAttributes,
+    model: &DataMarkerAttributes,
 ) -> Result<Option<DataPayload<M>>, DataError> {
     provider
         .load(DataRequest {
@@ -401,7 +439,7 @@ fn try_load<M: DataMarker, P: DataProvider<M> + ?Sized>(
 #[cfg(feature = "compiled_data")]
 fn try_load_static<M: DataMarker, P: DataProvider<M> + ?Sized>(
     provider: &P,
-    model: &'static DataMarkerAttributes,
+    model: &DataMarkerAttributes,
 ) -> Result<Option<&'static <M::DataStruct as yoke::Yokeable<'static>>::Output>, DataError> {
     provider
         .load(DataRequest {
@@ -442,4 +480,42 @@ mod tests {
         );
         assert_eq!(dict.complex_language_segment_utf16(&utf16), [4, 7, 11, 14]);
     }
+
+    /// A provider like the data of ICU4X 2.0, which predates [`SegmenterComplexScriptsV1`].
+    struct PreComplexScriptsProvider;
+
+    impl<M: DataMarker> DataProvider<M> for PreComplexScriptsProvider
+    where
+        crate::provider::Baked: DataProvider<M>,
+    {
+        fn load(&self, req: DataRequest) -> Result<DataResponse<M>, DataError> {
+            if M::INFO == SegmenterComplexScriptsV1::INFO {
+                return Err(DataErrorKind::MarkerNotFound.with_req(M::INFO, req));
+            }
+            crate::provider::Baked.load(req)
+        }
+    }
+
+    #[test]
+    fn thai_word_break_without_scripts_data() {
+        const TEST_STR: &str = "ภาษาไทยภาษาไทย";
+        let utf16: Vec<u16> = TEST_STR.encode_utf16().collect();
+
+        let lstm = ComplexPayloads::try_new_lstm(&PreComplexScriptsProvider).unwrap();
+        let lstm = lstm.as_borrowed();
+        assert_eq!(
+            lstm.complex_language_segment_str(TEST_STR),
+            [12, 21, 33, 42]
+        );
+        assert_eq!(lstm.complex_language_segment_utf16(&utf16), [4, 7, 11, 14]);
+
+        assert!(ComplexPayloads::try_new_dict(&PreComplexScriptsProvider).is_ok());
+    }
+
+    #[test]
+    fn compiled_models_fit() {
+        let scripts =
+            ScriptTable::Data(crate::provider::Baked::SINGLETON_SEGMENTER_COMPLEX_SCRIPTS_V1);
+        assert!(scripts.len() <= COMPILED_MODELS);
+    }
 }
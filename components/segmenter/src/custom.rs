@@ -0,0 +1,586 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use alloc::collections::BTreeMap;
+use alloc::string::String;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::ops::RangeInclusive;
+use icu_collections::codepointtrie;
+
+use crate::indices::{Latin1Indices, Utf16Indices};
+use crate::iterator_helpers::derive_usize_iterator_with_type;
+use crate::options::WordType;
+use crate::provider::*;
+use crate::rule_segmenter::*;
+use crate::stream::{stable_breaks, StreamingSegmenter};
+use utf8_iter::Utf8CharIndices;
+
+/// The name matching any property in a rule.
+const ANY: &str = "Any";
+/// The property of the code points that are not in any property.
+const UNKNOWN: &str = "Unknown";
+/// The start of text.
+const SOT: &str = "sot";
+/// The end of text.
+const EOT: &str = "eot";
+
+/// The number of properties and states that can be referenced by [`BreakState::Index`].
+const MAX_STATES: usize = 120;
+
+/// An error returned when building a [`CustomSegmenter`] from break rules.
+#[derive(Debug, Clone, PartialEq, Eq, displaydoc::Display)]
+#[non_exhaustive]
+pub enum CustomRulesError {
+    /// A rule, state or word type refers to a name that is neither a property nor a state.
+    #[displaydoc("Unknown property or state {0:?}")]
+    UnknownName(String),
+    /// A property is defined twice, or a property or state uses one of the predefined
+    /// names `Any`, `Unknown`, `sot` and `eot`.
+    #[displaydoc("Duplicate property or state {0:?}")]
+    DuplicateName(String),
+    /// There are more properties and states than the segmenter data can represent.
+    #[displaydoc("Too many properties and states")]
+    TooManyStates,
+    /// The properties are too fragmented to be stored in a code point trie.
+    #[displaydoc("The properties are too fragmented")]
+    PropertiesTooComplex,
+}
+
+impl core::error::Error for CustomRulesError {}
+
+/// Implements the [`Iterator`] trait over the boundaries of the given string, as defined by the
+/// rules of a [`CustomSegmenter`].
+///
+/// Lifetimes:
+///
+/// - `'data` = lifetime of the segmenter object from which this iterator was created
+/// - `'s` = lifetime of the string being segmented
+///
+/// The [`Iterator::Item`] is an [`usize`] representing index of a code unit
+/// _after_ the boundary (for a boundary at the end of text, this index is the length
+/// of the [`str`] or array of code units).
+///
+/// For examples of use, see [`CustomSegmenterBuilder`].
+#[derive(Debug)]
+pub struct CustomBreakIterator<'data, 's, Y: RuleBreakType>(RuleBreakIterator<'data, 's, Y>);
+
+derive_usize_iterator_with_type!(CustomBreakIterator, 'data);
+
+impl<Y: RuleBreakType> CustomBreakIterator<'_, '_, Y> {
+    /// Returns the word type of the segment preceding the current boundary, as set with
+    /// [`CustomSegmenterBuilder::set_word_type()`].
+    #[inline]
+    pub fn word_type(&self) -> WordType {
+        self.0.word_type()
+    }
+
+    /// Returns `true` when the segment preceding the current boundary is word-like.
+    #[inline]
+    pub fn is_word_like(&self) -> bool {
+        self.word_type().is_word_like()
+    }
+}
+
+/// A builder for a [`CustomSegmenter`], which segments text with break rules in the style of
+/// [Unicode Standard Annex #29][UAX29], _Unicode Text Segmentation_.
+///
+/// The rules are compiled into the same form of data as the rules of [`GraphemeClusterSegmenter`],
+/// [`WordSegmenter`] and [`SentenceSegmenter`]:
+///
+/// - *Properties* partition the code points. Code points that are in no property have the
+///   `Unknown` property.
+/// - *Rules* decide whether there is a boundary between two adjacent properties. The name `Any`
+///   matches any property, and the names `sot` and `eot` stand for the start and the end of text.
+///   The first rule that matches a pair of properties applies, like in UAX #29. A pair of
+///   properties that no rule matches is a boundary.
+/// - *States* extend rules to sequences of more than two code points. A state stands for a
+///   property followed by another one, and rules can use it as the left side.
+///
+/// # Examples
+///
+/// Keep hashtags together:
+///
+/// ```rust
+/// use icu::segmenter::options::WordType;
+/// use icu::segmenter::CustomSegmenterBuilder;
+///
+/// let mut builder = CustomSegmenterBuilder::new();
+/// builder.add_property("Letter", [0x41..=0x5A, 0x61..=0x7A]); // A-Z, a-z
+/// builder.add_property("Hash", [0x23..=0x23]); // #
+/// builder.set_word_type("Letter", WordType::Letter);
+/// builder.add_keep_rule(&["Letter"], &["Letter"]);
+/// builder.add_keep_rule(&["Hash"], &["Letter"]);
+/// builder.add_break_rule(&["Any"], &["Any"]);
+/// let segmenter = builder.build().unwrap();
+///
+/// let text = "I #love it";
+/// let breakpoints: Vec<usize> =
+///     segmenter.as_borrowed().segment_str(text).collect();
+/// assert_eq!(&breakpoints, &[0, 1, 2, 7, 8, 10]);
+/// ```
+///
+/// Keep e-mail addresses together, using a state to only keep `@` and `.` when they are
+/// followed by a letter:
+///
+/// ```rust
+/// use icu::properties::{props::Alphabetic, CodePointSetData};
+/// use icu::segmenter::CustomSegmenterBuilder;
+/// use itertools::Itertools;
+///
+/// let mut builder = CustomSegmenterBuilder::new();
+/// builder.add_property(
+///     "Letter",
+///     CodePointSetData::new::<Alphabetic>().iter_ranges(),
+/// );
+/// builder.add_property("Mid", [0x2E..=0x2E, 0x40..=0x40]); // . @
+/// builder.add_state("Letter_Mid", "Letter", "Mid");
+/// builder.add_keep_rule(&["Letter"], &["Letter"]);
+/// builder.add_keep_rule(&["Letter_Mid"], &["Letter"]);
+/// builder.add_break_rule(&["Any"], &["Any"]);
+/// let segmenter = builder.build().unwrap();
+///
+/// let text = "Write to me@example.com.";
+/// let segments: Vec<&str> = segmenter
+///     .as_borrowed()
+///     .segment_str(text)
+///     .tuple_windows()
+///     .map(|(i, j)| &text[i..j])
+///     .collect();
+/// assert_eq!(&segments, &["Write", " ", "to", " ", "me@example.com", "."]);
+/// ```
+///
+/// [UAX29]: https://www.unicode.org/reports/tr29/
+/// [`GraphemeClusterSegmenter`]: crate::GraphemeClusterSegmenter
+/// [`WordSegmenter`]: crate::WordSegmenter
+/// [`SentenceSegmenter`]: crate::SentenceSegmenter
+#[derive(Debug, Default)]
+pub struct CustomSegmenterBuilder {
+    properties: Vec<(String, Vec<RangeInclusive<u32>>)>,
+    states: Vec<StateDefinition>,
+    rules: Vec<RuleDefinition>,
+    word_types: Vec<(String, WordType)>,
+}
+
+#[derive(Debug)]
+struct StateDefinition {
+    name: String,
+    left: String,
+    right: String,
+    intermediate: bool,
+}
+
+#[derive(Debug)]
+struct RuleDefinition {
+    left: Vec<String>,
+    right: Vec<String>,
+    break_state: BreakState,
+}
+
+impl CustomSegmenterBuilder {
+    /// Creates a builder without properties, states, or rules.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a property for the code points in `ranges`.
+    ///
+    /// Code points that are in the ranges of several properties have the last one.
+    pub fn add_property(
+        &mut self,
+        name: &str,
+        ranges: impl IntoIterator<Item = RangeInclusive<u32>>,
+    ) {
+        self.properties
+            .push((name.into(), ranges.into_iter().collect()));
+    }
+
+    /// Adds a state `name` for the property `left` followed by the property `right`.
+    ///
+    /// If a rule with `name` on the left side does not match the property that follows, the
+    /// segmenter falls back to the boundary before `right`. `name` may also be an existing
+    /// property or state, which continues matching as if that property had been seen.
+    pub fn add_state(&mut self, name: &str, left: &str, right: &str) {
+        self.states.push(StateDefinition {
+            name: name.into(),
+            left: left.into(),
+            right: right.into(),
+            intermediate: false,
+        });
+    }
+
+    /// Adds a state like [`Self::add_state()`], for an intermediate match: the segmenter keeps
+    /// the boundary before `right` as its fallback, and looks ahead for a longer match.
+    pub fn add_intermediate_state(&mut self, name: &str, left: &str, right: &str) {
+        self.states.push(StateDefinition {
+            name: name.into(),
+            left: left.into(),
+            right: right.into(),
+            intermediate: true,
+        });
+    }
+
+    /// Adds a rule with a boundary between any property or state in `left` and any property in
+    /// `right` (`left ÷ right` in UAX #29).
+    pub fn add_break_rule(&mut self, left: &[&str], right: &[&str]) {
+        self.add_rule(left, right, BreakState::Break);
+    }
+
+    /// Adds a rule without a boundary between any property or state in `left` and any property
+    /// in `right` (`left × right` in UAX #29).
+    pub fn add_keep_rule(&mut self, left: &[&str], right: &[&str]) {
+        self.add_rule(left, right, BreakState::Keep);
+    }
+
+    /// Adds a rule that makes the states in `left` fall back to their previous boundary when
+    /// they are followed by any property in `right`.
+    pub fn add_no_match_rule(&mut self, left: &[&str], right: &[&str]) {
+        self.add_rule(left, right, BreakState::NoMatch);
+    }
+
+    fn add_rule(&mut self, left: &[&str], right: &[&str], break_state: BreakState) {
+        self.rules.push(RuleDefinition {
+            left: left.iter().map(|&s| s.into()).collect(),
+            right: right.iter().map(|&s| s.into()).collect(),
+            break_state,
+        });
+    }
+
+    /// Sets the [`WordType`] reported by [`CustomBreakIterator::word_type()`] for segments that
+    /// end with the property or state `name`.
+    pub fn set_word_type(&mut self, name: &str, word_type: WordType) {
+        self.word_types.push((name.into(), word_type));
+    }
+
+    /// Compiles the rules into a [`CustomSegmenter`].
+    pub fn build(self) -> Result<CustomSegmenter, CustomRulesError> {
+        Ok(CustomSegmenter {
+            data: self.build_data()?,
+        })
+    }
+
+    /// Compiles the rules into [`RuleBreakData`], the data of the rule-based segmenters.
+    ///
+    /// <div class="stab unstable">
+    /// 🚧 This code is considered unstable; it may change at any time, in breaking or non-breaking ways,
+    /// including in SemVer minor releases. Use [`Self::build()`] instead.
+    /// </div>
+    pub fn build_data(self) -> Result<RuleBreakData<'static>, CustomRulesError> {
+        let is_reserved = |name: &str| [ANY, UNKNOWN, SOT, EOT].contains(&name);
+
+        let mut names: Vec<&str> = vec![UNKNOWN];
+        for (name, _) in &self.properties {
+            if is_reserved(name) || names.contains(&name.as_str()) {
+                return Err(CustomRulesError::DuplicateName(name.clone()));
+            }
+            names.push(name);
+        }
+        let simple_properties_count = names.len();
+        for state in &self.states {
+            if state.name == ANY || state.name == SOT || state.name == EOT {
+                return Err(CustomRulesError::DuplicateName(state.name.clone()));
+            }
+            if !names.contains(&state.name.as_str()) {
+                names.push(&state.name);
+            }
+        }
+        names.push(SOT);
+        names.push(EOT);
+
+        let property_count = names.len();
+        if property_count > MAX_STATES {
+            return Err(CustomRulesError::TooManyStates);
+        }
+        let index = |name: &str| {
+            names
+                .iter()
+                .position(|&n| n == name)
+                .ok_or_else(|| CustomRulesError::UnknownName(name.into()))
+        };
+
+        // An earlier rule takes precedence, but a rule may override a `NoMatch` state.
+        fn set_break_state(table: &mut [Option<BreakState>], index: usize, state: BreakState) {
+            if let Some(entry @ (None | Some(BreakState::NoMatch))) = table.get_mut(index) {
+                *entry = Some(state);
+            }
+        }
+        let mut break_state_table = vec![None; property_count * property_count];
+        for rule in &self.rules {
+            for left in &rule.left {
+                if left == ANY {
+                    for right in &rule.right {
+                        if right == ANY {
+                            // Fill all unset entries.
+                            for entry in break_state_table.iter_mut().filter(|e| e.is_none()) {
+                                *entry = Some(rule.break_state);
+                            }
+                        } else {
+                            let right = index(right)?;
+                            for left in 0..simple_properties_count {
+                                set_break_state(
+                                    &mut break_state_table,
+                                    left * property_count + right,
+                                    rule.break_state,
+                                );
+                            }
+                        }
+                    }
+                    continue;
+                }
+                let left = index(left)?;
+                for right in &rule.right {
+                    if right == ANY {
+                        for right in 0..property_count {
+                            set_break_state(
+                                &mut break_state_table,
+                                left * property_count + right,
+                                rule.break_state,
+                            );
+                        }
+                        continue;
+                    }
+                    let right = index(right)?;
+                    set_break_state(
+                        &mut break_state_table,
+                        left * property_count + right,
+                        rule.break_state,
+                    );
+                    // A state only matches the properties of its rules.
+                    if left >= simple_properties_count {
+                        for right in 0..property_count {
+                            set_break_state(
+                                &mut break_state_table,
+                                left * property_count + right,
+                                BreakState::NoMatch,
+                            );
+                        }
+                    }
+                }
+            }
+        }
+
+        for state in &self.states {
+            let target = index(&state.name)? as u8;
+            if let Some(entry) = break_state_table
+                .get_mut(index(&state.left)? * property_count + index(&state.right)?)
+            {
+                *entry = Some(if state.intermediate {
+                    BreakState::Intermediate(target)
+                } else {
+                    BreakState::Index(target)
+                });
+            }
+        }
+
+        let mut word_type_table = vec![WordType::None; property_count - 1];
+        for (name, word_type) in &self.word_types {
+            if let Some(entry) = index(name)?
+                .checked_sub(1)
+                .and_then(|i| word_type_table.get_mut(i))
+            {
+                *entry = *word_type;
+            }
+        }
+
+        let mut properties_map = BTreeMap::new();
+        for (i, (_, ranges)) in self.properties.iter().enumerate() {
+            for range in ranges {
+                for c in *range.start()..=(*range.end()).min(char::MAX as u32) {
+                    properties_map.insert(c, i as u8 + 1);
+                }
+            }
+        }
+
+        Ok(RuleBreakData {
+            property_table: codepointtrie::builder::build_fast(&properties_map, 0)
+                .ok_or(CustomRulesError::PropertiesTooComplex)?,
+            break_state_table: break_state_table
+                .into_iter()
+                // Pairs that no rule matches are boundaries.
+                .map(|s| s.unwrap_or(BreakState::Break))
+                .collect(),
+            word_type_table: word_type_table.into_iter().collect(),
+            property_count: property_count as u8,
+            last_codepoint_property: (simple_properties_count - 1) as u8,
+            sot_property: (property_count - 2) as u8,
+            eot_property: (property_count - 1) as u8,
+            // Custom rules don't handle complex languages.
+            complex_property: 127,
+        })
+    }
+}
+
+/// Segments a string with custom break rules.
+///
+/// Most segmentation methods live on [`CustomSegmenterBorrowed`], which can be obtained via
+/// [`CustomSegmenter::as_borrowed()`].
+///
+/// A [`CustomSegmenter`] is built from rules with a [`CustomSegmenterBuilder`], see there for
+/// examples.
+#[derive(Debug)]
+pub struct CustomSegmenter {
+    data: RuleBreakData<'static>,
+}
+
+/// Segments a string with custom break rules (borrowed version).
+///
+/// See [`CustomSegmenterBuilder`] for examples.
+#[derive(Clone, Debug, Copy)]
+pub struct CustomSegmenterBorrowed<'data> {
+    data: &'data RuleBreakData<'data>,
+}
+
+impl CustomSegmenter {
+    /// Constructs a borrowed version of this type for more efficient querying.
+    ///
+    /// Most useful methods for segmentation are on this type.
+    pub fn as_borrowed(&self) -> CustomSegmenterBorrowed<'_> {
+        CustomSegmenterBorrowed { data: &self.data }
+    }
+}
+
+impl<'data> CustomSegmenterBorrowed<'data> {
+    fn segment<'s, Y: RuleBreakType>(
+        self,
+        iter: Y::IterAttr<'s>,
+        len: usize,
+    ) -> CustomBreakIterator<'data, 's, Y> {
+        CustomBreakIterator(RuleBreakIterator {
+            iter,
+            len,
+            current_pos_data: None,
+            reached_eof: false,
+            result_cache: Vec::new(),
+            data: self.data,
+            complex: None,
+            boundary_property: 0,
+            locale_override: None,
+            handle_complex_language: empty_handle_complex_language,
+        })
+    }
+
+    /// Creates a break iterator for an `str` (a UTF-8 string).
+    ///
+    /// There are always breakpoints at 0 and the string length, or only at 0 for the empty string.
+    pub fn segment_str<'s>(self, input: &'s str) -> CustomBreakIterator<'data, 's, Utf8> {
+        self.segment(input.char_indices(), input.len())
+    }
+
+    /// Creates a break iterator for a potentially ill-formed UTF8 string
+    ///
+    /// Invalid characters are treated as REPLACEMENT CHARACTER
+    ///
+    /// There are always breakpoints at 0 and the string length, or only at 0 for the empty string.
+    pub fn segment_utf8<'s>(
+        self,
+        input: &'s [u8],
+    ) -> CustomBreakIterator<'data, 's, PotentiallyIllFormedUtf8> {
+        self.segment(Utf8CharIndices::new(input), input.len())
+    }
+
+    /// Creates a break iterator for a Latin-1 (8-bit) string.
+    ///
+    /// There are always breakpoints at 0 and the string length, or only at 0 for the empty string.
+    pub fn segment_latin1<'s>(self, input: &'s [u8]) -> CustomBreakIterator<'data, 's, Latin1> {
+        self.segment(Latin1Indices::new(input), input.len())
+    }
+
+    /// Creates a break iterator for a UTF-16 string.
+    ///
+    /// There are always breakpoints at 0 and the string length, or only at 0 for the empty string.
+    pub fn segment_utf16<'s>(self, input: &'s [u16]) -> CustomBreakIterator<'data, 's, Utf16> {
+        self.segment(Utf16Indices::new(input), input.len())
+    }
+}
+
+impl crate::private::Sealed for CustomSegmenterBorrowed<'_> {}
+
+impl StreamingSegmenter for CustomSegmenterBorrowed<'_> {
+    fn stable_breaks_str(self, input: &str, is_final: bool) -> (Vec<usize>, usize) {
+        stable_breaks(self.segment_str(input).0, is_final)
+    }
+
+    fn stable_breaks_utf16(self, input: &[u16], is_final: bool) -> (Vec<usize>, usize) {
+        stable_breaks(self.segment_utf16(input).0, is_final)
+    }
+}
+
+#[test]
+fn supplementary_properties() {
+    let mut builder = CustomSegmenterBuilder::new();
+    builder.add_property("Emoji", [0x1F600..=0x1F64F]);
+    builder.add_property("Han", [0x4E00..=0x9FFF, 0x20000..=0x2A6DF]);
+    builder.add_keep_rule(&["Han"], &["Han"]);
+    builder.add_keep_rule(&["Emoji"], &["Emoji"]);
+    builder.add_break_rule(&["Any"], &["Any"]);
+    let data = builder.build_data().unwrap();
+    assert_eq!(data.property_table.get32(0x41), 0);
+    assert_eq!(data.property_table.get32(0x1F600), 1);
+    assert_eq!(data.property_table.get32(0x1F650), 0);
+    assert_eq!(data.property_table.get32(0x4E00), 2);
+    assert_eq!(data.property_table.get32(0x2A6DF), 2);
+    assert_eq!(data.property_table.get32(0x2A6E0), 0);
+    assert_eq!(data.property_table.get32(0x10FFFF), 0);
+
+    let segmenter = CustomSegmenter { data };
+    let text = "中𠀀文😀😃a";
+    let breaks: Vec<usize> = segmenter.as_borrowed().segment_str(text).collect();
+    assert_eq!(breaks, [0, 10, 18, 19]);
+    let utf16: Vec<u16> = text.encode_utf16().collect();
+    let breaks: Vec<usize> = segmenter.as_borrowed().segment_utf16(&utf16).collect();
+    assert_eq!(breaks, [0, 4, 8, 9]);
+}
+
+#[test]
+fn property_table() {
+    let mut builder = CustomSegmenterBuilder::new();
+    let ranges = |offset: u32| {
+        (0..0x30000)
+            .step_by(997)
+            .map(move |start| start + offset..=start + offset + 100)
+    };
+    builder.add_property("A", ranges(0));
+    builder.add_property("B", ranges(50));
+    builder.add_property("C", [0xE0000..=0xE007F]);
+    let data = builder.build_data().unwrap();
+
+    let mut expected = vec![0; 0x110000];
+    for (value, ranges) in [(1, ranges(0)), (2, ranges(50))] {
+        for range in ranges {
+            expected[*range.start() as usize..=*range.end() as usize].fill(value);
+        }
+    }
+    expected[0xE0000..=0xE007F].fill(3);
+    for (c, &value) in expected.iter().enumerate() {
+        assert_eq!(data.property_table.get32(c as u32), value, "U+{c:04X}");
+    }
+}
+
+#[test]
+fn invalid_rules() {
+    let mut builder = CustomSegmenterBuilder::new();
+    builder.add_property("Letter", [0x61..=0x7A]);
+    builder.add_keep_rule(&["Letter"], &["Digit"]);
+    assert_eq!(
+        builder.build().unwrap_err(),
+        CustomRulesError::UnknownName("Digit".into())
+    );
+
+    let mut builder = CustomSegmenterBuilder::new();
+    builder.add_property("Letter", [0x61..=0x7A]);
+    builder.add_property("Letter", [0x41..=0x5A]);
+    assert_eq!(
+        builder.build().unwrap_err(),
+        CustomRulesError::DuplicateName("Letter".into())
+    );
+
+    let mut builder = CustomSegmenterBuilder::new();
+    for i in 0..MAX_STATES as u32 {
+        builder.add_property(&alloc::format!("P{i}"), [i..=i]);
+    }
+    assert_eq!(
+        builder.build().unwrap_err(),
+        CustomRulesError::TooManyStates
+    );
+}
@@ -9,6 +9,7 @@ use crate::indices::{Latin1Indices, Utf16Indices};
 use crate::iterator_helpers::derive_usize_iterator_with_type;
 use crate::provider::*;
 use crate::rule_segmenter::*;
+use crate::stream::{stable_breaks, StreamingSegmenter};
 use utf8_iter::Utf8CharIndices;
 
 /// Implements the [`Iterator`] trait over the grapheme cluster boundaries of the given string.
@@ -166,6 +167,7 @@ impl<'data> GraphemeClusterSegmenterBorrowed<'data> {
             iter: input.char_indices(),
             len: input.len(),
             current_pos_data: None,
+            reached_eof: false,
             result_cache: Vec::new(),
             data: self.data,
             complex: None,
@@ -187,6 +189,7 @@ impl<'data> GraphemeClusterSegmenterBorrowed<'data> {
             iter: Utf8CharIndices::new(input),
             len: input.len(),
             current_pos_data: None,
+            reached_eof: false,
             result_cache: Vec::new(),
             data: self.data,
             complex: None,
@@ -206,6 +209,7 @@ impl<'data> GraphemeClusterSegmenterBorrowed<'data> {
             iter: Latin1Indices::new(input),
             len: input.len(),
             current_pos_data: None,
+            reached_eof: false,
             result_cache: Vec::new(),
             data: self.data,
             complex: None,
@@ -226,6 +230,7 @@ impl<'data> GraphemeClusterSegmenterBorrowed<'data> {
             iter: Utf16Indices::new(input),
             len: input.len(),
             current_pos_data: None,
+            reached_eof: false,
             result_cache: Vec::new(),
             data: self.data,
             complex: None,
@@ -235,6 +240,18 @@ impl<'data> GraphemeClusterSegmenterBorrowed<'data> {
         })
     }
 }
+impl crate::private::Sealed for GraphemeClusterSegmenterBorrowed<'_> {}
+
+impl StreamingSegmenter for GraphemeClusterSegmenterBorrowed<'_> {
+    fn stable_breaks_str(self, input: &str, is_final: bool) -> (Vec<usize>, usize) {
+        stable_breaks(self.segment_str(input).0, is_final)
+    }
+
+    fn stable_breaks_utf16(self, input: &[u16], is_final: bool) -> (Vec<usize>, usize) {
+        stable_breaks(self.segment_utf16(input).0, is_final)
+    }
+}
+
 impl GraphemeClusterSegmenterBorrowed<'static> {
     /// Cheaply converts a [`GraphemeClusterSegmenterBorrowed<'static>`] into a [`GraphemeClusterSegmenter`].
     ///
@@ -0,0 +1,254 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use alloc::string::String;
+use alloc::vec;
+use alloc::vec::Vec;
+use icu_locale_core::LanguageIdentifier;
+use icu_provider::prelude::*;
+use zerovec::VarZeroSlice;
+
+use crate::provider::*;
+
+/// Finds the points at which words can be hyphenated, using TeX-style hyphenation patterns for
+/// a language.
+///
+/// The patterns are not included in compiled data; they can be generated from the
+/// [hyph-utf8](https://ctan.org/pkg/hyph-utf8) pattern files with `icu4x-datagen`.
+///
+/// Most methods live on [`HyphenatorBorrowed`], which can be obtained via
+/// [`Hyphenator::as_borrowed()`]. A hyphenator can also be added to a [`LineSegmenter`] to
+/// find hyphenation points along with the line break opportunities, see
+/// [`LineSegmenter::with_hyphenator()`].
+///
+/// # Examples
+///
+/// ```
+/// use icu::locale::{langid, LocaleFallbacker};
+/// use icu::segmenter::Hyphenator;
+/// use icu_provider_adapters::fallback::LocaleFallbackProvider;
+/// # use icu::segmenter::provider::{HyphenationPatterns, SegmenterHyphenationPatternsV1};
+/// # use icu_provider::prelude::*;
+/// # struct ExamplePatterns;
+/// # impl DataProvider<SegmenterHyphenationPatternsV1> for ExamplePatterns {
+/// #     fn load(&self, req: DataRequest) -> Result<DataResponse<SegmenterHyphenationPatternsV1>, DataError> {
+/// #         if req.id.locale != &langid!("en").into() {
+/// #             return Err(DataErrorKind::IdentifierNotFound.with_req(SegmenterHyphenationPatternsV1::INFO, req));
+/// #         }
+/// #         let patterns = ["hen", "hena", "henat", "hyph", "io", "na", "nat", "on", "tio"];
+/// #         let pattern_levels: [&[u8]; 9] = [
+/// #             &[0, 0, 2, 0],
+/// #             &[0, 0, 0, 0, 4],
+/// #             &[0, 0, 0, 5, 0, 0],
+/// #             &[0, 0, 3, 0, 0],
+/// #             &[2, 0, 0],
+/// #             &[1, 0, 0],
+/// #             &[0, 2, 0, 0],
+/// #             &[0, 2, 0],
+/// #             &[1, 0, 0, 0],
+/// #         ];
+/// #         Ok(DataResponse {
+/// #             metadata: Default::default(),
+/// #             payload: DataPayload::from_owned(HyphenationPatterns {
+/// #                 patterns: patterns.as_slice().into(),
+/// #                 pattern_levels: pattern_levels.as_slice().into(),
+/// #                 exceptions: ["table"].as_slice().into(),
+/// #                 exception_points: [[2].as_slice()].as_slice().into(),
+/// #                 max_pattern_length: 5,
+/// #                 left_min: 2,
+/// #                 right_min: 3,
+/// #             }),
+/// #         })
+/// #     }
+/// # }
+///
+/// // For this example, `ExamplePatterns` provides a handful of made-up English patterns
+/// // for `en` only. Real patterns are generated from hyph-utf8 by `icu4x-datagen`.
+/// let provider = LocaleFallbackProvider::new(
+///     ExamplePatterns,
+///     LocaleFallbacker::new().static_to_owned(),
+/// );
+///
+/// // `en-US` falls back to the `en` patterns.
+/// let hyphenator =
+///     Hyphenator::try_new_unstable(&provider, &langid!("en-US")).unwrap();
+///
+/// assert_eq!(hyphenator.as_borrowed().hyphenate("hyphenation"), [2, 6]);
+/// assert_eq!(hyphenator.as_borrowed().hyphenate("Table"), [2]);
+/// ```
+///
+/// [`LineSegmenter`]: crate::LineSegmenter
+/// [`LineSegmenter::with_hyphenator()`]: crate::LineSegmenter::with_hyphenator
+#[derive(Debug)]
+pub struct Hyphenator {
+    payload: DataPayload<SegmenterHyphenationPatternsV1>,
+}
+
+/// Finds the points at which words can be hyphenated (borrowed version).
+///
+/// See [`Hyphenator`] for examples.
+#[derive(Clone, Debug, Copy)]
+pub struct HyphenatorBorrowed<'data> {
+    patterns: &'data VarZeroSlice<str>,
+    pattern_levels: &'data VarZeroSlice<[u8]>,
+    exceptions: &'data VarZeroSlice<str>,
+    exception_points: &'data VarZeroSlice<[u8]>,
+    max_pattern_length: u8,
+    left_min: u8,
+    right_min: u8,
+}
+
+impl Hyphenator {
+    icu_provider::gen_buffer_data_constructors!(
+        (content_locale: &LanguageIdentifier) -> error: DataError,
+        functions: [
+            try_new: skip,
+            try_new_with_buffer_provider,
+            try_new_unstable,
+            Self
+        ]
+    );
+
+    /// Creates a new [`Hyphenator`] for the given language using data from the given provider.
+    ///
+    /// The hyphenation patterns are not included in compiled data; they can be generated from
+    /// the hyph-utf8 pattern files with `icu4x-datagen`.
+    ///
+    /// The data is loaded for exactly `content_locale`. To fall back to less specific locales,
+    /// such as from `de-CH` to `de`, wrap the provider in a `LocaleFallbackProvider` from
+    /// `icu_provider_adapters`, as in the example on [`Hyphenator`].
+    ///
+    /// [📚 Help choosing a constructor](icu_provider::constructors)
+    pub fn try_new_unstable<D>(
+        provider: &D,
+        content_locale: &LanguageIdentifier,
+    ) -> Result<Self, DataError>
+    where
+        D: DataProvider<SegmenterHyphenationPatternsV1> + ?Sized,
+    {
+        let locale = DataLocale::from(content_locale);
+        Ok(Self {
+            payload: provider
+                .load(DataRequest {
+                    id: DataIdentifierBorrowed::for_locale(&locale),
+                    ..Default::default()
+                })?
+                .payload,
+        })
+    }
+
+    /// Constructs a borrowed version of this type for more efficient querying.
+    pub fn as_borrowed(&self) -> HyphenatorBorrowed<'_> {
+        let data = self.payload.get();
+        HyphenatorBorrowed {
+            patterns: &data.patterns,
+            pattern_levels: &data.pattern_levels,
+            exceptions: &data.exceptions,
+            exception_points: &data.exception_points,
+            max_pattern_length: data.max_pattern_length,
+            left_min: data.left_min,
+            right_min: data.right_min,
+        }
+    }
+}
+
+impl<'data> HyphenatorBorrowed<'data> {
+    /// Returns the byte indices at which `word` can be hyphenated.
+    ///
+    /// The whole string is hyphenated as one word; to find the hyphenation points of the words
+    /// in a text, add this hyphenator to a [`LineSegmenter`](crate::LineSegmenter).
+    pub fn hyphenate(self, word: &str) -> Vec<usize> {
+        let (indices, chars): (Vec<usize>, Vec<char>) = word.char_indices().unzip();
+        self.hyphenation_points(&chars)
+            .into_iter()
+            .filter_map(|i| indices.get(i).copied())
+            .collect()
+    }
+
+    /// Returns the indices of the characters of `word` before which it can be hyphenated.
+    pub(crate) fn hyphenation_points(self, word: &[char]) -> Vec<usize> {
+        // There is never a hyphenation point at the start or end of the word.
+        let left_min = usize::from(self.left_min).max(1);
+        let right_min = usize::from(self.right_min).max(1);
+        if word.len() < left_min + right_min {
+            return Vec::new();
+        }
+
+        let mut key = String::with_capacity(word.len() + 2);
+        key.push('.');
+        for &c in word {
+            // Patterns map characters one to one, so multi-character lowercase mappings are
+            // not applied.
+            let mut lowercase = c.to_lowercase();
+            match (lowercase.next(), lowercase.next()) {
+                (Some(lowercase), None) => key.push(lowercase),
+                _ => key.push(c),
+            }
+        }
+        key.push('.');
+
+        if let Some(points) = key
+            .get(1..key.len() - 1)
+            .and_then(|word| self.exceptions.binary_search(word).ok())
+            .and_then(|index| self.exception_points.get(index))
+        {
+            return points
+                .iter()
+                .map(|&i| usize::from(i))
+                .filter(|&i| 0 < i && i < word.len())
+                .collect();
+        }
+
+        // The character boundaries of the key. The level at boundary `i + 1` is the one
+        // before character `i` of the word.
+        let boundaries: Vec<usize> = key
+            .char_indices()
+            .map(|(i, _)| i)
+            .chain([key.len()])
+            .collect();
+        let mut levels = vec![0; boundaries.len()];
+        for (start, &start_index) in boundaries.iter().enumerate() {
+            for &end_index in boundaries
+                .iter()
+                .skip(start + 1)
+                .take(usize::from(self.max_pattern_length))
+            {
+                let Some(pattern) = key
+                    .get(start_index..end_index)
+                    .and_then(|pattern| self.patterns.binary_search(pattern).ok())
+                    .and_then(|index| self.pattern_levels.get(index))
+                else {
+                    continue;
+                };
+                for (level, &pattern_level) in levels.iter_mut().skip(start).zip(pattern) {
+                    *level = pattern_level.max(*level);
+                }
+            }
+        }
+
+        (left_min..=word.len() - right_min)
+            .filter(|&i| levels.get(i + 1).is_some_and(|level| level % 2 == 1))
+            .collect()
+    }
+}
+
+impl HyphenatorBorrowed<'static> {
+    /// Cheaply converts a [`HyphenatorBorrowed<'static>`] into a [`Hyphenator`].
+    ///
+    /// Note: Due to branching and indirection, using [`Hyphenator`] might inhibit some
+    /// compile-time optimizations that are possible with [`HyphenatorBorrowed`].
+    pub fn static_to_owned(self) -> Hyphenator {
+        Hyphenator {
+            payload: DataPayload::from_owned(HyphenationPatterns {
+                patterns: self.patterns.as_varzerovec(),
+                pattern_levels: self.pattern_levels.as_varzerovec(),
+                exceptions: self.exceptions.as_varzerovec(),
+                exception_points: self.exception_points.as_varzerovec(),
+                max_pattern_length: self.max_pattern_length,
+                left_min: self.left_min,
+                right_min: self.right_min,
+            }),
+        }
+    }
+}
@@ -11,9 +11,14 @@
 //!
 //! - Line segmenter that is compatible with [Unicode Standard Annex #14][UAX14], _Unicode Line
 //!   Breaking Algorithm_, with options to tailor line-breaking behavior for CSS [`line-break`] and
-//!   [`word-break`] properties.
+//!   [`word-break`] properties, and optional hyphenation of words with a [`Hyphenator`].
 //! - Grapheme cluster segmenter, word segmenter, and sentence segmenter that are compatible with
 //!   [Unicode Standard Annex #29][UAX29], _Unicode Text Segmentation_.
+//! - Custom segmenter with break rules in the style of UAX #29 that are compiled at runtime.
+//!
+//! Text that is not available as a single slice, such as the chunks of a rope, can be segmented
+//! with [`StrBreakStream`](iterators::StrBreakStream) and
+//! [`Utf16BreakStream`](iterators::Utf16BreakStream).
 //!
 //! [UAX14]: https://www.unicode.org/reports/tr14/
 //! [UAX29]: https://www.unicode.org/reports/tr29/
@@ -127,20 +132,32 @@ mod indices;
 mod iterator_helpers;
 mod rule_segmenter;
 
+/// [`CustomSegmenter`] and its related iterators, borrowed types, and builder.
+mod custom;
 /// [`GraphemeClusterSegmenter`] and its related iterators, borrowed types, and options.
 mod grapheme;
+/// [`Hyphenator`] and its borrowed type.
+mod hyphenation;
 /// [`LineSegmenter`] and its related iterators, borrowed types, and options.
 mod line;
 /// [`SentenceSegmenter`] and its related iterators, borrowed types, and options.
 mod sentence;
+/// [`StrBreakStream`](iterators::StrBreakStream) and [`Utf16BreakStream`](iterators::Utf16BreakStream).
+mod stream;
 /// [`WordSegmenter`] and its related iterators, borrowed types, and options.
 mod word;
 
 pub mod provider;
 
 // Main Segmenter and BreakIterator public types
+pub use crate::custom::CustomRulesError;
+pub use crate::custom::CustomSegmenter;
+pub use crate::custom::CustomSegmenterBorrowed;
+pub use crate::custom::CustomSegmenterBuilder;
 pub use crate::grapheme::GraphemeClusterSegmenter;
 pub use crate::grapheme::GraphemeClusterSegmenterBorrowed;
+pub use crate::hyphenation::Hyphenator;
+pub use crate::hyphenation::HyphenatorBorrowed;
 pub use crate::line::LineSegmenter;
 pub use crate::line::LineSegmenterBorrowed;
 pub use crate::sentence::SentenceSegmenter;
@@ -150,6 +167,7 @@ pub use crate::word::WordSegmenterBorrowed;
 
 /// Options structs and enums
 pub mod options {
+    pub use crate::line::LineBreakKind;
     pub use crate::line::LineBreakOptions;
     pub use crate::line::LineBreakStrictness;
     pub use crate::line::LineBreakWordOption;
@@ -164,14 +182,17 @@ pub mod options {
 pub mod scaffold {
     pub use crate::line::LineBreakType;
     pub use crate::rule_segmenter::{Latin1, PotentiallyIllFormedUtf8, RuleBreakType, Utf16, Utf8};
+    pub use crate::stream::StreamingSegmenter;
     pub use crate::word::WordBreakType;
 }
 
 /// Types supporting iteration over segments. Obtained from the segmenter types.
 pub mod iterators {
+    pub use crate::custom::CustomBreakIterator;
     pub use crate::grapheme::GraphemeClusterBreakIterator;
-    pub use crate::line::LineBreakIterator;
+    pub use crate::line::{LineBreakIterator, LineBreakIteratorWithBreakKind};
     pub use crate::sentence::SentenceBreakIterator;
+    pub use crate::stream::{StrBreakStream, Utf16BreakStream};
     pub use crate::word::{WordBreakIterator, WordBreakIteratorWithWordType};
 }
 
@@ -3,9 +3,11 @@
 // (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
 
 use crate::complex::*;
+use crate::hyphenation::{Hyphenator, HyphenatorBorrowed};
 use crate::indices::*;
 use crate::provider::*;
 use crate::rule_segmenter::*;
+use crate::stream::{stable_breaks, StreamingSegmenter};
 use alloc::string::String;
 use alloc::vec;
 use alloc::vec::Vec;
@@ -185,6 +187,17 @@ pub enum LineBreakWordOption {
     KeepAll,
 }
 
+/// The kind of a break that is returned by [`LineBreakIterator::break_kind()`].
+#[non_exhaustive]
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum LineBreakKind {
+    /// A mandatory break or a line break opportunity of the Unicode Line Breaking Algorithm.
+    Normal,
+    /// A hyphenation point inside of a word, found by the [`Hyphenator`] of the segmenter. A
+    /// hyphen is usually displayed before a line break here.
+    Hyphenation,
+}
+
 /// Options to tailor line-breaking behavior.
 #[non_exhaustive]
 #[derive(Copy, Clone, PartialEq, Eq, Debug, Default)]
@@ -357,6 +370,7 @@ pub struct LineSegmenter {
     options: ResolvedLineBreakOptions,
     payload: DataPayload<SegmenterBreakLineV1>,
     complex: ComplexPayloads,
+    hyphenator: Option<Hyphenator>,
 }
 
 /// Segments a string into lines (borrowed version).
@@ -367,6 +381,7 @@ pub struct LineSegmenterBorrowed<'data> {
     options: ResolvedLineBreakOptions,
     data: &'data RuleBreakData<'data>,
     complex: ComplexPayloadsBorrowed<'data>,
+    hyphenator: Option<HyphenatorBorrowed<'data>>,
 }
 
 impl LineSegmenter {
@@ -407,6 +422,7 @@ impl LineSegmenter {
         D: DataProvider<SegmenterBreakLineV1>
             + DataProvider<SegmenterLstmAutoV1>
             + DataProvider<SegmenterBreakGraphemeClusterV1>
+            + DataProvider<SegmenterComplexScriptsV1>
             + ?Sized,
     {
         Self::try_new_lstm_unstable(provider, options)
@@ -430,6 +446,7 @@ impl LineSegmenter {
             options: options.into(),
             data: crate::provider::Baked::SINGLETON_SEGMENTER_BREAK_LINE_V1,
             complex: ComplexPayloadsBorrowed::new_lstm(),
+            hyphenator: None,
         }
     }
 
@@ -454,12 +471,14 @@ impl LineSegmenter {
         D: DataProvider<SegmenterBreakLineV1>
             + DataProvider<SegmenterLstmAutoV1>
             + DataProvider<SegmenterBreakGraphemeClusterV1>
+            + DataProvider<SegmenterComplexScriptsV1>
             + ?Sized,
     {
         Ok(Self {
             options: options.into(),
             payload: provider.load(Default::default())?.payload,
             complex: ComplexPayloads::try_new_lstm(provider)?,
+            hyphenator: None,
         })
     }
 
@@ -486,6 +505,7 @@ impl LineSegmenter {
             // [1]: https://www.unicode.org/reports/tr14/#ID
             // [2]: https://www.unicode.org/reports/tr14/#SA
             complex: ComplexPayloadsBorrowed::new_southeast_asian(),
+            hyphenator: None,
         }
     }
 
@@ -508,6 +528,7 @@ impl LineSegmenter {
         D: DataProvider<SegmenterBreakLineV1>
             + DataProvider<SegmenterDictionaryExtendedV1>
             + DataProvider<SegmenterBreakGraphemeClusterV1>
+            + DataProvider<SegmenterComplexScriptsV1>
             + ?Sized,
     {
         Ok(Self {
@@ -520,6 +541,7 @@ impl LineSegmenter {
             // [1]: https://www.unicode.org/reports/tr14/#ID
             // [2]: https://www.unicode.org/reports/tr14/#SA
             complex: ComplexPayloads::try_new_southeast_asian(provider)?,
+            hyphenator: None,
         })
     }
 
@@ -531,11 +553,97 @@ impl LineSegmenter {
             options: self.options,
             data: self.payload.get(),
             complex: self.complex.as_borrowed(),
+            hyphenator: self.hyphenator.as_ref().map(Hyphenator::as_borrowed),
+        }
+    }
+
+    /// Adds a [`Hyphenator`] to this segmenter, so that the hyphenation points of words are
+    /// returned along with the line break opportunities.
+    ///
+    /// See [`LineSegmenterBorrowed::with_hyphenator()`] for an example.
+    pub fn with_hyphenator(self, hyphenator: Hyphenator) -> Self {
+        Self {
+            hyphenator: Some(hyphenator),
+            ..self
         }
     }
 }
 
 impl<'data> LineSegmenterBorrowed<'data> {
+    /// Adds a [`Hyphenator`] to this segmenter, so that the hyphenation points of words are
+    /// returned along with the line break opportunities.
+    ///
+    /// The hyphenation points are between the letters of words, where the Line_Break property
+    /// is Alphabetic or Hebrew_Letter, possibly followed by combining marks. They can be told
+    /// apart from other breaks with [`LineBreakIterator::break_kind()`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu::locale::langid;
+    /// use icu::segmenter::options::LineBreakKind;
+    /// use icu::segmenter::{Hyphenator, LineSegmenter};
+    /// # use icu::segmenter::provider::{HyphenationPatterns, SegmenterHyphenationPatternsV1};
+    /// # use icu_provider::prelude::*;
+    /// # struct ExamplePatterns;
+    /// # impl DataProvider<SegmenterHyphenationPatternsV1> for ExamplePatterns {
+    /// #     fn load(&self, _: DataRequest) -> Result<DataResponse<SegmenterHyphenationPatternsV1>, DataError> {
+    /// #         let patterns = ["hen", "hena", "henat", "hyph", "io", "na", "nat", "on", "tio"];
+    /// #         let pattern_levels: [&[u8]; 9] = [
+    /// #             &[0, 0, 2, 0],
+    /// #             &[0, 0, 0, 0, 4],
+    /// #             &[0, 0, 0, 5, 0, 0],
+    /// #             &[0, 0, 3, 0, 0],
+    /// #             &[2, 0, 0],
+    /// #             &[1, 0, 0],
+    /// #             &[0, 2, 0, 0],
+    /// #             &[0, 2, 0],
+    /// #             &[1, 0, 0, 0],
+    /// #         ];
+    /// #         Ok(DataResponse {
+    /// #             metadata: Default::default(),
+    /// #             payload: DataPayload::from_owned(HyphenationPatterns {
+    /// #                 patterns: patterns.as_slice().into(),
+    /// #                 pattern_levels: pattern_levels.as_slice().into(),
+    /// #                 exceptions: Default::default(),
+    /// #                 exception_points: Default::default(),
+    /// #                 max_pattern_length: 5,
+    /// #                 left_min: 2,
+    /// #                 right_min: 3,
+    /// #             }),
+    /// #         })
+    /// #     }
+    /// # }
+    /// # let provider = ExamplePatterns;
+    ///
+    /// // For this example, `ExamplePatterns` provides a handful of made-up English patterns.
+    /// // Real patterns are generated from hyph-utf8 by `icu4x-datagen`.
+    /// let hyphenator = Hyphenator::try_new_unstable(&provider, &langid!("en")).unwrap();
+    /// let segmenter = LineSegmenter::new_auto(Default::default())
+    ///     .with_hyphenator(hyphenator.as_borrowed());
+    ///
+    /// let breakpoints: Vec<(usize, LineBreakKind)> = segmenter
+    ///     .segment_str("Hyphenation rules")
+    ///     .iter_with_break_kind()
+    ///     .collect();
+    /// assert_eq!(
+    ///     &breakpoints,
+    ///     &[
+    ///         (0, LineBreakKind::Normal),
+    ///         (2, LineBreakKind::Hyphenation),
+    ///         (6, LineBreakKind::Hyphenation),
+    ///         (12, LineBreakKind::Normal),
+    ///         (17, LineBreakKind::Normal),
+    ///     ]
+    /// );
+    /// ```
+    pub fn with_hyphenator(self, hyphenator: HyphenatorBorrowed<'data>) -> Self {
+        Self {
+            hyphenator: Some(hyphenator),
+            ..self
+        }
+    }
+
     /// Creates a line break iterator for an `str` (a UTF-8 string).
     ///
     /// There are always breakpoints at 0 and the string length, or only at 0 for the empty string.
@@ -544,10 +652,15 @@ impl<'data> LineSegmenterBorrowed<'data> {
             iter: input.char_indices(),
             len: input.len(),
             current_pos_data: None,
+            reached_eof: false,
             result_cache: Vec::new(),
             data: self.data,
             options: self.options,
             complex: self.complex,
+            hyphenator: self.hyphenator,
+            break_kind: LineBreakKind::Normal,
+            pending_breaks: Vec::new(),
+            segment_start: None,
         }
     }
     /// Creates a line break iterator for a potentially ill-formed UTF8 string
@@ -563,10 +676,15 @@ impl<'data> LineSegmenterBorrowed<'data> {
             iter: Utf8CharIndices::new(input),
             len: input.len(),
             current_pos_data: None,
+            reached_eof: false,
             result_cache: Vec::new(),
             data: self.data,
             options: self.options,
             complex: self.complex,
+            hyphenator: self.hyphenator,
+            break_kind: LineBreakKind::Normal,
+            pending_breaks: Vec::new(),
+            segment_start: None,
         }
     }
     /// Creates a line break iterator for a Latin-1 (8-bit) string.
@@ -577,10 +695,15 @@ impl<'data> LineSegmenterBorrowed<'data> {
             iter: Latin1Indices::new(input),
             len: input.len(),
             current_pos_data: None,
+            reached_eof: false,
             result_cache: Vec::new(),
             data: self.data,
             options: self.options,
             complex: self.complex,
+            hyphenator: self.hyphenator,
+            break_kind: LineBreakKind::Normal,
+            pending_breaks: Vec::new(),
+            segment_start: None,
         }
     }
 
@@ -592,14 +715,31 @@ impl<'data> LineSegmenterBorrowed<'data> {
             iter: Utf16Indices::new(input),
             len: input.len(),
             current_pos_data: None,
+            reached_eof: false,
             result_cache: Vec::new(),
             data: self.data,
             options: self.options,
             complex: self.complex,
+            hyphenator: self.hyphenator,
+            break_kind: LineBreakKind::Normal,
+            pending_breaks: Vec::new(),
+            segment_start: None,
         }
     }
 }
 
+impl crate::private::Sealed for LineSegmenterBorrowed<'_> {}
+
+impl StreamingSegmenter for LineSegmenterBorrowed<'_> {
+    fn stable_breaks_str(self, input: &str, is_final: bool) -> (Vec<usize>, usize) {
+        stable_breaks(self.segment_str(input), is_final)
+    }
+
+    fn stable_breaks_utf16(self, input: &[u16], is_final: bool) -> (Vec<usize>, usize) {
+        stable_breaks(self.segment_utf16(input), is_final)
+    }
+}
+
 impl LineSegmenterBorrowed<'static> {
     /// Cheaply converts a [`LineSegmenterBorrowed<'static>`] into a [`LineSegmenter`].
     ///
@@ -610,6 +750,7 @@ impl LineSegmenterBorrowed<'static> {
             payload: DataPayload::from_static_ref(self.data),
             complex: self.complex.static_to_owned(),
             options: self.options,
+            hyphenator: self.hyphenator.map(HyphenatorBorrowed::static_to_owned),
         }
     }
 }
@@ -762,16 +903,126 @@ pub struct LineBreakIterator<'data, 's, Y: LineBreakType> {
     iter: Y::IterAttr<'s>,
     len: usize,
     current_pos_data: Option<(usize, Y::CharType)>,
-    result_cache: Vec<usize>,
+    pub(crate) result_cache: Vec<usize>,
+    /// Whether the end of the input has been read, possibly as lookahead.
+    pub(crate) reached_eof: bool,
     data: &'data RuleBreakData<'data>,
     options: ResolvedLineBreakOptions,
     complex: ComplexPayloadsBorrowed<'data>,
+    hyphenator: Option<HyphenatorBorrowed<'data>>,
+    break_kind: LineBreakKind,
+    /// The breaks to return before finding the next break opportunity, in reverse order.
+    pub(crate) pending_breaks: Vec<(usize, LineBreakKind)>,
+    /// The first character after the last break opportunity, and the iterator after it.
+    #[expect(clippy::type_complexity)] // used internally in one place only
+    segment_start: Option<((usize, Y::CharType), Y::IterAttr<'s>)>,
+}
+
+impl<'data, 's, Y: LineBreakType> LineBreakIterator<'data, 's, Y> {
+    /// Returns the kind of the current break.
+    #[inline]
+    pub fn break_kind(&self) -> LineBreakKind {
+        self.break_kind
+    }
+
+    /// Returns an iterator over pairs of break position and break kind.
+    pub fn iter_with_break_kind(self) -> LineBreakIteratorWithBreakKind<'data, 's, Y> {
+        LineBreakIteratorWithBreakKind(self)
+    }
+}
+
+/// Line break iterator that also returns the break kind
+// We can use impl Trait here once `use<..>` syntax is available, see https://github.com/rust-lang/rust/issues/61756
+#[derive(Debug)]
+pub struct LineBreakIteratorWithBreakKind<'data, 's, Y: LineBreakType>(
+    LineBreakIterator<'data, 's, Y>,
+);
+
+impl<Y: LineBreakType> Iterator for LineBreakIteratorWithBreakKind<'_, '_, Y> {
+    type Item = (usize, LineBreakKind);
+    fn next(&mut self) -> Option<Self::Item> {
+        let index = self.0.next()?;
+        Some((index, self.0.break_kind))
+    }
 }
 
 impl<Y: LineBreakType> Iterator for LineBreakIterator<'_, '_, Y> {
     type Item = usize;
 
     fn next(&mut self) -> Option<Self::Item> {
+        if let Some((index, kind)) = self.pending_breaks.pop() {
+            self.break_kind = kind;
+            return Some(index);
+        }
+        let Some(hyphenator) = self.hyphenator else {
+            return self.next_opportunity();
+        };
+        let segment_start = self.segment_start.take();
+        let index = self.next_opportunity()?;
+        self.segment_start = self
+            .current_pos_data
+            .map(|start| (start, self.iter.clone()));
+        self.break_kind = LineBreakKind::Normal;
+        let Some((start, iter)) = segment_start else {
+            return Some(index);
+        };
+        let hyphens = self.hyphenation_points(hyphenator, start, iter, index);
+        if hyphens.is_empty() {
+            return Some(index);
+        }
+        self.pending_breaks.push((index, LineBreakKind::Normal));
+        self.pending_breaks.extend(
+            hyphens
+                .into_iter()
+                .rev()
+                .map(|hyphen| (hyphen, LineBreakKind::Hyphenation)),
+        );
+        self.next()
+    }
+}
+
+impl<'s, Y: LineBreakType> LineBreakIterator<'_, 's, Y> {
+    /// Returns the hyphenation points of the words in the segment from `start` to `end`.
+    fn hyphenation_points(
+        &self,
+        hyphenator: HyphenatorBorrowed,
+        start: (usize, Y::CharType),
+        iter: Y::IterAttr<'s>,
+        end: usize,
+    ) -> Vec<usize> {
+        let mut hyphens = Vec::new();
+        let mut indices = Vec::new();
+        let mut word = Vec::new();
+        for (index, c) in core::iter::once(start)
+            .chain(iter)
+            .take_while(|&(index, _)| index < end)
+            .map(|(index, c)| (index, char::from_u32(c.into())))
+            .chain([(end, None)])
+        {
+            let letter = c.filter(|&c| match self.data.property_table.get32(c as u32) {
+                AL | HL => true,
+                CM | ZWJ => !word.is_empty(),
+                _ => false,
+            });
+            if let Some(c) = letter {
+                indices.push(index);
+                word.push(c);
+            } else if !word.is_empty() {
+                hyphens.extend(
+                    hyphenator
+                        .hyphenation_points(&word)
+                        .into_iter()
+                        .filter_map(|i| indices.get(i).copied()),
+                );
+                indices.clear();
+                word.clear();
+            }
+        }
+        hyphens
+    }
+
+    /// Returns the next mandatory break or break opportunity.
+    fn next_opportunity(&mut self) -> Option<usize> {
         match self.check_eof() {
             StringBoundaryPosType::Start => return Some(0),
             StringBoundaryPosType::End => return None,
@@ -1017,6 +1268,7 @@ enum StringBoundaryPosType {
 impl<Y: LineBreakType> LineBreakIterator<'_, '_, Y> {
     fn advance_iter(&mut self) {
         self.current_pos_data = self.iter.next();
+        self.reached_eof |= self.current_pos_data.is_none();
     }
 
     fn is_eof(&self) -> bool {
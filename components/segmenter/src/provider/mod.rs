@@ -21,7 +21,7 @@ pub use lstm::*;
 use crate::options::WordType;
 use icu_collections::codepointtrie::CodePointTrie;
 use icu_provider::prelude::*;
-use zerovec::ZeroVec;
+use zerovec::{VarZeroVec, ZeroVec};
 
 #[cfg(feature = "compiled_data")]
 #[derive(Debug)]
@@ -54,6 +54,7 @@ const _: () = {
     impl_segmenter_break_word_v1!(Baked);
     impl_segmenter_break_word_override_v1!(Baked);
     impl_segmenter_break_sentence_override_v1!(Baked);
+    impl_segmenter_complex_scripts_v1!(Baked);
 };
 
 icu_provider::data_marker!(
@@ -80,6 +81,13 @@ icu_provider::data_marker!(
     #[cfg(feature = "datagen")]
     attributes_domain = "segmenter"
 );
+icu_provider::data_marker!(
+    /// `SegmenterComplexScriptsV1`
+    SegmenterComplexScriptsV1,
+    "segmenter/complex/scripts/v1",
+    ComplexScripts<'static>,
+    is_singleton = true
+);
 icu_provider::data_marker!(
     /// `SegmenterBreakSentenceOverrideV1`
     SegmenterBreakSentenceOverrideV1,
@@ -92,6 +100,12 @@ icu_provider::data_marker!(
     "segmenter/break/word/override/v1",
     RuleBreakDataOverride<'static>,
 );
+icu_provider::data_marker!(
+    /// `SegmenterHyphenationPatternsV1`
+    SegmenterHyphenationPatternsV1,
+    "segmenter/hyphenation/patterns/v1",
+    HyphenationPatterns<'static>,
+);
 icu_provider::data_marker!(
     /// `SegmenterBreakLineV1`
     SegmenterBreakLineV1,
@@ -132,8 +146,10 @@ pub const MARKERS: &[DataMarkerInfo] = &[
     SegmenterBreakSentenceV1::INFO,
     SegmenterBreakWordOverrideV1::INFO,
     SegmenterBreakWordV1::INFO,
+    SegmenterComplexScriptsV1::INFO,
     SegmenterDictionaryAutoV1::INFO,
     SegmenterDictionaryExtendedV1::INFO,
+    SegmenterHyphenationPatternsV1::INFO,
     SegmenterLstmAutoV1::INFO,
 ];
 
@@ -206,6 +222,94 @@ icu_provider::data_struct!(
     #[cfg(feature = "datagen")]
 );
 
+/// The dictionary and LSTM models used for text in scripts that require complex context
+/// analysis, such as Thai or Japanese.
+///
+/// A run of text whose code points map to the same model is segmented with that model. The
+/// models themselves are loaded from [`SegmenterDictionaryExtendedV1`],
+/// [`SegmenterDictionaryAutoV1`], and [`SegmenterLstmAutoV1`], depending on the constructor.
+///
+/// <div class="stab unstable">
+/// 🚧 This code is considered unstable; it may change at any time, in breaking or non-breaking ways,
+/// including in SemVer minor releases. While the serde representation of data structs is guaranteed
+/// to be stable, their Rust representation might not be. Use with caution.
+/// </div>
+#[derive(Debug, PartialEq, Clone, yoke::Yokeable, zerofrom::ZeroFrom)]
+#[cfg_attr(feature = "datagen", derive(serde::Serialize, databake::Bake))]
+#[cfg_attr(feature = "datagen", databake(path = icu_segmenter::provider))]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+pub struct ComplexScripts<'data> {
+    /// Maps each code point to one plus the index of its model, or to zero if it is not
+    /// segmented with a model.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub models_by_code_point: CodePointTrie<'data, u8>,
+
+    /// The data marker attributes of the dictionary of each model, or the empty string if the
+    /// model has no dictionary.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub dictionaries: VarZeroVec<'data, str>,
+
+    /// The data marker attributes prefix of the LSTM of each model, or the empty string if the
+    /// model has no LSTM.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub lstms: VarZeroVec<'data, str>,
+}
+
+icu_provider::data_struct!(
+    ComplexScripts<'_>,
+    #[cfg(feature = "datagen")]
+);
+
+/// Hyphenation patterns in the style of TeX, as described in Franklin Liang's
+/// [thesis](https://tug.org/docs/liang/).
+///
+/// A pattern is a substring of a word, where `.` marks the start or end of the word, with a
+/// level between and around its characters. The level of each position in a word is the
+/// highest level that a matching pattern gives it, and a word can be hyphenated where that
+/// level is odd.
+///
+/// <div class="stab unstable">
+/// 🚧 This code is considered unstable; it may change at any time, in breaking or non-breaking ways,
+/// including in SemVer minor releases. While the serde representation of data structs is guaranteed
+/// to be stable, their Rust representation might not be. Use with caution.
+/// </div>
+#[derive(Debug, PartialEq, Clone, yoke::Yokeable, zerofrom::ZeroFrom)]
+#[cfg_attr(feature = "datagen", derive(serde::Serialize, databake::Bake))]
+#[cfg_attr(feature = "datagen", databake(path = icu_segmenter::provider))]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+pub struct HyphenationPatterns<'data> {
+    /// The lowercase characters of each pattern, sorted.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub patterns: VarZeroVec<'data, str>,
+
+    /// The levels of each pattern in [`Self::patterns`], one more than the number of
+    /// characters.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub pattern_levels: VarZeroVec<'data, [u8]>,
+
+    /// The sorted lowercase words that are not hyphenated with the patterns.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub exceptions: VarZeroVec<'data, str>,
+
+    /// The character indices at which each word in [`Self::exceptions`] can be hyphenated.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub exception_points: VarZeroVec<'data, [u8]>,
+
+    /// The number of characters of the longest pattern.
+    pub max_pattern_length: u8,
+
+    /// The minimum number of characters before a hyphenation point.
+    pub left_min: u8,
+
+    /// The minimum number of characters after a hyphenation point.
+    pub right_min: u8,
+}
+
+icu_provider::data_struct!(
+    HyphenationPatterns<'_>,
+    #[cfg(feature = "datagen")]
+);
+
 pub(crate) struct UCharDictionaryBreakDataV1;
 
 impl DynamicDataMarker for UCharDictionaryBreakDataV1 {
@@ -46,6 +46,8 @@ pub struct RuleBreakIterator<'data, 's, Y: RuleBreakType> {
     pub(crate) len: usize,
     pub(crate) current_pos_data: Option<(usize, Y::CharType)>,
     pub(crate) result_cache: alloc::vec::Vec<usize>,
+    /// Whether the end of the input has been read, possibly as lookahead.
+    pub(crate) reached_eof: bool,
     pub(crate) data: &'data RuleBreakData<'data>,
     pub(crate) complex: Option<ComplexPayloadsBorrowed<'data>>,
     pub(crate) boundary_property: u8,
@@ -208,6 +210,7 @@ impl<Y: RuleBreakType> Iterator for RuleBreakIterator<'_, '_, Y> {
 impl<Y: RuleBreakType> RuleBreakIterator<'_, '_, Y> {
     pub(crate) fn advance_iter(&mut self) {
         self.current_pos_data = self.iter.next();
+        self.reached_eof |= self.current_pos_data.is_none();
     }
 
     pub(crate) fn is_eof(&self) -> bool {
@@ -10,6 +10,7 @@ use crate::indices::{Latin1Indices, Utf16Indices};
 use crate::iterator_helpers::derive_usize_iterator_with_type;
 use crate::provider::*;
 use crate::rule_segmenter::*;
+use crate::stream::{stable_breaks, StreamingSegmenter};
 use utf8_iter::Utf8CharIndices;
 
 /// Options to tailor sentence breaking behavior.
@@ -199,6 +200,7 @@ impl<'data> SentenceSegmenterBorrowed<'data> {
             iter: input.char_indices(),
             len: input.len(),
             current_pos_data: None,
+            reached_eof: false,
             result_cache: Vec::new(),
             data: self.data,
             complex: None,
@@ -220,6 +222,7 @@ impl<'data> SentenceSegmenterBorrowed<'data> {
             iter: Utf8CharIndices::new(input),
             len: input.len(),
             current_pos_data: None,
+            reached_eof: false,
             result_cache: Vec::new(),
             data: self.data,
             complex: None,
@@ -236,6 +239,7 @@ impl<'data> SentenceSegmenterBorrowed<'data> {
             iter: Latin1Indices::new(input),
             len: input.len(),
             current_pos_data: None,
+            reached_eof: false,
             result_cache: Vec::new(),
             data: self.data,
             complex: None,
@@ -253,6 +257,7 @@ impl<'data> SentenceSegmenterBorrowed<'data> {
             iter: Utf16Indices::new(input),
             len: input.len(),
             current_pos_data: None,
+            reached_eof: false,
             result_cache: Vec::new(),
             data: self.data,
             complex: None,
@@ -263,6 +268,18 @@ impl<'data> SentenceSegmenterBorrowed<'data> {
     }
 }
 
+impl crate::private::Sealed for SentenceSegmenterBorrowed<'_> {}
+
+impl StreamingSegmenter for SentenceSegmenterBorrowed<'_> {
+    fn stable_breaks_str(self, input: &str, is_final: bool) -> (Vec<usize>, usize) {
+        stable_breaks(self.segment_str(input).0, is_final)
+    }
+
+    fn stable_breaks_utf16(self, input: &[u16], is_final: bool) -> (Vec<usize>, usize) {
+        stable_breaks(self.segment_utf16(input).0, is_final)
+    }
+}
+
 impl SentenceSegmenterBorrowed<'static> {
     /// Cheaply converts a [`SentenceSegmenterBorrowed<'static>`] into a [`SentenceSegmenter`].
     ///
@@ -0,0 +1,354 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use crate::line::{LineBreakIterator, LineBreakType};
+use crate::rule_segmenter::{RuleBreakIterator, RuleBreakType};
+
+/// A segmenter that can segment text given in chunks, see [`StrBreakStream`] and
+/// [`Utf16BreakStream`].
+///
+/// <div class="stab unstable">
+/// 🚫 This trait is sealed; it cannot be implemented by user code. If an API requests an item that implements this
+/// trait, please consider using a type from the implementors listed below.
+/// </div>
+pub trait StreamingSegmenter: crate::private::Sealed + Copy {
+    /// Returns the breaks in `input` that do not depend on any text after it, and the last of
+    /// them from which segmentation can be restarted. If `is_final`, `input` is the rest of the
+    /// text, and all of its breaks are returned.
+    #[doc(hidden)]
+    fn stable_breaks_str(self, input: &str, is_final: bool) -> (Vec<usize>, usize);
+
+    /// Same as [`Self::stable_breaks_str`], for UTF-16 input.
+    #[doc(hidden)]
+    fn stable_breaks_utf16(self, input: &[u16], is_final: bool) -> (Vec<usize>, usize);
+}
+
+/// A break iterator whose state between breaks can be inspected.
+pub(crate) trait ResumableBreakIterator: Iterator<Item = usize> {
+    /// Whether the end of the input has been read, in which case the last break depends on
+    /// the text that follows the input.
+    fn reached_eof(&self) -> bool;
+
+    /// Whether the last break was taken from the breaks cached for a complex script run or a
+    /// hyphenated segment, which cannot be restarted from the middle.
+    fn in_cached_run(&self) -> bool;
+}
+
+impl<Y: RuleBreakType> ResumableBreakIterator for RuleBreakIterator<'_, '_, Y> {
+    fn reached_eof(&self) -> bool {
+        self.reached_eof
+    }
+
+    fn in_cached_run(&self) -> bool {
+        !self.result_cache.is_empty()
+    }
+}
+
+impl<Y: LineBreakType> ResumableBreakIterator for LineBreakIterator<'_, '_, Y> {
+    fn reached_eof(&self) -> bool {
+        self.reached_eof
+    }
+
+    fn in_cached_run(&self) -> bool {
+        !self.result_cache.is_empty() || !self.pending_breaks.is_empty()
+    }
+}
+
+/// Implements [`StreamingSegmenter`] on top of a break iterator.
+///
+/// The state of the break iterators between two breaks consists of the position and the
+/// breaks cached for a complex script run or a hyphenated segment, so a break that was found
+/// without reading the end of the input is the same as for the whole text, and segmentation
+/// can be restarted there unless it was taken from such a cache.
+pub(crate) fn stable_breaks(
+    mut iter: impl ResumableBreakIterator,
+    is_final: bool,
+) -> (Vec<usize>, usize) {
+    let mut breaks = Vec::new();
+    let mut restart = 0;
+    while let Some(index) = iter.next() {
+        if !is_final && iter.reached_eof() {
+            break;
+        }
+        breaks.push(index);
+        if !iter.in_cached_run() {
+            restart = index;
+        }
+    }
+    (breaks, restart)
+}
+
+/// The part of the stream state that does not depend on the encoding.
+#[derive(Debug, Default)]
+struct StreamState {
+    /// The index of the start of the pending text in the whole text.
+    offset: usize,
+    /// The last break that was returned.
+    last_break: Option<usize>,
+    /// The length of pending text above which it is segmented as if the text ended there.
+    max_pending: Option<usize>,
+    /// The length of pending text at which it is segmented again, which is twice its length
+    /// after it was last segmented.
+    resegment_at: usize,
+}
+
+impl StreamState {
+    /// Converts the stable breaks of the pending text to indices in the whole text, skipping
+    /// the ones that were already returned, and returns how much of the pending text can be
+    /// dropped.
+    fn advance(&mut self, (breaks, restart): (Vec<usize>, usize)) -> (Vec<usize>, usize) {
+        let offset = self.offset;
+        let last_break = self.last_break;
+        let breaks: Vec<usize> = breaks
+            .into_iter()
+            .map(|index| offset + index)
+            .filter(|&index| last_break.is_none_or(|last| index > last))
+            .collect();
+        if let Some(&last) = breaks.last() {
+            self.last_break = Some(last);
+        }
+        self.offset += restart;
+        (breaks, restart)
+    }
+
+    /// Whether the pending text, of the given length, has grown enough to be segmented again.
+    ///
+    /// Waiting until the text that could not be dropped has doubled makes the total time
+    /// linear in the length of the text. This only delays breaks to a later call, since the
+    /// dropped text always ends at a break from which segmentation can be restarted.
+    fn should_segment(&self, len: usize) -> bool {
+        len >= self.resegment_at || self.exceeds_max_pending(len)
+    }
+
+    /// Records the length of the pending text after it was segmented.
+    fn segmented(&mut self, len: usize) {
+        self.resegment_at = len.saturating_mul(2);
+    }
+
+    /// Whether the pending text, of the given length, is longer than allowed.
+    fn exceeds_max_pending(&self, len: usize) -> bool {
+        self.max_pending.is_some_and(|max| len > max)
+    }
+
+    /// Converts the breaks of pending text of the given length that was segmented as if it
+    /// were the end of the text, after which the stream restarts at its end.
+    fn cut(&mut self, (breaks, _): (Vec<usize>, usize), len: usize) -> Vec<usize> {
+        let end = self.offset + len;
+        let breaks = self.advance((breaks, 0)).0;
+        self.offset = end;
+        self.last_break = Some(end);
+        breaks
+    }
+}
+
+/// Segments a UTF-8 string that is given in chunks.
+///
+/// The breaks are the same as when segmenting the concatenation of the chunks, and are returned
+/// as indices into it as soon as they no longer depend on the text that follows. The stream
+/// keeps the text after the last break from which segmentation can be restarted, which is
+/// usually a few characters, but includes any complex script run (such as Thai) that has not
+/// ended yet.
+///
+/// The pending text is segmented again once it has doubled since it was last segmented, so
+/// the total time is linear in the length of the text. A break can therefore be returned by a
+/// later call to [`push`](Self::push) than the first one after which it is known, but the
+/// breaks are always the same. [`with_max_pending`](Self::with_max_pending) also bounds the
+/// memory, but changes the breaks of longer runs.
+///
+/// Each of the borrowed segmenter types can be used, such as [`LineSegmenterBorrowed`] or
+/// [`WordSegmenterBorrowed`].
+///
+/// # Examples
+///
+/// ```
+/// use icu::segmenter::iterators::StrBreakStream;
+/// use icu::segmenter::WordSegmenter;
+///
+/// let segmenter = WordSegmenter::new_auto(Default::default());
+/// let mut stream = StrBreakStream::new(segmenter);
+///
+/// let mut breakpoints: Vec<usize> = Vec::new();
+/// for chunk in ["Hello Wo", "rld. Xin ch", "ào thế giới!"] {
+///     breakpoints.extend(stream.push(chunk));
+/// }
+/// breakpoints.extend(stream.finish());
+///
+/// assert_eq!(
+///     &breakpoints,
+///     &[0, 5, 6, 11, 12, 13, 16, 17, 22, 23, 28, 29, 35, 36]
+/// );
+/// ```
+///
+/// [`LineSegmenterBorrowed`]: crate::LineSegmenterBorrowed
+/// [`WordSegmenterBorrowed`]: crate::WordSegmenterBorrowed
+#[derive(Debug)]
+pub struct StrBreakStream<S> {
+    segmenter: S,
+    pending: String,
+    state: StreamState,
+}
+
+impl<S: StreamingSegmenter> StrBreakStream<S> {
+    /// Creates a stream that segments text with the given segmenter.
+    pub fn new(segmenter: S) -> Self {
+        Self {
+            segmenter,
+            pending: String::new(),
+            state: StreamState::default(),
+        }
+    }
+
+    /// Limits the pending text to `max_pending` code units.
+    ///
+    /// When more text is pending after a chunk is pushed, the pending text is segmented as if
+    /// the text ended there, and segmentation restarts after it. This adds a break at that
+    /// position, and the breaks just before it may differ from the ones of the whole text.
+    ///
+    /// This only happens when more than `max_pending` code units follow the last break from
+    /// which segmentation can be restarted, such as in a complex script run (such as Thai), a
+    /// long word, or a long sequence of characters without a line break opportunity. The breaks
+    /// of text without such runs are the same as without a limit.
+    pub fn with_max_pending(mut self, max_pending: usize) -> Self {
+        self.state.max_pending = Some(max_pending);
+        self
+    }
+
+    /// Appends a chunk to the text, and returns the breaks that have become known.
+    pub fn push(&mut self, chunk: &str) -> Vec<usize> {
+        self.pending.push_str(chunk);
+        if !self.state.should_segment(self.pending.len()) {
+            return Vec::new();
+        }
+        let (mut breaks, restart) = self
+            .state
+            .advance(self.segmenter.stable_breaks_str(&self.pending, false));
+        self.pending.drain(..restart);
+        if self.state.exceeds_max_pending(self.pending.len()) {
+            breaks.extend(self.state.cut(
+                self.segmenter.stable_breaks_str(&self.pending, true),
+                self.pending.len(),
+            ));
+            self.pending.clear();
+        }
+        self.state.segmented(self.pending.len());
+        breaks
+    }
+
+    /// Ends the text, and returns the remaining breaks.
+    pub fn finish(mut self) -> Vec<usize> {
+        self.state
+            .advance(self.segmenter.stable_breaks_str(&self.pending, true))
+            .0
+    }
+
+    /// Returns the text that has been kept to find the next breaks.
+    pub fn pending(&self) -> &str {
+        &self.pending
+    }
+}
+
+/// Returns the UTF-16 text without a trailing lead surrogate, which may be the start of a
+/// pair, so it is not segmented yet.
+fn complete_utf16(text: &[u16]) -> &[u16] {
+    match text.split_last() {
+        Some((&last, rest)) if (0xD800..0xDC00).contains(&last) => rest,
+        _ => text,
+    }
+}
+
+/// Segments a UTF-16 string that is given in chunks.
+///
+/// This is the same as [`StrBreakStream`], except that a surrogate pair may also be split
+/// between two chunks. Like [`StrBreakStream`], the pending text is segmented again once it
+/// has doubled, and can be bounded with [`with_max_pending`](Self::with_max_pending).
+///
+/// # Examples
+///
+/// ```
+/// use icu::segmenter::iterators::Utf16BreakStream;
+/// use icu::segmenter::LineSegmenter;
+///
+/// let segmenter = LineSegmenter::new_auto(Default::default());
+/// let mut stream = Utf16BreakStream::new(segmenter);
+///
+/// let text: Vec<u16> = "Hello World. Xin chào thế giới!".encode_utf16().collect();
+/// let mut breakpoints: Vec<usize> = Vec::new();
+/// for chunk in text.chunks(4) {
+///     breakpoints.extend(stream.push(chunk));
+/// }
+/// breakpoints.extend(stream.finish());
+///
+/// assert_eq!(&breakpoints, &[0, 6, 13, 17, 22, 26, 31]);
+/// ```
+#[derive(Debug)]
+pub struct Utf16BreakStream<S> {
+    segmenter: S,
+    pending: Vec<u16>,
+    state: StreamState,
+}
+
+impl<S: StreamingSegmenter> Utf16BreakStream<S> {
+    /// Creates a stream that segments text with the given segmenter.
+    pub fn new(segmenter: S) -> Self {
+        Self {
+            segmenter,
+            pending: Vec::new(),
+            state: StreamState::default(),
+        }
+    }
+
+    /// Limits the pending text to `max_pending` code units.
+    ///
+    /// When more text is pending after a chunk is pushed, the pending text is segmented as if
+    /// the text ended there, and segmentation restarts after it. This adds a break at that
+    /// position, and the breaks just before it may differ from the ones of the whole text.
+    ///
+    /// This only happens when more than `max_pending` code units follow the last break from
+    /// which segmentation can be restarted, such as in a complex script run (such as Thai), a
+    /// long word, or a long sequence of characters without a line break opportunity. The breaks
+    /// of text without such runs are the same as without a limit.
+    pub fn with_max_pending(mut self, max_pending: usize) -> Self {
+        self.state.max_pending = Some(max_pending);
+        self
+    }
+
+    /// Appends a chunk to the text, and returns the breaks that have become known.
+    pub fn push(&mut self, chunk: &[u16]) -> Vec<usize> {
+        self.pending.extend_from_slice(chunk);
+        if !self.state.should_segment(self.pending.len()) {
+            return Vec::new();
+        }
+        let (mut breaks, restart) = self.state.advance(
+            self.segmenter
+                .stable_breaks_utf16(complete_utf16(&self.pending), false),
+        );
+        self.pending.drain(..restart);
+        let input = complete_utf16(&self.pending);
+        if self.state.exceeds_max_pending(input.len()) {
+            let len = input.len();
+            breaks.extend(
+                self.state
+                    .cut(self.segmenter.stable_breaks_utf16(input, true), len),
+            );
+            self.pending.drain(..len);
+        }
+        self.state.segmented(self.pending.len());
+        breaks
+    }
+
+    /// Ends the text, and returns the remaining breaks.
+    pub fn finish(mut self) -> Vec<usize> {
+        self.state
+            .advance(self.segmenter.stable_breaks_utf16(&self.pending, true))
+            .0
+    }
+
+    /// Returns the text that has been kept to find the next breaks.
+    pub fn pending(&self) -> &[u16] {
+        &self.pending
+    }
+}
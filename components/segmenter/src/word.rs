@@ -7,6 +7,7 @@ use crate::indices::{Latin1Indices, Utf16Indices};
 use crate::iterator_helpers::derive_usize_iterator_with_type;
 use crate::provider::*;
 use crate::rule_segmenter::*;
+use crate::stream::{stable_breaks, StreamingSegmenter};
 use alloc::string::String;
 use alloc::vec;
 use alloc::vec::Vec;
@@ -264,6 +265,7 @@ impl WordSegmenter {
             + DataProvider<SegmenterDictionaryAutoV1>
             + DataProvider<SegmenterLstmAutoV1>
             + DataProvider<SegmenterBreakGraphemeClusterV1>
+            + DataProvider<SegmenterComplexScriptsV1>
             + ?Sized,
     {
         Ok(Self {
@@ -355,6 +357,7 @@ impl WordSegmenter {
             + DataProvider<SegmenterBreakWordOverrideV1>
             + DataProvider<SegmenterLstmAutoV1>
             + DataProvider<SegmenterBreakGraphemeClusterV1>
+            + DataProvider<SegmenterComplexScriptsV1>
             + ?Sized,
     {
         Ok(Self {
@@ -439,6 +442,7 @@ impl WordSegmenter {
             + DataProvider<SegmenterDictionaryAutoV1>
             + DataProvider<SegmenterDictionaryExtendedV1>
             + DataProvider<SegmenterBreakGraphemeClusterV1>
+            + DataProvider<SegmenterComplexScriptsV1>
             + ?Sized,
     {
         Ok(Self {
@@ -484,6 +488,7 @@ impl<'data> WordSegmenterBorrowed<'data> {
             iter: input.char_indices(),
             len: input.len(),
             current_pos_data: None,
+            reached_eof: false,
             result_cache: Vec::new(),
             data: self.data,
             complex: Some(self.complex),
@@ -506,6 +511,7 @@ impl<'data> WordSegmenterBorrowed<'data> {
             iter: Utf8CharIndices::new(input),
             len: input.len(),
             current_pos_data: None,
+            reached_eof: false,
             result_cache: Vec::new(),
             data: self.data,
             complex: Some(self.complex),
@@ -523,6 +529,7 @@ impl<'data> WordSegmenterBorrowed<'data> {
             iter: Latin1Indices::new(input),
             len: input.len(),
             current_pos_data: None,
+            reached_eof: false,
             result_cache: Vec::new(),
             data: self.data,
             complex: Some(self.complex),
@@ -540,6 +547,7 @@ impl<'data> WordSegmenterBorrowed<'data> {
             iter: Utf16Indices::new(input),
             len: input.len(),
             current_pos_data: None,
+            reached_eof: false,
             result_cache: Vec::new(),
             data: self.data,
             complex: Some(self.complex),
@@ -550,6 +558,18 @@ impl<'data> WordSegmenterBorrowed<'data> {
     }
 }
 
+impl crate::private::Sealed for WordSegmenterBorrowed<'_> {}
+
+impl StreamingSegmenter for WordSegmenterBorrowed<'_> {
+    fn stable_breaks_str(self, input: &str, is_final: bool) -> (Vec<usize>, usize) {
+        stable_breaks(self.segment_str(input).0, is_final)
+    }
+
+    fn stable_breaks_utf16(self, input: &[u16], is_final: bool) -> (Vec<usize>, usize) {
+        stable_breaks(self.segment_utf16(input).0, is_final)
+    }
+}
+
 impl WordSegmenterBorrowed<'static> {
     /// Cheaply converts a [`WordSegmenterBorrowed<'static>`] into a [`WordSegmenter`].
     ///
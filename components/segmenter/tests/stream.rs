@@ -0,0 +1,169 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use icu_segmenter::iterators::{StrBreakStream, Utf16BreakStream};
+use icu_segmenter::scaffold::StreamingSegmenter;
+use icu_segmenter::{GraphemeClusterSegmenter, LineSegmenter, SentenceSegmenter, WordSegmenter};
+
+const TEXTS: &[&str] = &[
+    "",
+    "Hello World. Xin chào thế giới!",
+    "He said \"Stop.\"  Then (he left).\r\nA 3.5 kg box costs $12,000.00 e.g. today.",
+    "👨‍👩‍👧‍👦🇺🇸🇫🇷 a\u{301}\u{302} 😀😃 ᄀᄀᄀ각ᆨᆨ",
+    "aภาษาไทยภาษาไทยb ภาษาไทย. ေမာင္မင္းႀကီးသား",
+    "中文𠀀字 日本語のテキスト。 한국어 텍스트",
+];
+
+/// Checks that the breaks of every split of the text into two or three chunks are the same as
+/// for the whole text.
+fn check<S: StreamingSegmenter>(
+    segmenter: S,
+    segment_str: impl Fn(&str) -> Vec<usize>,
+    segment_utf16: impl Fn(&[u16]) -> Vec<usize>,
+) {
+    for text in TEXTS {
+        let expected = segment_str(text);
+        for (i, _) in text.char_indices().step_by(2) {
+            for (j, _) in text[i..].char_indices().step_by(5) {
+                let chunks = [&text[..i], &text[i..i + j], &text[i + j..]];
+                let mut stream = StrBreakStream::new(segmenter);
+                let mut breaks = Vec::new();
+                for chunk in chunks {
+                    breaks.extend(stream.push(chunk));
+                }
+                breaks.extend(stream.finish());
+                assert_eq!(breaks, expected, "{chunks:?}");
+            }
+        }
+
+        let utf16: Vec<u16> = text.encode_utf16().collect();
+        let expected = segment_utf16(&utf16);
+        for i in (0..=utf16.len()).step_by(2) {
+            for j in (i..=utf16.len()).step_by(5) {
+                let chunks = [&utf16[..i], &utf16[i..j], &utf16[j..]];
+                let mut stream = Utf16BreakStream::new(segmenter);
+                let mut breaks = Vec::new();
+                for chunk in chunks {
+                    breaks.extend(stream.push(chunk));
+                }
+                breaks.extend(stream.finish());
+                assert_eq!(breaks, expected, "{text:?} split at {i} and {j}");
+            }
+        }
+    }
+}
+
+#[test]
+fn line_stream() {
+    let segmenter = LineSegmenter::new_auto(Default::default());
+    check(
+        segmenter,
+        |s| segmenter.segment_str(s).collect(),
+        |s| segmenter.segment_utf16(s).collect(),
+    );
+}
+
+#[test]
+fn word_stream() {
+    let segmenter = WordSegmenter::new_auto(Default::default());
+    check(
+        segmenter,
+        |s| segmenter.segment_str(s).collect(),
+        |s| segmenter.segment_utf16(s).collect(),
+    );
+}
+
+#[test]
+fn grapheme_stream() {
+    let segmenter = GraphemeClusterSegmenter::new();
+    check(
+        segmenter,
+        |s| segmenter.segment_str(s).collect(),
+        |s| segmenter.segment_utf16(s).collect(),
+    );
+}
+
+#[test]
+fn sentence_stream() {
+    let segmenter = SentenceSegmenter::new(Default::default());
+    check(
+        segmenter,
+        |s| segmenter.segment_str(s).collect(),
+        |s| segmenter.segment_utf16(s).collect(),
+    );
+}
+
+#[test]
+fn pending_text() {
+    let segmenter = WordSegmenter::new_auto(Default::default());
+    let mut stream = StrBreakStream::new(segmenter);
+    assert_eq!(stream.push("Hello, wor"), [0, 5, 6, 7]);
+    // The word may continue in the next chunk.
+    assert_eq!(stream.pending(), "wor");
+    assert!(stream.push("ld").is_empty());
+    assert_eq!(stream.pending(), "world");
+    assert_eq!(stream.finish(), [12]);
+}
+
+#[test]
+fn small_chunks() {
+    // A long run without a restart point is only segmented again once it has doubled, which
+    // delays some breaks but does not change them.
+    let segmenter = WordSegmenter::new_auto(Default::default());
+    let text = "ภาษาไทย".repeat(50) + " Hello World. " + &"ภาษาไทย".repeat(50);
+    let mut stream = StrBreakStream::new(segmenter);
+    let mut breaks = Vec::new();
+    for (i, c) in text.char_indices() {
+        breaks.extend(stream.push(&text[i..i + c.len_utf8()]));
+    }
+    breaks.extend(stream.finish());
+    assert_eq!(breaks, segmenter.segment_str(&text).collect::<Vec<_>>());
+
+    let utf16: Vec<u16> = text.encode_utf16().collect();
+    let mut stream = Utf16BreakStream::new(segmenter);
+    let mut breaks = Vec::new();
+    for chunk in utf16.chunks(1) {
+        breaks.extend(stream.push(chunk));
+    }
+    breaks.extend(stream.finish());
+    assert_eq!(breaks, segmenter.segment_utf16(&utf16).collect::<Vec<_>>());
+}
+
+#[test]
+fn max_pending() {
+    let segmenter = WordSegmenter::new_auto(Default::default());
+    let text = "ภาษาไทย".repeat(20);
+
+    let mut stream = StrBreakStream::new(segmenter).with_max_pending(30);
+    let mut breaks = Vec::new();
+    for (i, c) in text.char_indices() {
+        breaks.extend(stream.push(&text[i..i + c.len_utf8()]));
+        assert!(stream.pending().len() <= 30);
+    }
+    breaks.extend(stream.finish());
+    assert_eq!(breaks.first(), Some(&0));
+    assert_eq!(breaks.last(), Some(&text.len()));
+    assert!(breaks.windows(2).all(|w| w[0] < w[1]));
+    assert!(breaks.iter().all(|&i| text.is_char_boundary(i)));
+
+    let utf16: Vec<u16> = text.encode_utf16().collect();
+    let mut stream = Utf16BreakStream::new(segmenter).with_max_pending(10);
+    let mut breaks = Vec::new();
+    for chunk in utf16.chunks(3) {
+        breaks.extend(stream.push(chunk));
+        assert!(stream.pending().len() <= 10);
+    }
+    breaks.extend(stream.finish());
+    assert_eq!(breaks.first(), Some(&0));
+    assert_eq!(breaks.last(), Some(&utf16.len()));
+    assert!(breaks.windows(2).all(|w| w[0] < w[1]));
+
+    // Text that never exceeds the limit is segmented as a whole.
+    let text = "Hello World. Xin chào thế giới!";
+    let mut stream = StrBreakStream::new(segmenter).with_max_pending(10);
+    let mut breaks = stream.push(&text[..8]);
+    breaks.extend(stream.push(&text[8..]));
+    breaks.extend(stream.finish());
+    assert_eq!(breaks, segmenter.segment_str(text).collect::<Vec<_>>());
+}
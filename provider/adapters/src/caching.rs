@@ -0,0 +1,369 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Data provider that caches the responses of another provider.
+//!
+//! Use [`CachingProvider`] to avoid repeatedly deserializing the same data, for example when
+//! formatters are constructed per request from an `FsDataProvider` or a `BlobDataProvider`.
+
+use alloc::boxed::Box;
+use alloc::collections::BTreeMap;
+use core::any::Any;
+use core::fmt;
+use icu_provider::prelude::*;
+use yoke::Yokeable;
+
+#[cfg(not(feature = "sync"))]
+type Lock<T> = core::cell::RefCell<T>;
+#[cfg(feature = "sync")]
+type Lock<T> = std::sync::Mutex<T>;
+
+#[cfg(not(feature = "sync"))]
+type ErasedResponse = Box<dyn Any>;
+#[cfg(feature = "sync")]
+type ErasedResponse = Box<dyn Any + Send + Sync>;
+
+type CacheKey = (DataMarkerInfo, DataIdentifierCow<'static>);
+
+/// A response that can be stored in the cache. With the `sync` Cargo feature, this requires
+/// the response to be `Send + Sync`.
+trait Cacheable {
+    fn erase(self) -> ErasedResponse;
+}
+
+#[cfg(not(feature = "sync"))]
+impl<T: Any> Cacheable for T {
+    fn erase(self) -> ErasedResponse {
+        Box::new(self)
+    }
+}
+
+#[cfg(feature = "sync")]
+impl<T: Any + Send + Sync> Cacheable for T {
+    fn erase(self) -> ErasedResponse {
+        Box::new(self)
+    }
+}
+
+/// A data provider that caches the responses of an inner provider.
+///
+/// Successful responses are cached by [`DataMarkerInfo`] and [`DataIdentifierCow`]. When the
+/// cache is full, the least recently used response is evicted. Errors are never cached, and
+/// requests with [`DataRequestMetadata::attributes_prefix_match`] are always forwarded to the
+/// inner provider.
+///
+/// With the `sync` Cargo feature, the cache is guarded by a mutex and the provider can be
+/// shared between threads.
+///
+/// # Examples
+///
+/// ```
+/// use icu_locale::langid;
+/// use icu_provider::hello_world::*;
+/// use icu_provider::prelude::*;
+/// use icu_provider_adapters::caching::CachingProvider;
+///
+/// let provider = CachingProvider::new(HelloWorldProvider, 16);
+///
+/// for _ in 0..3 {
+///     let response: DataResponse<HelloWorldV1> = provider
+///         .load(DataRequest {
+///             id: DataIdentifierBorrowed::for_locale(&langid!("de").into()),
+///             ..Default::default()
+///         })
+///         .expect("Loading should succeed");
+///     assert_eq!(response.payload.get().message, "Hallo Welt");
+/// }
+///
+/// let statistics = provider.statistics();
+/// assert_eq!(statistics.hits, 2);
+/// assert_eq!(statistics.misses, 1);
+/// assert_eq!(statistics.len, 1);
+/// ```
+///
+/// Least recently used responses are evicted:
+///
+/// ```
+/// use icu_locale::langid;
+/// use icu_provider::hello_world::*;
+/// use icu_provider::prelude::*;
+/// use icu_provider_adapters::caching::CachingProvider;
+///
+/// let provider = CachingProvider::new(HelloWorldProvider, 2);
+///
+/// let load = |locale: &DataLocale| -> DataResponse<HelloWorldV1> {
+///     provider
+///         .load(DataRequest {
+///             id: DataIdentifierBorrowed::for_locale(locale),
+///             ..Default::default()
+///         })
+///         .expect("Loading should succeed")
+/// };
+///
+/// load(&langid!("de").into()); // miss
+/// load(&langid!("ru").into()); // miss
+/// load(&langid!("de").into()); // hit
+/// load(&langid!("ja").into()); // miss, evicts "ru"
+/// load(&langid!("de").into()); // hit
+/// load(&langid!("ru").into()); // miss, evicts "ja"
+///
+/// let statistics = provider.statistics();
+/// assert_eq!(statistics.hits, 2);
+/// assert_eq!(statistics.misses, 4);
+/// assert_eq!(statistics.len, 2);
+/// ```
+pub struct CachingProvider<P> {
+    inner: P,
+    capacity: usize,
+    cache: Lock<Cache>,
+}
+
+/// Usage statistics of a [`CachingProvider`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+#[non_exhaustive]
+pub struct CacheStatistics {
+    /// The number of requests that were served from the cache.
+    pub hits: u64,
+    /// The number of requests that were forwarded to the inner provider.
+    pub misses: u64,
+    /// The number of responses currently in the cache.
+    pub len: usize,
+}
+
+#[derive(Default)]
+struct Cache {
+    entries: BTreeMap<CacheKey, (u64, ErasedResponse)>,
+    /// The keys of `entries`, ordered from least to most recently used.
+    recency: BTreeMap<u64, CacheKey>,
+    tick: u64,
+    hits: u64,
+    misses: u64,
+}
+
+impl Cache {
+    fn get<M>(&mut self, key: &CacheKey) -> Option<DataResponse<M>>
+    where
+        M: DynamicDataMarker,
+        for<'a> <M::DataStruct as Yokeable<'a>>::Output: Clone,
+    {
+        let Some((last_used, response)) = self
+            .entries
+            .get_mut(key)
+            .and_then(|(last_used, response)| Some((last_used, response.downcast_ref()?)))
+        else {
+            self.misses += 1;
+            return None;
+        };
+        self.hits += 1;
+        self.tick += 1;
+        if let Some(key) = self.recency.remove(last_used) {
+            self.recency.insert(self.tick, key);
+        }
+        *last_used = self.tick;
+        Some(DataResponse::clone(response))
+    }
+
+    fn insert(&mut self, key: CacheKey, response: ErasedResponse, capacity: usize) {
+        if capacity == 0 {
+            return;
+        }
+        self.tick += 1;
+        if let Some((last_used, _)) = self.entries.insert(key.clone(), (self.tick, response)) {
+            self.recency.remove(&last_used);
+        }
+        self.recency.insert(self.tick, key);
+        while self.entries.len() > capacity {
+            let Some((_, key)) = self.recency.pop_first() else {
+                break;
+            };
+            self.entries.remove(&key);
+        }
+    }
+}
+
+impl<P> CachingProvider<P> {
+    /// Creates a [`CachingProvider`] that caches up to `capacity` responses of `inner`.
+    pub fn new(inner: P, capacity: usize) -> Self {
+        Self {
+            inner,
+            capacity,
+            cache: Default::default(),
+        }
+    }
+
+    /// Returns a reference to the inner provider.
+    pub fn inner(&self) -> &P {
+        &self.inner
+    }
+
+    /// Returns the inner provider, dropping the cache.
+    pub fn into_inner(self) -> P {
+        self.inner
+    }
+
+    /// Returns the number of cache hits and misses so far, and the current size of the cache.
+    pub fn statistics(&self) -> CacheStatistics {
+        self.with_cache(|cache| CacheStatistics {
+            hits: cache.hits,
+            misses: cache.misses,
+            len: cache.entries.len(),
+        })
+    }
+
+    /// Removes all responses from the cache. The statistics are retained.
+    pub fn clear(&self) {
+        self.with_cache(|cache| {
+            cache.entries.clear();
+            cache.recency.clear();
+        })
+    }
+
+    fn with_cache<R>(&self, f: impl FnOnce(&mut Cache) -> R) -> R {
+        #[cfg(not(feature = "sync"))]
+        return f(&mut self.cache.borrow_mut());
+        #[cfg(feature = "sync")]
+        return f(&mut self
+            .cache
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner));
+    }
+
+    fn load_cached<M>(
+        &self,
+        marker: DataMarkerInfo,
+        req: DataRequest,
+        load: impl FnOnce(DataRequest) -> Result<DataResponse<M>, DataError>,
+    ) -> Result<DataResponse<M>, DataError>
+    where
+        M: DynamicDataMarker,
+        for<'a> <M::DataStruct as Yokeable<'a>>::Output: Clone,
+        DataResponse<M>: Cacheable,
+    {
+        if req.metadata.attributes_prefix_match {
+            return load(req);
+        }
+        let key = (marker, req.id.into_owned());
+        if let Some(response) = self.with_cache(|cache| cache.get(&key)) {
+            return Ok(response);
+        }
+        // The inner provider is called without holding the lock, so concurrent misses
+        // for the same key may both load the data.
+        let response = load(req)?;
+        let erased = response.clone().erase();
+        self.with_cache(|cache| cache.insert(key, erased, self.capacity));
+        Ok(response)
+    }
+}
+
+impl<M, P> DataProvider<M> for CachingProvider<P>
+where
+    M: DataMarker,
+    P: DataProvider<M>,
+    for<'a> <M::DataStruct as Yokeable<'a>>::Output: Clone,
+    DataResponse<M>: Cacheable,
+{
+    fn load(&self, req: DataRequest) -> Result<DataResponse<M>, DataError> {
+        self.load_cached(M::INFO, req, |req| self.inner.load(req))
+    }
+}
+
+impl<P: fmt::Debug> fmt::Debug for CachingProvider<P> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CachingProvider")
+            .field("inner", &self.inner)
+            .field("capacity", &self.capacity)
+            .field("statistics", &self.statistics())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::cell::Cell;
+    use icu_locale::langid;
+    use icu_provider::hello_world::*;
+
+    /// A [`HelloWorldProvider`] that counts how often it is called.
+    #[derive(Default)]
+    struct CountingProvider {
+        loads: Cell<usize>,
+    }
+
+    impl DataProvider<HelloWorldV1> for CountingProvider {
+        fn load(&self, req: DataRequest) -> Result<DataResponse<HelloWorldV1>, DataError> {
+            self.loads.set(self.loads.get() + 1);
+            HelloWorldProvider.load(req)
+        }
+    }
+
+    fn load(
+        provider: &CachingProvider<CountingProvider>,
+        locale: &DataLocale,
+    ) -> Result<DataResponse<HelloWorldV1>, DataError> {
+        provider.load(DataRequest {
+            id: DataIdentifierBorrowed::for_locale(locale),
+            ..Default::default()
+        })
+    }
+
+    #[test]
+    fn test_hit() {
+        let provider = CachingProvider::new(CountingProvider::default(), 4);
+        let first = load(&provider, &langid!("de").into()).unwrap();
+        let second = load(&provider, &langid!("de").into()).unwrap();
+
+        assert_eq!(second.payload.get().message, "Hallo Welt");
+        assert_eq!(first.metadata, second.metadata);
+        assert_eq!(provider.inner().loads.get(), 1);
+        assert_eq!(
+            provider.statistics(),
+            CacheStatistics {
+                hits: 1,
+                misses: 1,
+                len: 1
+            }
+        );
+    }
+
+    #[test]
+    fn test_miss() {
+        let provider = CachingProvider::new(CountingProvider::default(), 4);
+        let de = load(&provider, &langid!("de").into()).unwrap();
+        let ru = load(&provider, &langid!("ru").into()).unwrap();
+
+        assert_eq!(de.payload.get().message, "Hallo Welt");
+        assert_eq!(ru.payload.get().message, "Привет, мир");
+        assert_eq!(provider.inner().loads.get(), 2);
+        assert_eq!(
+            provider.statistics(),
+            CacheStatistics {
+                hits: 0,
+                misses: 2,
+                len: 2
+            }
+        );
+
+        provider.clear();
+        load(&provider, &langid!("de").into()).unwrap();
+        assert_eq!(provider.inner().loads.get(), 3);
+    }
+
+    #[test]
+    fn test_error_not_cached() {
+        let provider = CachingProvider::new(CountingProvider::default(), 4);
+        let locale = langid!("zz").into();
+        let expected = HelloWorldProvider
+            .load(DataRequest {
+                id: DataIdentifierBorrowed::for_locale(&locale),
+                ..Default::default()
+            })
+            .unwrap_err();
+
+        assert_eq!(load(&provider, &locale).unwrap_err(), expected);
+        assert_eq!(load(&provider, &locale).unwrap_err(), expected);
+        assert_eq!(provider.inner().loads.get(), 2);
+        assert_eq!(provider.statistics().len, 0);
+    }
+}
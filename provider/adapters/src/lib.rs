@@ -8,9 +8,11 @@
 //! - Use the [`either`] module to choose between multiple provider types at runtime.
 //! - Use the [`filter`] module to programmatically reject certain data requests.
 //! - Use the [`fallback`] module to automatically resolve arbitrary locales for data loading.
+//! - Use the [`caching`] module to avoid repeatedly loading the same data.
+//! - Use the [`overlay`] module to patch individual fields of the data.
 
 // https://github.com/unicode-org/icu4x/blob/main/documents/process/boilerplate.md#library-annotations
-#![cfg_attr(not(any(test, doc)), no_std)]
+#![cfg_attr(not(any(test, doc, feature = "std")), no_std)]
 #![cfg_attr(
     not(test),
     deny(
@@ -28,9 +30,12 @@
 
 extern crate alloc;
 
+pub mod caching;
 pub mod either;
 pub mod empty;
 pub mod fallback;
 pub mod filter;
 pub mod fixed;
 pub mod fork;
+#[cfg(feature = "overlay")]
+pub mod overlay;
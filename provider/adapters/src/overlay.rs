@@ -0,0 +1,208 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Data provider that patches individual fields of the data of another provider.
+//!
+//! Use [`OverlayProvider`] to override a few values, such as a month name or a grouping
+//! separator, without replacing whole payloads.
+
+use alloc::collections::btree_map::Entry;
+use alloc::collections::BTreeMap;
+use icu_provider::prelude::*;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use yoke::Yokeable;
+
+/// A data provider that applies partial JSON patches to the data of an inner provider.
+///
+/// Patches are added per marker and [`DataIdentifierCow`], and are applied to responses for
+/// exactly that identifier. A patch is merged into the JSON representation of the data
+/// struct: objects are merged recursively, and all other values, including arrays, replace
+/// the value in the data.
+///
+/// Patches are validated against the serde schema of the data struct when they are added.
+/// Since patching serializes the data, the data struct needs to implement [`Serialize`]. Data
+/// structs in ICU4X components do so with the `datagen` Cargo feature of their crate.
+///
+/// ✨ *Enabled with the `overlay` Cargo feature.*
+///
+/// # Examples
+///
+/// ```
+/// use icu_locale::langid;
+/// use icu_provider::hello_world::*;
+/// use icu_provider::prelude::*;
+/// use icu_provider_adapters::overlay::OverlayProvider;
+/// use writeable::assert_writeable_eq;
+///
+/// let mut provider = OverlayProvider::new(HelloWorldProvider);
+/// provider
+///     .add_patch::<HelloWorldV1>(
+///         DataIdentifierBorrowed::for_locale(&langid!("de").into()),
+///         r#"{ "message": "Moin Welt" }"#,
+///     )
+///     .expect("Patch matches the schema");
+///
+/// // German data is patched:
+/// let formatter =
+///     HelloWorldFormatter::try_new_unstable(&provider, langid!("de").into())
+///         .unwrap();
+/// assert_writeable_eq!(formatter.format(), "Moin Welt");
+///
+/// // Other locales are unaffected:
+/// let formatter =
+///     HelloWorldFormatter::try_new_unstable(&provider, langid!("ja").into())
+///         .unwrap();
+/// assert_writeable_eq!(formatter.format(), "こんにちは世界");
+/// ```
+///
+/// Patches that do not match the schema are rejected:
+///
+/// ```
+/// use icu_locale::langid;
+/// use icu_provider::hello_world::*;
+/// use icu_provider::prelude::*;
+/// use icu_provider_adapters::overlay::OverlayProvider;
+///
+/// let mut provider = OverlayProvider::new(HelloWorldProvider);
+/// let locale = langid!("de").into();
+/// let id = DataIdentifierBorrowed::for_locale(&locale);
+///
+/// // Wrong type:
+/// assert!(provider
+///     .add_patch::<HelloWorldV1>(id, r#"{ "message": 42 }"#)
+///     .is_err());
+///
+/// // Unknown field:
+/// assert!(provider
+///     .add_patch::<HelloWorldV1>(id, r#"{ "mesage": "Moin Welt" }"#)
+///     .is_err());
+/// ```
+#[derive(Debug)]
+pub struct OverlayProvider<P> {
+    inner: P,
+    patches: BTreeMap<(DataMarkerInfo, DataIdentifierCow<'static>), Value>,
+}
+
+impl<P> OverlayProvider<P> {
+    /// Creates an [`OverlayProvider`] without any patches.
+    pub fn new(inner: P) -> Self {
+        Self {
+            inner,
+            patches: BTreeMap::new(),
+        }
+    }
+
+    /// Returns a reference to the inner provider.
+    pub fn inner(&self) -> &P {
+        &self.inner
+    }
+
+    /// Returns the inner provider, dropping the patches.
+    pub fn into_inner(self) -> P {
+        self.inner
+    }
+
+    /// Adds a JSON patch for the data of marker `M` and the given identifier.
+    ///
+    /// If there already is a patch for the marker and identifier, the new patch is merged
+    /// into it.
+    ///
+    /// The patch is validated by applying it to the data of the inner provider. An error is
+    /// returned if the inner provider has no such data, if the patched data cannot be
+    /// deserialized, or if the patch contains fields that are not part of the data struct.
+    pub fn add_patch<M>(&mut self, id: DataIdentifierBorrowed, patch: &str) -> Result<(), DataError>
+    where
+        M: DataMarker,
+        P: DataProvider<M>,
+        M::DataStruct: Deserialize<'static>,
+        for<'a> <M::DataStruct as Yokeable<'a>>::Output: Serialize,
+    {
+        let req = DataRequest {
+            id,
+            ..Default::default()
+        };
+        let patch: Value =
+            serde_json::from_str(patch).map_err(|e| DataError::from(e).with_req(M::INFO, req))?;
+        let patched =
+            apply(&self.inner.load(req)?.payload, &patch).map_err(|e| e.with_req(M::INFO, req))?;
+        if !contains(
+            &serde_json::to_value(patched.get())
+                .map_err(|e| DataError::from(e).with_req(M::INFO, req))?,
+            &patch,
+        ) {
+            return Err(
+                DataError::custom("Patch does not match the data struct").with_req(M::INFO, req)
+            );
+        }
+        match self.patches.entry((M::INFO, id.into_owned())) {
+            Entry::Vacant(entry) => {
+                entry.insert(patch);
+            }
+            Entry::Occupied(mut entry) => merge(entry.get_mut(), patch),
+        }
+        Ok(())
+    }
+}
+
+/// Merges `patch` into `target`.
+fn merge(target: &mut Value, patch: Value) {
+    match (target, patch) {
+        (Value::Object(target), Value::Object(patch)) => {
+            for (key, value) in patch {
+                match target.get_mut(&key) {
+                    Some(target) => merge(target, value),
+                    None => {
+                        target.insert(key, value);
+                    }
+                }
+            }
+        }
+        (target, patch) => *target = patch,
+    }
+}
+
+/// Whether all values in `patch` are present in `value`.
+fn contains(value: &Value, patch: &Value) -> bool {
+    match (value, patch) {
+        (Value::Object(value), Value::Object(patch)) => patch
+            .iter()
+            .all(|(key, patch)| value.get(key).is_some_and(|value| contains(value, patch))),
+        (value, patch) => value == patch,
+    }
+}
+
+fn apply<M>(payload: &DataPayload<M>, patch: &Value) -> Result<DataPayload<M>, DataError>
+where
+    M: DynamicDataMarker,
+    M::DataStruct: Deserialize<'static>,
+    for<'a> <M::DataStruct as Yokeable<'a>>::Output: Serialize,
+{
+    let mut value = serde_json::to_value(payload.get())?;
+    merge(&mut value, patch.clone());
+    Ok(DataPayload::from_owned(M::DataStruct::deserialize(value)?))
+}
+
+impl<M, P> DataProvider<M> for OverlayProvider<P>
+where
+    M: DataMarker,
+    P: DataProvider<M>,
+    M::DataStruct: Deserialize<'static>,
+    for<'a> <M::DataStruct as Yokeable<'a>>::Output: Serialize,
+{
+    fn load(&self, req: DataRequest) -> Result<DataResponse<M>, DataError> {
+        let mut response = self.inner.load(req)?;
+        if self.patches.is_empty() {
+            return Ok(response);
+        }
+        // The map is covariant in the lifetime of its keys, so it can be queried with the
+        // borrowed identifier without allocating an owned one.
+        let patches: &BTreeMap<(DataMarkerInfo, DataIdentifierCow), Value> = &self.patches;
+        if let Some(patch) = patches.get(&(M::INFO, req.id.as_cow())) {
+            response.payload =
+                apply(&response.payload, patch).map_err(|e| e.with_req(M::INFO, req))?;
+        }
+        Ok(response)
+    }
+}
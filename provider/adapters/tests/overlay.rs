@@ -0,0 +1,102 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+#![cfg(feature = "overlay")]
+
+use icu_calendar::Gregorian;
+use icu_datetime::fieldsets::enums::DateFieldSet;
+use icu_datetime::input::Date;
+use icu_datetime::pattern::{DateTimePattern, FixedCalendarDateTimeNames, MonthNameLength};
+use icu_datetime::provider::neo::DatetimeNamesMonthGregorianV1;
+use icu_decimal::input::Decimal;
+use icu_decimal::provider::DecimalSymbolsV1;
+use icu_decimal::DecimalFormatter;
+use icu_locale::{langid, LanguageIdentifier};
+use icu_provider::prelude::*;
+use icu_provider_adapters::overlay::OverlayProvider;
+use writeable::{assert_writeable_eq, TryWriteable};
+
+#[test]
+fn month_name() {
+    let mut provider = OverlayProvider::new(icu_datetime::provider::Baked);
+    let en = langid!("en").into();
+    let abbreviated = DataIdentifierBorrowed::for_marker_attributes_and_locale(
+        DataMarkerAttributes::from_str_or_panic("3"),
+        &en,
+    );
+    provider
+        .add_patch::<DatetimeNamesMonthGregorianV1>(
+            abbreviated,
+            r#"{ "Linear": ["Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sept", "Oct", "Nov", "Dec"] }"#,
+        )
+        .expect("Patch matches the schema");
+
+    let date = Date::try_new_gregorian(2025, 9, 1).unwrap();
+    let format = |locale: LanguageIdentifier, length, pattern: &str| {
+        let pattern: DateTimePattern = pattern.parse().unwrap();
+        let mut names: FixedCalendarDateTimeNames<Gregorian, DateFieldSet> =
+            FixedCalendarDateTimeNames::try_new(locale.into()).unwrap();
+        names
+            .load_month_names(&provider, length)
+            .unwrap()
+            .include_decimal_formatter()
+            .unwrap();
+        names
+            .with_pattern_unchecked(&pattern)
+            .format(&date)
+            .try_write_to_string()
+            .unwrap()
+            .into_owned()
+    };
+
+    assert_eq!(
+        format(langid!("en"), MonthNameLength::Abbreviated, "MMM d, y"),
+        "Sept 1, 2025"
+    );
+
+    // Other lengths and locales are unaffected:
+    assert_eq!(
+        format(langid!("en"), MonthNameLength::Wide, "MMMM d, y"),
+        "September 1, 2025"
+    );
+    assert_eq!(
+        format(langid!("fr"), MonthNameLength::Abbreviated, "d MMM y"),
+        "1 sept. 2025"
+    );
+
+    // Lists are replaced as a whole, so a patch cannot change a single month name:
+    assert!(provider
+        .add_patch::<DatetimeNamesMonthGregorianV1>(abbreviated, r#"{ "Linear": "Sept" }"#)
+        .is_err());
+}
+
+#[test]
+fn grouping_separator() {
+    let mut provider = OverlayProvider::new(icu_decimal::provider::Baked);
+    provider
+        .add_patch::<DecimalSymbolsV1>(
+            DataIdentifierBorrowed::for_locale(&langid!("en").into()),
+            r#"{ "strings": { "grouping_separator": "’" } }"#,
+        )
+        .expect("Patch matches the schema");
+
+    let number = Decimal::from(1234567);
+
+    let formatter =
+        DecimalFormatter::try_new_unstable(&provider, langid!("en").into(), Default::default())
+            .unwrap();
+    assert_writeable_eq!(formatter.format(&number), "1’234’567");
+
+    // The other symbols are unaffected:
+    let number = Decimal::try_from_str("-1234.5").unwrap();
+    assert_writeable_eq!(formatter.format(&number), "-1’234.5");
+
+    // Unknown fields are rejected:
+    assert!(provider
+        .add_patch::<DecimalSymbolsV1>(
+            DataIdentifierBorrowed::for_locale(&langid!("en").into()),
+            r#"{ "strings": { "grouping": "’" } }"#,
+        )
+        .is_err());
+}
@@ -5,8 +5,8 @@
 use crate::blob_schema::BlobSchema;
 use icu_provider::buf::BufferFormat;
 use icu_provider::prelude::*;
-use icu_provider::Cart;
 use icu_provider::DynamicDryDataProvider;
+use icu_provider::StableBytesCart;
 use yoke::*;
 
 /// A data provider that reads from serialized blobs of data.
@@ -81,7 +81,7 @@ use yoke::*;
 /// ```
 #[derive(Clone)]
 pub struct BlobDataProvider {
-    pub(crate) data: Yoke<BlobSchema<'static>, Option<Cart>>,
+    pub(crate) data: Yoke<BlobSchema<'static>, Option<StableBytesCart>>,
 }
 
 impl core::fmt::Debug for BlobDataProvider {
@@ -97,7 +97,60 @@ impl BlobDataProvider {
     #[cfg(feature = "alloc")]
     pub fn try_new_from_blob(blob: alloc::boxed::Box<[u8]>) -> Result<Self, DataError> {
         Ok(Self {
-            data: Cart::try_make_yoke(blob, |bytes| {
+            data: StableBytesCart::try_make_yoke_owned(blob, |bytes| {
+                BlobSchema::deserialize_and_check(&mut postcard::Deserializer::from_bytes(bytes))
+            })?,
+        })
+    }
+
+    /// Create a [`BlobDataProvider`] by memory-mapping a blob file.
+    ///
+    /// Unlike [`try_new_from_blob`](BlobDataProvider::try_new_from_blob), the file is not read
+    /// onto the heap: its pages are loaded on demand, and they are shared by all processes that
+    /// map the same file. The mapping is kept alive for as long as the provider or any data
+    /// loaded from it.
+    ///
+    /// ✨ *Enabled with the `mmap` Cargo feature.*
+    ///
+    /// # Safety
+    ///
+    /// The file must not be modified or truncated, by this or any other process, while it is
+    /// mapped. Otherwise, the behavior is undefined.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu_locale_core::locale;
+    /// use icu_provider::hello_world::HelloWorldFormatter;
+    /// use icu_provider_blob::BlobDataProvider;
+    /// use writeable::assert_writeable_eq;
+    ///
+    /// // Safety: the file is not modified while the provider is in use.
+    /// let provider = unsafe {
+    ///     BlobDataProvider::try_new_from_mmap_file("tests/data/v3.postcard")
+    /// }
+    /// .expect("Deserialization should succeed");
+    ///
+    /// let formatter = HelloWorldFormatter::try_new_with_buffer_provider(
+    ///     &provider,
+    ///     locale!("la").into(),
+    /// )
+    /// .expect("locale exists");
+    ///
+    /// assert_writeable_eq!(formatter.format(), "Ave, munde");
+    /// ```
+    #[cfg(feature = "mmap")]
+    pub unsafe fn try_new_from_mmap_file(
+        path: impl AsRef<std::path::Path>,
+    ) -> Result<Self, DataError> {
+        let path = path.as_ref();
+        let file =
+            std::fs::File::open(path).map_err(|e| DataError::from(e).with_path_context(path))?;
+        // Safety: the caller guarantees that the file is not modified while it is mapped.
+        let mmap = unsafe { memmap2::Mmap::map(&file) }
+            .map_err(|e| DataError::from(e).with_path_context(path))?;
+        Ok(Self {
+            data: StableBytesCart::try_make_yoke(mmap, |bytes| {
                 BlobSchema::deserialize_and_check(&mut postcard::Deserializer::from_bytes(bytes))
             })?,
         })
@@ -125,7 +178,7 @@ impl DynamicDataProvider<BufferMarker> for BlobDataProvider {
         marker: DataMarkerInfo,
         req: DataRequest,
     ) -> Result<DataResponse<BufferMarker>, DataError> {
-        let payload: Yoke<(&[u8], Option<u64>), Option<Cart>> = self
+        let payload: Yoke<(&[u8], Option<u64>), Option<StableBytesCart>> = self
             .data
             .try_map_project_cloned(|blob, _| blob.load(marker, req))?;
         let mut metadata = DataResponseMetadata::default();
@@ -133,7 +186,9 @@ impl DynamicDataProvider<BufferMarker> for BlobDataProvider {
         metadata.checksum = payload.get().1;
         Ok(DataResponse {
             metadata,
-            payload: DataPayload::from_yoked_buffer(payload.map_project(|(bytes, _), _| bytes)),
+            payload: DataPayload::from_yoked_stable_buffer(
+                payload.map_project(|(bytes, _), _| bytes),
+            ),
         })
     }
 }
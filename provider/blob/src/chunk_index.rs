@@ -0,0 +1,92 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+#[cfg(feature = "chunked")]
+use alloc::borrow::Cow;
+use icu_provider::{marker::DataMarkerIdHash, prelude::*};
+#[cfg(feature = "chunked")]
+use writeable::Writeable;
+#[cfg(feature = "chunked")]
+use zerotrie::ZeroTrieSimpleAscii;
+use zerovec::vecs::{VarZeroSlice, ZeroSlice};
+
+/// A versioned Serde schema for the index of chunked ICU4X data blobs.
+///
+/// Each chunk is a regular data blob (see [`BlobSchema`](crate::blob_schema::BlobSchema)); the
+/// index records which chunk contains the data for a request.
+#[derive(serde::Deserialize, yoke::Yokeable)]
+#[yoke(prove_covariance_manually)]
+#[cfg_attr(feature = "export", derive(serde::Serialize))]
+#[derive(Debug, Clone)]
+pub(crate) enum ChunkIndex<'data> {
+    #[serde(borrow)]
+    V001ByMarker(ChunksByMarkerV1<'data>),
+    #[serde(borrow)]
+    V001ByLocale(ChunksByLocaleV1<'data>),
+}
+
+/// An index of chunks that each contain the data for a single marker.
+#[derive(Clone, Copy, Debug, serde::Deserialize, yoke::Yokeable)]
+#[yoke(prove_covariance_manually)]
+#[cfg_attr(feature = "export", derive(serde::Serialize))]
+pub(crate) struct ChunksByMarkerV1<'data> {
+    /// Sorted list of marker hashes.
+    #[serde(borrow)]
+    pub markers: &'data ZeroSlice<DataMarkerIdHash>,
+    /// The chunk names of the markers.
+    /// Weak invariant: markers and chunks are the same length
+    #[serde(borrow)]
+    pub chunks: &'data VarZeroSlice<str>,
+}
+
+/// An index of chunks that each contain the data for a single locale, named by that locale.
+#[derive(Clone, Copy, Debug, serde::Deserialize, yoke::Yokeable)]
+#[yoke(prove_covariance_manually)]
+#[cfg_attr(feature = "export", derive(serde::Serialize))]
+pub(crate) struct ChunksByLocaleV1<'data> {
+    /// Sorted list of marker hashes.
+    #[serde(borrow)]
+    pub markers: &'data ZeroSlice<DataMarkerIdHash>,
+    /// Trie of the locales that have a chunk. The values are unused.
+    #[serde(borrow)]
+    pub locales: &'data ZeroSlice<u8>,
+}
+
+#[cfg(feature = "chunked")]
+impl<'data> ChunkIndex<'data> {
+    /// Returns the name of the chunk containing the data for the request.
+    pub fn chunk_name(
+        &self,
+        marker: DataMarkerInfo,
+        req: DataRequest,
+    ) -> Result<Cow<'data, str>, DataError> {
+        if marker.is_singleton && !req.id.locale.is_unknown() {
+            return Err(DataErrorKind::InvalidRequest.with_req(marker, req));
+        }
+        match self {
+            ChunkIndex::V001ByMarker(s) => {
+                let marker_index = s
+                    .markers
+                    .binary_search(&marker.id.hashed())
+                    .ok()
+                    .ok_or_else(|| DataErrorKind::MarkerNotFound.with_req(marker, req))?;
+                s.chunks
+                    .get(marker_index)
+                    .map(Cow::Borrowed)
+                    .ok_or_else(|| DataError::custom("Invalid index bytes").with_req(marker, req))
+            }
+            ChunkIndex::V001ByLocale(s) => {
+                s.markers
+                    .binary_search(&marker.id.hashed())
+                    .ok()
+                    .ok_or_else(|| DataErrorKind::MarkerNotFound.with_req(marker, req))?;
+                let name = req.id.locale.write_to_string();
+                ZeroTrieSimpleAscii::from_store(s.locales.as_ule_slice())
+                    .get(name.as_bytes())
+                    .ok_or_else(|| DataErrorKind::IdentifierNotFound.with_req(marker, req))?;
+                Ok(Cow::Owned(name.into_owned()))
+            }
+        }
+    }
+}
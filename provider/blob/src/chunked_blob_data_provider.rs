@@ -0,0 +1,352 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use crate::chunk_index::ChunkIndex;
+use crate::BlobDataProvider;
+use alloc::borrow::Cow;
+use alloc::boxed::Box;
+use alloc::collections::BTreeMap;
+use alloc::string::String;
+use icu_provider::prelude::*;
+use icu_provider::Cart;
+use serde::Deserialize;
+use std::sync::{Mutex, PoisonError};
+use yoke::*;
+
+type ChunkLoader = Box<dyn Fn(&str) -> Result<Box<[u8]>, DataError> + Send + Sync>;
+
+/// A data provider that reads from data blobs split into chunks, which are loaded on demand.
+///
+/// The chunks and their index are created by
+/// [`ChunkedBlobExporter`](crate::export::ChunkedBlobExporter). The provider is created from the
+/// index, and chunks are added either by a loader callback, which is called the first time a
+/// chunk is needed, or ahead of time with [`insert_chunk`](Self::insert_chunk). The latter
+/// allows loading chunks asynchronously, for example over the network.
+///
+/// Like [`BlobDataProvider`], this provider implements [`BufferProvider`], so it can be used in
+/// `*_with_buffer_provider` constructors across ICU4X.
+///
+/// ✨ *Enabled with the `chunked` Cargo feature.*
+///
+/// # `Sync + Send`
+///
+/// Loaded chunks are kept in a mutex. Like [`BlobDataProvider`], this provider is
+/// `Sync + Send` when the `sync` Cargo feature on the [`icu_provider`] crate is enabled.
+///
+/// # Examples
+///
+/// Load chunks from files as they are needed:
+///
+/// ```
+/// use icu_locale_core::locale;
+/// use icu_provider::hello_world::HelloWorldFormatter;
+/// use icu_provider_blob::ChunkedBlobDataProvider;
+/// use writeable::assert_writeable_eq;
+///
+/// let index = std::fs::read("tests/data/chunked/index.postcard")
+///     .expect("Reading pre-computed index");
+///
+/// let provider =
+///     ChunkedBlobDataProvider::try_new_from_index(index.into_boxed_slice())
+///         .expect("Deserialization should succeed")
+///         .with_loader(|name| {
+///             Ok(std::fs::read(format!("tests/data/chunked/{name}.postcard"))?
+///                 .into_boxed_slice())
+///         });
+/// assert!(!provider.contains_chunk("HelloWorldV1"));
+///
+/// let formatter = HelloWorldFormatter::try_new_with_buffer_provider(
+///     &provider,
+///     locale!("la").into(),
+/// )
+/// .expect("locale exists");
+///
+/// assert_writeable_eq!(formatter.format(), "Ave, munde");
+/// assert!(provider.contains_chunk("HelloWorldV1"));
+/// ```
+///
+/// Fetch chunks ahead of time, for example asynchronously:
+///
+/// ```
+/// use icu_locale_core::locale;
+/// use icu_provider::hello_world::{HelloWorldFormatter, HelloWorldV1};
+/// use icu_provider::prelude::*;
+/// use icu_provider_blob::ChunkedBlobDataProvider;
+/// use writeable::assert_writeable_eq;
+///
+/// # fn fetch(name: &str) -> Box<[u8]> {
+/// #     std::fs::read(format!("tests/data/chunked/{name}.postcard")).unwrap().into_boxed_slice()
+/// # }
+/// # let index = std::fs::read("tests/data/chunked/index.postcard").unwrap().into_boxed_slice();
+/// let provider = ChunkedBlobDataProvider::try_new_from_index(index)
+///     .expect("Deserialization should succeed");
+///
+/// let name = provider
+///     .chunk_name(
+///         HelloWorldV1::INFO,
+///         DataRequest {
+///             id: DataIdentifierBorrowed::for_locale(&locale!("la").into()),
+///             ..Default::default()
+///         },
+///     )
+///     .expect("locale exists");
+/// assert_eq!(name, "HelloWorldV1");
+///
+/// // Get the chunk, for example with an asynchronous network request
+/// let chunk = fetch(&name);
+/// provider
+///     .insert_chunk(&name, chunk)
+///     .expect("Deserialization should succeed");
+///
+/// let formatter = HelloWorldFormatter::try_new_with_buffer_provider(
+///     &provider,
+///     locale!("la").into(),
+/// )
+/// .expect("locale exists");
+///
+/// assert_writeable_eq!(formatter.format(), "Ave, munde");
+/// ```
+///
+/// [`BufferProvider`]: icu_provider::buf::BufferProvider
+pub struct ChunkedBlobDataProvider {
+    index: Yoke<ChunkIndex<'static>, Option<Cart>>,
+    chunks: Mutex<BTreeMap<String, BlobDataProvider>>,
+    loader: Option<ChunkLoader>,
+}
+
+impl core::fmt::Debug for ChunkedBlobDataProvider {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("ChunkedBlobDataProvider")
+            .field("index", &"[...]")
+            .field("chunks", &self.chunks)
+            .field("loader", &self.loader.as_ref().map(|_| "<loader>"))
+            .finish()
+    }
+}
+
+impl ChunkedBlobDataProvider {
+    /// Create a [`ChunkedBlobDataProvider`] from the index of chunked ICU4X data.
+    ///
+    /// No chunks are loaded until a loader is added with [`with_loader`](Self::with_loader),
+    /// or chunks are added with [`insert_chunk`](Self::insert_chunk).
+    pub fn try_new_from_index(index: Box<[u8]>) -> Result<Self, DataError> {
+        Ok(Self {
+            index: Cart::try_make_yoke(index, |bytes| {
+                ChunkIndex::deserialize(&mut postcard::Deserializer::from_bytes(bytes))
+            })?,
+            chunks: Default::default(),
+            loader: None,
+        })
+    }
+
+    /// Sets a callback that returns the bytes of a chunk given its name.
+    ///
+    /// The callback is called when data from a chunk is requested that has not been loaded yet.
+    pub fn with_loader(
+        self,
+        loader: impl Fn(&str) -> Result<Box<[u8]>, DataError> + Send + Sync + 'static,
+    ) -> Self {
+        Self {
+            loader: Some(Box::new(loader)),
+            ..self
+        }
+    }
+
+    /// Returns the name of the chunk that contains the data for a request.
+    ///
+    /// Returns an error if the index shows that there is no data for the request.
+    pub fn chunk_name(
+        &self,
+        marker: DataMarkerInfo,
+        req: DataRequest,
+    ) -> Result<Cow<'_, str>, DataError> {
+        self.index.get().chunk_name(marker, req)
+    }
+
+    /// Returns whether the chunk with the given name has been loaded.
+    pub fn contains_chunk(&self, name: &str) -> bool {
+        self.chunks
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .contains_key(name)
+    }
+
+    /// Adds the bytes of the chunk with the given name.
+    ///
+    /// If the chunk has already been loaded, this does nothing.
+    pub fn insert_chunk(&self, name: &str, chunk: Box<[u8]>) -> Result<(), DataError> {
+        let chunk = BlobDataProvider::try_new_from_blob(chunk)?;
+        self.chunks
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .entry(name.into())
+            .or_insert(chunk);
+        Ok(())
+    }
+
+    fn chunk(&self, name: &str) -> Result<BlobDataProvider, DataError> {
+        if let Some(chunk) = self
+            .chunks
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .get(name)
+        {
+            return Ok(chunk.clone());
+        }
+        let Some(loader) = self.loader.as_ref() else {
+            return Err(DataError::custom("Chunk not loaded").with_display_context(name));
+        };
+        // The loader is called without holding the lock, so that other chunks can be used
+        // in the meantime.
+        self.insert_chunk(name, loader(name)?)?;
+        self.chunks
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .get(name)
+            .cloned()
+            .ok_or_else(|| DataError::custom("Chunk not loaded").with_display_context(name))
+    }
+}
+
+impl DynamicDataProvider<BufferMarker> for ChunkedBlobDataProvider {
+    fn load_data(
+        &self,
+        marker: DataMarkerInfo,
+        req: DataRequest,
+    ) -> Result<DataResponse<BufferMarker>, DataError> {
+        let name = self.chunk_name(marker, req)?;
+        self.chunk(&name)
+            .map_err(|e| e.with_req(marker, req))?
+            .load_data(marker, req)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::export::*;
+    use icu_provider::hello_world::*;
+    use icu_provider_export::prelude::*;
+
+    fn export(strategy: ChunkingStrategy, name: &str) -> std::path::PathBuf {
+        let path = std::env::temp_dir().join(name);
+        let _ = std::fs::remove_dir_all(&path);
+        std::fs::create_dir_all(&path).unwrap();
+
+        let chunk_path = path.clone();
+        let exporter = ChunkedBlobExporter::new_with_sinks(
+            strategy,
+            Box::new(std::fs::File::create(path.join("index.postcard")).unwrap()),
+            Box::new(move |name| {
+                Ok(Box::new(std::fs::File::create(
+                    chunk_path.join(format!("{name}.postcard")),
+                )?))
+            }),
+        );
+        ExportDriver::new(
+            [DataLocaleFamily::FULL],
+            DeduplicationStrategy::None.into(),
+            LocaleFallbacker::new().static_to_owned(),
+        )
+        .export(&HelloWorldProvider, exporter)
+        .unwrap();
+        path
+    }
+
+    fn check(path: &std::path::Path) {
+        let chunk_path = path.to_owned();
+        let provider = ChunkedBlobDataProvider::try_new_from_index(
+            std::fs::read(path.join("index.postcard"))
+                .unwrap()
+                .into_boxed_slice(),
+        )
+        .unwrap()
+        .with_loader(move |name| {
+            Ok(std::fs::read(chunk_path.join(format!("{name}.postcard")))?.into_boxed_slice())
+        });
+
+        for id in HelloWorldProvider.iter_ids().unwrap() {
+            let req = DataRequest {
+                id: id.as_borrowed(),
+                ..Default::default()
+            };
+            let expected: DataResponse<HelloWorldV1> = HelloWorldProvider.load(req).unwrap();
+            let actual: DataResponse<HelloWorldV1> = provider.as_deserializing().load(req).unwrap();
+            assert_eq!(actual.payload.get(), expected.payload.get(), "{id:?}");
+        }
+
+        assert!(matches!(
+            DataProvider::<HelloWorldV1>::load(
+                &provider.as_deserializing(),
+                DataRequest {
+                    id: DataIdentifierBorrowed::for_locale(&icu_locale_core::langid!("xx").into()),
+                    ..Default::default()
+                }
+            ),
+            Err(DataError {
+                kind: DataErrorKind::IdentifierNotFound,
+                ..
+            })
+        ));
+
+        icu_provider::data_marker!(MissingV1, HelloWorld<'static>);
+        assert!(matches!(
+            DataProvider::<MissingV1>::load(&provider.as_deserializing(), Default::default()),
+            Err(DataError {
+                kind: DataErrorKind::MarkerNotFound,
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn test_by_locale() {
+        let path = export(ChunkingStrategy::ByLocale, "icu4x_chunked_by_locale_test");
+        check(&path);
+        let _ = std::fs::remove_dir_all(&path);
+    }
+
+    #[test]
+    fn test_by_marker() {
+        let path = export(ChunkingStrategy::ByMarker, "icu4x_chunked_by_marker_test");
+        assert_eq!(
+            std::fs::read(path.join("index.postcard")).unwrap(),
+            include_bytes!("../tests/data/chunked/index.postcard")
+        );
+        assert_eq!(
+            std::fs::read(path.join("HelloWorldV1.postcard")).unwrap(),
+            include_bytes!("../tests/data/chunked/HelloWorldV1.postcard")
+        );
+        check(&path);
+        let _ = std::fs::remove_dir_all(&path);
+    }
+
+    #[test]
+    fn test_without_loader() {
+        let provider = ChunkedBlobDataProvider::try_new_from_index(
+            include_bytes!("../tests/data/chunked/index.postcard")
+                .to_vec()
+                .into_boxed_slice(),
+        )
+        .unwrap();
+        let locale = icu_locale_core::langid!("la").into();
+        let req = DataRequest {
+            id: DataIdentifierBorrowed::for_locale(&locale),
+            ..Default::default()
+        };
+
+        assert!(DataProvider::<HelloWorldV1>::load(&provider.as_deserializing(), req).is_err());
+
+        provider
+            .insert_chunk(
+                "HelloWorldV1",
+                include_bytes!("../tests/data/chunked/HelloWorldV1.postcard")
+                    .to_vec()
+                    .into_boxed_slice(),
+            )
+            .unwrap();
+        let response: DataResponse<HelloWorldV1> = provider.as_deserializing().load(req).unwrap();
+        assert_eq!(response.payload.get().message, "Ave, munde");
+    }
+}
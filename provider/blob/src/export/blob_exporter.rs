@@ -22,27 +22,33 @@ use postcard::ser_flavors::{AllocVec, Flavor};
 /// A data exporter that writes data to a single-file blob.
 /// See the module-level docs for an example.
 pub struct BlobExporter<'w> {
-    /// Map of marker path hash -> locale byte string -> blob ID
-    resources: Mutex<BTreeMap<DataMarkerIdHash, BTreeMap<Vec<u8>, usize>>>,
-    checksums: Mutex<BTreeMap<DataMarkerIdHash, u64>>,
-    // All seen markers
-    all_markers: Mutex<BTreeSet<DataMarkerIdHash>>,
-    /// Map from blob to blob ID
-    unique_resources: Mutex<HashMap<Vec<u8>, usize>>,
+    blob: BlobBuilder,
     sink: Box<dyn std::io::Write + Sync + 'w>,
 }
 
 impl core::fmt::Debug for BlobExporter<'_> {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_struct("BlobExporter")
-            .field("resources", &self.resources)
-            .field("unique_resources", &self.unique_resources)
-            .field("all_markers", &self.all_markers)
+            .field("resources", &self.blob.resources)
+            .field("unique_resources", &self.blob.unique_resources)
+            .field("all_markers", &self.blob.all_markers)
             .field("sink", &"<sink>")
             .finish()
     }
 }
 
+/// The data of a blob that is being exported, independent of where it is written to.
+#[derive(Debug, Default)]
+pub(crate) struct BlobBuilder {
+    /// Map of marker path hash -> locale byte string -> blob ID
+    resources: Mutex<BTreeMap<DataMarkerIdHash, BTreeMap<Vec<u8>, usize>>>,
+    checksums: Mutex<BTreeMap<DataMarkerIdHash, u64>>,
+    // All seen markers
+    all_markers: Mutex<BTreeSet<DataMarkerIdHash>>,
+    /// Map from blob to blob ID
+    unique_resources: Mutex<HashMap<Vec<u8>, usize>>,
+}
+
 impl<'w> BlobExporter<'w> {
     /// Creates a version 1 [`BlobExporter`] that writes to the given I/O stream.
     ///
@@ -50,10 +56,7 @@ impl<'w> BlobExporter<'w> {
     /// targeting only ICU4X 1.4 and above, see [BlobExporter::new_with_sink()].
     pub fn new_with_sink(sink: Box<dyn std::io::Write + Sync + 'w>) -> Self {
         Self {
-            resources: Default::default(),
-            unique_resources: Default::default(),
-            checksums: Default::default(),
-            all_markers: Default::default(),
+            blob: Default::default(),
             sink,
         }
     }
@@ -65,6 +68,29 @@ impl DataExporter for BlobExporter<'_> {
         marker: DataMarkerInfo,
         id: DataIdentifierBorrowed,
         payload: &DataPayload<ExportMarker>,
+    ) -> Result<(), DataError> {
+        self.blob.put_payload(marker, id, payload)
+    }
+
+    fn flush(&self, marker: DataMarkerInfo, metadata: FlushMetadata) -> Result<(), DataError> {
+        self.blob.flush(marker, metadata)
+    }
+
+    fn close(&mut self) -> Result<ExporterCloseMetadata, DataError> {
+        if let Some(output) = self.blob.build()? {
+            log::info!("Writing blob to output stream...");
+            self.sink.write_all(&output)?;
+        }
+        Ok(Default::default())
+    }
+}
+
+impl BlobBuilder {
+    pub(crate) fn put_payload(
+        &self,
+        marker: DataMarkerInfo,
+        id: DataIdentifierBorrowed,
+        payload: &DataPayload<ExportMarker>,
     ) -> Result<(), DataError> {
         let mut serializer = postcard::Serializer {
             output: AllocVec::new(),
@@ -97,7 +123,11 @@ impl DataExporter for BlobExporter<'_> {
         Ok(())
     }
 
-    fn flush(&self, marker: DataMarkerInfo, metadata: FlushMetadata) -> Result<(), DataError> {
+    pub(crate) fn flush(
+        &self,
+        marker: DataMarkerInfo,
+        metadata: FlushMetadata,
+    ) -> Result<(), DataError> {
         if let Some(checksum) = metadata.checksum {
             self.checksums
                 .lock()
@@ -110,10 +140,6 @@ impl DataExporter for BlobExporter<'_> {
             .insert(marker.id.hashed());
         Ok(())
     }
-
-    fn close(&mut self) -> Result<ExporterCloseMetadata, DataError> {
-        self.close_internal()
-    }
 }
 
 struct FinalizedBuffers {
@@ -123,8 +149,8 @@ struct FinalizedBuffers {
     remap: HashMap<usize, usize>,
 }
 
-impl BlobExporter<'_> {
-    fn finalize_buffers(&mut self) -> FinalizedBuffers {
+impl BlobBuilder {
+    fn finalize_buffers(&self) -> FinalizedBuffers {
         // The blob IDs are unstable due to the parallel nature of datagen.
         // In order to make a canonical form, we sort them lexicographically now.
 
@@ -152,7 +178,8 @@ impl BlobExporter<'_> {
         FinalizedBuffers { vzv, remap }
     }
 
-    fn close_internal(&mut self) -> Result<ExporterCloseMetadata, DataError> {
+    /// Serializes the blob, or returns `None` if no markers were flushed.
+    pub(crate) fn build(&self) -> Result<Option<Vec<u8>>, DataError> {
         let FinalizedBuffers { mut vzv, remap } = self.finalize_buffers();
 
         let all_markers = self.all_markers.lock().expect("poison");
@@ -186,36 +213,34 @@ impl BlobExporter<'_> {
             })
             .collect();
 
-        if !markers.is_empty() {
-            if let Ok(locales_vzv) =
-                VarZeroVecOwned::<[u8]>::try_from_elements(locales_vec.as_slice())
-            {
-                let blob = BlobSchema::V003(BlobSchemaV1 {
-                    markers: &markers,
-                    locales: &locales_vzv,
-                    buffers: &vzv,
-                });
-                log::info!("Serializing blob to output stream...");
-
-                let output = postcard::to_allocvec(&blob)?;
-                self.sink.write_all(&output)?;
-            } else {
-                log::info!("Upgrading to BlobSchema (bigger)...");
-                let locales_vzv =
-                    VarZeroVecOwned::<[u8], Index32>::try_from_elements(locales_vec.as_slice())
-                        .expect("Locales vector does not fit in Index32 buffer!");
-                let blob = BlobSchema::V003Bigger(BlobSchemaV1 {
-                    markers: &markers,
-                    locales: &locales_vzv,
-                    buffers: &vzv,
-                });
-                log::info!("Serializing blob to output stream...");
-
-                let output = postcard::to_allocvec(&blob)?;
-                self.sink.write_all(&output)?;
-            }
+        if markers.is_empty() {
+            return Ok(None);
         }
 
-        Ok(Default::default())
+        let output = if let Ok(locales_vzv) =
+            VarZeroVecOwned::<[u8]>::try_from_elements(locales_vec.as_slice())
+        {
+            let blob = BlobSchema::V003(BlobSchemaV1 {
+                markers: &markers,
+                locales: &locales_vzv,
+                buffers: &vzv,
+            });
+            log::info!("Serializing blob...");
+            postcard::to_allocvec(&blob)?
+        } else {
+            log::info!("Upgrading to BlobSchema (bigger)...");
+            let locales_vzv =
+                VarZeroVecOwned::<[u8], Index32>::try_from_elements(locales_vec.as_slice())
+                    .expect("Locales vector does not fit in Index32 buffer!");
+            let blob = BlobSchema::V003Bigger(BlobSchemaV1 {
+                markers: &markers,
+                locales: &locales_vzv,
+                buffers: &vzv,
+            });
+            log::info!("Serializing blob...");
+            postcard::to_allocvec(&blob)?
+        };
+
+        Ok(Some(output))
     }
 }
@@ -0,0 +1,218 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+// This is "export" feature, and there are many internal invariants
+#![allow(clippy::expect_used)]
+
+use super::blob_exporter::BlobBuilder;
+use crate::chunk_index::*;
+use icu_provider::export::*;
+use icu_provider::prelude::*;
+use std::collections::BTreeMap;
+use std::sync::{Arc, Mutex};
+use zerotrie::ZeroTrieSimpleAscii;
+use zerovec::{VarZeroVec, ZeroVec};
+
+/// How a [`ChunkedBlobExporter`] splits data into chunks.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ChunkingStrategy {
+    /// One chunk per locale, named by the locale, such as `de-CH`. Data for singleton
+    /// markers is in the `und` chunk.
+    ByLocale,
+    /// One chunk per marker, named by the marker, such as `HelloWorldV1`.
+    ByMarker,
+}
+
+/// A sink for a chunk, given its name.
+type ChunkSink<'w> =
+    Box<dyn Fn(&str) -> Result<Box<dyn std::io::Write + 'w>, DataError> + Sync + 'w>;
+
+/// A data exporter that splits data into chunks of blobs, plus an index, for use with
+/// [`ChunkedBlobDataProvider`](crate::ChunkedBlobDataProvider).
+///
+/// # Examples
+///
+/// ```
+/// use icu_provider_blob::export::*;
+/// use icu_provider_export::prelude::*;
+///
+/// let demo_path = std::env::temp_dir().join("icu4x_chunked_blob_demo");
+/// # let _ = std::fs::remove_dir_all(&demo_path);
+/// std::fs::create_dir_all(&demo_path).unwrap();
+///
+/// // Set up the exporter, writing each chunk to its own file
+/// let chunk_path = demo_path.clone();
+/// let exporter = ChunkedBlobExporter::new_with_sinks(
+///     ChunkingStrategy::ByLocale,
+///     Box::new(std::fs::File::create(demo_path.join("index.postcard")).unwrap()),
+///     Box::new(move |name| {
+///         Ok(Box::new(std::fs::File::create(
+///             chunk_path.join(format!("{name}.postcard")),
+///         )?))
+///     }),
+/// );
+///
+/// // Export something. Make sure to use the same fallback data at runtime!
+/// ExportDriver::new(
+///     [DataLocaleFamily::FULL],
+///     DeduplicationStrategy::Maximal.into(),
+///     LocaleFallbacker::new().static_to_owned(),
+/// )
+/// .export(&icu_provider::hello_world::HelloWorldProvider, exporter)
+/// .unwrap();
+///
+/// assert!(demo_path.join("de.postcard").exists());
+/// #
+/// # let _ = std::fs::remove_dir_all(&demo_path);
+/// ```
+pub struct ChunkedBlobExporter<'w> {
+    strategy: ChunkingStrategy,
+    /// Map of chunk name -> data of that chunk
+    ///
+    /// The builders are shared so that payloads are serialized without holding the lock on
+    /// the map.
+    chunks: Mutex<BTreeMap<String, Arc<BlobBuilder>>>,
+    /// All flushed markers
+    flushed: Mutex<BTreeMap<DataMarkerInfo, FlushMetadata>>,
+    index_sink: Box<dyn std::io::Write + Sync + 'w>,
+    chunk_sink: ChunkSink<'w>,
+}
+
+impl core::fmt::Debug for ChunkedBlobExporter<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("ChunkedBlobExporter")
+            .field("strategy", &self.strategy)
+            .field("chunks", &self.chunks)
+            .field("flushed", &self.flushed)
+            .field("index_sink", &"<sink>")
+            .field("chunk_sink", &"<sink>")
+            .finish()
+    }
+}
+
+impl<'w> ChunkedBlobExporter<'w> {
+    /// Creates a [`ChunkedBlobExporter`] that writes the index to `index_sink`, and each
+    /// chunk to the I/O stream returned by `chunk_sink` for the chunk's name.
+    ///
+    /// The sinks are only requested when the exporter is closed.
+    pub fn new_with_sinks(
+        strategy: ChunkingStrategy,
+        index_sink: Box<dyn std::io::Write + Sync + 'w>,
+        chunk_sink: ChunkSink<'w>,
+    ) -> Self {
+        Self {
+            strategy,
+            chunks: Default::default(),
+            flushed: Default::default(),
+            index_sink,
+            chunk_sink,
+        }
+    }
+
+    fn chunk_name(&self, marker: DataMarkerInfo, id: DataIdentifierBorrowed) -> String {
+        match self.strategy {
+            ChunkingStrategy::ByLocale => id.locale.to_string(),
+            ChunkingStrategy::ByMarker => marker.id.name().to_string(),
+        }
+    }
+}
+
+impl DataExporter for ChunkedBlobExporter<'_> {
+    fn put_payload(
+        &self,
+        marker: DataMarkerInfo,
+        id: DataIdentifierBorrowed,
+        payload: &DataPayload<ExportMarker>,
+    ) -> Result<(), DataError> {
+        let chunk = self
+            .chunks
+            .lock()
+            .expect("poison")
+            .entry(self.chunk_name(marker, id))
+            .or_default()
+            .clone();
+        chunk.put_payload(marker, id, payload)
+    }
+
+    fn flush(&self, marker: DataMarkerInfo, metadata: FlushMetadata) -> Result<(), DataError> {
+        self.flushed
+            .lock()
+            .expect("poison")
+            .insert(marker, metadata);
+        Ok(())
+    }
+
+    fn close(&mut self) -> Result<ExporterCloseMetadata, DataError> {
+        let flushed = core::mem::take(&mut *self.flushed.lock().expect("poison"));
+        let mut chunks = core::mem::take(&mut *self.chunks.lock().expect("poison"));
+
+        // Every marker is added to every chunk that can contain it, so that chunks can
+        // distinguish missing markers from missing identifiers.
+        for (&marker, &metadata) in &flushed {
+            match self.strategy {
+                ChunkingStrategy::ByLocale => {
+                    for chunk in chunks.values() {
+                        chunk.flush(marker, metadata)?;
+                    }
+                }
+                ChunkingStrategy::ByMarker => {
+                    chunks
+                        .entry(self.chunk_name(marker, Default::default()))
+                        .or_default()
+                        .flush(marker, metadata)?;
+                }
+            }
+        }
+
+        for (name, chunk) in &chunks {
+            if let Some(output) = chunk.build()? {
+                log::info!("Writing chunk {name} to output stream...");
+                (self.chunk_sink)(name)?.write_all(&output)?;
+            }
+        }
+
+        let markers: BTreeMap<_, _> = flushed
+            .keys()
+            .map(|marker| {
+                (
+                    marker.id.hashed(),
+                    self.chunk_name(*marker, Default::default()),
+                )
+            })
+            .collect();
+        let marker_hashes: ZeroVec<_> = markers.keys().copied().collect();
+
+        let output = match self.strategy {
+            ChunkingStrategy::ByLocale => {
+                let locales = ZeroVec::alloc_from_slice(
+                    &ZeroTrieSimpleAscii::try_from(
+                        &chunks
+                            .keys()
+                            .map(|name| (name.as_bytes(), 0))
+                            .collect::<BTreeMap<_, _>>(),
+                    )
+                    .expect("in-bounds")
+                    .into_store(),
+                );
+                postcard::to_allocvec(&ChunkIndex::V001ByLocale(ChunksByLocaleV1 {
+                    markers: &marker_hashes,
+                    locales: &locales,
+                }))?
+            }
+            ChunkingStrategy::ByMarker => {
+                let names: Vec<&str> = markers.values().map(String::as_str).collect();
+                let chunks: VarZeroVec<str> = names.as_slice().into();
+                postcard::to_allocvec(&ChunkIndex::V001ByMarker(ChunksByMarkerV1 {
+                    markers: &marker_hashes,
+                    chunks: &chunks,
+                }))?
+            }
+        };
+        log::info!("Serializing chunk index to output stream...");
+        self.index_sink.write_all(&output)?;
+
+        Ok(Default::default())
+    }
+}
@@ -58,5 +58,7 @@
 //! ```
 
 mod blob_exporter;
+mod chunked_blob_exporter;
 
 pub use blob_exporter::BlobExporter;
+pub use chunked_blob_exporter::{ChunkedBlobExporter, ChunkingStrategy};
@@ -11,12 +11,19 @@
 //! $ icu4x-datagen --markers all --locales full --format blob --out data.postcard
 //! ```
 //!
+//! For clients that should only download the data they use, such as web clients, data can be
+//! split into chunks with [`export::ChunkedBlobExporter`] and loaded on demand with
+//! [`ChunkedBlobDataProvider`].
+//!
 //! [`ICU4X`]: ../icu/index.html
 //! [`BufferProvider`]: icu_provider::buf::BufferProvider
 //! [`icu_provider_export`]: ../icu_provider_export/index.html
 
 // https://github.com/unicode-org/icu4x/blob/main/documents/process/boilerplate.md#library-annotations
-#![cfg_attr(not(any(test, feature = "export")), no_std)]
+#![cfg_attr(
+    not(any(test, feature = "export", feature = "mmap", feature = "chunked")),
+    no_std
+)]
 #![cfg_attr(
     not(test),
     deny(
@@ -37,8 +44,14 @@ extern crate alloc;
 
 mod blob_data_provider;
 mod blob_schema;
+#[cfg(any(feature = "chunked", feature = "export"))]
+mod chunk_index;
+#[cfg(feature = "chunked")]
+mod chunked_blob_data_provider;
 
 #[cfg(feature = "export")]
 pub mod export;
 
 pub use blob_data_provider::BlobDataProvider;
+#[cfg(feature = "chunked")]
+pub use chunked_blob_data_provider::ChunkedBlobDataProvider;
@@ -120,6 +120,31 @@ fn test_format() {
     check_hello_world(blob_provider.as_deserializing(), true);
 }
 
+#[cfg(feature = "mmap")]
+#[test]
+fn test_mmap() {
+    // Safety: the test data is not modified while the tests run.
+    let blob_provider =
+        unsafe { BlobDataProvider::try_new_from_mmap_file("tests/data/v3.postcard") }.unwrap();
+    check_hello_world(blob_provider.as_deserializing(), true);
+
+    // The mapping outlives the provider as long as data borrows from it.
+    let payload: DataPayload<HelloWorldV1> = blob_provider
+        .as_deserializing()
+        .load(DataRequest {
+            id: DataIdentifierBorrowed::for_locale(&"la".parse().unwrap()),
+            ..Default::default()
+        })
+        .unwrap()
+        .payload;
+    drop(blob_provider);
+    assert_eq!(payload.get().message, "Ave, munde");
+
+    assert!(
+        unsafe { BlobDataProvider::try_new_from_mmap_file("tests/data/missing.postcard") }.is_err()
+    );
+}
+
 // This tests that the Bigger format works by attempting to export something with 26^4 = 456976 data entries
 #[test]
 fn test_format_bigger() {
@@ -129,7 +129,7 @@ pub use request::{DataLocale, DataMarkerAttributes, DataRequest, DataRequestMeta
 mod response;
 #[doc(hidden)] // TODO(#4467): establish this as an internal API
 pub use response::DataPayloadOr;
-pub use response::{Cart, DataPayload, DataResponse, DataResponseMetadata};
+pub use response::{Cart, DataPayload, DataResponse, DataResponseMetadata, StableBytesCart};
 
 #[path = "marker.rs"]
 mod marker_full;
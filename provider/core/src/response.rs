@@ -153,12 +153,12 @@ pub struct DataPayload<M: DynamicDataMarker>(pub(crate) DataPayloadInner<M>);
 pub struct DataPayloadOr<M: DynamicDataMarker, O>(pub(crate) DataPayloadOrInner<M, O>);
 
 pub(crate) enum DataPayloadInner<M: DynamicDataMarker> {
-    Yoke(Yoke<M::DataStruct, CartableOptionPointer<CartInner>>),
+    Yoke(Yoke<M::DataStruct, CartableOptionPointer<PayloadCart>>),
     StaticRef(&'static M::DataStruct),
 }
 
 pub(crate) enum DataPayloadOrInner<M: DynamicDataMarker, O> {
-    Yoke(Yoke<M::DataStruct, CartableOptionPointer<CartInner>>),
+    Yoke(Yoke<M::DataStruct, CartableOptionPointer<PayloadCart>>),
     Inner(DataPayloadOrInnerInner<M, O>),
 }
 
@@ -181,6 +181,42 @@ pub(crate) type CartInner = SelectedRc<Box<[u8]>>;
 #[cfg(not(feature = "alloc"))]
 pub(crate) type CartInner = &'static ();
 
+/// The cart type of a [`DataPayload`] and a [`StableBytesCart`] (private typedef).
+#[cfg(feature = "alloc")]
+pub(crate) type PayloadCart = SelectedRc<PayloadBuffer>;
+#[cfg(not(feature = "alloc"))]
+pub(crate) type PayloadCart = &'static ();
+
+/// The bytes that a [`DataPayload`] borrows from.
+#[cfg(feature = "alloc")]
+pub(crate) enum PayloadBuffer {
+    /// Bytes on the heap.
+    Owned(Box<[u8]>),
+    /// Bytes shared with a [`Cart`].
+    Shared(Cart),
+    /// Bytes owned by any other stable pointer, such as a memory map.
+    Stable(Box<dyn stable_deref_trait::StableDeref<Target = [u8]> + Send + Sync>),
+}
+
+#[cfg(feature = "alloc")]
+impl Deref for PayloadBuffer {
+    type Target = [u8];
+    fn deref(&self) -> &Self::Target {
+        match self {
+            Self::Owned(bytes) => bytes,
+            Self::Shared(cart) => cart,
+            Self::Stable(bytes) => bytes,
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl Debug for PayloadBuffer {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        Debug::fmt(&**self, f)
+    }
+}
+
 // Safety: Rc, Arc, and () are CloneableCart, and our impl delegates.
 unsafe impl yoke::CloneableCart for Cart {}
 
@@ -209,15 +245,74 @@ impl Cart {
             .map(Yoke::wrap_cart_in_option)
     }
 
-    /// Helper function to convert `Yoke<Y, Option<Cart>>` to `Yoke<Y, Option<CartInner>>`.
+    /// Helper function to share the bytes of a `Cart` with a [`DataPayload`].
     #[inline]
-    pub(crate) fn unwrap_cart<Y>(yoke: Yoke<Y, Option<Cart>>) -> Yoke<Y, Option<CartInner>>
+    fn into_payload_cart(self) -> PayloadCart {
+        #[cfg(feature = "alloc")]
+        return SelectedRc::new(PayloadBuffer::Shared(self));
+        #[cfg(not(feature = "alloc"))]
+        return self.0;
+    }
+}
+
+/// A "cart" like [`Cart`], for bytes that may be owned by any stable pointer instead of a
+/// [`Box`], such as a memory-mapped file.
+///
+/// To create a [`Yoke`] with this cart, use [`StableBytesCart::try_make_yoke`] or
+/// [`StableBytesCart::try_make_yoke_owned`]. Then, convert it to a [`DataPayload`] with
+/// [`DataPayload::from_yoked_stable_buffer`].
+#[derive(Clone, Debug)]
+pub struct StableBytesCart(#[allow(dead_code)] PayloadCart);
+
+// Safety: Rc, Arc, and () are CloneableCart, and our impl delegates.
+unsafe impl yoke::CloneableCart for StableBytesCart {}
+
+#[cfg(feature = "alloc")]
+impl Deref for StableBytesCart {
+    type Target = [u8];
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+// Safety: both Rc and Arc are StableDeref, and the bytes behind the inner Box or StableDeref
+// pointer do not move.
+#[cfg(feature = "alloc")]
+unsafe impl stable_deref_trait::StableDeref for StableBytesCart {}
+
+#[cfg(feature = "alloc")]
+impl StableBytesCart {
+    /// Creates a `Yoke<Y, Option<StableBytesCart>>` from bytes owned by a stable pointer by
+    /// applying `f`.
+    ///
+    /// The pointer is kept alive for as long as any data borrows from it. For a [`Box`], use
+    /// [`StableBytesCart::try_make_yoke_owned`], which does not allocate it again.
+    pub fn try_make_yoke<Y, F, E, B>(bytes: B, f: F) -> Result<Yoke<Y, Option<Self>>, E>
     where
         for<'a> Y: Yokeable<'a>,
+        F: FnOnce(&[u8]) -> Result<<Y as Yokeable>::Output, E>,
+        B: stable_deref_trait::StableDeref<Target = [u8]> + Send + Sync + 'static,
     {
-        // Safety: `Cart` has one field and we are removing it from the newtype,
-        // and we are preserving it in the new cart, unwrapping it from the newtype.
-        unsafe { yoke.replace_cart(|option_cart| option_cart.map(|cart| cart.0)) }
+        Self::try_attach(PayloadBuffer::Stable(Box::new(bytes)), f)
+    }
+
+    /// Creates a `Yoke<Y, Option<StableBytesCart>>` from owned bytes by applying `f`.
+    pub fn try_make_yoke_owned<Y, F, E>(bytes: Box<[u8]>, f: F) -> Result<Yoke<Y, Option<Self>>, E>
+    where
+        for<'a> Y: Yokeable<'a>,
+        F: FnOnce(&[u8]) -> Result<<Y as Yokeable>::Output, E>,
+    {
+        Self::try_attach(PayloadBuffer::Owned(bytes), f)
+    }
+
+    fn try_attach<Y, F, E>(buffer: PayloadBuffer, f: F) -> Result<Yoke<Y, Option<Self>>, E>
+    where
+        for<'a> Y: Yokeable<'a>,
+        F: FnOnce(&[u8]) -> Result<<Y as Yokeable>::Output, E>,
+    {
+        Yoke::try_attach_to_cart(SelectedRc::new(buffer), |b| f(b))
+            // Safety: The cart is only wrapped, no data is leaked
+            .map(|yoke| unsafe { yoke.replace_cart(StableBytesCart) })
+            .map(Yoke::wrap_cart_in_option)
     }
 }
 
@@ -898,15 +993,31 @@ impl DataPayload<BufferMarker> {
     /// Converts an owned byte buffer into a `DataPayload<BufferMarker>`.
     #[cfg(feature = "alloc")]
     pub fn from_owned_buffer(buffer: Box<[u8]>) -> Self {
-        let yoke = Yoke::attach_to_cart(SelectedRc::new(buffer), |b| &**b)
+        let yoke = Yoke::attach_to_cart(SelectedRc::new(PayloadBuffer::Owned(buffer)), |b| &**b)
             .wrap_cart_in_option()
             .convert_cart_into_option_pointer();
         Self(DataPayloadInner::Yoke(yoke))
     }
 
     /// Converts a yoked byte buffer into a `DataPayload<BufferMarker>`.
+    ///
+    /// This allocates a small shared pointer to the [`Cart`]. To avoid it, create the [`Yoke`]
+    /// with a [`StableBytesCart`] and use [`DataPayload::from_yoked_stable_buffer`].
     pub fn from_yoked_buffer(yoke: Yoke<&'static [u8], Option<Cart>>) -> Self {
-        let yoke = Cart::unwrap_cart(yoke);
+        // Safety: the `Cart` is moved into the new cart without being dropped or moving its
+        // bytes, so the data stays valid.
+        let yoke =
+            unsafe { yoke.replace_cart(|option_cart| option_cart.map(Cart::into_payload_cart)) };
+        Self(DataPayloadInner::Yoke(
+            yoke.convert_cart_into_option_pointer(),
+        ))
+    }
+
+    /// Converts a byte buffer yoked to a [`StableBytesCart`] into a `DataPayload<BufferMarker>`.
+    pub fn from_yoked_stable_buffer(yoke: Yoke<&'static [u8], Option<StableBytesCart>>) -> Self {
+        // Safety: `StableBytesCart` has one field and we are removing it from the newtype,
+        // and we are preserving it in the new cart, unwrapping it from the newtype.
+        let yoke = unsafe { yoke.replace_cart(|option_cart| option_cart.map(|cart| cart.0)) };
         Self(DataPayloadInner::Yoke(
             yoke.convert_cart_into_option_pointer(),
         ))
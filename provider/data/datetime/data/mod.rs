@@ -24,6 +24,7 @@ include!("datetime_names_month_chinese_v1.rs.data");
 include!("timezone_names_locations_root_v1.rs.data");
 include!("timezone_names_specific_short_v1.rs.data");
 include!("datetime_patterns_glue_v1.rs.data");
+include!("datetime_patterns_interval_v1.rs.data");
 include!("datetime_names_month_japanext_v1.rs.data");
 include!("timezone_names_cities_root_v1.rs.data");
 include!("datetime_names_year_indian_v1.rs.data");
@@ -113,6 +114,7 @@ macro_rules! impl_data_provider {
         impl_timezone_names_locations_root_v1!($provider);
         impl_timezone_names_specific_short_v1!($provider);
         impl_datetime_patterns_glue_v1!($provider);
+        impl_datetime_patterns_interval_v1!($provider);
         impl_datetime_names_month_japanext_v1!($provider);
         impl_timezone_names_cities_root_v1!($provider);
         impl_datetime_names_year_indian_v1!($provider);
@@ -8,6 +8,7 @@ include!("locale_exemplar_characters_index_v1.rs.data");
 include!("locale_exemplar_characters_auxiliary_v1.rs.data");
 include!("locale_likely_subtags_extended_v1.rs.data");
 include!("locale_script_direction_v1.rs.data");
+include!("locale_language_matching_v1.rs.data");
 include!("locale_likely_subtags_script_region_v1.rs.data");
 include!("locale_exemplar_characters_punctuation_v1.rs.data");
 /// Marks a type as a data provider. You can then use macros like
@@ -54,6 +55,7 @@ macro_rules! impl_data_provider {
         impl_locale_exemplar_characters_auxiliary_v1!($provider);
         impl_locale_likely_subtags_extended_v1!($provider);
         impl_locale_script_direction_v1!($provider);
+        impl_locale_language_matching_v1!($provider);
         impl_locale_likely_subtags_script_region_v1!($provider);
         impl_locale_exemplar_characters_punctuation_v1!($provider);
     };
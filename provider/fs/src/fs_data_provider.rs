@@ -6,10 +6,12 @@ use crate::datapath::marker_to_path;
 use crate::manifest::Manifest;
 use icu_provider::prelude::*;
 use icu_provider::DynamicDryDataProvider;
+use icu_provider::IterableDynamicDataProvider;
+use std::collections::BTreeSet;
 use std::fmt::Debug;
 use std::fmt::Write;
 use std::fs;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 /// A data provider that reads ICU4X data from a filesystem directory.
 ///
@@ -107,6 +109,14 @@ impl FsDataProvider {
         metadata.checksum = checksum;
         Ok((metadata, path))
     }
+
+    /// Returns the locale of a data file, or `None` if the path is not a data file.
+    fn locale_from_path(&self, path: &Path) -> Option<DataLocale> {
+        if path.extension()? != self.manifest.file_extension {
+            return None;
+        }
+        path.file_stem()?.to_str()?.parse().ok()
+    }
 }
 
 impl DynamicDataProvider<BufferMarker> for FsDataProvider {
@@ -133,3 +143,46 @@ impl DynamicDryDataProvider<BufferMarker> for FsDataProvider {
         Ok(self.dry_load_internal(marker, req)?.0)
     }
 }
+
+impl IterableDynamicDataProvider<BufferMarker> for FsDataProvider {
+    fn iter_ids_for_marker(
+        &self,
+        marker: DataMarkerInfo,
+    ) -> Result<BTreeSet<DataIdentifierCow<'_>>, DataError> {
+        let path = marker_to_path(marker.id, &self.root);
+        if marker.is_singleton {
+            return if path.with_extension(self.manifest.file_extension).exists() {
+                Ok([Default::default()].into_iter().collect())
+            } else {
+                Err(DataErrorKind::MarkerNotFound.with_marker(marker))
+            };
+        }
+        if !path.is_dir() {
+            return Err(DataErrorKind::MarkerNotFound.with_marker(marker));
+        }
+        let mut ids = BTreeSet::new();
+        let entries =
+            fs::read_dir(&path).map_err(|e| DataError::from(e).with_path_context(&path))?;
+        for entry in entries {
+            let entry = entry?;
+            if entry.file_type()?.is_dir() {
+                // Directories contain the data for marker attributes
+                let name = entry.file_name();
+                let Some(attributes) = name
+                    .to_str()
+                    .and_then(|a| DataMarkerAttributes::try_from_str(a).ok())
+                else {
+                    continue;
+                };
+                for entry in fs::read_dir(entry.path())? {
+                    if let Some(locale) = self.locale_from_path(&entry?.path()) {
+                        ids.insert(DataIdentifierCow::from_owned(attributes.to_owned(), locale));
+                    }
+                }
+            } else if let Some(locale) = self.locale_from_path(&entry.path()) {
+                ids.insert(DataIdentifierCow::from_locale(locale));
+            }
+        }
+        Ok(ids)
+    }
+}
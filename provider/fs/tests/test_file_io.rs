@@ -5,6 +5,7 @@
 use icu_locale_core::langid;
 use icu_provider::hello_world::{HelloWorld, HelloWorldProvider, HelloWorldV1};
 use icu_provider::prelude::*;
+use icu_provider::IterableDynamicDataProvider;
 use icu_provider_fs::FsDataProvider;
 
 const PATHS: &[&str] = &[
@@ -44,6 +45,18 @@ fn test_provider() {
     }
 }
 
+#[test]
+fn test_iter() {
+    for path in PATHS {
+        let provider = FsDataProvider::try_new(path.into()).unwrap();
+        assert_eq!(
+            provider.iter_ids_for_marker(HelloWorldV1::INFO).unwrap(),
+            HelloWorldProvider.iter_ids().unwrap(),
+            "{path}"
+        );
+    }
+}
+
 #[test]
 fn test_errors() {
     for path in PATHS {
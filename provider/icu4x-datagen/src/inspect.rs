@@ -0,0 +1,364 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Subcommands that inspect existing blob and fs exports.
+
+use crate::{all_markers, lookup_marker, IterableBufferProvider, ReexportableBufferProvider};
+use clap::Subcommand;
+use eyre::WrapErr;
+use icu_provider::hello_world::HelloWorldV1;
+use icu_provider::prelude::*;
+use icu_provider_blob::BlobDataProvider;
+use icu_provider_fs::export::serializers::{AbstractSerializer, Json};
+use icu_provider_fs::FsDataProvider;
+use std::collections::BTreeSet;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+#[derive(Subcommand)]
+pub(crate) enum Command {
+    #[command(about = "List the markers in a blob file or fs directory, with their sizes.")]
+    List {
+        #[arg(value_name = "PATH")]
+        #[arg(help = "Path to a blob file or fs directory.")]
+        input: PathBuf,
+
+        #[arg(long, short, num_args = 1..)]
+        #[arg(
+            help = "Only list these data markers. Defaults to all markers known to this binary."
+        )]
+        markers: Vec<String>,
+
+        #[arg(long)]
+        #[arg(help = "Also list the identifiers (marker attributes and locale) of each marker.")]
+        ids: bool,
+    },
+    #[command(about = "Print a data payload from a blob file or fs directory as JSON.")]
+    Dump {
+        #[arg(value_name = "PATH")]
+        #[arg(help = "Path to a blob file or fs directory.")]
+        input: PathBuf,
+
+        #[arg(long, short)]
+        #[arg(help = "The data marker of the payload.")]
+        marker: String,
+
+        #[arg(long, short, default_value = "und")]
+        #[arg(help = "The locale of the payload.")]
+        locale: String,
+
+        #[arg(long, short, default_value = "")]
+        #[arg(help = "The marker attributes of the payload.")]
+        attributes: String,
+    },
+    #[command(
+        about = "Compare two blob files or fs directories, listing added, removed, and changed payloads with their sizes."
+    )]
+    Diff {
+        #[arg(value_name = "OLD")]
+        #[arg(help = "Path to the old blob file or fs directory.")]
+        old: PathBuf,
+
+        #[arg(value_name = "NEW")]
+        #[arg(help = "Path to the new blob file or fs directory.")]
+        new: PathBuf,
+
+        #[arg(long, short, num_args = 1..)]
+        #[arg(
+            help = "Only compare these data markers. Defaults to all markers known to this binary."
+        )]
+        markers: Vec<String>,
+    },
+}
+
+impl Command {
+    pub(crate) fn run(self) -> eyre::Result<()> {
+        match self {
+            Command::List {
+                input,
+                markers,
+                ids,
+            } => list(
+                &open(&input)?,
+                &lookup_markers(&markers)?,
+                ids,
+                &mut std::io::stdout(),
+            ),
+            Command::Dump {
+                input,
+                marker,
+                locale,
+                attributes,
+            } => {
+                let marker = lookup_marker(&marker)?;
+                let locale: DataLocale = locale.parse().wrap_err(locale)?;
+                let attributes = DataMarkerAttributes::try_from_str(&attributes)
+                    .map_err(|_| eyre::eyre!("Invalid marker attributes {attributes:?}"))?;
+                let response = open(&input)?.load_data(
+                    marker,
+                    DataRequest {
+                        id: DataIdentifierBorrowed::for_marker_attributes_and_locale(
+                            attributes, &locale,
+                        ),
+                        ..Default::default()
+                    },
+                )?;
+                Json::pretty().serialize(&response.payload, &mut std::io::stdout())?;
+                Ok(())
+            }
+            Command::Diff { old, new, markers } => diff(
+                &open(&old)?,
+                &open(&new)?,
+                &lookup_markers(&markers)?,
+                &mut std::io::stdout(),
+            ),
+        }
+    }
+}
+
+/// Opens a blob file, or an fs directory if `path` is a directory.
+fn open(path: &Path) -> eyre::Result<ReexportableBufferProvider> {
+    let provider: Box<dyn IterableBufferProvider> = if path.is_dir() {
+        Box::new(FsDataProvider::try_new(path.into())?)
+    } else {
+        Box::new(BlobDataProvider::try_new_from_blob(
+            std::fs::read(path)
+                .with_context(|| path.to_string_lossy().to_string())?
+                .into(),
+        )?)
+    };
+    Ok(ReexportableBufferProvider(provider))
+}
+
+fn lookup_markers(names: &[String]) -> eyre::Result<Vec<DataMarkerInfo>> {
+    if names.is_empty() {
+        Ok(core::iter::once(HelloWorldV1::INFO)
+            .chain(all_markers())
+            .collect())
+    } else {
+        names.iter().map(|n| lookup_marker(n)).collect()
+    }
+}
+
+/// Returns the identifiers of `marker`, or `None` if the marker is not present.
+fn ids(
+    provider: &ReexportableBufferProvider,
+    marker: DataMarkerInfo,
+) -> eyre::Result<Option<BTreeSet<DataIdentifierCow<'_>>>> {
+    match provider.0.iter_ids_for_marker(marker) {
+        Ok(ids) => Ok(Some(ids)),
+        Err(DataError {
+            kind: DataErrorKind::MarkerNotFound,
+            ..
+        }) => Ok(None),
+        Err(e) => Err(e.into()),
+    }
+}
+
+fn load_buffer(
+    provider: &ReexportableBufferProvider,
+    marker: DataMarkerInfo,
+    id: &DataIdentifierCow,
+) -> Result<DataPayload<BufferMarker>, DataError> {
+    Ok(provider
+        .0
+        .load_data(
+            marker,
+            DataRequest {
+                id: id.as_borrowed(),
+                ..Default::default()
+            },
+        )?
+        .payload)
+}
+
+fn size(
+    provider: &ReexportableBufferProvider,
+    marker: DataMarkerInfo,
+    id: &DataIdentifierCow,
+) -> Result<usize, DataError> {
+    Ok(load_buffer(provider, marker, id)?.get().len())
+}
+
+fn display_id(id: &DataIdentifierCow) -> String {
+    if id.marker_attributes.is_empty() {
+        id.locale.to_string()
+    } else {
+        format!("{}/{}", id.marker_attributes.as_str(), id.locale)
+    }
+}
+
+fn list(
+    provider: &ReexportableBufferProvider,
+    markers: &[DataMarkerInfo],
+    list_ids: bool,
+    out: &mut impl Write,
+) -> eyre::Result<()> {
+    for &marker in markers {
+        let Some(ids) = ids(provider, marker)? else {
+            continue;
+        };
+        let sizes = ids
+            .iter()
+            .map(|id| Ok((id, size(provider, marker, id)?)))
+            .collect::<Result<Vec<_>, DataError>>()?;
+        writeln!(
+            out,
+            "{}: {} identifiers, {} bytes",
+            marker.id.name(),
+            ids.len(),
+            sizes.iter().map(|(_, size)| size).sum::<usize>()
+        )?;
+        if list_ids {
+            for (id, size) in sizes {
+                writeln!(out, "    {}: {size} bytes", display_id(id))?;
+            }
+        }
+    }
+    Ok(())
+}
+
+fn diff(
+    old: &ReexportableBufferProvider,
+    new: &ReexportableBufferProvider,
+    markers: &[DataMarkerInfo],
+    out: &mut impl Write,
+) -> eyre::Result<()> {
+    for &marker in markers {
+        match (ids(old, marker)?, ids(new, marker)?) {
+            (None, None) => {}
+            (None, Some(ids)) => {
+                let size = ids
+                    .iter()
+                    .map(|id| size(new, marker, id))
+                    .sum::<Result<usize, _>>()?;
+                writeln!(out, "+ {}: {size} bytes", marker.id.name())?;
+            }
+            (Some(ids), None) => {
+                let size = ids
+                    .iter()
+                    .map(|id| size(old, marker, id))
+                    .sum::<Result<usize, _>>()?;
+                writeln!(out, "- {}: {size} bytes", marker.id.name())?;
+            }
+            (Some(old_ids), Some(new_ids)) => {
+                let mut changes = Vec::new();
+                let (mut old_total, mut new_total) = (0, 0);
+                for id in old_ids.union(&new_ids) {
+                    let old_buffer = old_ids
+                        .contains(id)
+                        .then(|| load_buffer(old, marker, id))
+                        .transpose()?;
+                    let new_buffer = new_ids
+                        .contains(id)
+                        .then(|| load_buffer(new, marker, id))
+                        .transpose()?;
+                    match (old_buffer, new_buffer) {
+                        (None, None) => unreachable!("id is in one of the sets"),
+                        (None, Some(new_buffer)) => {
+                            new_total += new_buffer.get().len();
+                            changes.push(format!(
+                                "    + {}: {} bytes",
+                                display_id(id),
+                                new_buffer.get().len()
+                            ));
+                        }
+                        (Some(old_buffer), None) => {
+                            old_total += old_buffer.get().len();
+                            changes.push(format!(
+                                "    - {}: {} bytes",
+                                display_id(id),
+                                old_buffer.get().len()
+                            ));
+                        }
+                        (Some(old_buffer), Some(new_buffer)) => {
+                            let (old_size, new_size) =
+                                (old_buffer.get().len(), new_buffer.get().len());
+                            old_total += old_size;
+                            new_total += new_size;
+                            // Payloads in different buffer formats can be equal even if their
+                            // bytes differ, so fall back to comparing the deserialized data.
+                            if old_buffer.get() == new_buffer.get() {
+                                continue;
+                            }
+                            let req = DataRequest {
+                                id: id.as_borrowed(),
+                                ..Default::default()
+                            };
+                            if old.load_data(marker, req)?.payload
+                                != new.load_data(marker, req)?.payload
+                            {
+                                changes.push(format!(
+                                    "    ~ {}: {old_size} -> {new_size} bytes ({})",
+                                    display_id(id),
+                                    delta(old_size, new_size)
+                                ));
+                            }
+                        }
+                    }
+                }
+                if !changes.is_empty() {
+                    writeln!(
+                        out,
+                        "~ {}: {old_total} -> {new_total} bytes ({})",
+                        marker.id.name(),
+                        delta(old_total, new_total)
+                    )?;
+                    for change in changes {
+                        writeln!(out, "{change}")?;
+                    }
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+fn delta(old: usize, new: usize) -> String {
+    if new >= old {
+        format!("+{}", new - old)
+    } else {
+        format!("-{}", old - new)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn open_test_data(path: &str) -> ReexportableBufferProvider {
+        open(&Path::new(env!("CARGO_MANIFEST_DIR")).join(path)).unwrap()
+    }
+
+    #[test]
+    fn test_list() {
+        let blob = open_test_data("../blob/tests/data/v3.postcard");
+        let mut out = Vec::new();
+        list(&blob, &[HelloWorldV1::INFO], false, &mut out).unwrap();
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "HelloWorldV1: 27 identifiers, 479 bytes\n"
+        );
+
+        let fs = open_test_data("../fs/tests/data/json");
+        let mut out = Vec::new();
+        list(&fs, &[HelloWorldV1::INFO], true, &mut out).unwrap();
+        let out = String::from_utf8(out).unwrap();
+        assert!(out.starts_with("HelloWorldV1: 27 identifiers, 857 bytes\n"));
+        assert!(out.contains("\n    de-AT: 26 bytes\n"));
+        assert!(out.contains("\n    reverse/ja: 36 bytes\n"));
+    }
+
+    #[test]
+    fn test_diff_fs_blob() {
+        let fs = open_test_data("../fs/tests/data/json");
+        let blob = open_test_data("../blob/tests/data/v3.postcard");
+
+        // The same data in JSON and postcard is not reported as changed.
+        let mut out = Vec::new();
+        diff(&fs, &blob, &[HelloWorldV1::INFO], &mut out).unwrap();
+        diff(&blob, &fs, &[HelloWorldV1::INFO], &mut out).unwrap();
+        assert_eq!(String::from_utf8(out).unwrap(), "");
+    }
+}
@@ -9,6 +9,16 @@
 //! $ icu4x-datagen --markers all --locales de en-AU --format blob --out data.postcard
 //! ```
 //!
+//! Existing blob files and fs directories can be inspected and compared with the subcommands
+//! behind the `inspect` Cargo feature:
+//!
+//! ```bash
+//! $ cargo install icu4x-datagen --features inspect
+//! $ icu4x-datagen list data.postcard --ids
+//! $ icu4x-datagen dump data.postcard --marker DecimalSymbolsV1 --locale de
+//! $ icu4x-datagen diff old.postcard data.postcard
+//! ```
+//!
 //! More details can be found by running `--help`, or by consulting the [`icu_provider_export`] documentation.
 
 // If no exporter feature is enabled this all doesn't make sense
@@ -39,8 +49,12 @@ use simple_logger::SimpleLogger;
 use std::collections::HashMap;
 use std::path::PathBuf;
 
+#[cfg(feature = "inspect")]
+mod inspect;
+
 #[derive(Parser)]
 #[command(name = "icu4x-datagen")]
+#[command(subcommand_negates_reqs = true)]
 #[command(author = "The ICU4X Project Developers", version = option_env!("CARGO_PKG_VERSION"))]
 #[command(about = format!("Learn more at: https://docs.rs/icu_provider_export/{}", option_env!("CARGO_PKG_VERSION").unwrap_or("")), long_about = None)]
 struct Cli {
@@ -48,11 +62,11 @@ struct Cli {
     #[arg(help = "Requests verbose output")]
     verbose: bool,
 
-    #[arg(long, value_enum)]
+    #[arg(long, value_enum, required = true)]
     #[arg(
         help = "Select the output format: a directory tree of files (fs), a single blob (blob), or a Rust module (baked)."
     )]
-    format: Format,
+    format: Option<Format>,
 
     #[arg(short = 'W', long)]
     #[arg(help = "Delete the output before writing data.")]
@@ -138,6 +152,30 @@ struct Cli {
     #[cfg(feature = "provider")]
     tzdb_root: Option<PathBuf>,
 
+    #[arg(long, value_name = "TAG", default_value = "latest")]
+    #[arg(
+        help = "Download Unicode security data from this version (https://www.unicode.org/Public/security/)\n\
+                  Use 'latest' for the latest version verified to work with this version of the binary.\n\
+                  Ignored if '--uts39-root' is present. Requires binary to be built with `networking` Cargo feature (enabled by default)."
+    )]
+    #[cfg_attr(not(feature = "networking"), arg(hide = true))]
+    #[cfg(feature = "provider")]
+    uts39_tag: String,
+
+    #[arg(long, value_name = "PATH")]
+    #[arg(help = "Path to a local Unicode security data directory or ZIP file \
+                (see https://www.unicode.org/Public/security/).")]
+    #[cfg(feature = "provider")]
+    uts39_root: Option<PathBuf>,
+
+    #[arg(long, value_name = "PATH")]
+    #[arg(
+        help = "Path to a local directory or ZIP file with the hyph-utf8 hyphenation patterns \
+                (see https://ctan.org/pkg/hyph-utf8), containing the `txt` and `tex` directories."
+    )]
+    #[cfg(feature = "provider")]
+    hyph_utf8_root: Option<PathBuf>,
+
     #[arg(long, value_enum, default_value_t = TrieType::Small)]
     #[arg(
         help = "Whether to optimize CodePointTrie data structures for size (\"small\") or speed (\"fast\").\n\
@@ -216,6 +254,10 @@ struct Cli {
     #[arg(help = "Use data from this blob file instead of generating it from sources")]
     #[cfg(feature = "blob_input")]
     input_blob: Option<PathBuf>,
+
+    #[command(subcommand)]
+    #[cfg(feature = "inspect")]
+    command: Option<inspect::Command>,
 }
 
 #[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, ValueEnum, Debug)]
@@ -289,6 +331,15 @@ fn main() -> eyre::Result<()> {
             .unwrap()
     }
 
+    #[cfg(feature = "inspect")]
+    if let Some(command) = cli.command {
+        return command.run();
+    }
+
+    let Some(format) = cli.format else {
+        eyre::bail!("--format is required")
+    };
+
     let markers = if !cli.markers.is_empty() {
         match cli.markers.as_slice() {
             [x] if x == "none" => Default::default(),
@@ -301,13 +352,7 @@ fn main() -> eyre::Result<()> {
             }
             markers => markers
                 .iter()
-                .map(|k| match marker_lookup().get(k.as_str()) {
-                    Some(Some(marker)) => Ok(*marker),
-                    Some(None) => {
-                        eyre::bail!("Marker {k:?} requires `experimental` Cargo feature")
-                    }
-                    None => eyre::bail!("Unknown marker {k:?}"),
-                })
+                .map(|k| lookup_marker(k))
                 .collect::<Result<_, _>>()?,
         }
     } else if let Some(bin_path) = &cli.markers_for_bin {
@@ -351,6 +396,14 @@ fn main() -> eyre::Result<()> {
             eyre::bail!(
                 "Timezone data is required for this invocation, set --tzdb-path or --tzdb-tag"
             );
+        } else if SourceDataProvider::is_missing_uts39_error(e) {
+            eyre::bail!(
+                "Unicode security data is required for this invocation, set --uts39-root or --uts39-tag"
+            );
+        } else if SourceDataProvider::is_missing_hyph_utf8_error(e) {
+            eyre::bail!(
+                "Hyphenation pattern data is required for this invocation, set --hyph-utf8-root"
+            );
         }
 
         Err(e.into())
@@ -371,7 +424,7 @@ fn main() -> eyre::Result<()> {
                 std::fs::read(cli.input_blob.unwrap())?.into(),
             )?;
             let fallbacker = LocaleFallbacker::try_new_with_buffer_provider(&provider)?;
-            (Box::new(ReexportableBlobDataProvider(provider)), fallbacker)
+            (Box::new(ReexportableBufferProvider(Box::new(provider))), fallbacker)
         },
 
         #[cfg(all(not(feature = "provider"), feature = "blob_input"))]
@@ -438,6 +491,22 @@ fn main() -> eyre::Result<()> {
                 (None, _) => p,
             };
 
+            p = match (cli.uts39_root, cli.uts39_tag.as_str()) {
+                (Some(path), _) => p.with_uts39(&path)?,
+                #[cfg(feature = "networking")]
+                (_, "latest") => {
+                    p.with_uts39_for_tag(SourceDataProvider::TESTED_UTS39_TAG)
+                }
+                #[cfg(feature = "networking")]
+                (_, tag) => p.with_uts39_for_tag(tag),
+                #[cfg(not(feature = "networking"))]
+                (None, _) => p,
+            };
+
+            if let Some(path) = cli.hyph_utf8_root {
+                p = p.with_hyph_utf8(&path)?;
+            }
+
             if cli.locales.as_slice() == ["recommended"] {
                 preprocessed_locales = Some(PreprocessedLocales::Locales(
                     p.locales_for_coverage_levels([
@@ -493,7 +562,7 @@ fn main() -> eyre::Result<()> {
             DeduplicationStrategy::RetainBaseLanguages
         }
         Some(Deduplication::None) => DeduplicationStrategy::None,
-        None => match cli.format {
+        None => match format {
             Format::Fs | Format::Blob => DeduplicationStrategy::None,
             Format::Baked if cli.no_internal_fallback && cli.deduplication.is_none() =>
                 eyre::bail!("--no-internal-fallback requires an explicit --deduplication value. Baked exporter would default to maximal deduplication, which might not be intended"),
@@ -528,7 +597,7 @@ fn main() -> eyre::Result<()> {
         driver.with_segmenter_models(cli.segmenter_models.clone())
     };
 
-    let metadata: Result<ExportMetadata, DataError> = match cli.format {
+    let metadata: Result<ExportMetadata, DataError> = match format {
         #[cfg(not(feature = "fs_exporter"))]
         Format::Fs => {
             eyre::bail!("Exporting to an FsProvider requires the `fs_exporter` Cargo feature")
@@ -600,6 +669,14 @@ fn main() -> eyre::Result<()> {
     Ok(())
 }
 
+fn lookup_marker(name: &str) -> eyre::Result<DataMarkerInfo> {
+    match marker_lookup().get(name) {
+        Some(Some(marker)) => Ok(*marker),
+        Some(None) => eyre::bail!("Marker {name:?} requires `experimental` Cargo feature"),
+        None => eyre::bail!("Unknown marker {name:?}"),
+    }
+}
+
 macro_rules! cb {
     ($($marker_ty:ty:$marker:ident,)+ #[experimental] $($emarker_ty:ty:$emarker:ident,)+) => {
         fn all_markers() -> Vec<DataMarkerInfo> {
@@ -652,7 +729,7 @@ macro_rules! cb {
 
         #[cfg(feature = "blob_input")]
         icu_provider::export::make_exportable_provider!(
-            ReexportableBlobDataProvider,
+            ReexportableBufferProvider,
             [
                 icu_provider::hello_world::HelloWorldV1,
                 $(
@@ -675,27 +752,39 @@ use icu_provider::buf::DeserializingBufferProvider;
 #[cfg(feature = "blob_input")]
 use icu_provider::prelude::*;
 #[cfg(feature = "blob_input")]
-use icu_provider_blob::BlobDataProvider;
+use icu_provider::IterableDynamicDataProvider;
+
+#[cfg(feature = "blob_input")]
+trait IterableBufferProvider:
+    BufferProvider + IterableDynamicDataProvider<BufferMarker> + Sync
+{
+}
+
+#[cfg(feature = "blob_input")]
+impl<P> IterableBufferProvider for P where
+    P: BufferProvider + IterableDynamicDataProvider<BufferMarker> + Sync
+{
+}
 
+/// Deserializes the data of a [`BufferProvider`], such as a `BlobDataProvider`,
+/// so that it can be exported again.
 #[cfg(feature = "blob_input")]
-struct ReexportableBlobDataProvider(icu_provider_blob::BlobDataProvider);
+struct ReexportableBufferProvider(Box<dyn IterableBufferProvider>);
 
 #[cfg(feature = "blob_input")]
-impl<M: DataMarker> DataProvider<M> for ReexportableBlobDataProvider
+impl<M: DataMarker> DataProvider<M> for ReexportableBufferProvider
 where
-    BlobDataProvider: AsDeserializingBufferProvider,
-    for<'a> DeserializingBufferProvider<'a, BlobDataProvider>: DataProvider<M>,
+    for<'a> DeserializingBufferProvider<'a, dyn IterableBufferProvider>: DataProvider<M>,
 {
     fn load(&self, req: DataRequest) -> Result<DataResponse<M>, DataError> {
-        self.0.as_deserializing().load(req)
+        (*self.0).as_deserializing().load(req)
     }
 }
 
 #[cfg(feature = "blob_input")]
-impl<M: DataMarker> IterableDataProvider<M> for ReexportableBlobDataProvider
+impl<M: DataMarker> IterableDataProvider<M> for ReexportableBufferProvider
 where
-    BlobDataProvider: AsDeserializingBufferProvider,
-    for<'a> DeserializingBufferProvider<'a, BlobDataProvider>: DataProvider<M>,
+    for<'a> DeserializingBufferProvider<'a, dyn IterableBufferProvider>: DataProvider<M>,
 {
     fn iter_ids(&self) -> Result<std::collections::BTreeSet<DataIdentifierCow<'_>>, DataError> {
         self.0.iter_ids_for_marker(M::INFO)
@@ -55,6 +55,7 @@ macro_rules! registry(
             icu::locale::provider::LocaleExemplarCharactersMainV1: LocaleExemplarCharactersMainV1,
             icu::locale::provider::LocaleExemplarCharactersNumbersV1: LocaleExemplarCharactersNumbersV1,
             icu::locale::provider::LocaleExemplarCharactersPunctuationV1: LocaleExemplarCharactersPunctuationV1,
+            icu::locale::provider::LocaleLanguageMatchingV1: LocaleLanguageMatchingV1,
             icu::locale::provider::LocaleLikelySubtagsExtendedV1: LocaleLikelySubtagsExtendedV1,
             icu::locale::provider::LocaleLikelySubtagsLanguageV1: LocaleLikelySubtagsLanguageV1,
             icu::locale::provider::LocaleLikelySubtagsScriptRegionV1: LocaleLikelySubtagsScriptRegionV1,
@@ -200,12 +201,15 @@ macro_rules! registry(
             icu::segmenter::provider::SegmenterBreakSentenceV1: SegmenterBreakSentenceV1,
             icu::segmenter::provider::SegmenterBreakWordOverrideV1: SegmenterBreakWordOverrideV1,
             icu::segmenter::provider::SegmenterBreakWordV1: SegmenterBreakWordV1,
+            icu::segmenter::provider::SegmenterComplexScriptsV1: SegmenterComplexScriptsV1,
+            icu::segmenter::provider::SegmenterHyphenationPatternsV1: SegmenterHyphenationPatternsV1,
             icu::time::provider::iana::TimezoneIdentifiersIanaExtendedV1: TimezoneIdentifiersIanaExtendedV1,
             icu::time::provider::iana::TimezoneIdentifiersIanaCoreV1: TimezoneIdentifiersIanaCoreV1,
             icu::time::provider::windows::TimezoneIdentifiersWindowsV1: TimezoneIdentifiersWindowsV1,
             icu::datetime::provider::neo::DatetimeNamesWeekdayV1: DatetimeNamesWeekdayV1,
             icu::datetime::provider::neo::DatetimeNamesDayperiodV1: DatetimeNamesDayperiodV1,
             icu::datetime::provider::neo::DatetimePatternsGlueV1: DatetimePatternsGlueV1,
+            icu::datetime::provider::neo::DatetimePatternsIntervalV1: DatetimePatternsIntervalV1,
             icu::datetime::provider::neo::DatetimeNamesYearBuddhistV1: DatetimeNamesYearBuddhistV1,
             icu::datetime::provider::neo::DatetimeNamesYearChineseV1: DatetimeNamesYearChineseV1,
             icu::datetime::provider::neo::DatetimeNamesYearCopticV1: DatetimeNamesYearCopticV1,
@@ -255,6 +259,7 @@ macro_rules! registry(
             icu::experimental::dimension::provider::currency::patterns::CurrencyPatternsDataV1: CurrencyPatternsDataV1,
             icu::experimental::dimension::provider::currency::extended::CurrencyExtendedDataV1: CurrencyExtendedDataV1,
             icu::experimental::dimension::provider::percent::PercentEssentialsV1: PercentEssentialsV1,
+            icu::experimental::dimension::provider::range::NumberRangePatternsV1: NumberRangePatternsV1,
             icu::experimental::dimension::provider::units::display_name::UnitsDisplayNameV1: UnitsDisplayNameV1,
             icu::experimental::dimension::provider::units::essentials::UnitsEssentialsV1: UnitsEssentialsV1,
             icu::experimental::duration::provider::DigitalDurationDataV1: DigitalDurationDataV1,
@@ -288,7 +293,9 @@ macro_rules! registry(
             icu::experimental::relativetime::provider::LongYearRelativeV1: LongYearRelativeV1,
             icu::experimental::relativetime::provider::ShortYearRelativeV1: ShortYearRelativeV1,
             icu::experimental::relativetime::provider::NarrowYearRelativeV1: NarrowYearRelativeV1,
+            icu::experimental::scientificdecimal::provider::ScientificDecimalSymbolsV1: ScientificDecimalSymbolsV1,
             icu::experimental::personnames::provider::PersonNamesFormatV1: PersonNamesFormatV1,
+            icu::experimental::spoof::provider::ConfusablesV1: ConfusablesV1,
             icu::experimental::transliterate::provider::TransliteratorRulesV1: TransliteratorRulesV1,
             icu::experimental::units::provider::UnitsInfoV1: UnitsInfoV1,
             icu::plurals::provider::PluralsRangesV1: PluralsRangesV1,
@@ -190,6 +190,8 @@ pub(crate) struct DateTimeFormats {
     pub(crate) short: LengthPattern,
     #[serde(rename = "availableFormats")]
     pub(crate) available_formats: AvailableFormats,
+    #[serde(rename = "intervalFormats")]
+    pub(crate) interval_formats: Option<IntervalFormats>,
 }
 
 impl DateTimeFormats {
@@ -205,6 +207,15 @@ impl DateTimeFormats {
 #[derive(PartialEq, Clone, Debug, Deserialize)]
 pub(crate) struct AvailableFormats(pub(crate) HashMap<String, String>);
 
+/// The interval patterns, keyed by skeleton and then by the greatest difference.
+#[derive(PartialEq, Clone, Debug, Deserialize)]
+pub(crate) struct IntervalFormats {
+    #[serde(rename = "intervalFormatFallback")]
+    pub(crate) fallback: String,
+    #[serde(flatten)]
+    pub(crate) skeletons: HashMap<String, HashMap<String, String>>,
+}
+
 #[derive(PartialEq, Clone, Debug, Deserialize)]
 pub(crate) struct CyclicNameSets {
     pub(crate) years: Option<Contexts<BTreeMap<u8, String>>>,
@@ -0,0 +1,339 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use std::collections::{BTreeMap, HashSet};
+
+use crate::cldr_serde::ca::IntervalFormats;
+use crate::{IterableDataProviderCached, SourceDataProvider};
+use icu::datetime::fieldsets::enums::*;
+use icu::datetime::provider::fields::{Field, FieldLength, FieldSymbol, Hour};
+use icu::datetime::provider::neo::*;
+use icu::datetime::provider::pattern::{runtime, PatternItem};
+use icu_provider::prelude::*;
+use zerovec::{VarZeroVec, ZeroVec};
+
+use super::neo_skeleton::{gen_date_components, gen_time_components};
+use super::DatagenCalendar;
+
+/// The letter of a field in an interval format skeleton, and whether the field is numeric.
+///
+/// Day periods are not part of the skeleton, since they are implied by the hour cycle.
+fn skeleton_letter(field: Field) -> Option<(char, bool)> {
+    let numeric = field.length <= FieldLength::Two;
+    Some(match field.symbol {
+        FieldSymbol::Era => ('G', false),
+        FieldSymbol::Year(_) => ('y', true),
+        FieldSymbol::Month(_) => ('M', numeric),
+        FieldSymbol::Day(_) => ('d', true),
+        FieldSymbol::Weekday(_) => ('E', false),
+        FieldSymbol::DayPeriod(_) => return None,
+        FieldSymbol::Hour(Hour::H23) => ('H', true),
+        FieldSymbol::Hour(_) => ('h', true),
+        FieldSymbol::Minute => ('m', true),
+        FieldSymbol::Second(_) | FieldSymbol::DecimalSecond(_) => ('s', true),
+        FieldSymbol::TimeZone(_) => ('v', false),
+        FieldSymbol::Week(_) => ('w', true),
+    })
+}
+
+/// The letter of a field for matching the fields of an interval pattern to a pattern.
+fn field_letter(field: Field) -> char {
+    match field.symbol {
+        FieldSymbol::DayPeriod(_) => 'a',
+        _ => skeleton_letter(field)
+            .map(|(letter, _)| letter)
+            .unwrap_or('?'),
+    }
+}
+
+fn fields<'a>(items: impl IntoIterator<Item = &'a PatternItem>) -> Vec<Field> {
+    items
+        .into_iter()
+        .filter_map(|item| match item {
+            PatternItem::Field(field) => Some(*field),
+            PatternItem::Literal(_) => None,
+        })
+        .collect()
+}
+
+fn interval_field(letter: char) -> Option<IntervalField> {
+    Some(match letter {
+        'G' => IntervalField::Era,
+        'y' => IntervalField::Year,
+        'M' => IntervalField::Month,
+        'd' => IntervalField::Day,
+        'a' | 'B' => IntervalField::DayPeriod,
+        'h' | 'H' => IntervalField::Hour,
+        'm' => IntervalField::Minute,
+        _ => return None,
+    })
+}
+
+/// Computes the interval patterns for a pattern, keyed by the greatest difference.
+///
+/// This selects the interval format skeleton with the same fields as the pattern, preferring
+/// skeletons with the same field lengths, and then changes the fields of its interval patterns
+/// to the fields of the pattern, so that they use the same names. Interval patterns that
+/// cannot be adapted to the pattern are skipped.
+fn interval_patterns(
+    pattern: &runtime::Pattern,
+    interval_formats: &IntervalFormats,
+) -> Vec<(IntervalField, runtime::Pattern<'static>)> {
+    let pattern_fields = fields(&pattern.items.to_vec());
+    let mut pattern_skeleton = pattern_fields
+        .iter()
+        .filter_map(|&field| skeleton_letter(field))
+        .collect::<Vec<_>>();
+    pattern_skeleton.sort();
+
+    let Some((_, intervals)) = interval_formats
+        .skeletons
+        .iter()
+        .collect::<BTreeMap<_, _>>()
+        .into_iter()
+        .filter_map(|(skeleton, intervals)| {
+            let skeleton = skeleton.parse::<runtime::Pattern>().ok()?.items.to_vec();
+            // Skeletons with flexible day periods (`B`) are not supported
+            if skeleton
+                .iter()
+                .any(|item| matches!(item, PatternItem::Literal(_)))
+            {
+                return None;
+            }
+            let skeleton = fields(&skeleton);
+            let mut letters = skeleton
+                .iter()
+                .filter_map(|&field| skeleton_letter(field))
+                .collect::<Vec<_>>();
+            letters.sort();
+            if letters != pattern_skeleton {
+                return None;
+            }
+            let same_lengths = skeleton
+                .iter()
+                .filter(|field| pattern_fields.contains(field))
+                .count();
+            Some((same_lengths, intervals))
+        })
+        // `max_by_key` returns the last maximum, but we want the first skeleton
+        .rev()
+        .max_by_key(|&(same_lengths, _)| same_lengths)
+    else {
+        return Vec::new();
+    };
+
+    let mut result = intervals
+        .iter()
+        .filter_map(|(letter, interval_pattern)| {
+            let difference = interval_field(letter.parse().ok()?)?;
+            let items = interval_pattern
+                .parse::<runtime::Pattern>()
+                .ok()?
+                .items
+                .iter()
+                .map(|item| match item {
+                    PatternItem::Field(field) => pattern_fields
+                        .iter()
+                        .find(|&&f| field_letter(f) == field_letter(field))
+                        .map(|&f| PatternItem::Field(f)),
+                    PatternItem::Literal(c) => Some(PatternItem::Literal(c)),
+                })
+                .collect::<Option<Vec<_>>>()?;
+            let interval_fields = fields(&items);
+            // The interval pattern has to show every field of the pattern, at least one of
+            // them twice, and the field that differs.
+            let is_valid = pattern_fields.iter().all(|f| interval_fields.contains(f))
+                && interval_fields
+                    .iter()
+                    .enumerate()
+                    .any(|(i, f)| interval_fields[..i].contains(f))
+                && pattern_fields
+                    .iter()
+                    .any(|&f| interval_field(field_letter(f)) == Some(difference));
+            is_valid.then(|| (difference, runtime::Pattern::from(items)))
+        })
+        .collect::<Vec<_>>();
+    result.sort_by_key(|&(difference, _)| difference);
+    result
+}
+
+impl SourceDataProvider {
+    fn load_interval_patterns(
+        &self,
+        locale: &DataLocale,
+        attributes: &DataMarkerAttributes,
+    ) -> Result<IntervalPatterns<'static>, DataError> {
+        // Time patterns are calendar-independent and come from the generic calendar
+        let calendar = if TimeFieldSet::ALL_DATA_MARKER_ATTRIBUTES.contains(&attributes) {
+            None
+        } else {
+            Some(DatagenCalendar::Gregorian)
+        };
+        let packed = if calendar.is_none() {
+            self.make_packed_skeleton_data(locale, calendar, attributes, gen_time_components)?
+        } else {
+            self.make_packed_skeleton_data(locale, calendar, attributes, gen_date_components)?
+        };
+        let builder = packed.to_builder();
+
+        let data = self.get_datetime_resources(locale, calendar)?;
+        let interval_formats =
+            data.datetime_formats
+                .interval_formats
+                .as_ref()
+                .ok_or_else(|| {
+                    DataError::custom("Missing interval formats").with_debug_context(locale)
+                })?;
+
+        let mut patterns = BTreeMap::new();
+        let variants = [
+            Some(&builder.standard),
+            builder.variant0.as_ref(),
+            builder.variant1.as_ref(),
+        ];
+        for (variant, lengths) in variants.into_iter().enumerate() {
+            let lengths = lengths.unwrap_or(&builder.standard);
+            for (length, pattern) in [&lengths.long, &lengths.medium, &lengths.short]
+                .into_iter()
+                .enumerate()
+            {
+                for (difference, interval_pattern) in
+                    interval_patterns(pattern.other(), interval_formats)
+                {
+                    patterns.insert(
+                        IntervalPatternKey {
+                            length: length as u8,
+                            variant: variant as u8,
+                            difference,
+                        },
+                        interval_pattern,
+                    );
+                }
+            }
+        }
+
+        Ok(IntervalPatterns {
+            fallback: interval_formats
+                .fallback
+                .parse()
+                .map_err(|_| DataError::custom("Invalid interval fallback pattern"))?,
+            keys: patterns.keys().copied().collect::<ZeroVec<_>>(),
+            patterns: VarZeroVec::from(&patterns.into_values().collect::<Vec<_>>()),
+        })
+    }
+}
+
+impl DataProvider<DatetimePatternsIntervalV1> for SourceDataProvider {
+    fn load(
+        &self,
+        req: DataRequest,
+    ) -> Result<DataResponse<DatetimePatternsIntervalV1>, DataError> {
+        self.check_req::<DatetimePatternsIntervalV1>(req)?;
+        Ok(DataResponse {
+            metadata: Default::default(),
+            payload: DataPayload::from_owned(
+                self.load_interval_patterns(req.id.locale, req.id.marker_attributes)?,
+            ),
+        })
+    }
+}
+
+impl IterableDataProviderCached<DatetimePatternsIntervalV1> for SourceDataProvider {
+    fn iter_ids_cached(&self) -> Result<HashSet<DataIdentifierCow<'static>>, DataError> {
+        let mut ids = HashSet::new();
+        for locale in self
+            .cldr()?
+            .dates(DatagenCalendar::Gregorian.cldr_name())
+            .list_locales()?
+        {
+            ids.extend(
+                DateFieldSet::ALL_DATA_MARKER_ATTRIBUTES
+                    .iter()
+                    .chain(CalendarPeriodFieldSet::ALL_DATA_MARKER_ATTRIBUTES)
+                    .map(|attrs| DataIdentifierCow::from_borrowed_and_owned(attrs, locale)),
+            );
+        }
+        for locale in self.cldr()?.dates("generic").list_locales()? {
+            ids.extend(
+                TimeFieldSet::ALL_DATA_MARKER_ATTRIBUTES
+                    .iter()
+                    .map(|attrs| DataIdentifierCow::from_borrowed_and_owned(attrs, locale)),
+            );
+        }
+        Ok(ids)
+    }
+}
+
+#[test]
+fn test_en_interval_patterns() {
+    use icu::datetime::pattern::DateTimePattern;
+    use icu::locale::locale;
+    use zerofrom::ZeroFrom;
+
+    let provider = SourceDataProvider::new_testing();
+
+    let load = |attributes: &str| {
+        let payload: DataPayload<DatetimePatternsIntervalV1> = provider
+            .load(DataRequest {
+                id: DataIdentifierBorrowed::for_marker_attributes_and_locale(
+                    DataMarkerAttributes::from_str_or_panic(attributes),
+                    &locale!("en").into(),
+                ),
+                metadata: Default::default(),
+            })
+            .unwrap()
+            .payload;
+        let payload = payload.get();
+        payload
+            .keys
+            .iter()
+            .zip(payload.patterns.iter())
+            .map(|(key, pattern)| {
+                (
+                    (key.length, key.variant, key.difference),
+                    DateTimePattern::from(runtime::Pattern::zero_from(pattern)).to_string(),
+                )
+            })
+            .collect::<BTreeMap<_, _>>()
+    };
+
+    let patterns = load("ym0d");
+    // Medium, standard
+    assert_eq!(
+        patterns.get(&(1, 0, IntervalField::Day)).unwrap(),
+        "MMM d\u{2009}–\u{2009}d, y"
+    );
+    assert_eq!(
+        patterns.get(&(1, 0, IntervalField::Month)).unwrap(),
+        "MMM d\u{2009}–\u{2009}MMM d, y"
+    );
+    // Long, standard: the month length follows the pattern
+    assert_eq!(
+        patterns.get(&(0, 0, IntervalField::Day)).unwrap(),
+        "MMMM d\u{2009}–\u{2009}d, y"
+    );
+    // Short, standard: the year length follows the pattern
+    assert_eq!(
+        patterns.get(&(2, 0, IntervalField::Day)).unwrap(),
+        "M/d/yy\u{2009}–\u{2009}M/d/yy"
+    );
+    // Medium, with era
+    assert_eq!(
+        patterns.get(&(1, 2, IntervalField::Era)).unwrap(),
+        "MMM d, y GGG\u{2009}–\u{2009}MMM d, y GGG"
+    );
+
+    let patterns = load("j");
+    // Medium, hour and minute
+    assert_eq!(
+        patterns.get(&(1, 1, IntervalField::Hour)).unwrap(),
+        "h:mm\u{2009}–\u{2009}h:mm\u{202f}a"
+    );
+    assert_eq!(
+        patterns.get(&(1, 1, IntervalField::DayPeriod)).unwrap(),
+        "h:mm\u{202f}a\u{2009}–\u{2009}h:mm\u{202f}a"
+    );
+    // There are no interval formats with seconds
+    assert!(!patterns.keys().any(|&(_, variant, _)| variant == 2));
+}
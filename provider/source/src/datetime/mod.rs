@@ -7,6 +7,7 @@ use crate::SourceDataProvider;
 use icu::calendar::AnyCalendarKind;
 use icu_provider::prelude::*;
 
+mod interval;
 mod legacy;
 mod names;
 mod neo;
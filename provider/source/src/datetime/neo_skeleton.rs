@@ -137,7 +137,7 @@ impl SourceDataProvider {
         })
     }
 
-    fn make_packed_skeleton_data(
+    pub(super) fn make_packed_skeleton_data(
         &self,
         locale: &DataLocale,
         calendar: Option<DatagenCalendar>,
@@ -456,7 +456,7 @@ fn test_check_for_field() {
 }
 
 /// Convert from a semantic time field set to classical component options for calculating the pattern.
-fn gen_time_components(
+pub(super) fn gen_time_components(
     _: Length,
     attributes: &DataMarkerAttributes,
     _: &cldr_serde::ca::Dates,
@@ -482,7 +482,7 @@ fn gen_time_components(
 }
 
 /// Convert from a semantic date field set to classical component options for calculating the pattern.
-fn gen_date_components(
+pub(super) fn gen_date_components(
     length: Length,
     attributes: &DataMarkerAttributes,
     data: &cldr_serde::ca::Dates,
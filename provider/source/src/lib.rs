@@ -51,8 +51,14 @@ mod personnames;
 mod plurals;
 mod properties;
 #[cfg(feature = "experimental")]
+mod range;
+#[cfg(feature = "experimental")]
 mod relativetime;
+#[cfg(feature = "experimental")]
+mod scientificdecimal;
 mod segmenter;
+#[cfg(feature = "experimental")]
+mod spoof;
 mod time_zones;
 #[cfg(feature = "experimental")]
 mod transforms;
@@ -74,15 +80,21 @@ mod tests;
 /// If a required data source has not been set, `DataProvider::load` will
 /// fail with the appropriate error:
 /// * [`is_missing_cldr_error`](Self::is_missing_cldr_error)
+/// * [`is_missing_hyph_utf8_error`](Self::is_missing_hyph_utf8_error)
 /// * [`is_missing_icuexport_error`](Self::is_missing_icuexport_error)
 /// * [`is_missing_segmenter_lstm_error`](Self::is_missing_segmenter_lstm_error)
+/// * [`is_missing_tzdb_error`](Self::is_missing_tzdb_error)
+/// * [`is_missing_uts39_error`](Self::is_missing_uts39_error)
 #[allow(clippy::exhaustive_structs)] // any information will be added to SourceData
 #[derive(Debug, Clone)]
 pub struct SourceDataProvider {
     cldr_paths: Option<Arc<CldrCache>>,
+    hyph_utf8_paths: Option<Arc<AbstractFs>>,
     icuexport_paths: Option<Arc<SerdeCache>>,
     segmenter_lstm_paths: Option<Arc<SerdeCache>>,
     tzdb_paths: Option<Arc<TzdbCache>>,
+    #[cfg_attr(not(feature = "experimental"), allow(dead_code))]
+    uts39_paths: Option<Arc<AbstractFs>>,
     trie_type: TrieType,
     collation_root_han: CollationRootHan,
     pub(crate) timezone_horizon: ZoneNameTimestamp,
@@ -121,12 +133,16 @@ impl SourceDataProvider {
     /// The TZDB tag that has been verified to work with this version of `SourceDataProvider`.
     pub const TESTED_TZDB_TAG: &'static str = "2025b";
 
+    /// The Unicode security data version that has been verified to work with this version of `SourceDataProvider`.
+    pub const TESTED_UTS39_TAG: &'static str = "16.0.0";
+
     /// A provider using the data that has been verified to work with this version of `SourceDataProvider`.
     ///
     /// See [`TESTED_CLDR_TAG`](Self::TESTED_CLDR_TAG),
     /// [`TESTED_ICUEXPORT_TAG`](Self::TESTED_ICUEXPORT_TAG),
     /// [`TESTED_SEGMENTER_LSTM_TAG`](Self::TESTED_SEGMENTER_LSTM_TAG),
-    /// [`TESTED_TZDB_TAG`](Self::TESTED_TZDB_TAG).
+    /// [`TESTED_TZDB_TAG`](Self::TESTED_TZDB_TAG),
+    /// [`TESTED_UTS39_TAG`](Self::TESTED_UTS39_TAG).
     ///
     /// ✨ *Enabled with the `networking` Cargo feature.*
     #[cfg(feature = "networking")]
@@ -141,6 +157,7 @@ impl SourceDataProvider {
                     .with_icuexport_for_tag(Self::TESTED_ICUEXPORT_TAG)
                     .with_segmenter_lstm_for_tag(Self::TESTED_SEGMENTER_LSTM_TAG)
                     .with_tzdb_for_tag(Self::TESTED_TZDB_TAG)
+                    .with_uts39_for_tag(Self::TESTED_UTS39_TAG)
             })
             .clone()
     }
@@ -153,9 +170,11 @@ impl SourceDataProvider {
     pub fn new_custom() -> Self {
         Self {
             cldr_paths: None,
+            hyph_utf8_paths: None,
             icuexport_paths: None,
             segmenter_lstm_paths: None,
             tzdb_paths: None,
+            uts39_paths: None,
             trie_type: Default::default(),
             timezone_horizon: ZoneNameTimestamp::from_zoned_date_time_iso(
                 ZonedDateTime::try_offset_only_from_str("2015-01-01T00:00:00Z", Iso).unwrap(),
@@ -209,6 +228,26 @@ impl SourceDataProvider {
         })
     }
 
+    /// Adds Unicode security source data to the provider. The path should point to a local
+    /// directory or ZIP file containing `confusables.txt` (see
+    /// [Unicode](https://www.unicode.org/Public/security/)).
+    pub fn with_uts39(self, root: &Path) -> Result<Self, DataError> {
+        Ok(Self {
+            uts39_paths: Some(Arc::new(AbstractFs::new(root)?)),
+            ..self
+        })
+    }
+
+    /// Adds hyphenation pattern source data to the provider. The path should point to a local
+    /// directory or ZIP file with the `txt` and `tex` directories of the hyph-utf8 patterns
+    /// (see [CTAN](https://ctan.org/pkg/hyph-utf8)).
+    pub fn with_hyph_utf8(self, root: &Path) -> Result<Self, DataError> {
+        Ok(Self {
+            hyph_utf8_paths: Some(Arc::new(AbstractFs::new(root)?)),
+            ..self
+        })
+    }
+
     /// Adds CLDR source data to the provider. The data will be downloaded from GitHub
     /// using the given tag (see [GitHub releases](https://github.com/unicode-org/cldr-json/releases)).
     ///
@@ -280,12 +319,32 @@ impl SourceDataProvider {
         }
     }
 
+    /// Adds Unicode security source data to the provider. The data will be downloaded from
+    /// unicode.org using the given version (see [Unicode](https://www.unicode.org/Public/security/)).
+    ///
+    /// Also see: [`TESTED_UTS39_TAG`](Self::TESTED_UTS39_TAG)
+    ///
+    /// ✨ *Enabled with the `networking` Cargo feature.*
+    #[cfg(feature = "networking")]
+    pub fn with_uts39_for_tag(self, tag: &str) -> Self {
+        Self {
+            uts39_paths: Some(Arc::new(AbstractFs::new_from_url(format!(
+                "https://www.unicode.org/Public/security/{tag}/uts39-data-{tag}.zip",
+            )))),
+            ..self
+        }
+    }
+
     const MISSING_CLDR_ERROR: DataError =
         DataError::custom("Missing CLDR data. Use `.with_cldr[_for_tag]` to set CLDR data.");
 
     const MISSING_ICUEXPORT_ERROR: DataError =
         DataError::custom("Missing ICU data. Use `.with_icuexport[_for_tag]` to set ICU data.");
 
+    const MISSING_HYPH_UTF8_ERROR: DataError = DataError::custom(
+        "Missing hyphenation pattern data. Use `.with_hyph_utf8` to set hyphenation pattern data.",
+    );
+
     const MISSING_SEGMENTER_LSTM_ERROR: DataError = DataError::custom(
         "Missing segmenter data. Use `.with_segmenter_lstm[_for_tag]` to set segmenter data.",
     );
@@ -293,12 +352,22 @@ impl SourceDataProvider {
     const MISSING_TZDB_ERROR: DataError =
         DataError::custom("Missing tzdb data. Use `.with_tzdb[_for_tag]` to set tzdb data.");
 
+    const MISSING_UTS39_ERROR: DataError = DataError::custom(
+        "Missing Unicode security data. Use `.with_uts39[_for_tag]` to set Unicode security data.",
+    );
+
     /// Identifies errors that are due to missing CLDR data.
     pub fn is_missing_cldr_error(mut e: DataError) -> bool {
         e.marker = None;
         e == Self::MISSING_CLDR_ERROR
     }
 
+    /// Identifies errors that are due to missing hyphenation pattern data.
+    pub fn is_missing_hyph_utf8_error(mut e: DataError) -> bool {
+        e.marker = None;
+        e == Self::MISSING_HYPH_UTF8_ERROR
+    }
+
     /// Identifies errors that are due to missing ICU export data.
     pub fn is_missing_icuexport_error(mut e: DataError) -> bool {
         e.marker = None;
@@ -317,10 +386,22 @@ impl SourceDataProvider {
         e == Self::MISSING_TZDB_ERROR
     }
 
+    /// Identifies errors that are due to missing Unicode security data.
+    pub fn is_missing_uts39_error(mut e: DataError) -> bool {
+        e.marker = None;
+        e == Self::MISSING_UTS39_ERROR
+    }
+
     fn cldr(&self) -> Result<&CldrCache, DataError> {
         self.cldr_paths.as_deref().ok_or(Self::MISSING_CLDR_ERROR)
     }
 
+    fn hyph_utf8(&self) -> Result<&AbstractFs, DataError> {
+        self.hyph_utf8_paths
+            .as_deref()
+            .ok_or(Self::MISSING_HYPH_UTF8_ERROR)
+    }
+
     fn icuexport(&self) -> Result<&SerdeCache, DataError> {
         self.icuexport_paths
             .as_deref()
@@ -337,6 +418,11 @@ impl SourceDataProvider {
         self.tzdb_paths.as_deref().ok_or(Self::MISSING_TZDB_ERROR)
     }
 
+    #[cfg(feature = "experimental")]
+    fn uts39(&self) -> Result<&AbstractFs, DataError> {
+        self.uts39_paths.as_deref().ok_or(Self::MISSING_UTS39_ERROR)
+    }
+
     /// Set this to use tries optimized for speed instead of data size
     pub fn with_fast_tries(self) -> Self {
         Self {
@@ -0,0 +1,304 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use crate::cldr_serde;
+use crate::SourceDataProvider;
+
+use icu::locale::provider::*;
+use icu::locale::subtags::{Language, Region, Script};
+use icu_provider::prelude::*;
+use std::collections::{BTreeMap, BTreeSet, HashSet};
+use zerovec::{VarZeroVec, ZeroSlice};
+
+impl DataProvider<LocaleLanguageMatchingV1> for SourceDataProvider {
+    fn load(&self, req: DataRequest) -> Result<DataResponse<LocaleLanguageMatchingV1>, DataError> {
+        self.check_req::<LocaleLanguageMatchingV1>(req)?;
+        let matching: &cldr_serde::language_matching::Resource = self
+            .cldr()?
+            .core()
+            .read_and_parse("supplemental/languageMatching.json")?;
+        let containment: &cldr_serde::territory_containment::Resource = self
+            .cldr()?
+            .core()
+            .read_and_parse("supplemental/territoryContainment.json")?;
+
+        Ok(DataResponse {
+            metadata: Default::default(),
+            payload: DataPayload::from_owned(transform(matching, containment)?),
+        })
+    }
+}
+
+impl crate::IterableDataProviderCached<LocaleLanguageMatchingV1> for SourceDataProvider {
+    fn iter_ids_cached(&self) -> Result<HashSet<DataIdentifierCow<'static>>, DataError> {
+        Ok(HashSet::from_iter([Default::default()]))
+    }
+}
+
+/// Builds the region sets and keeps track of their indices.
+struct RegionSets<'a> {
+    containment: BTreeMap<Region, Vec<Region>>,
+    variables: &'a BTreeMap<&'a str, &'a str>,
+    sets: Vec<Vec<Region>>,
+}
+
+impl RegionSets<'_> {
+    /// Returns the index of a set, and whether it is negated, for a region pattern
+    /// such as `*`, `GB`, `$enUS`, or `$!enUS`.
+    fn get(&mut self, pattern: &str) -> Result<(u8, bool), DataError> {
+        let (members, negated) = if pattern == "*" {
+            // Any region is outside of the empty set
+            (Vec::new(), true)
+        } else if let Some(variable) = pattern.strip_prefix('$') {
+            let (variable, negated) = match variable.strip_prefix('!') {
+                Some(variable) => (variable, true),
+                None => (variable, false),
+            };
+            let value = self.variables.get(variable).ok_or_else(|| {
+                DataError::custom("Unknown language matching variable")
+                    .with_display_context(variable)
+            })?;
+            (self.expand(value)?, negated)
+        } else {
+            (self.expand(pattern)?, false)
+        };
+        let index = match self.sets.iter().position(|set| *set == members) {
+            Some(index) => index,
+            None => {
+                self.sets.push(members);
+                self.sets.len() - 1
+            }
+        };
+        let index = u8::try_from(index)
+            .map_err(|_| DataError::custom("Too many language matching region sets"))?;
+        Ok((index, negated))
+    }
+
+    /// Expands a `+`-separated list of regions to all regions they contain, as well as
+    /// all macroregions that are fully contained in the list.
+    fn expand(&self, value: &str) -> Result<Vec<Region>, DataError> {
+        let mut members = BTreeSet::new();
+        for region in value.split('+') {
+            let region = region
+                .parse::<Region>()
+                .map_err(|_| DataError::custom("Invalid region").with_display_context(region))?;
+            self.add_contained(region, &mut members);
+        }
+        for (&macroregion, _) in self.containment.iter() {
+            let mut leaves = BTreeSet::new();
+            self.add_contained(macroregion, &mut leaves);
+            if leaves
+                .iter()
+                .filter(|region| !self.containment.contains_key(region))
+                .all(|region| members.contains(region))
+            {
+                members.insert(macroregion);
+            }
+        }
+        Ok(members.into_iter().collect())
+    }
+
+    fn add_contained(&self, region: Region, members: &mut BTreeSet<Region>) {
+        if members.insert(region) {
+            for &contained in self.containment.get(&region).into_iter().flatten() {
+                self.add_contained(contained, members);
+            }
+        }
+    }
+}
+
+fn transform(
+    matching: &cldr_serde::language_matching::Resource,
+    containment: &cldr_serde::territory_containment::Resource,
+) -> Result<LanguageMatching<'static>, DataError> {
+    use cldr_serde::language_matching::Entry;
+
+    let entries = || {
+        matching
+            .supplemental
+            .language_matching
+            .written_new
+            .iter()
+            .flat_map(|entry| entry.iter())
+    };
+
+    let variables = entries()
+        .filter_map(|(name, entry)| match entry {
+            Entry::Variable(variable) => {
+                Some((name.trim_start_matches('$'), variable.value.as_str()))
+            }
+            _ => None,
+        })
+        .collect::<BTreeMap<_, _>>();
+
+    let mut region_sets = RegionSets {
+        containment: containment
+            .supplemental
+            .territory_containment
+            .iter()
+            // Skip deprecated containment such as `151#status`
+            .filter_map(|(region, containment)| Some((region.parse().ok()?, containment)))
+            .map(|(region, containment)| {
+                (
+                    region,
+                    containment
+                        .contains
+                        .iter()
+                        .filter_map(|r| r.parse().ok())
+                        .collect(),
+                )
+            })
+            .collect(),
+        variables: &variables,
+        sets: Vec::new(),
+    };
+
+    let parse_language = |subtag: &str| -> Result<Language, DataError> {
+        if subtag == "*" {
+            Ok(Language::UNKNOWN)
+        } else {
+            subtag
+                .parse()
+                .map_err(|_| DataError::custom("Invalid language").with_display_context(subtag))
+        }
+    };
+    let parse_script = |subtag: &str| -> Result<Option<Script>, DataError> {
+        if subtag == "*" {
+            Ok(None)
+        } else {
+            subtag
+                .parse()
+                .map(Some)
+                .map_err(|_| DataError::custom("Invalid script").with_display_context(subtag))
+        }
+    };
+
+    let mut languages = Vec::new();
+    let mut scripts = Vec::new();
+    let mut regions = Vec::new();
+    let mut default_language_distance = None;
+    let mut default_script_distance = None;
+    let mut default_region_distance = None;
+
+    for (_, entry) in entries() {
+        let Entry::Rule(rule) = entry else {
+            continue;
+        };
+        let distance = rule.distance.parse::<u8>().map_err(|_| {
+            DataError::custom("Invalid distance").with_display_context(&rule.distance)
+        })?;
+        let oneway = rule.oneway.as_deref() == Some("true");
+        let desired = rule.desired.split('_').collect::<Vec<_>>();
+        let supported = rule.supported.split('_').collect::<Vec<_>>();
+        let is_default = desired.iter().chain(supported.iter()).all(|s| *s == "*");
+
+        match (desired.as_slice(), supported.as_slice()) {
+            ([_], [_]) if is_default => default_language_distance = Some(distance),
+            ([_, _], [_, _]) if is_default => default_script_distance = Some(distance),
+            ([_, _, _], [_, _, _]) if is_default => default_region_distance = Some(distance),
+            (&[dl], &[sl]) => {
+                languages.push((parse_language(dl)?, parse_language(sl)?, distance, oneway))
+            }
+            (&[dl, ds], &[sl, ss]) => scripts.push((
+                parse_language(dl)?,
+                parse_script(ds)?,
+                parse_language(sl)?,
+                parse_script(ss)?,
+                distance,
+                oneway,
+            )),
+            (&[dl, ds, dr], &[sl, ss, sr]) if dl == sl && ds == ss => {
+                let (desired_set, desired_negated) = region_sets.get(dr)?;
+                let (supported_set, supported_negated) = region_sets.get(sr)?;
+                regions.push(RegionMatchRule {
+                    language: parse_language(dl)?,
+                    script: parse_script(ds)?,
+                    desired_set,
+                    desired_negated,
+                    supported_set,
+                    supported_negated,
+                    distance,
+                    oneway,
+                })
+            }
+            _ => {
+                return Err(DataError::custom("Unsupported language matching rule")
+                    .with_display_context(&rule.desired))
+            }
+        }
+    }
+
+    let missing_default = || DataError::custom("Missing default language matching distance");
+
+    Ok(LanguageMatching {
+        languages: languages.into_iter().collect(),
+        scripts: scripts.into_iter().collect(),
+        regions: regions.into_iter().collect(),
+        region_sets: VarZeroVec::<ZeroSlice<Region>>::from(&region_sets.sets),
+        default_language_distance: default_language_distance.ok_or_else(missing_default)?,
+        default_script_distance: default_script_distance.ok_or_else(missing_default)?,
+        default_region_distance: default_region_distance.ok_or_else(missing_default)?,
+    })
+}
+
+#[test]
+fn test_basic() {
+    use icu::locale::subtags::{language, region, script};
+
+    let provider = SourceDataProvider::new_testing();
+
+    let matching: DataResponse<LocaleLanguageMatchingV1> =
+        provider.load(Default::default()).unwrap();
+    let matching = matching.payload.get();
+
+    assert_eq!(matching.default_language_distance, 80);
+    assert_eq!(matching.default_script_distance, 50);
+    assert_eq!(matching.default_region_distance, 4);
+
+    assert!(matching
+        .languages
+        .iter()
+        .any(|rule| rule == (language!("nb"), language!("no"), 1, false)));
+    assert!(matching.scripts.iter().any(|rule| rule
+        == (
+            language!("zh"),
+            Some(script!("Hant")),
+            language!("zh"),
+            Some(script!("Hans")),
+            19,
+            true
+        )));
+
+    // en_*_GB ↔ en_*_$!enUS
+    let rule = matching
+        .regions
+        .iter()
+        .find(|rule| rule.language == language!("en") && rule.distance == 3)
+        .unwrap();
+    assert_eq!(rule.script, None);
+    assert!(!rule.desired_negated);
+    assert_eq!(
+        matching.region_sets.get(rule.desired_set.into()).unwrap(),
+        [region!("GB")].as_slice()
+    );
+    assert!(rule.supported_negated);
+    let en_us = matching.region_sets.get(rule.supported_set.into()).unwrap();
+    assert!(en_us.binary_search(&region!("PR")).is_ok());
+    assert!(en_us.binary_search(&region!("GB")).is_err());
+
+    // $americas includes macroregions in the Americas
+    let americas = matching
+        .regions
+        .iter()
+        .find(|rule| rule.language == language!("es") && !rule.desired_negated)
+        .unwrap();
+    let americas = matching
+        .region_sets
+        .get(americas.desired_set.into())
+        .unwrap();
+    for region in [region!("019"), region!("419"), region!("MX"), region!("US")] {
+        assert!(americas.binary_search(&region).is_ok());
+    }
+    assert!(americas.binary_search(&region!("ES")).is_err());
+}
@@ -4,5 +4,6 @@
 
 pub(crate) mod aliases;
 pub(crate) mod directionality;
+pub(crate) mod language_matching;
 pub(crate) mod likely_subtags;
 pub(crate) mod parents;
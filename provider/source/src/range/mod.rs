@@ -0,0 +1,166 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use std::collections::HashSet;
+
+use crate::cldr_serde;
+use crate::IterableDataProviderCached;
+use crate::SourceDataProvider;
+
+use icu::experimental::dimension::provider::range::*;
+use icu_pattern::DoublePlaceholderPattern;
+use icu_pattern::SinglePlaceholderPattern;
+use icu_provider::prelude::*;
+use icu_provider::DataProvider;
+use zerovec::VarZeroCow;
+
+impl DataProvider<NumberRangePatternsV1> for SourceDataProvider {
+    fn load(&self, req: DataRequest) -> Result<DataResponse<NumberRangePatternsV1>, DataError> {
+        self.check_req::<NumberRangePatternsV1>(req)?;
+
+        let numbers_resource: &cldr_serde::numbers::Resource = self
+            .cldr()?
+            .numbers()
+            .read_and_parse(req.id.locale, "numbers.json")?;
+
+        // TODO(#3838): these patterns might be numbering system dependent.
+        let misc_patterns = numbers_resource
+            .main
+            .value
+            .numbers
+            .numsys_data
+            .misc_patterns
+            .get("latn")
+            .ok_or_else(|| DataError::custom("Could not find the misc patterns"))?;
+
+        let range_pattern =
+            DoublePlaceholderPattern::try_from_str(&misc_patterns.range, Default::default())
+                .map_err(|e| {
+                    DataError::custom("Could not parse range pattern").with_display_context(&e)
+                })?;
+
+        let approximately_pattern = SinglePlaceholderPattern::try_from_str(
+            &misc_patterns.approximately,
+            Default::default(),
+        )
+        .map_err(|e| {
+            DataError::custom("Could not parse approximately pattern").with_display_context(&e)
+        })?;
+
+        Ok(DataResponse {
+            metadata: Default::default(),
+            payload: DataPayload::from_owned(NumberRangePatterns {
+                range_pattern: VarZeroCow::new_owned(range_pattern),
+                approximately_pattern: VarZeroCow::new_owned(approximately_pattern),
+            }),
+        })
+    }
+}
+
+impl IterableDataProviderCached<NumberRangePatternsV1> for SourceDataProvider {
+    fn iter_ids_cached(&self) -> Result<HashSet<DataIdentifierCow<'static>>, DataError> {
+        Ok(self
+            .cldr()?
+            .numbers()
+            .list_locales()?
+            .map(DataIdentifierCow::from_locale)
+            .collect())
+    }
+}
+
+#[test]
+fn test_basic() {
+    use icu::locale::langid;
+    use writeable::assert_writeable_eq;
+
+    let provider = SourceDataProvider::new_testing();
+
+    let en: DataResponse<NumberRangePatternsV1> = provider
+        .load(DataRequest {
+            id: DataIdentifierCow::from_locale(langid!("en").into()).as_borrowed(),
+            ..Default::default()
+        })
+        .unwrap();
+    let en = en.payload.get();
+    assert_writeable_eq!(en.range_pattern.interpolate(["3", "5"]), "3–5");
+    assert_writeable_eq!(en.approximately_pattern.interpolate(["5"]), "~5");
+
+    let ja: DataResponse<NumberRangePatternsV1> = provider
+        .load(DataRequest {
+            id: DataIdentifierCow::from_locale(langid!("ja").into()).as_borrowed(),
+            ..Default::default()
+        })
+        .unwrap();
+    let ja = ja.payload.get();
+    assert_writeable_eq!(ja.range_pattern.interpolate(["3", "5"]), "3～5");
+    assert_writeable_eq!(ja.approximately_pattern.interpolate(["5"]), "約 5");
+}
+
+#[test]
+fn test_units() {
+    use icu::experimental::dimension::range::formatter::NumberRangeFormatter;
+    use icu::experimental::dimension::range::options::RangeCollapse;
+    use icu::experimental::dimension::units::formatter::UnitsFormatter;
+    use icu::experimental::dimension::units::options::{UnitsFormatterOptions, Width};
+    use icu::locale::locale;
+    use writeable::assert_writeable_eq;
+
+    let provider = SourceDataProvider::new_testing();
+
+    let one = "1".parse().unwrap();
+    let three = "3".parse().unwrap();
+    let five = "5".parse().unwrap();
+
+    let fmt =
+        NumberRangeFormatter::try_new_unstable(&provider, locale!("en").into(), Default::default())
+            .unwrap();
+
+    let meter_fmt = UnitsFormatter::try_new_unstable(
+        &provider,
+        locale!("en").into(),
+        "meter",
+        Default::default(),
+    )
+    .unwrap();
+    assert_writeable_eq!(
+        fmt.format(
+            meter_fmt.format_fixed_decimal(&three),
+            meter_fmt.format_fixed_decimal(&five),
+        ),
+        "3–5 m"
+    );
+
+    let meter_fmt = UnitsFormatter::try_new_unstable(
+        &provider,
+        locale!("en").into(),
+        "meter",
+        UnitsFormatterOptions::from(Width::Long),
+    )
+    .unwrap();
+    assert_writeable_eq!(
+        fmt.format(
+            meter_fmt.format_fixed_decimal(&one),
+            meter_fmt.format_fixed_decimal(&five),
+        ),
+        "1–5 meters"
+    );
+    assert_writeable_eq!(
+        fmt.format_approximately(meter_fmt.format_fixed_decimal(&one)),
+        "~1 meter"
+    );
+
+    let fmt = NumberRangeFormatter::try_new_unstable(
+        &provider,
+        locale!("en").into(),
+        RangeCollapse::None.into(),
+    )
+    .unwrap();
+    assert_writeable_eq!(
+        fmt.format(
+            meter_fmt.format_fixed_decimal(&one),
+            meter_fmt.format_fixed_decimal(&five),
+        ),
+        "1 meter – 5 meters"
+    );
+}
@@ -0,0 +1,80 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use std::borrow::Cow;
+use std::collections::HashSet;
+
+use crate::cldr_serde;
+use crate::IterableDataProviderCached;
+use crate::SourceDataProvider;
+
+use icu::experimental::scientificdecimal::provider::*;
+use icu_provider::prelude::*;
+
+impl DataProvider<ScientificDecimalSymbolsV1> for SourceDataProvider {
+    fn load(
+        &self,
+        req: DataRequest,
+    ) -> Result<DataResponse<ScientificDecimalSymbolsV1>, DataError> {
+        self.check_req::<ScientificDecimalSymbolsV1>(req)?;
+
+        let resource: &cldr_serde::numbers::Resource = self
+            .cldr()?
+            .numbers()
+            .read_and_parse(req.id.locale, "numbers.json")?;
+
+        let numbers = &resource.main.value.numbers;
+        let nsname = &numbers.default_numbering_system;
+
+        let symbols = numbers.numsys_data.symbols.get(nsname).ok_or_else(|| {
+            DataError::custom("Could not find numbering system").with_display_context(nsname)
+        })?;
+
+        Ok(DataResponse {
+            metadata: Default::default(),
+            payload: DataPayload::from_owned(ScientificDecimalSymbols {
+                exponential: Cow::Owned(symbols.exponential.clone()),
+                superscripting_exponent: Cow::Owned(symbols.superscripting_exponent.clone()),
+                minus_sign: Cow::Owned(symbols.minus_sign.clone()),
+                plus_sign: Cow::Owned(symbols.plus_sign.clone()),
+            }),
+        })
+    }
+}
+
+impl IterableDataProviderCached<ScientificDecimalSymbolsV1> for SourceDataProvider {
+    fn iter_ids_cached(&self) -> Result<HashSet<DataIdentifierCow<'static>>, DataError> {
+        Ok(self
+            .cldr()?
+            .numbers()
+            .list_locales()?
+            .map(DataIdentifierCow::from_locale)
+            .collect())
+    }
+}
+
+#[test]
+fn test_basic() {
+    use icu::locale::langid;
+
+    let provider = SourceDataProvider::new_testing();
+
+    let en: DataResponse<ScientificDecimalSymbolsV1> = provider
+        .load(DataRequest {
+            id: DataIdentifierCow::from_locale(langid!("en").into()).as_borrowed(),
+            ..Default::default()
+        })
+        .unwrap();
+    assert_eq!(en.payload.get().exponential, "E");
+    assert_eq!(en.payload.get().superscripting_exponent, "×");
+
+    let ar_eg: DataResponse<ScientificDecimalSymbolsV1> = provider
+        .load(DataRequest {
+            id: DataIdentifierCow::from_locale(langid!("ar-EG").into()).as_borrowed(),
+            ..Default::default()
+        })
+        .unwrap();
+    assert_eq!(ar_eg.payload.get().exponential, "أس");
+    assert_eq!(ar_eg.payload.get().minus_sign, "\u{61c}-");
+}
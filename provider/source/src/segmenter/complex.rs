@@ -0,0 +1,139 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use crate::SourceDataProvider;
+use icu::properties::props::Script;
+use icu::segmenter::provider::*;
+use icu_provider::prelude::*;
+use std::collections::HashSet;
+
+/// The models for scripts that require complex context analysis, as the scripts of each
+/// model, the attributes of its dictionary, and the attributes prefix of its LSTM.
+///
+/// New models are added here, together with their dictionary or LSTM data.
+const MODELS: &[(&[Script], &str, &str)] = &[
+    (&[Script::Thai], "thaidict", "Thai_"),
+    (&[Script::Lao], "laodict", "Lao_"),
+    (&[Script::Myanmar], "burmesedict", "Burmese_"),
+    (&[Script::Khmer], "khmerdict", "Khmer_"),
+    (
+        &[Script::Han, Script::Hiragana, Script::Katakana],
+        "cjdict",
+        "",
+    ),
+];
+
+#[cfg(any(feature = "use_wasm", feature = "use_icu4c"))]
+fn generate_complex_scripts(
+    provider: &SourceDataProvider,
+    trie_type: crate::TrieType,
+) -> Result<ComplexScripts<'static>, DataError> {
+    use icu::collections::codepointtrie;
+    use icu::properties::script::ScriptWithExtensions;
+    use icu_codepointtrie_builder::{CodePointTrieBuilder, CodePointTrieBuilderData};
+
+    let data = ScriptWithExtensions::try_new_unstable(provider)?;
+    let scx = data.as_borrowed();
+
+    // A code point belongs to a model if its script is one of the model's scripts, or, for
+    // Common and Inherited characters such as U+30FC KATAKANA-HIRAGANA PROLONGED SOUND MARK,
+    // if all of its script extensions are.
+    let models_by_code_point = (0..=u32::from(char::MAX))
+        .map(|cp| {
+            let script = scx.get_script_val32(cp);
+            MODELS
+                .iter()
+                .position(|(scripts, ..)| {
+                    if matches!(script, Script::Common | Script::Inherited) {
+                        scx.get_script_extensions_val32(cp)
+                            .iter()
+                            .all(|s| scripts.contains(&s))
+                    } else {
+                        scripts.contains(&script)
+                    }
+                })
+                .map_or(0, |i| i as u8 + 1)
+        })
+        .collect::<Vec<u8>>();
+
+    Ok(ComplexScripts {
+        models_by_code_point: CodePointTrieBuilder {
+            data: CodePointTrieBuilderData::ValuesByCodePoint(&models_by_code_point),
+            default_value: 0,
+            error_value: 0,
+            trie_type: match trie_type {
+                crate::TrieType::Fast => codepointtrie::TrieType::Fast,
+                crate::TrieType::Small => codepointtrie::TrieType::Small,
+            },
+        }
+        .build(),
+        dictionaries: MODELS
+            .iter()
+            .map(|&(_, dictionary, _)| dictionary)
+            .collect::<Vec<_>>()
+            .as_slice()
+            .into(),
+        lstms: MODELS
+            .iter()
+            .map(|&(.., lstm)| lstm)
+            .collect::<Vec<_>>()
+            .as_slice()
+            .into(),
+    })
+}
+
+impl DataProvider<SegmenterComplexScriptsV1> for SourceDataProvider {
+    fn load(&self, req: DataRequest) -> Result<DataResponse<SegmenterComplexScriptsV1>, DataError> {
+        #[cfg(not(any(feature = "use_wasm", feature = "use_icu4c")))]
+        return Err(DataError::custom(
+            "icu_provider_source must be built with use_icu4c or use_wasm to build segmentation rules",
+        )
+        .with_req(SegmenterComplexScriptsV1::INFO, req));
+        #[cfg(any(feature = "use_wasm", feature = "use_icu4c"))]
+        return {
+            self.check_req::<SegmenterComplexScriptsV1>(req)?;
+            let data =
+                generate_complex_scripts(&super::hardcoded_segmenter_provider(), self.trie_type())?;
+            Ok(DataResponse {
+                metadata: Default::default(),
+                payload: DataPayload::from_owned(data),
+            })
+        };
+    }
+}
+
+impl crate::IterableDataProviderCached<SegmenterComplexScriptsV1> for SourceDataProvider {
+    fn iter_ids_cached(&self) -> Result<HashSet<DataIdentifierCow<'static>>, DataError> {
+        Ok(HashSet::from_iter([Default::default()]))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn load_complex_scripts() {
+        let provider = SourceDataProvider::new_testing();
+        let response: DataResponse<SegmenterComplexScriptsV1> = provider
+            .load(Default::default())
+            .expect("Loading should succeed!");
+        let data = response.payload.get();
+        let model = |ch: char| {
+            let index = usize::from(data.models_by_code_point.get(ch)).checked_sub(1)?;
+            data.dictionaries.get(index)
+        };
+
+        assert_eq!(model('ภ'), Some("thaidict"));
+        assert_eq!(model('ກ'), Some("laodict"));
+        assert_eq!(model('က'), Some("burmesedict"));
+        assert_eq!(model('ក'), Some("khmerdict"));
+        assert_eq!(model('中'), Some("cjdict"));
+        assert_eq!(model('か'), Some("cjdict"));
+        assert_eq!(model('ー'), Some("cjdict"));
+        assert_eq!(model('𠀀'), Some("cjdict"));
+        assert_eq!(model('。'), None);
+        assert_eq!(model('a'), None);
+    }
+}
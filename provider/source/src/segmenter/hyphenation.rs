@@ -0,0 +1,235 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! This module contains provider implementations backed by the hyph-utf8 hyphenation patterns.
+
+use crate::{IterableDataProviderCached, SourceDataProvider};
+use icu::segmenter::provider::*;
+use icu_provider::prelude::*;
+use std::collections::{BTreeMap, HashSet};
+use zerovec::VarZeroVec;
+
+/// The `\lefthyphenmin` and `\righthyphenmin` that TeX uses if a language does not set them.
+const DEFAULT_HYPHENMINS: (u8, u8) = (2, 3);
+
+/// Returns the hyph-utf8 tag of the given locale, such as `en-us` or `de-1996`.
+fn hyph_utf8_tag(locale: &DataLocale) -> String {
+    locale.to_string().to_ascii_lowercase()
+}
+
+/// Parses a pattern such as `hen5at` into its characters and its levels.
+fn parse_pattern(pattern: &str) -> Result<(String, Vec<u8>), DataError> {
+    let mut chars = String::new();
+    let mut levels = vec![0];
+    for c in pattern.chars() {
+        if let Some(level) = c.to_digit(10) {
+            // `to_digit(10)` is at most 9
+            *levels.last_mut().unwrap() = level as u8;
+        } else {
+            chars.push(c);
+            levels.push(0);
+        }
+    }
+    if chars.is_empty() {
+        return Err(DataError::custom("Empty hyphenation pattern").with_display_context(pattern));
+    }
+    Ok((chars, levels))
+}
+
+/// Parses an exception such as `ta-ble` into its lowercase word and its hyphenation points.
+fn parse_exception(exception: &str) -> Result<(String, Vec<u8>), DataError> {
+    let mut word = String::new();
+    let mut points = Vec::new();
+    for c in exception.chars() {
+        if c == '-' {
+            points.push(u8::try_from(word.chars().count()).map_err(|_| {
+                DataError::custom("Hyphenation exception too long").with_display_context(exception)
+            })?);
+        } else {
+            word.extend(c.to_lowercase());
+        }
+    }
+    Ok((word, points))
+}
+
+/// Parses the `hyphenmins` of the YAML header of a `.tex` pattern file:
+///
+/// ```text
+/// % hyphenmins:
+/// %     typesetting:
+/// %         left: 2
+/// %         right: 3
+/// ```
+fn parse_hyphenmins(tex: &str) -> Option<(u8, u8)> {
+    let mut lines = tex
+        .lines()
+        .map_while(|line| line.strip_prefix('%'))
+        .skip_while(|line| line.trim() != "hyphenmins:")
+        .skip_while(|line| line.trim() != "typesetting:")
+        .skip(1);
+    let left = lines
+        .next()?
+        .trim()
+        .strip_prefix("left:")?
+        .trim()
+        .parse()
+        .ok()?;
+    let right = lines
+        .next()?
+        .trim()
+        .strip_prefix("right:")?
+        .trim()
+        .parse()
+        .ok()?;
+    Some((left, right))
+}
+
+impl DataProvider<SegmenterHyphenationPatternsV1> for SourceDataProvider {
+    fn load(
+        &self,
+        req: DataRequest,
+    ) -> Result<DataResponse<SegmenterHyphenationPatternsV1>, DataError> {
+        self.check_req::<SegmenterHyphenationPatternsV1>(req)?;
+        let root = self.hyph_utf8()?;
+        let tag = hyph_utf8_tag(req.id.locale);
+
+        let mut patterns = BTreeMap::new();
+        for pattern in root
+            .read_to_string(&format!("txt/hyph-{tag}.pat.txt"))?
+            .split_whitespace()
+        {
+            let (chars, levels) = parse_pattern(pattern)?;
+            patterns.insert(chars, levels);
+        }
+
+        let exceptions_path = format!("txt/hyph-{tag}.hyp.txt");
+        let mut exceptions = BTreeMap::new();
+        if root.file_exists(&exceptions_path)? {
+            for exception in root.read_to_string(&exceptions_path)?.split_whitespace() {
+                let (word, points) = parse_exception(exception)?;
+                exceptions.insert(word, points);
+            }
+        }
+
+        let tex_path = format!("tex/hyph-{tag}.tex");
+        let (left_min, right_min) = if root.file_exists(&tex_path)? {
+            parse_hyphenmins(&root.read_to_string(&tex_path)?).unwrap_or(DEFAULT_HYPHENMINS)
+        } else {
+            DEFAULT_HYPHENMINS
+        };
+
+        let max_pattern_length = patterns
+            .keys()
+            .map(|pattern| pattern.chars().count())
+            .max()
+            .unwrap_or_default();
+
+        let (patterns, pattern_levels): (Vec<&str>, Vec<&[u8]>) = patterns
+            .iter()
+            .map(|(chars, levels)| (chars.as_str(), levels.as_slice()))
+            .unzip();
+        let (exceptions, exception_points): (Vec<&str>, Vec<&[u8]>) = exceptions
+            .iter()
+            .map(|(word, points)| (word.as_str(), points.as_slice()))
+            .unzip();
+
+        Ok(DataResponse {
+            metadata: Default::default(),
+            payload: DataPayload::from_owned(HyphenationPatterns {
+                patterns: VarZeroVec::from(&patterns),
+                pattern_levels: VarZeroVec::from(&pattern_levels),
+                exceptions: VarZeroVec::from(&exceptions),
+                exception_points: VarZeroVec::from(&exception_points),
+                max_pattern_length: u8::try_from(max_pattern_length).map_err(|_| {
+                    DataError::custom("Hyphenation pattern too long").with_debug_context(&tag)
+                })?,
+                left_min,
+                right_min,
+            }),
+        })
+    }
+}
+
+impl IterableDataProviderCached<SegmenterHyphenationPatternsV1> for SourceDataProvider {
+    fn iter_ids_cached(&self) -> Result<HashSet<DataIdentifierCow<'static>>, DataError> {
+        Ok(self
+            .hyph_utf8()?
+            .list("txt")?
+            .filter_map(|file| {
+                let tag = file.strip_prefix("hyph-")?.strip_suffix(".pat.txt")?;
+                // Tags that are not locales, such as `mn-cyrl-x-lmc`, are skipped.
+                let locale = DataLocale::try_from_str(tag).ok()?;
+                (hyph_utf8_tag(&locale) == tag).then_some(DataIdentifierCow::from_locale(locale))
+            })
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use icu::locale::langid;
+    use icu::segmenter::options::LineBreakKind;
+    use icu::segmenter::{Hyphenator, LineSegmenter};
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(
+            parse_pattern("hen5at").unwrap(),
+            ("henat".into(), vec![0, 0, 0, 5, 0, 0])
+        );
+        assert_eq!(
+            parse_pattern(".hy3ph").unwrap(),
+            (".hyph".into(), vec![0, 0, 0, 3, 0, 0])
+        );
+        assert_eq!(
+            parse_exception("Ta-ble").unwrap(),
+            ("table".into(), vec![2])
+        );
+        assert_eq!(
+            parse_hyphenmins("% title: Hyphenation patterns\n% hyphenmins:\n%     typesetting:\n%         left: 1\n%         right: 2\n"),
+            Some((1, 2))
+        );
+    }
+
+    #[test]
+    fn test_hyphenate() {
+        let provider = SourceDataProvider::new_testing();
+
+        let locales: HashSet<_> =
+            IterableDataProviderCached::<SegmenterHyphenationPatternsV1>::iter_ids_cached(
+                &provider,
+            )
+            .unwrap();
+        assert!(locales.contains(&DataIdentifierCow::from_locale(
+            DataLocale::try_from_str("en-US").unwrap()
+        )));
+
+        let hyphenator = Hyphenator::try_new_unstable(&provider, &langid!("en-US")).unwrap();
+        let hyphenator = hyphenator.as_borrowed();
+        assert_eq!(hyphenator.hyphenate("hyphenation"), [2, 6]);
+        assert_eq!(hyphenator.hyphenate("Table"), [2]);
+        assert_eq!(hyphenator.hyphenate("associate"), [2, 4]);
+        assert!(hyphenator.hyphenate("hen").is_empty());
+
+        let segmenter = LineSegmenter::new_auto(Default::default()).with_hyphenator(hyphenator);
+        let breakpoints: Vec<(usize, LineBreakKind)> = segmenter
+            .segment_str("Tables: hyphenation-associates.")
+            .iter_with_break_kind()
+            .collect();
+        assert_eq!(
+            breakpoints,
+            [
+                (0, LineBreakKind::Normal),
+                (8, LineBreakKind::Normal),
+                (10, LineBreakKind::Hyphenation),
+                (14, LineBreakKind::Hyphenation),
+                (20, LineBreakKind::Normal),
+                (22, LineBreakKind::Hyphenation),
+                (24, LineBreakKind::Hyphenation),
+                (31, LineBreakKind::Normal),
+            ]
+        );
+    }
+}
@@ -27,7 +27,9 @@ use std::ops::RangeInclusive;
 use std::sync::OnceLock;
 use zerovec::ZeroVec;
 
+mod complex;
 mod dictionary;
+mod hyphenation;
 mod lstm;
 
 // state machine name define by builtin name
@@ -821,6 +823,10 @@ fn hardcoded_segmenter_provider() -> SourceDataProvider {
                             "uprops/small/sc.toml",
                             include_bytes!("../../data/segmenter/uprops/small/sc.toml").as_slice(),
                         ),
+                        (
+                            "uprops/small/scx.toml",
+                            include_bytes!("../../data/segmenter/uprops/small/scx.toml").as_slice(),
+                        ),
                         (
                             "uprops/small/WB.toml",
                             include_bytes!("../../data/segmenter/uprops/small/WB.toml").as_slice(),
@@ -921,5 +927,59 @@ mod tests {
         response.payload.get();
     }
 
+    #[test]
+    #[cfg(any(feature = "use_wasm", feature = "use_icu4c"))]
+    fn custom_segmenter_builder_matches_rules() {
+        use icu::segmenter::CustomSegmenterBuilder;
+
+        for rules_file in [
+            "segmenter/grapheme.toml",
+            "segmenter/line.toml",
+            "segmenter/sentence.toml",
+            "segmenter/word.toml",
+        ] {
+            let provider = hardcoded_segmenter_provider();
+            let expected = generate_rule_break_data(&provider, rules_file, crate::TrieType::Small);
+
+            let segmenter = provider
+                .icuexport()
+                .unwrap()
+                .read_and_parse_toml::<SegmenterRuleTable>(rules_file)
+                .unwrap();
+            let mut builder = CustomSegmenterBuilder::new();
+            for p in &segmenter.tables {
+                match (&p.left, &p.right) {
+                    (Some(left), Some(right)) if p.interm_break_state.is_some() => {
+                        builder.add_intermediate_state(&p.name, left, right)
+                    }
+                    (Some(left), Some(right)) => builder.add_state(&p.name, left, right),
+                    _ => builder.add_property(&p.name, []),
+                }
+            }
+            for rule in &segmenter.rules {
+                let left: Vec<&str> = rule.left.iter().map(String::as_str).collect();
+                let right: Vec<&str> = rule.right.iter().map(String::as_str).collect();
+                match rule.break_state {
+                    Some(true) => builder.add_break_rule(&left, &right),
+                    Some(false) => builder.add_keep_rule(&left, &right),
+                    None => builder.add_no_match_rule(&left, &right),
+                }
+            }
+            let actual = builder.build_data().unwrap();
+
+            assert_eq!(
+                actual.break_state_table, expected.break_state_table,
+                "{rules_file}"
+            );
+            assert_eq!(actual.property_count, expected.property_count);
+            assert_eq!(
+                actual.last_codepoint_property,
+                expected.last_codepoint_property
+            );
+            assert_eq!(actual.sot_property, expected.sot_property);
+            assert_eq!(actual.eot_property, expected.eot_property);
+        }
+    }
+
     // TODO: Add loading override table data. But no locales in testdata.
 }
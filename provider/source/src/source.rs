@@ -317,7 +317,7 @@ impl AbstractFs {
         Ok(s)
     }
 
-    fn list(&self, path: &str) -> Result<impl Iterator<Item = String>, DataError> {
+    pub(crate) fn list(&self, path: &str) -> Result<impl Iterator<Item = String>, DataError> {
         self.init()?;
         Ok(match self {
             Self::Fs(root) => std::fs::read_dir(root.join(path))
@@ -362,7 +362,7 @@ impl AbstractFs {
         })
     }
 
-    fn file_exists(&self, path: &str) -> Result<bool, DataError> {
+    pub(crate) fn file_exists(&self, path: &str) -> Result<bool, DataError> {
         self.init()?;
         Ok(match self {
             Self::Fs(root) => root.join(path).is_file(),
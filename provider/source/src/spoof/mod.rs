@@ -0,0 +1,113 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! This module contains provider implementations backed by the Unicode
+//! security data files of UTS #39.
+
+use crate::SourceDataProvider;
+use icu::experimental::spoof::provider::*;
+use icu_provider::prelude::*;
+use std::collections::{BTreeMap, HashSet};
+
+/// Parses a space-separated sequence of hexadecimal code points.
+fn parse_code_points(field: &str) -> Result<String, DataError> {
+    field
+        .split_whitespace()
+        .map(|hex| {
+            u32::from_str_radix(hex, 16)
+                .ok()
+                .and_then(char::from_u32)
+                .ok_or_else(|| {
+                    DataError::custom("Invalid code point in confusables.txt")
+                        .with_display_context(hex)
+                })
+        })
+        .collect()
+}
+
+impl DataProvider<ConfusablesV1> for SourceDataProvider {
+    fn load(&self, req: DataRequest) -> Result<DataResponse<ConfusablesV1>, DataError> {
+        self.check_req::<ConfusablesV1>(req)?;
+        let source = self.uts39()?.read_to_string("confusables.txt")?;
+
+        let mut prototypes = BTreeMap::new();
+        for line in source.trim_start_matches('\u{FEFF}').lines() {
+            let line = line.split('#').next().unwrap_or_default().trim();
+            if line.is_empty() {
+                continue;
+            }
+            let mut fields = line.split(';');
+            let (Some(source), Some(target)) = (fields.next(), fields.next()) else {
+                return Err(
+                    DataError::custom("Invalid line in confusables.txt").with_display_context(line)
+                );
+            };
+            let source = parse_code_points(source)?;
+            let mut chars = source.chars();
+            let (Some(source), None) = (chars.next(), chars.next()) else {
+                return Err(
+                    DataError::custom("Confusable source is not a single code point")
+                        .with_display_context(line),
+                );
+            };
+            prototypes.insert(source, parse_code_points(target)?);
+        }
+
+        Ok(DataResponse {
+            metadata: Default::default(),
+            payload: DataPayload::from_owned(Confusables {
+                prototypes: prototypes
+                    .iter()
+                    .map(|(source, target)| (source, target.as_str()))
+                    .collect(),
+            }),
+        })
+    }
+}
+
+impl crate::IterableDataProviderCached<ConfusablesV1> for SourceDataProvider {
+    fn iter_ids_cached(&self) -> Result<HashSet<DataIdentifierCow<'static>>, DataError> {
+        Ok(HashSet::from_iter([Default::default()]))
+    }
+}
+
+#[test]
+fn test_confusables() {
+    let provider = SourceDataProvider::new_testing();
+    let data: DataPayload<ConfusablesV1> = provider.load(Default::default()).unwrap().payload;
+    let prototypes = &data.get().prototypes;
+
+    assert_eq!(prototypes.get(&'а'), Some("a"));
+    assert_eq!(prototypes.get(&'1'), Some("l"));
+    assert_eq!(prototypes.get(&'m'), Some("rn"));
+    assert_eq!(prototypes.get(&'a'), None);
+}
+
+#[test]
+fn test_spoof_checker() {
+    use icu::experimental::spoof::{RestrictionLevel, SpoofChecker};
+
+    let provider = SourceDataProvider::new_testing();
+    let checker = SpoofChecker::try_new_unstable(&provider).unwrap();
+
+    assert!(checker.are_confusable("paypal", "раураl"));
+    assert!(checker.are_confusable("Illinois", "llIinois"));
+    assert!(checker.are_confusable("paypal", "paypa1"));
+    assert!(!checker.are_confusable("paypal", "paypai"));
+
+    assert!(checker.is_mixed_script("раураl"));
+    assert_eq!(checker.restriction_level("paypal"), RestrictionLevel::Ascii);
+    assert_eq!(
+        checker.restriction_level("раура"),
+        RestrictionLevel::SingleScript
+    );
+    assert_eq!(
+        checker.restriction_level("Tokyo東京"),
+        RestrictionLevel::HighlyRestrictive
+    );
+    assert_eq!(
+        checker.restriction_level("раураl"),
+        RestrictionLevel::MinimallyRestrictive
+    );
+}
@@ -238,6 +238,7 @@ impl SourceDataProvider {
                         ("cldr-core/supplemental/calendarData.json", include_bytes!("../../tests/data/cldr/cldr-core/supplemental/calendarData.json").as_slice()),
                         ("cldr-core/supplemental/currencyData.json", include_bytes!("../../tests/data/cldr/cldr-core/supplemental/currencyData.json").as_slice()),
                         ("cldr-core/supplemental/units.json", include_bytes!("../../tests/data/cldr/cldr-core/supplemental/units.json").as_slice()),
+                        ("cldr-core/supplemental/languageMatching.json", include_bytes!("../../tests/data/cldr/cldr-core/supplemental/languageMatching.json").as_slice()),
                         ("cldr-core/supplemental/likelySubtags.json", include_bytes!("../../tests/data/cldr/cldr-core/supplemental/likelySubtags.json").as_slice()),
                         ("cldr-core/supplemental/metaZones.json", include_bytes!("../../tests/data/cldr/cldr-core/supplemental/metaZones.json").as_slice()),
                         ("cldr-core/supplemental/primaryZones.json", include_bytes!("../../tests/data/cldr/cldr-core/supplemental/primaryZones.json").as_slice()),
@@ -247,6 +248,7 @@ impl SourceDataProvider {
                         ("cldr-core/supplemental/parentLocales.json", include_bytes!("../../tests/data/cldr/cldr-core/supplemental/parentLocales.json").as_slice()),
                         ("cldr-core/supplemental/pluralRanges.json", include_bytes!("../../tests/data/cldr/cldr-core/supplemental/pluralRanges.json").as_slice()),
                         ("cldr-core/supplemental/plurals.json", include_bytes!("../../tests/data/cldr/cldr-core/supplemental/plurals.json").as_slice()),
+                        ("cldr-core/supplemental/territoryContainment.json", include_bytes!("../../tests/data/cldr/cldr-core/supplemental/territoryContainment.json").as_slice()),
                         ("cldr-core/supplemental/weekData.json", include_bytes!("../../tests/data/cldr/cldr-core/supplemental/weekData.json").as_slice()),
                         ("cldr-dates-full/main/ar/ca-generic.json", include_bytes!("../../tests/data/cldr/cldr-dates-full/main/ar/ca-generic.json").as_slice()),
                         ("cldr-dates-full/main/ar-EG/ca-generic.json", include_bytes!("../../tests/data/cldr/cldr-dates-full/main/ar-EG/ca-generic.json").as_slice()),
@@ -694,6 +696,18 @@ impl SourceDataProvider {
                         ("vanguard.zi", include_bytes!("../../tests/data/tzdb/vanguard.zi").as_slice())
                     ].into_iter().collect(),
                 ), transitions: Default::default() })),
+                uts39_paths: Some(Arc::new(AbstractFs::Memory(
+                    [
+                        ("confusables.txt", include_bytes!("../../tests/data/uts39/confusables.txt").as_slice())
+                    ].into_iter().collect(),
+                ))),
+                hyph_utf8_paths: Some(Arc::new(AbstractFs::Memory(
+                    [
+                        ("tex/hyph-en-us.tex", include_bytes!("../../tests/data/hyph-utf8/tex/hyph-en-us.tex").as_slice()),
+                        ("txt/hyph-en-us.hyp.txt", include_bytes!("../../tests/data/hyph-utf8/txt/hyph-en-us.hyp.txt").as_slice()),
+                        ("txt/hyph-en-us.pat.txt", include_bytes!("../../tests/data/hyph-utf8/txt/hyph-en-us.pat.txt").as_slice())
+                    ].into_iter().collect(),
+                ))),
                 ..SourceDataProvider::new_custom()
             })
             .clone()
@@ -274,6 +274,109 @@ lazy_static::lazy_static! {
         // Serde-specific
         "icu::datetime::fieldsets::serde",
 
+        // Not yet planned
+        // The parsers are generic over the field set like the formatters, so they need
+        // one FFI type per FFI formatter type. DateTimeParseError carries a UTF-8 byte
+        // index, which needs a UTF-16 variant for JS and Dart.
+        "icu::datetime::parse",
+        "icu::datetime::DateTimeParser",
+        "icu::datetime::FixedCalendarDateTimeParser",
+
+        // Not yet planned
+        // The symbols accessor returns a data struct, which is not exposed over FFI.
+        // Both accessors exist for the parsers, which are not exposed either.
+        "icu::decimal::DecimalFormatter::symbols",
+        "icu::decimal::DecimalFormatter::digits",
+
+        // Not yet planned
+        // DecimalParser::parse returns the parsed length as a UTF-8 byte index, which needs
+        // a UTF-16 variant for JS and Dart, and DecimalParseError carries such an index too.
+        "icu::decimal::DecimalParser",
+        "icu::decimal::DecimalParseError",
+
+        // Not yet planned
+        // LocaleMatcher::find_best takes an iterator of desired locales and returns a
+        // LocaleMatch borrowing from the supported slice; this needs FFI support for
+        // slices of opaque Locales.
+        "icu::locale::LocaleMatcher",
+        "icu::locale::LocaleMatcherOptions",
+        "icu::locale::LocaleMatch",
+        "icu::locale::FavorSubtag",
+
+        // Not yet planned
+        // DateTimeIntervalFormatter is generic over the field set, like DateTimeFormatter,
+        // and needs the same per-field-set FFI types. Parts are not exposed over FFI for
+        // any datetime formatter yet.
+        "icu::datetime::DateTimeIntervalFormatter",
+        "icu::datetime::FormattedDateTimeInterval",
+        "icu::datetime::parts::START_RANGE",
+        "icu::datetime::parts::END_RANGE",
+
+        // Not yet planned
+        // The CollationRulesError variants carry the byte index of the failing rule;
+        // FFI error enums cannot carry data.
+        "icu::collator::Collator::try_new_from_rules",
+        "icu::collator::CollationRulesError",
+
+        // Not yet planned
+        // StringSearcher borrows the CollatorBorrowed it was created from and returns
+        // ranges (and Vecs of ranges), which needs FFI support for borrowed opaques and
+        // range slices.
+        "icu::collator::StringSearcher",
+
+        // Not yet planned
+        // Uts46Errors holds a Vec of per-label errors that is returned alongside the
+        // output string; FFI can only return a single error enum.
+        "icu::normalizer::uts46::Uts46Processor",
+        "icu::normalizer::uts46::Uts46ProcessorBorrowed",
+        "icu::normalizer::uts46::Uts46Options",
+        "icu::normalizer::uts46::Uts46Error",
+        "icu::normalizer::uts46::Uts46Errors",
+
+        // Not yet planned
+        // The streams are generic over the normalizer and keep state between calls;
+        // normalize_read_to takes std::io::Read and Write, which have no FFI equivalent.
+        "icu::normalizer::StreamingNormalizer",
+        "icu::normalizer::Utf16NormalizerStream",
+        "icu::normalizer::Utf8NormalizerStream",
+
+        // Not yet planned
+        // is_fcd only gives the standard FCD check on an NFD normalizer, but the FFI
+        // DecomposingNormalizer does not record whether it was created as NFD or NFKD.
+        // new_fcc should be added to FFI together with the FCD check.
+        "icu::normalizer::ComposingNormalizer::new_fcc",
+        "icu::normalizer::ComposingNormalizer::try_new_fcc_with_buffer_provider",
+        "icu::normalizer::ComposingNormalizer::try_new_fcc_unstable",
+        "icu::normalizer::ComposingNormalizerBorrowed::new_fcc",
+        "icu::normalizer::DecomposingNormalizerBorrowed::is_fcd",
+        "icu::normalizer::DecomposingNormalizerBorrowed::is_fcd_utf8",
+        "icu::normalizer::DecomposingNormalizerBorrowed::is_fcd_utf16",
+
+        // Not yet planned
+        // The CustomRulesError variants carry the offending rule names, which FFI error
+        // enums cannot carry, and CustomBreakIterator would need its own FFI iterator
+        // types for each encoding.
+        "icu::segmenter::CustomSegmenter",
+        "icu::segmenter::CustomSegmenterBorrowed",
+        "icu::segmenter::CustomSegmenterBuilder",
+        "icu::segmenter::CustomRulesError",
+        "icu::segmenter::iterators::CustomBreakIterator",
+
+        // Not yet planned
+        // The streams are generic over the segmenter and return Vecs of break offsets per
+        // chunk; FFI would need one stream type per segmenter and encoding.
+        "icu::segmenter::iterators::StrBreakStream",
+        "icu::segmenter::iterators::Utf16BreakStream",
+        "icu::segmenter::scaffold::StreamingSegmenter",
+
+        // Not yet planned
+        // Hyphenator::hyphenate returns a Vec of UTF-8 byte offsets, and the line break
+        // kind iterator would need new FFI iterator types for each encoding.
+        "icu::segmenter::Hyphenator",
+        "icu::segmenter::HyphenatorBorrowed",
+        "icu::segmenter::iterators::LineBreakIteratorWithBreakKind",
+        "icu::segmenter::options::LineBreakKind",
+
         // Stuff that is experimental
         //
         // We should occasionally review these
@@ -213,6 +213,18 @@ fn main() -> eyre::Result<()> {
         &mut Default::default(),
     )?;
 
+    std::fs::remove_dir_all(out_root.join("tests/data/uts39"))?;
+    extract_zip(
+        cached(&format!(
+            "https://www.unicode.org/Public/security/{0}/uts39-data-{0}.zip",
+            SourceDataProvider::TESTED_UTS39_TAG,
+        ))
+        .with_context(|| "Failed to download UTS #39 ZIP".to_owned())?,
+        UTS39_GLOB.iter().copied().map(String::from).collect(),
+        out_root.join("tests/data/uts39"),
+        &mut Default::default(),
+    )?;
+
     let mut tzdb_data = TZDB_GLOB.iter().copied().collect::<BTreeSet<_>>();
 
     let gen_files = ["rearguard.zi", "vanguard.zi"];
@@ -260,6 +272,24 @@ fn main() -> eyre::Result<()> {
         })
         .collect::<Vec<_>>()
         .join(",\n                        ");
+    let uts39_data = UTS39_GLOB
+        .iter()
+        .map(|path| {
+            let path = path.replace('\\', "/");
+            format!(r#"("{path}", include_bytes!("../../tests/data/uts39/{path}").as_slice())"#)
+        })
+        .collect::<Vec<_>>()
+        .join(",\n                        ");
+    // The hyph-utf8 patterns are not released in versioned archives, so the test data is an
+    // excerpt that is checked in.
+    let hyph_utf8_data = HYPH_UTF8_GLOB
+        .iter()
+        .map(|path| {
+            let path = path.replace('\\', "/");
+            format!(r#"("{path}", include_bytes!("../../tests/data/hyph-utf8/{path}").as_slice())"#)
+        })
+        .collect::<Vec<_>>()
+        .join(",\n                        ");
 
     write!(&mut crlify::BufWriterWithLineEndingFix::new(File::create(out_root.join("src/tests/data.rs")).unwrap()), "\
 // This file is part of ICU4X. For terms of use, please see the file
@@ -297,6 +327,16 @@ impl SourceDataProvider {{
                         {tzdb_data}
                     ].into_iter().collect(),
                 ), transitions: Default::default() }})),
+                uts39_paths: Some(Arc::new(AbstractFs::Memory(
+                    [
+                        {uts39_data}
+                    ].into_iter().collect(),
+                ))),
+                hyph_utf8_paths: Some(Arc::new(AbstractFs::Memory(
+                    [
+                        {hyph_utf8_data}
+                    ].into_iter().collect(),
+                ))),
                 ..SourceDataProvider::new_custom()
             }})
             .clone()
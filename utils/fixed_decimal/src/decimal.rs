@@ -10,10 +10,10 @@ use core::fmt;
 use core::ops::RangeInclusive;
 use core::str::FromStr;
 
-use crate::{uint_iterator::IntIterator, IncrementLike, NoIncrement};
 #[cfg(feature = "ryu")]
-use crate::{FloatPrecision, LimitError};
-use crate::{ParseError, RoundingIncrement, UnsignedRoundingMode};
+use crate::FloatPrecision;
+use crate::{uint_iterator::IntIterator, IncrementLike, NoIncrement};
+use crate::{ArithmeticError, LimitError, ParseError, RoundingIncrement, UnsignedRoundingMode};
 
 // UnsignedDecimal assumes usize (digits.len()) is at least as big as a u16
 #[cfg(not(any(
@@ -1801,6 +1801,360 @@ impl UnsignedDecimal {
         Ok(())
     }
 
+    /// Adds another [`UnsignedDecimal`] to this [`UnsignedDecimal`].
+    ///
+    /// The result is exact. Its number of fraction digits is the larger one of those of the
+    /// operands, and it has no leading zeros.
+    ///
+    /// If the result does not fit into the bounds of an [`UnsignedDecimal`], a [`LimitError`]
+    /// is returned and `self` is unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_decimal::UnsignedDecimal;
+    /// # use std::str::FromStr;
+    ///
+    /// let mut dec = UnsignedDecimal::from_str("9.95").unwrap();
+    /// dec.add(&UnsignedDecimal::from_str("0.050").unwrap())
+    ///     .expect("in bounds");
+    /// assert_eq!("10.000", dec.to_string());
+    /// ```
+    pub fn add(&mut self, other: &UnsignedDecimal) -> Result<(), LimitError> {
+        let low = cmp::min(self.nonzero_magnitude_end(), other.nonzero_magnitude_end());
+        let high = cmp::max(self.magnitude, other.magnitude);
+        let mut digits = SmallVec::<[u8; 8]>::new();
+        let mut carry = 0;
+        for magnitude in low..=high {
+            let sum = self.digit_at(magnitude) + other.digit_at(magnitude) + carry;
+            digits.push(sum % 10);
+            carry = sum / 10;
+        }
+        digits.push(carry);
+        digits.reverse();
+        let mut result = Self::try_from_descending_digits(digits, low as i32)?;
+        result.include_lower_magnitudes(self, other);
+        *self = result;
+        Ok(())
+    }
+
+    /// Returns the sum of this [`UnsignedDecimal`] and another [`UnsignedDecimal`].
+    ///
+    /// See [`UnsignedDecimal::add`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_decimal::UnsignedDecimal;
+    /// # use std::str::FromStr;
+    ///
+    /// let dec = UnsignedDecimal::from_str("0.1").unwrap();
+    /// let sum = dec
+    ///     .added(&UnsignedDecimal::from_str("0.2").unwrap())
+    ///     .expect("in bounds");
+    /// assert_eq!("0.3", sum.to_string());
+    /// ```
+    pub fn added(mut self, other: &UnsignedDecimal) -> Result<Self, LimitError> {
+        self.add(other)?;
+        Ok(self)
+    }
+
+    /// Computes the absolute difference between this number and another [`UnsignedDecimal`],
+    /// together with the ordering of this number with respect to the other one.
+    ///
+    /// The number of fraction digits of the result is the larger one of those of the operands.
+    pub(crate) fn abs_diff(&self, other: &UnsignedDecimal) -> Result<(Self, Ordering), LimitError> {
+        let ordering = self.cmp_numerically(other);
+        let (larger, smaller) = match ordering {
+            Ordering::Less => (other, self),
+            _ => (self, other),
+        };
+        let low = cmp::min(self.nonzero_magnitude_end(), other.nonzero_magnitude_end());
+        let mut digits = SmallVec::<[u8; 8]>::new();
+        let mut borrow = 0;
+        for magnitude in low..=larger.magnitude {
+            let difference = 10 + larger.digit_at(magnitude) - smaller.digit_at(magnitude) - borrow;
+            digits.push(difference % 10);
+            borrow = 1 - difference / 10;
+        }
+        debug_assert_eq!(borrow, 0);
+        digits.reverse();
+        let mut result = Self::try_from_descending_digits(digits, low as i32)?;
+        result.include_lower_magnitudes(self, other);
+        Ok((result, ordering))
+    }
+
+    /// Multiplies this [`UnsignedDecimal`] by another [`UnsignedDecimal`].
+    ///
+    /// The result is exact. Its number of fraction digits is the sum of the numbers of fraction
+    /// digits of the operands, and it has no leading zeros.
+    ///
+    /// If the result does not fit into the bounds of an [`UnsignedDecimal`], a [`LimitError`]
+    /// is returned and `self` is unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_decimal::UnsignedDecimal;
+    /// # use std::str::FromStr;
+    ///
+    /// let mut dec = UnsignedDecimal::from_str("1.50").unwrap();
+    /// dec.multiply(&UnsignedDecimal::from_str("2.5").unwrap())
+    ///     .expect("in bounds");
+    /// assert_eq!("3.750", dec.to_string());
+    /// ```
+    pub fn multiply(&mut self, other: &UnsignedDecimal) -> Result<(), LimitError> {
+        // Digits of the product in ascending order of magnitude.
+        let mut digits: SmallVec<[u8; 8]> =
+            core::iter::repeat_n(0, self.digits.len() + other.digits.len()).collect();
+        for (i, a) in self.digits.iter().rev().enumerate() {
+            let mut carry = 0;
+            let mut row = digits.iter_mut().skip(i);
+            for b in other.digits.iter().rev() {
+                if let Some(digit) = row.next() {
+                    let product = *digit + a * b + carry;
+                    *digit = product % 10;
+                    carry = product / 10;
+                }
+            }
+            for digit in row {
+                if carry == 0 {
+                    break;
+                }
+                let sum = *digit + carry;
+                *digit = sum % 10;
+                carry = sum / 10;
+            }
+        }
+        digits.reverse();
+        let low = self.nonzero_magnitude_end() as i32 + other.nonzero_magnitude_end() as i32;
+        let mut result = Self::try_from_descending_digits(digits, low)?;
+        let lower_magnitude = self.lower_magnitude as i32 + other.lower_magnitude as i32;
+        result.lower_magnitude = cmp::min(
+            result.lower_magnitude,
+            cmp::max(lower_magnitude, i16::MIN as i32) as i16,
+        );
+        #[cfg(debug_assertions)]
+        result.check_invariants();
+        *self = result;
+        Ok(())
+    }
+
+    /// Returns the product of this [`UnsignedDecimal`] and another [`UnsignedDecimal`].
+    ///
+    /// See [`UnsignedDecimal::multiply`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_decimal::UnsignedDecimal;
+    /// # use std::str::FromStr;
+    ///
+    /// let dec = UnsignedDecimal::from_str("0.2").unwrap();
+    /// let product = dec
+    ///     .multiplied(&UnsignedDecimal::from(3u32))
+    ///     .expect("in bounds");
+    /// assert_eq!("0.6", product.to_string());
+    /// ```
+    pub fn multiplied(mut self, other: &UnsignedDecimal) -> Result<Self, LimitError> {
+        self.multiply(other)?;
+        Ok(self)
+    }
+
+    /// Divides this [`UnsignedDecimal`] by another [`UnsignedDecimal`], rounding the quotient
+    /// at a particular digit position and increment, using the specified rounding mode.
+    ///
+    /// The quotient is rounded as if it had been computed exactly, and it is padded with
+    /// trailing zeros up to `position`.
+    ///
+    /// If the divisor is zero, [`ArithmeticError::DivisionByZero`] is returned, and if the
+    /// result does not fit into the bounds of an [`UnsignedDecimal`], [`ArithmeticError::Limit`]
+    /// is returned. In both cases, `self` is unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_decimal::{
+    ///     ArithmeticError, RoundingIncrement, UnsignedDecimal, UnsignedRoundingMode,
+    /// };
+    ///
+    /// let mut dec = UnsignedDecimal::from(10u32);
+    /// dec.divide(
+    ///     &UnsignedDecimal::from(3u32),
+    ///     -2,
+    ///     UnsignedRoundingMode::HalfEven,
+    ///     RoundingIncrement::MultiplesOf1,
+    /// )
+    /// .expect("nonzero divisor");
+    /// assert_eq!("3.33", dec.to_string());
+    ///
+    /// let mut dec = UnsignedDecimal::from(1u32);
+    /// dec.divide(
+    ///     &UnsignedDecimal::from(8u32),
+    ///     -1,
+    ///     UnsignedRoundingMode::Expand,
+    ///     RoundingIncrement::MultiplesOf5,
+    /// )
+    /// .expect("nonzero divisor");
+    /// assert_eq!("0.5", dec.to_string());
+    ///
+    /// assert_eq!(
+    ///     dec.divide(
+    ///         &UnsignedDecimal::from(0u32),
+    ///         0,
+    ///         UnsignedRoundingMode::HalfEven,
+    ///         RoundingIncrement::MultiplesOf1,
+    ///     ),
+    ///     Err(ArithmeticError::DivisionByZero)
+    /// );
+    /// ```
+    pub fn divide(
+        &mut self,
+        divisor: &UnsignedDecimal,
+        position: i16,
+        mode: UnsignedRoundingMode,
+        increment: RoundingIncrement,
+    ) -> Result<(), ArithmeticError> {
+        let mut quotient = self.quotient_for_rounding(divisor, position)?;
+        quotient.round_with_mode_and_increment(position, mode, increment);
+        quotient.pad_end(position);
+        *self = quotient;
+        Ok(())
+    }
+
+    /// Returns the quotient of this [`UnsignedDecimal`] and another [`UnsignedDecimal`],
+    /// rounded at a particular digit position and increment, using the specified rounding mode.
+    ///
+    /// See [`UnsignedDecimal::divide`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_decimal::{RoundingIncrement, UnsignedDecimal, UnsignedRoundingMode};
+    ///
+    /// let dec = UnsignedDecimal::from(2u32);
+    /// let quotient = dec
+    ///     .divided(
+    ///         &UnsignedDecimal::from(3u32),
+    ///         -3,
+    ///         UnsignedRoundingMode::Trunc,
+    ///         RoundingIncrement::MultiplesOf1,
+    ///     )
+    ///     .expect("nonzero divisor");
+    /// assert_eq!("0.666", quotient.to_string());
+    /// ```
+    pub fn divided(
+        mut self,
+        divisor: &UnsignedDecimal,
+        position: i16,
+        mode: UnsignedRoundingMode,
+        increment: RoundingIncrement,
+    ) -> Result<Self, ArithmeticError> {
+        self.divide(divisor, position, mode, increment)?;
+        Ok(self)
+    }
+
+    /// Computes the quotient of this number and `divisor` down to the digit at `position - 1`.
+    ///
+    /// If the division is inexact, a nonzero digit is appended at `position - 2`, so that
+    /// rounding the result at `position` gives the same result as rounding the exact quotient.
+    pub(crate) fn quotient_for_rounding(
+        &self,
+        divisor: &UnsignedDecimal,
+        position: i16,
+    ) -> Result<Self, ArithmeticError> {
+        if divisor.is_zero() {
+            return Err(ArithmeticError::DivisionByZero);
+        }
+        // With self = A × 10^a and divisor = B × 10^b, where A and B are the integers formed by
+        // the digits, the quotient digits down to `position - 1` are A × 10^shift / B.
+        let shift = self.nonzero_magnitude_end() as i32
+            - divisor.nonzero_magnitude_end() as i32
+            - (position as i32 - 1);
+        // Digits of B × 10^max(-shift, 0) in ascending order of magnitude.
+        let divisor_digits: SmallVec<[u8; 8]> = core::iter::repeat_n(0, (-shift).max(0) as usize)
+            .chain(divisor.digits.iter().rev().copied())
+            .collect();
+        // Digits of the remainder in ascending order of magnitude, without leading zeros.
+        let mut remainder = SmallVec::<[u8; 8]>::new();
+        let mut quotient = SmallVec::<[u8; 8]>::new();
+        for digit in self
+            .digits
+            .iter()
+            .copied()
+            .chain(core::iter::repeat_n(0, shift.max(0) as usize))
+        {
+            if !remainder.is_empty() || digit != 0 {
+                remainder.insert(0, digit);
+            }
+            let mut quotient_digit = 0;
+            while cmp_ascending_digits(&remainder, &divisor_digits) != Ordering::Less {
+                sub_ascending_digits(&mut remainder, &divisor_digits);
+                quotient_digit += 1;
+            }
+            quotient.push(quotient_digit);
+        }
+        let mut low = position as i32 - 1;
+        if !remainder.is_empty() {
+            quotient.push(1);
+            low -= 1;
+        }
+        Ok(Self::try_from_descending_digits(quotient, low)?)
+    }
+
+    /// Compares the numeric values of this number and another [`UnsignedDecimal`], ignoring
+    /// leading and trailing zeros.
+    fn cmp_numerically(&self, other: &UnsignedDecimal) -> Ordering {
+        match (self.is_zero(), other.is_zero()) {
+            (true, true) => Ordering::Equal,
+            (true, false) => Ordering::Less,
+            (false, true) => Ordering::Greater,
+            (false, false) => self
+                .magnitude
+                .cmp(&other.magnitude)
+                .then_with(|| self.digits.cmp(&other.digits)),
+        }
+    }
+
+    /// Creates a number from digits in descending order of magnitude, the last of which has
+    /// the magnitude `low`. Leading and trailing zeros are removed.
+    fn try_from_descending_digits(
+        mut digits: SmallVec<[u8; 8]>,
+        low: i32,
+    ) -> Result<Self, LimitError> {
+        let trailing_zeros = digits.iter().rev().take_while(|d| **d == 0).count();
+        digits.truncate(digits.len() - trailing_zeros);
+        let leading_zeros = digits.iter().take_while(|d| **d == 0).count();
+        digits.drain(..leading_zeros);
+        if digits.is_empty() {
+            return Ok(Self::default());
+        }
+        let low = low + trailing_zeros as i32;
+        let magnitude = low + digits.len() as i32 - 1;
+        let (Ok(low), Ok(magnitude)) = (i16::try_from(low), i16::try_from(magnitude)) else {
+            return Err(LimitError);
+        };
+        let result = Self {
+            digits,
+            magnitude,
+            upper_magnitude: cmp::max(magnitude, 0),
+            lower_magnitude: cmp::min(low, 0),
+        };
+        #[cfg(debug_assertions)]
+        result.check_invariants();
+        Ok(result)
+    }
+
+    /// Pads the end of this number to the lower magnitudes of the operands it was computed from.
+    fn include_lower_magnitudes(&mut self, a: &UnsignedDecimal, b: &UnsignedDecimal) {
+        self.lower_magnitude = cmp::min(
+            self.lower_magnitude,
+            cmp::min(a.lower_magnitude, b.lower_magnitude),
+        );
+        #[cfg(debug_assertions)]
+        self.check_invariants();
+    }
+
     /// Appends a slice of digits to the end of `self.digits` with optional inner zeroes.
     ///
     /// This function does not check invariants.
@@ -1853,6 +2207,28 @@ impl UnsignedDecimal {
     }
 }
 
+/// Compares two numbers given as digits in ascending order of magnitude, without leading zeros.
+fn cmp_ascending_digits(a: &[u8], b: &[u8]) -> Ordering {
+    a.len()
+        .cmp(&b.len())
+        .then_with(|| a.iter().rev().cmp(b.iter().rev()))
+}
+
+/// Subtracts `b` from `a`, where both are given as digits in ascending order of magnitude,
+/// without leading zeros, and `a >= b`.
+fn sub_ascending_digits(a: &mut SmallVec<[u8; 8]>, b: &[u8]) {
+    let mut borrow = 0;
+    for (i, digit) in a.iter_mut().enumerate() {
+        let difference = 10 + *digit - b.get(i).copied().unwrap_or(0) - borrow;
+        *digit = difference % 10;
+        borrow = 1 - difference / 10;
+    }
+    debug_assert_eq!(borrow, 0);
+    while a.last() == Some(&0) {
+        a.pop();
+    }
+}
+
 /// Render the `FixedDecimal` as a string of ASCII digits with a possible decimal point.
 ///
 /// # Examples
@@ -4174,3 +4550,66 @@ fn test_rounding_increment() {
     );
     assert_eq!("2.50", dec.to_string());
 }
+
+#[test]
+fn test_arithmetic() {
+    let a = UnsignedDecimal::from_str("0.75").unwrap();
+    let b = UnsignedDecimal::from_str("12.5").unwrap();
+    assert_eq!("13.25", a.clone().added(&b).unwrap().to_string());
+    assert_eq!("9.375", a.clone().multiplied(&b).unwrap().to_string());
+
+    let (difference, ordering) = a.abs_diff(&b).unwrap();
+    assert_eq!("11.75", difference.to_string());
+    assert_eq!(Ordering::Less, ordering);
+    let (difference, ordering) = b.abs_diff(&a).unwrap();
+    assert_eq!("11.75", difference.to_string());
+    assert_eq!(Ordering::Greater, ordering);
+    let (difference, ordering) = a.abs_diff(&a).unwrap();
+    assert_eq!("0.00", difference.to_string());
+    assert_eq!(Ordering::Equal, ordering);
+
+    for (mode, expected) in [
+        (UnsignedRoundingMode::Trunc, "0.068"),
+        (UnsignedRoundingMode::Expand, "0.069"),
+        (UnsignedRoundingMode::HalfTrunc, "0.068"),
+        (UnsignedRoundingMode::HalfExpand, "0.068"),
+        (UnsignedRoundingMode::HalfEven, "0.068"),
+    ] {
+        // 0.75 / 11 = 0.0681818...
+        assert_eq!(
+            expected,
+            a.clone()
+                .divided(
+                    &UnsignedDecimal::from(11u32),
+                    -3,
+                    mode,
+                    RoundingIncrement::MultiplesOf1
+                )
+                .unwrap()
+                .to_string(),
+            "{mode:?}"
+        );
+    }
+
+    let mut max = UnsignedDecimal::from(9u32);
+    max.multiply_pow10(i16::MAX);
+    assert_eq!(Err(LimitError), max.clone().added(&max));
+    assert_eq!(
+        Err(ArithmeticError::Limit),
+        max.clone().divided(
+            &UnsignedDecimal::from_str("0.1").unwrap(),
+            0,
+            UnsignedRoundingMode::HalfEven,
+            RoundingIncrement::MultiplesOf1,
+        )
+    );
+    assert_eq!(
+        Err(ArithmeticError::DivisionByZero),
+        max.divided(
+            &UnsignedDecimal::default(),
+            0,
+            UnsignedRoundingMode::HalfEven,
+            RoundingIncrement::MultiplesOf1,
+        )
+    );
+}
@@ -132,6 +132,26 @@ pub enum ParseError {
 
 impl core::error::Error for ParseError {}
 
+/// An error returned by the arithmetic operations on [`Decimal`] and [`UnsignedDecimal`].
+#[derive(Display, Debug, Copy, Clone, PartialEq)]
+#[non_exhaustive]
+pub enum ArithmeticError {
+    /// See [`LimitError`].
+    #[displaydoc("Magnitude or number of digits exceeded")]
+    Limit,
+    /// The divisor of a division is zero.
+    #[displaydoc("Division by zero")]
+    DivisionByZero,
+}
+
+impl core::error::Error for ArithmeticError {}
+
+impl From<LimitError> for ArithmeticError {
+    fn from(_: LimitError) -> Self {
+        Self::Limit
+    }
+}
+
 // TODO(#5065): implement these while `WithCompactExponent` and `WithScientificExponent` are implemented.
 // pub type FixedDecimalOrInfinity = WithInfinity<UnsignedDecimal>;
 // pub type DecimalOrInfinity = Signed<FixedDecimalOrInfinity>;
@@ -27,6 +27,36 @@ impl ScientificDecimal {
             exponent,
         }
     }
+
+    /// Returns a reference to the significand of `self`.
+    /// ```
+    /// # use fixed_decimal::Decimal;
+    /// # use fixed_decimal::ScientificDecimal;
+    /// # use std::str::FromStr;
+    /// #
+    /// assert_eq!(
+    ///     ScientificDecimal::from_str("+1.20e6").unwrap().significand(),
+    ///     &Decimal::from_str("+1.20").unwrap()
+    /// );
+    /// ```
+    pub fn significand(&self) -> &Decimal {
+        &self.significand
+    }
+
+    /// Returns a reference to the exponent of `self`.
+    /// ```
+    /// # use fixed_decimal::FixedInteger;
+    /// # use fixed_decimal::ScientificDecimal;
+    /// # use std::str::FromStr;
+    /// #
+    /// assert_eq!(
+    ///     ScientificDecimal::from_str("+1.20e-06").unwrap().exponent(),
+    ///     &FixedInteger::from_str("-06").unwrap()
+    /// );
+    /// ```
+    pub fn exponent(&self) -> &FixedInteger {
+        &self.exponent
+    }
 }
 
 /// Render the [`ScientificDecimal`] as a string of ASCII digits with a possible decimal point,
@@ -2,17 +2,18 @@
 // called LICENSE at the top level of the ICU4X source tree
 // (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
 
+use core::cmp::Ordering;
 use core::fmt;
 use core::ops::{Deref, DerefMut};
 use core::str::FromStr;
 
 use crate::uint_iterator::IntIterator;
-use crate::{variations::Signed, UnsignedDecimal};
 #[cfg(feature = "ryu")]
-use crate::{FloatPrecision, LimitError};
+use crate::FloatPrecision;
+use crate::{variations::Signed, UnsignedDecimal};
 use crate::{
-    IncrementLike, NoIncrement, ParseError, RoundingIncrement, Sign, SignDisplay,
-    SignedRoundingMode, UnsignedRoundingMode,
+    ArithmeticError, IncrementLike, LimitError, NoIncrement, ParseError, RoundingIncrement, Sign,
+    SignDisplay, SignedRoundingMode, UnsignedRoundingMode,
 };
 
 /// A Type containing a [`UnsignedDecimal`] and a [`Sign`] to represent a signed decimal number.
@@ -855,6 +856,263 @@ impl Decimal {
     }
 }
 
+impl Decimal {
+    /// Adds another [`Decimal`] to this [`Decimal`].
+    ///
+    /// The result is exact. Its number of fraction digits is the larger one of those of the
+    /// operands, and it has no leading zeros. A nonnegative result has
+    /// no sign, except for the sum of two negative zeros, which is a negative zero.
+    ///
+    /// If the result does not fit into the bounds of a [`Decimal`], a [`LimitError`] is
+    /// returned and `self` is unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_decimal::Decimal;
+    /// # use std::str::FromStr;
+    ///
+    /// let mut dec = Decimal::from_str("-1.25").unwrap();
+    /// dec.add(&Decimal::from_str("0.5").unwrap())
+    ///     .expect("in bounds");
+    /// assert_eq!("-0.75", dec.to_string());
+    /// ```
+    pub fn add(&mut self, other: &Decimal) -> Result<(), LimitError> {
+        self.add_signed(&other.absolute, other.sign == Sign::Negative)
+    }
+
+    /// Returns the sum of this [`Decimal`] and another [`Decimal`].
+    ///
+    /// See [`Decimal::add`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_decimal::Decimal;
+    ///
+    /// let sum = Decimal::from(-3).added(&Decimal::from(5)).expect("in bounds");
+    /// assert_eq!("2", sum.to_string());
+    /// ```
+    pub fn added(mut self, other: &Decimal) -> Result<Self, LimitError> {
+        self.add(other)?;
+        Ok(self)
+    }
+
+    /// Subtracts another [`Decimal`] from this [`Decimal`].
+    ///
+    /// The result is exact. Its number of fraction digits is the larger one of those of the
+    /// operands, and it has no leading zeros. A nonnegative result has
+    /// no sign, except for a negative zero minus a zero, which is a negative zero.
+    ///
+    /// If the result does not fit into the bounds of a [`Decimal`], a [`LimitError`] is
+    /// returned and `self` is unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_decimal::Decimal;
+    /// # use std::str::FromStr;
+    ///
+    /// let mut dec = Decimal::from_str("10.00").unwrap();
+    /// dec.subtract(&Decimal::from_str("12.5").unwrap())
+    ///     .expect("in bounds");
+    /// assert_eq!("-2.50", dec.to_string());
+    /// ```
+    pub fn subtract(&mut self, other: &Decimal) -> Result<(), LimitError> {
+        self.add_signed(&other.absolute, other.sign != Sign::Negative)
+    }
+
+    /// Returns the difference between this [`Decimal`] and another [`Decimal`].
+    ///
+    /// See [`Decimal::subtract`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_decimal::Decimal;
+    ///
+    /// let difference = Decimal::from(3)
+    ///     .subtracted(&Decimal::from(-5))
+    ///     .expect("in bounds");
+    /// assert_eq!("8", difference.to_string());
+    /// ```
+    pub fn subtracted(mut self, other: &Decimal) -> Result<Self, LimitError> {
+        self.subtract(other)?;
+        Ok(self)
+    }
+
+    /// Multiplies this [`Decimal`] by another [`Decimal`].
+    ///
+    /// The result is exact. Its number of fraction digits is the sum of the numbers of fraction
+    /// digits of the operands, and it has no leading zeros. The result is negative if exactly
+    /// one of the operands is negative, and has no sign otherwise.
+    ///
+    /// If the result does not fit into the bounds of a [`Decimal`], a [`LimitError`] is
+    /// returned and `self` is unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_decimal::Decimal;
+    /// # use std::str::FromStr;
+    ///
+    /// let mut dec = Decimal::from_str("19.99").unwrap();
+    /// dec.multiply(&Decimal::from(-3)).expect("in bounds");
+    /// assert_eq!("-59.97", dec.to_string());
+    /// ```
+    pub fn multiply(&mut self, other: &Decimal) -> Result<(), LimitError> {
+        self.absolute.multiply(&other.absolute)?;
+        self.sign = Self::sign_of_product(self.sign, other.sign);
+        Ok(())
+    }
+
+    /// Returns the product of this [`Decimal`] and another [`Decimal`].
+    ///
+    /// See [`Decimal::multiply`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_decimal::Decimal;
+    /// # use std::str::FromStr;
+    ///
+    /// let product = Decimal::from_str("-0.5")
+    ///     .unwrap()
+    ///     .multiplied(&Decimal::from_str("-0.5").unwrap())
+    ///     .expect("in bounds");
+    /// assert_eq!("0.25", product.to_string());
+    /// ```
+    pub fn multiplied(mut self, other: &Decimal) -> Result<Self, LimitError> {
+        self.multiply(other)?;
+        Ok(self)
+    }
+
+    /// Divides this [`Decimal`] by another [`Decimal`], rounding the quotient at a particular
+    /// digit position and increment, using the specified rounding mode.
+    ///
+    /// The quotient is rounded as if it had been computed exactly, and it is padded with
+    /// trailing zeros up to `position`. It is negative if exactly one of the operands is
+    /// negative, and has no sign otherwise.
+    ///
+    /// If the divisor is zero, [`ArithmeticError::DivisionByZero`] is returned, and if the
+    /// result does not fit into the bounds of a [`Decimal`], [`ArithmeticError::Limit`] is
+    /// returned. In both cases, `self` is unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_decimal::{
+    ///     Decimal, RoundingIncrement, SignedRoundingMode, UnsignedRoundingMode,
+    /// };
+    ///
+    /// let mut dec = Decimal::from(-10);
+    /// dec.divide(
+    ///     &Decimal::from(3),
+    ///     -2,
+    ///     SignedRoundingMode::Floor,
+    ///     RoundingIncrement::MultiplesOf1,
+    /// )
+    /// .expect("nonzero divisor");
+    /// assert_eq!("-3.34", dec.to_string());
+    ///
+    /// let mut dec = Decimal::from(100);
+    /// dec.divide(
+    ///     &Decimal::from(-7),
+    ///     -2,
+    ///     SignedRoundingMode::Unsigned(UnsignedRoundingMode::HalfExpand),
+    ///     RoundingIncrement::MultiplesOf5,
+    /// )
+    /// .expect("nonzero divisor");
+    /// assert_eq!("-14.30", dec.to_string());
+    /// ```
+    pub fn divide(
+        &mut self,
+        divisor: &Decimal,
+        position: i16,
+        mode: SignedRoundingMode,
+        increment: RoundingIncrement,
+    ) -> Result<(), ArithmeticError> {
+        let mut quotient = Decimal::new(
+            Self::sign_of_product(self.sign, divisor.sign),
+            self.absolute
+                .quotient_for_rounding(&divisor.absolute, position)?,
+        );
+        quotient.round_with_mode_and_increment(position, mode, increment);
+        quotient.pad_end(position);
+        *self = quotient;
+        Ok(())
+    }
+
+    /// Returns the quotient of this [`Decimal`] and another [`Decimal`], rounded at a
+    /// particular digit position and increment, using the specified rounding mode.
+    ///
+    /// See [`Decimal::divide`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_decimal::{
+    ///     Decimal, RoundingIncrement, SignedRoundingMode, UnsignedRoundingMode,
+    /// };
+    ///
+    /// let quotient = Decimal::from(1)
+    ///     .divided(
+    ///         &Decimal::from(-8),
+    ///         -2,
+    ///         SignedRoundingMode::Unsigned(UnsignedRoundingMode::HalfEven),
+    ///         RoundingIncrement::MultiplesOf1,
+    ///     )
+    ///     .expect("nonzero divisor");
+    /// assert_eq!("-0.12", quotient.to_string());
+    /// ```
+    pub fn divided(
+        mut self,
+        divisor: &Decimal,
+        position: i16,
+        mode: SignedRoundingMode,
+        increment: RoundingIncrement,
+    ) -> Result<Self, ArithmeticError> {
+        self.divide(divisor, position, mode, increment)?;
+        Ok(self)
+    }
+
+    /// Adds a number with the given absolute value and sign to this number.
+    fn add_signed(
+        &mut self,
+        other: &UnsignedDecimal,
+        other_is_negative: bool,
+    ) -> Result<(), LimitError> {
+        let self_is_negative = self.sign == Sign::Negative;
+        let result_is_negative = if self_is_negative == other_is_negative {
+            self.absolute.add(other)?;
+            self_is_negative
+        } else {
+            let (difference, ordering) = self.absolute.abs_diff(other)?;
+            self.absolute = difference;
+            match ordering {
+                Ordering::Greater => self_is_negative,
+                Ordering::Less => other_is_negative,
+                Ordering::Equal => false,
+            }
+        };
+        self.sign = if result_is_negative {
+            Sign::Negative
+        } else {
+            Sign::None
+        };
+        Ok(())
+    }
+
+    /// Returns the sign of the product or quotient of numbers with the given signs.
+    fn sign_of_product(a: Sign, b: Sign) -> Sign {
+        if (a == Sign::Negative) != (b == Sign::Negative) {
+            Sign::Negative
+        } else {
+            Sign::None
+        }
+    }
+}
+
 /// Render the [`Decimal`] as a string of ASCII digits with a possible decimal point.
 ///
 /// # Examples
@@ -3995,3 +4253,328 @@ fn test_rounding_increment() {
     );
     assert_eq!("2.50", dec.to_string());
 }
+
+#[test]
+fn test_arithmetic() {
+    #[derive(Debug)]
+    struct TestCase {
+        pub input_1: &'static str,
+        pub input_2: &'static str,
+        pub sum: &'static str,
+        pub difference: &'static str,
+        pub product: &'static str,
+    }
+    let cases = [
+        TestCase {
+            input_1: "0",
+            input_2: "0",
+            sum: "0",
+            difference: "0",
+            product: "0",
+        },
+        TestCase {
+            input_1: "1.5",
+            input_2: "2.25",
+            sum: "3.75",
+            difference: "-0.75",
+            product: "3.375",
+        },
+        TestCase {
+            input_1: "-1.5",
+            input_2: "2.25",
+            sum: "0.75",
+            difference: "-3.75",
+            product: "-3.375",
+        },
+        TestCase {
+            input_1: "-1.5",
+            input_2: "-2.25",
+            sum: "-3.75",
+            difference: "0.75",
+            product: "3.375",
+        },
+        TestCase {
+            input_1: "999.99",
+            input_2: "0.01",
+            sum: "1000.00",
+            difference: "999.98",
+            product: "9.9999",
+        },
+        TestCase {
+            input_1: "1.00",
+            input_2: "-1",
+            sum: "0.00",
+            difference: "2.00",
+            product: "-1.00",
+        },
+        TestCase {
+            input_1: "0012.30",
+            input_2: "4",
+            sum: "16.30",
+            difference: "8.30",
+            product: "49.20",
+        },
+        TestCase {
+            input_1: "1000000",
+            input_2: "0.000001",
+            sum: "1000000.000001",
+            difference: "999999.999999",
+            product: "1.000000",
+        },
+        TestCase {
+            input_1: "123456789012345678901234567890",
+            input_2: "98765432109876543210",
+            sum: "123456789111111111011111111100",
+            difference: "123456788913580246791358024680",
+            product: "12193263113702179522496570642237463801111263526900",
+        },
+    ];
+    for cas in &cases {
+        let input_1 = Decimal::from_str(cas.input_1).unwrap();
+        let input_2 = Decimal::from_str(cas.input_2).unwrap();
+        assert_eq!(
+            cas.sum,
+            input_1.clone().added(&input_2).unwrap().to_string(),
+            "{cas:?}"
+        );
+        assert_eq!(
+            cas.sum,
+            input_2.clone().added(&input_1).unwrap().to_string(),
+            "{cas:?}"
+        );
+        assert_eq!(
+            cas.difference,
+            input_1.clone().subtracted(&input_2).unwrap().to_string(),
+            "{cas:?}"
+        );
+        assert_eq!(
+            cas.product,
+            input_1.clone().multiplied(&input_2).unwrap().to_string(),
+            "{cas:?}"
+        );
+        assert_eq!(
+            cas.product,
+            input_2.clone().multiplied(&input_1).unwrap().to_string(),
+            "{cas:?}"
+        );
+    }
+
+    let small = Decimal::from_str("1E-300").unwrap();
+    let large = Decimal::from_str("1E300").unwrap();
+    assert_eq!(
+        format!("1{}.{}1", "0".repeat(300), "0".repeat(299)),
+        small.clone().added(&large).unwrap().to_string()
+    );
+    assert_eq!(
+        format!("-{}.{}", "9".repeat(300), "9".repeat(300)),
+        small.clone().subtracted(&large).unwrap().to_string()
+    );
+    assert_eq!(
+        format!("1.{}", "0".repeat(300)),
+        small.multiplied(&large).unwrap().to_string()
+    );
+
+    // Negative zeros
+    let negative_zero = Decimal::from_str("-0").unwrap();
+    let zero = Decimal::from(0);
+    assert_eq!(
+        Sign::Negative,
+        negative_zero.clone().added(&negative_zero).unwrap().sign()
+    );
+    assert_eq!(
+        Sign::None,
+        negative_zero.clone().added(&zero).unwrap().sign()
+    );
+    assert_eq!(
+        Sign::Negative,
+        zero.clone().multiplied(&Decimal::from(-1)).unwrap().sign()
+    );
+
+    // Limits
+    let mut dec = Decimal::from(5);
+    dec.multiply_pow10(i16::MAX);
+    assert_eq!(Err(LimitError), dec.clone().added(&dec));
+    assert_eq!(Err(LimitError), dec.clone().multiplied(&Decimal::from(2)));
+    let unchanged = dec.clone();
+    assert_eq!(Err(LimitError), dec.multiply(&Decimal::from(10)));
+    assert_eq!(unchanged, dec);
+}
+
+#[test]
+fn test_division() {
+    #[derive(Debug)]
+    struct TestCase {
+        pub dividend: &'static str,
+        pub divisor: &'static str,
+        pub position: i16,
+        pub mode: SignedRoundingMode,
+        pub increment: RoundingIncrement,
+        pub expected: &'static str,
+    }
+    let half_even = SignedRoundingMode::Unsigned(UnsignedRoundingMode::HalfEven);
+    let half_expand = SignedRoundingMode::Unsigned(UnsignedRoundingMode::HalfExpand);
+    let cases = [
+        TestCase {
+            dividend: "1",
+            divisor: "3",
+            position: -5,
+            mode: half_even,
+            increment: RoundingIncrement::MultiplesOf1,
+            expected: "0.33333",
+        },
+        TestCase {
+            dividend: "2",
+            divisor: "3",
+            position: -5,
+            mode: half_even,
+            increment: RoundingIncrement::MultiplesOf1,
+            expected: "0.66667",
+        },
+        TestCase {
+            dividend: "1",
+            divisor: "4",
+            position: -3,
+            mode: half_even,
+            increment: RoundingIncrement::MultiplesOf1,
+            expected: "0.250",
+        },
+        // Exactly half: ties to even.
+        TestCase {
+            dividend: "1",
+            divisor: "8",
+            position: -2,
+            mode: half_even,
+            increment: RoundingIncrement::MultiplesOf1,
+            expected: "0.12",
+        },
+        TestCase {
+            dividend: "1",
+            divisor: "8",
+            position: -2,
+            mode: half_expand,
+            increment: RoundingIncrement::MultiplesOf1,
+            expected: "0.13",
+        },
+        // Just above half because of the digits beyond the rounding position.
+        TestCase {
+            dividend: "12501",
+            divisor: "100000",
+            position: -2,
+            mode: half_even,
+            increment: RoundingIncrement::MultiplesOf1,
+            expected: "0.13",
+        },
+        TestCase {
+            dividend: "-1",
+            divisor: "3",
+            position: -2,
+            mode: SignedRoundingMode::Ceil,
+            increment: RoundingIncrement::MultiplesOf1,
+            expected: "-0.33",
+        },
+        TestCase {
+            dividend: "-1",
+            divisor: "3",
+            position: -2,
+            mode: SignedRoundingMode::Floor,
+            increment: RoundingIncrement::MultiplesOf1,
+            expected: "-0.34",
+        },
+        TestCase {
+            dividend: "1",
+            divisor: "-3000",
+            position: -2,
+            mode: SignedRoundingMode::Floor,
+            increment: RoundingIncrement::MultiplesOf1,
+            expected: "-0.01",
+        },
+        TestCase {
+            dividend: "1",
+            divisor: "3000",
+            position: -2,
+            mode: SignedRoundingMode::Floor,
+            increment: RoundingIncrement::MultiplesOf1,
+            expected: "0.00",
+        },
+        TestCase {
+            dividend: "10",
+            divisor: "3",
+            position: -2,
+            mode: half_even,
+            increment: RoundingIncrement::MultiplesOf5,
+            expected: "3.35",
+        },
+        TestCase {
+            dividend: "7.5",
+            divisor: "0.003",
+            position: 2,
+            mode: half_expand,
+            increment: RoundingIncrement::MultiplesOf1,
+            expected: "2500",
+        },
+        TestCase {
+            dividend: "100",
+            divisor: "7",
+            position: 1,
+            mode: half_expand,
+            increment: RoundingIncrement::MultiplesOf25,
+            expected: "00",
+        },
+        TestCase {
+            dividend: "1000",
+            divisor: "7",
+            position: 1,
+            mode: half_expand,
+            increment: RoundingIncrement::MultiplesOf25,
+            expected: "250",
+        },
+        TestCase {
+            dividend: "0",
+            divisor: "-7",
+            position: -1,
+            mode: half_even,
+            increment: RoundingIncrement::MultiplesOf1,
+            expected: "-0.0",
+        },
+        TestCase {
+            dividend: "123456789012345678901234567890",
+            divisor: "0.0000000001",
+            position: 0,
+            mode: half_even,
+            increment: RoundingIncrement::MultiplesOf1,
+            expected: "1234567890123456789012345678900000000000",
+        },
+        TestCase {
+            dividend: "12193263113702179522496570642237463801111263526900",
+            divisor: "98765432109876543210",
+            position: -3,
+            mode: half_even,
+            increment: RoundingIncrement::MultiplesOf1,
+            expected: "123456789012345678901234567890.000",
+        },
+    ];
+    for cas in &cases {
+        let dividend = Decimal::from_str(cas.dividend).unwrap();
+        let divisor = Decimal::from_str(cas.divisor).unwrap();
+        assert_eq!(
+            cas.expected,
+            dividend
+                .divided(&divisor, cas.position, cas.mode, cas.increment)
+                .unwrap()
+                .to_string(),
+            "{cas:?}"
+        );
+    }
+
+    let mut dec = Decimal::from(1);
+    assert_eq!(
+        Err(ArithmeticError::DivisionByZero),
+        dec.divide(
+            &Decimal::from_str("-0.00").unwrap(),
+            0,
+            half_even,
+            RoundingIncrement::MultiplesOf1
+        )
+    );
+    assert_eq!(Decimal::from(1), dec);
+}